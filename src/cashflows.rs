@@ -1,6 +1,12 @@
+pub mod cash_flows;
 pub mod cashflow;
 pub mod coupon;
+pub mod dividend;
 pub mod fixedratecoupon;
 pub mod fixedrateleg;
+pub mod iborcoupon;
+pub mod iborleg;
 pub mod irrfinder;
+pub mod overnightindexedcoupon;
+pub mod overnightleg;
 pub mod simplecashflow;
@@ -0,0 +1,172 @@
+use crate::{
+    datetime::date::Date,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Real, BASIS_POINT},
+};
+
+use super::cashflow::CashFlow;
+
+/// NPV of the cash flows.
+/// The NPV is the sum of the cash flows, each discounted according to the given term structure.
+///
+/// Cash flows paid on the settlement date are only included if `include_settlement_date_flows`
+/// is set. If `npv_date` is not given, the NPV is taken as of the reference date of the curve.
+pub fn npv<T: CashFlow>(
+    cashflows: &[T],
+    discount_curve: &dyn YieldTermStructure,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    npv_date: Date,
+) -> Real {
+    if cashflows.is_empty() {
+        return 0.0;
+    }
+    let settlement_date = if settlement_date == Date::default() {
+        discount_curve.reference_date()
+    } else {
+        settlement_date
+    };
+    let mut npv = 0.0;
+    for cf in cashflows {
+        if !cf.has_occurred(&settlement_date, include_settlement_date_flows)
+            && !cf.trading_ex_coupon(settlement_date)
+        {
+            npv += cf.amount() * discount_curve.discount_from_date(&cf.date(), false);
+        }
+    }
+    if npv_date == Date::default() {
+        npv
+    } else {
+        npv / discount_curve.discount_from_date(&npv_date, false)
+    }
+}
+
+/// Basis-point sensitivity of the cash flows.
+///
+/// This is the change in NPV for a one basis point change in the coupon rates, i.e. the sum of
+/// `nominal * accrual period * discount` over the coupons, scaled by one basis point. Cash flows
+/// which are not coupons (e.g. redemptions) do not contribute.
+pub fn bps<T: CashFlow>(
+    cashflows: &[T],
+    discount_curve: &dyn YieldTermStructure,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    npv_date: Date,
+) -> Real {
+    if cashflows.is_empty() {
+        return 0.0;
+    }
+    let settlement_date = if settlement_date == Date::default() {
+        discount_curve.reference_date()
+    } else {
+        settlement_date
+    };
+    let mut bps = 0.0;
+    for cf in cashflows {
+        if cf.has_occurred(&settlement_date, include_settlement_date_flows)
+            || cf.trading_ex_coupon(settlement_date)
+        {
+            continue;
+        }
+        if let Some(coupon) = cf.as_coupon() {
+            bps += coupon.nominal()
+                * coupon.accrual_period()
+                * discount_curve.discount_from_date(&coupon.date(), false);
+        }
+    }
+    let bps = BASIS_POINT * bps;
+    if npv_date == Date::default() {
+        bps
+    } else {
+        bps / discount_curve.discount_from_date(&npv_date, false)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::{
+            cashflow::{CashFlow, CashFlowLeg},
+            fixedratecoupon::FixedRateCoupon,
+            simplecashflow::Redemption,
+        },
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+        },
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::{bps, npv};
+
+    fn leg(settlement: Date) -> CashFlowLeg {
+        let dc = DayCounter::actual360();
+        let start = Date::new(15, March, 2022);
+        let end = Date::new(15, March, 2024);
+        vec![
+            // coupon paid exactly on the settlement date
+            Rc::new(FixedRateCoupon::new(
+                settlement,
+                100.0,
+                0.04,
+                dc.clone(),
+                start,
+                settlement,
+                None,
+                None,
+                None,
+            )),
+            Rc::new(FixedRateCoupon::new(
+                end, 100.0, 0.04, dc, settlement, end, None, None, None,
+            )),
+            Rc::new(Redemption::new(100.0, end)),
+        ]
+    }
+
+    #[test]
+    fn test_npv_settlement_date_flows() {
+        let settlement = Date::new(15, March, 2023);
+        let curve = FlatForward::new(
+            settlement,
+            0.03,
+            DayCounter::actual360(),
+            Continuous,
+            Annual,
+        );
+        let leg = leg(settlement);
+        let df = curve.discount_from_date(&leg[1].date(), false);
+        let later_flows = (leg[1].amount() + leg[2].amount()) * df;
+
+        let excluded = npv(&leg, &curve, false, settlement, Date::default());
+        assert!((excluded - later_flows).abs() < 1.0e-12);
+
+        let included = npv(&leg, &curve, true, settlement, Date::default());
+        assert!((included - (later_flows + leg[0].amount())).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_bps_settlement_date_flows() {
+        let settlement = Date::new(15, March, 2023);
+        let curve = FlatForward::new(
+            settlement,
+            0.03,
+            DayCounter::actual360(),
+            Continuous,
+            Annual,
+        );
+        let leg = leg(settlement);
+        let df = curve.discount_from_date(&leg[1].date(), false);
+        let later_coupon = 1.0e-4 * 100.0 * (366.0 / 360.0) * df;
+
+        let excluded = bps(&leg, &curve, false, settlement, Date::default());
+        assert!((excluded - later_coupon).abs() < 1.0e-12);
+
+        let included = bps(&leg, &curve, true, settlement, Date::default());
+        let first_coupon = 1.0e-4 * 100.0 * (365.0 / 360.0);
+        assert!((included - (later_coupon + first_coupon)).abs() < 1.0e-12);
+    }
+}
@@ -9,7 +9,8 @@ use crate::datetime::SerialNumber;
 use crate::maths::solvers1d::solver1d::Solver1D;
 use crate::rates::compounding::Compounding;
 use crate::rates::interestrate::InterestRate;
-use crate::types::{Rate, Real, Size, Time};
+use crate::termstructures::yieldtermstructure::YieldTermStructure;
+use crate::types::{DiscountFactor, Rate, Real, Size, Time};
 
 use super::coupon::Coupon;
 use super::irrfinder::IrrFinder;
@@ -31,6 +32,11 @@ pub trait CashFlow {
     /// actual amount paid at the cash flow date.
     fn amount(&self) -> Real;
 
+    /// Returns the cash flow as a [Coupon] if it is one, otherwise `None`
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        None
+    }
+
     /// Returns the date at which the cashflow occurs
     fn date(&self) -> Date;
 
@@ -75,12 +81,7 @@ pub trait CashFlow {
             );
             coupon_period - accrued_period
         } else {
-            daycounter.year_fraction(
-                &last_date,
-                &cashflow_date,
-                &ref_start_date,
-                &ref_end_date,
-            )
+            daycounter.year_fraction(&last_date, &cashflow_date, &ref_start_date, &ref_end_date)
         }
     }
 
@@ -142,6 +143,10 @@ where
         (**self).amount()
     }
 
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        (**self).as_coupon()
+    }
+
     fn date(&self) -> Date {
         (**self).date()
     }
@@ -157,8 +162,9 @@ where
 
 // -------------------------------------------------------------------------------------------------
 
+/// Accrued amount of the coupons paid on the next payment date
 pub fn accrued_amount<T: CashFlow>(
-    leg: &Vec<T>,
+    leg: &[T],
     include_settlement_date_flows: bool,
     date: Date,
 ) -> Real {
@@ -176,40 +182,55 @@ pub fn accrued_amount<T: CashFlow>(
     result
 }
 
-pub fn accrued_days<T: Coupon>(
-    leg: &Vec<T>,
+/// Accrued days of the first coupon paid on the next payment date
+pub fn accrued_days<T: CashFlow>(
+    leg: &[T],
     include_settlement_date_flows: bool,
     date: Date,
 ) -> SerialNumber {
-    if let Some(mut i) = next_cashflow(leg, include_settlement_date_flows, date) {
-        let payment_date = leg[i].date();
-        while i < leg.len() {
-            let cf = &leg[i];
-            if cf.date() == payment_date {
-                return cf.accrued_days(date);
-            }
-            i += 1;
-        }
-    }
-    0
+    next_coupons(leg, include_settlement_date_flows, date)
+        .next()
+        .map_or(0, |coupon| coupon.accrued_days(date))
 }
 
-pub fn accrued_period<T: Coupon>(
-    leg: &Vec<T>,
+/// Accrued period, as a fraction of year, of the first coupon paid on the next payment date
+pub fn accrued_period<T: CashFlow>(
+    leg: &[T],
     include_settlement_date_flows: bool,
     date: Date,
 ) -> Time {
-    if let Some(mut i) = next_cashflow(leg, include_settlement_date_flows, date) {
-        let payment_date = leg[i].date();
-        while i < leg.len() {
-            let cf = &leg[i];
-            if cf.date() == payment_date {
-                return cf.accrued_period(date);
-            }
-            i += 1;
-        }
-    }
-    0.0
+    next_coupons(leg, include_settlement_date_flows, date)
+        .next()
+        .map_or(0.0, |coupon| coupon.accrued_period(date))
+}
+
+/// Rate of the coupons paid on the next payment date.
+///
+/// If several coupons are paid on that date, their rates are added up. Returns 0.0 when there
+/// are no coupons left to pay.
+pub fn next_coupon_rate<T: CashFlow>(
+    leg: &[T],
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> Rate {
+    next_coupons(leg, include_settlement_date_flows, settlement_date)
+        .map(|coupon| coupon.rate())
+        .sum()
+}
+
+/// Coupons paid on the date of the next cash flow
+fn next_coupons<T: CashFlow>(
+    leg: &[T],
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> impl Iterator<Item = &dyn Coupon> {
+    let i = next_cashflow(leg, include_settlement_date_flows, settlement_date)
+        .unwrap_or(leg.len());
+    let payment_date = leg.get(i).map(|cf| cf.date());
+    leg[i..]
+        .iter()
+        .take_while(move |cf| Some(cf.date()) == payment_date)
+        .filter_map(|cf| cf.as_coupon())
 }
 
 /// Implied internal rate of return.
@@ -258,23 +279,91 @@ pub fn maturity_date<T: CashFlow>(cashflows: &Vec<T>) -> Date {
     d
 }
 
-/// Return `Some(index)` where `index` is index of first cash flow in the [Leg] if there are
-/// cash flows.  Otherwise return `None`.
+/// Return `Some(index)` where `index` is index of first cash flow in the [CashFlowLeg] which has
+/// not occurred yet at the settlement date.  Otherwise return `None`.
+///
+/// The cash flows must be sorted by date, as a binary search is used.
 pub fn next_cashflow<T: CashFlow>(
     leg: &[T],
     include_settlement_date_flows: bool,
     settlement_date: Date,
 ) -> Option<Size> {
-    for (index, cf) in leg.iter().enumerate() {
-        if !cf.has_occurred(&settlement_date, include_settlement_date_flows) {
-            return Some(index);
-        }
+    let i =
+        leg.partition_point(|cf| cf.has_occurred(&settlement_date, include_settlement_date_flows));
+    if i < leg.len() {
+        Some(i)
+    } else {
+        None
     }
-    None
 }
 
-/// NPV of the cash flows.
-/// The NPV is the sum of the cash flows, each discounted according to the given term structure.
+/// Return `Some(index)` where `index` is index of last cash flow in the [CashFlowLeg] which has
+/// already occurred at the settlement date.  Otherwise return `None`.
+///
+/// The cash flows must be sorted by date, as a binary search is used.
+pub fn previous_cashflow<T: CashFlow>(
+    leg: &[T],
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> Option<Size> {
+    let i =
+        leg.partition_point(|cf| cf.has_occurred(&settlement_date, include_settlement_date_flows));
+    i.checked_sub(1)
+}
+
+/// Row of a cash flow report: a flow together with its discounted value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowReport {
+    /// Payment date
+    pub date: Date,
+    /// Undiscounted amount
+    pub amount: Real,
+    /// Discount factor from the reference date of the curve to the payment date
+    pub discount: DiscountFactor,
+    /// Amount times discount factor
+    pub present_value: Real,
+}
+
+/// Breaks down the NPV of the cash flows into the contribution of each flow, in the order of
+/// the leg.
+///
+/// Flows are skipped on the same conditions as in [cash_flows::npv](super::cash_flows::npv):
+/// those paid before the settlement date, or on it unless `include_settlement_date_flows` is
+/// set, and those trading ex-coupon. The present values therefore add up to the NPV as of the
+/// reference date of the curve.
+pub fn flow_analysis<T: CashFlow>(
+    cashflows: &[T],
+    discount_curve: &dyn YieldTermStructure,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+) -> Vec<FlowReport> {
+    let settlement_date = if settlement_date == Date::default() {
+        discount_curve.reference_date()
+    } else {
+        settlement_date
+    };
+    cashflows
+        .iter()
+        .filter(|cf| {
+            !cf.has_occurred(&settlement_date, include_settlement_date_flows)
+                && !cf.trading_ex_coupon(settlement_date)
+        })
+        .map(|cf| {
+            let date = cf.date();
+            let amount = cf.amount();
+            let discount = discount_curve.discount_from_date(&date, false);
+            FlowReport {
+                date,
+                amount,
+                discount,
+                present_value: amount * discount,
+            }
+        })
+        .collect()
+}
+
+/// NPV of the cash flows given a constant yield.
+/// The NPV is the sum of the cash flows, each discounted according to the given yield.
 pub fn npv<T: CashFlow>(
     cashflows: &[T],
     interestrate: &InterestRate,
@@ -313,6 +402,54 @@ pub fn npv<T: CashFlow>(
     npv
 }
 
+/// Calculate the simple duration, i.e. the average time of the cash flows weighted by their
+/// present values according to the given IRR `y`:
+///
+/// D_simple = (Σ t_i c_i B(t_i)) / (Σ c_i B(t_i))
+pub fn simple_duration<T: CashFlow>(
+    cashflows: &[T],
+    y: &InterestRate,
+    include_settlement_date_flows: bool,
+    settlement_date: Date,
+    npv_date: Date,
+) -> Real {
+    if cashflows.is_empty() {
+        return 0.0;
+    }
+
+    let npv_date = if npv_date == Date::default() {
+        settlement_date
+    } else {
+        npv_date
+    };
+
+    let mut p: Real = 0.0;
+    let mut t: Time = 0.0;
+    let mut weighted_times: Real = 0.0;
+    let mut last_date = npv_date;
+    let daycounter = &y.daycounter;
+
+    for cf in cashflows {
+        if cf.has_occurred(&settlement_date, include_settlement_date_flows) {
+            continue;
+        }
+        let mut c = cf.amount();
+        if cf.trading_ex_coupon(settlement_date) {
+            c = 0.0;
+        }
+        t += cf.get_stepwise_discount_time(daycounter, npv_date, last_date);
+        let discount_factor = y.discount_factor(t);
+        p += c * discount_factor;
+        weighted_times += t * c * discount_factor;
+        last_date = cf.date();
+    }
+    if p == 0.0 {
+        // no cash flows
+        return 0.0;
+    }
+    weighted_times / p
+}
+
 ///
 /// Calculate the modified duration which is defined as
 ///
@@ -382,3 +519,121 @@ pub fn modified_duration<T: CashFlow>(
     }
     -dpdy / p // reverse derivative sign
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cashflows::{
+            cashflow::{
+                accrued_amount, accrued_days, flow_analysis, next_cashflow, next_coupon_rate,
+                previous_cashflow, CashFlow,
+            },
+            cash_flows::npv,
+            fixedrateleg::FixedRateLeg,
+        },
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::Unadjusted,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::{Annual, Semiannual},
+            holidays::nilholiday::NilHoliday,
+            months::Month::*,
+            period::Period,
+            schedulebuilder::ScheduleBuilder,
+        },
+        instruments::{bond::Bond, fixedratebond::FixedRateBond},
+        rates::compounding::Compounding::{Continuous, Simple},
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    #[test]
+    fn test_flow_analysis() {
+        let today = Date::new(15, January, 2024);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            today,
+            Date::new(15, January, 2026),
+            Period::from(Semiannual),
+            NilHoliday::new(),
+        )
+        .with_convention(Unadjusted)
+        .build();
+        let bond = FixedRateBond::new(0, 100.0, schedule, vec![0.05], DayCounter::actual360());
+        let leg = bond.cashflows();
+        let curve = FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        );
+
+        // four coupons and the redemption
+        let report = flow_analysis(leg, &curve, false, today);
+        assert_eq!(report.len(), 5);
+        for (row, cf) in report.iter().zip(leg) {
+            assert_eq!(row.date, cf.date());
+            assert_eq!(row.amount, cf.amount());
+            assert_eq!(row.discount, curve.discount_from_date(&cf.date(), false));
+            assert_eq!(row.present_value, row.amount * row.discount);
+        }
+        let total: f64 = report.iter().map(|row| row.present_value).sum();
+        let expected = npv(leg, &curve, false, today, Date::default());
+        assert!((total - expected).abs() < 1.0e-12);
+
+        // the first coupon is only reported if settlement date flows are included
+        let first_payment = Date::new(15, July, 2024);
+        assert_eq!(flow_analysis(leg, &curve, false, first_payment).len(), 4);
+        let report = flow_analysis(leg, &curve, true, first_payment);
+        assert_eq!(report.len(), 5);
+        assert_eq!(report[0].date, first_payment);
+    }
+
+    #[test]
+    fn test_next_and_previous_cashflow() {
+        let today = Date::new(15, July, 2023);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            Date::new(15, January, 2023),
+            Date::new(15, January, 2025),
+            Period::from(Semiannual),
+            NilHoliday::new(),
+        )
+        .with_convention(Unadjusted)
+        .build();
+        let leg = FixedRateLeg::new(schedule, vec![100.0], vec![])
+            .with_coupon_rate(0.04, DayCounter::actual360(), Simple, Annual)
+            .build();
+        assert_eq!(leg.len(), 4);
+
+        // settlement on a payment date: the coupon has already been paid
+        let next = next_cashflow(&leg, false, today).unwrap();
+        assert_eq!(leg[next].date(), Date::new(15, January, 2024));
+        let previous = previous_cashflow(&leg, false, today).unwrap();
+        assert_eq!(leg[previous].date(), today);
+        assert_eq!(accrued_days(&leg, false, today), 0);
+        assert_eq!(accrued_amount(&leg, false, today), 0.0);
+
+        // ... unless settlement date flows are included
+        let next = next_cashflow(&leg, true, today).unwrap();
+        assert_eq!(leg[next].date(), today);
+        assert!(previous_cashflow(&leg, true, today).is_none());
+        assert_eq!(accrued_days(&leg, true, today), 181);
+
+        // one day after the payment date
+        let date = today + 1;
+        assert_eq!(previous_cashflow(&leg, false, date), Some(0));
+        assert_eq!(accrued_days(&leg, false, date), 1);
+        assert!((accrued_amount(&leg, false, date) - 100.0 * 0.04 / 360.0).abs() < 1.0e-12);
+        assert_eq!(next_coupon_rate(&leg, false, date), 0.04);
+
+        // no cash flows left after maturity
+        let date = Date::new(16, January, 2025);
+        assert!(next_cashflow(&leg, false, date).is_none());
+        assert_eq!(next_coupon_rate(&leg, false, date), 0.0);
+        assert!(previous_cashflow(&leg, false, Date::new(1, January, 2023)).is_none());
+    }
+}
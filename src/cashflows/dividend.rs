@@ -0,0 +1,107 @@
+use crate::{datetime::date::Date, types::Real};
+
+use super::cashflow::CashFlow;
+
+/// Discrete dividends paid by an underlying, in increasing order of date
+pub type DividendSchedule = Vec<Dividend>;
+
+/// Discrete dividend, paid on its ex-dividend date.
+///
+/// The underlying drops by the dividend on that date, either by a fixed cash amount or by a
+/// fraction of its value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dividend {
+    /// Predetermined cash amount
+    Fixed { date: Date, amount: Real },
+    /// Fraction `rate` of the value of the underlying; `nominal` is the value of the
+    /// underlying used to express the dividend as a cash amount, if known
+    Fractional {
+        date: Date,
+        rate: Real,
+        nominal: Option<Real>,
+    },
+}
+
+impl Dividend {
+    pub fn fixed(date: Date, amount: Real) -> Self {
+        Dividend::Fixed { date, amount }
+    }
+
+    pub fn fractional(date: Date, rate: Real) -> Self {
+        Dividend::Fractional {
+            date,
+            rate,
+            nominal: None,
+        }
+    }
+
+    pub fn fractional_with_nominal(date: Date, rate: Real, nominal: Real) -> Self {
+        Dividend::Fractional {
+            date,
+            rate,
+            nominal: Some(nominal),
+        }
+    }
+
+    /// Cash amount paid when the underlying is worth `underlying`
+    pub fn amount_for(&self, underlying: Real) -> Real {
+        match self {
+            Dividend::Fixed { amount, .. } => *amount,
+            Dividend::Fractional { rate, .. } => rate * underlying,
+        }
+    }
+}
+
+/// For a fractional dividend the amount is the rate times the nominal, which must be given.
+impl CashFlow for Dividend {
+    fn accrual_start_date(&self) -> Date {
+        Date::default()
+    }
+
+    fn accrual_end_date(&self) -> Date {
+        Date::default()
+    }
+
+    fn accrued_amount(&self, _date: Date) -> Real {
+        0.0
+    }
+
+    fn amount(&self) -> Real {
+        match self {
+            Dividend::Fixed { amount, .. } => *amount,
+            Dividend::Fractional { rate, nominal, .. } => {
+                rate * nominal.expect("no nominal given for fractional dividend")
+            }
+        }
+    }
+
+    fn date(&self) -> Date {
+        match self {
+            Dividend::Fixed { date, .. } | Dividend::Fractional { date, .. } => *date,
+        }
+    }
+
+    fn reference_period_start(&self) -> Date {
+        Date::default()
+    }
+
+    fn reference_period_end(&self) -> Date {
+        Date::default()
+    }
+}
+
+/// Builds a schedule of fixed dividends from their dates and amounts
+pub fn dividend_schedule(dates: &[Date], amounts: &[Real]) -> DividendSchedule {
+    assert_eq!(
+        dates.len(),
+        amounts.len(),
+        "dates/amounts count mismatch: {} vs {}",
+        dates.len(),
+        amounts.len()
+    );
+    dates
+        .iter()
+        .zip(amounts)
+        .map(|(date, amount)| Dividend::fixed(*date, *amount))
+        .collect()
+}
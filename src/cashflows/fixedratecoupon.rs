@@ -1,7 +1,7 @@
 use crate::{
     datetime::{date::Date, daycounter::DayCounter, frequency::Frequency::Annual},
     rates::{compounding::Compounding::Simple, interestrate::InterestRate},
-    types::{Rate, Real},
+    types::{Integer, Rate, Real, Time},
 };
 
 use super::{cashflow::CashFlow, coupon::Coupon};
@@ -67,6 +67,34 @@ impl FixedRateCoupon {
             rate: interest_rate,
         }
     }
+
+    /// Days accrued from the start of the accrual period to `settlement`, which is clamped to
+    /// the accrual period.
+    ///
+    /// This differs from [Coupon::accrued_days], which gives the accrued days of a coupon still
+    /// to be paid: that one is zero after the payment date, while this one stays at the length
+    /// of the accrual period, which is what bond reports usually expect. Settlement between the
+    /// end of the accrual period and a later payment date gives the same result with both.
+    pub fn clamped_accrued_days(&self, settlement: Date) -> Integer {
+        self.rate
+            .daycounter
+            .day_count(&self.accrual_start_date, &self.clamp_to_accrual(settlement))
+    }
+
+    /// Year fraction accrued from the start of the accrual period to `settlement`, which is
+    /// clamped to the accrual period, using the coupon day counter and reference period.
+    pub fn accrual_fraction(&self, settlement: Date) -> Time {
+        self.rate.daycounter.year_fraction(
+            &self.accrual_start_date,
+            &self.clamp_to_accrual(settlement),
+            &self.ref_period_start,
+            &self.ref_period_end,
+        )
+    }
+
+    fn clamp_to_accrual(&self, date: Date) -> Date {
+        date.clamp(self.accrual_start_date, self.accrual_end_date)
+    }
 }
 
 impl CashFlow for FixedRateCoupon {
@@ -112,6 +140,10 @@ impl CashFlow for FixedRateCoupon {
         self.nominal * (compound_factor - 1.0)
     }
 
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+
     fn date(&self) -> Date {
         self.payment_date
     }
@@ -148,7 +180,7 @@ impl Coupon for FixedRateCoupon {
 #[cfg(test)]
 mod test {
     use crate::{
-        cashflows::{cashflow, fixedrateleg::FixedRateLeg},
+        cashflows::{cashflow, coupon::Coupon, fixedrateleg::FixedRateLeg},
         context::pricing_context::PricingContext,
         datetime::{
             businessdayconvention::BusinessDayConvention,
@@ -164,6 +196,45 @@ mod test {
         rates::{compounding::Compounding, interestrate::InterestRate},
     };
 
+    use super::FixedRateCoupon;
+
+    #[test]
+    fn test_accrual_fraction() {
+        let start = Date::new(15, January, 2024);
+        let end = Date::new(15, July, 2024);
+        let coupon = FixedRateCoupon::new(
+            end,
+            100.0,
+            0.04,
+            DayCounter::actual_actual_old_isma(),
+            start,
+            end,
+            Some(start),
+            Some(end),
+            None,
+        );
+
+        // inside the period: 91 of the 182 days of a half-year reference period
+        let settlement = Date::new(15, April, 2024);
+        assert_eq!(coupon.clamped_accrued_days(settlement), 91);
+        assert!((coupon.accrual_fraction(settlement) - 0.5 * 91.0 / 182.0).abs() < 1.0e-15);
+
+        // nothing accrued at the start of the period, or before it
+        assert_eq!(coupon.clamped_accrued_days(start), 0);
+        assert_eq!(coupon.accrual_fraction(start), 0.0);
+        assert_eq!(coupon.clamped_accrued_days(start - 10), 0);
+        assert_eq!(coupon.accrual_fraction(start - 10), 0.0);
+
+        // the full period at its end, or after it
+        assert_eq!(coupon.clamped_accrued_days(end), 182);
+        assert_eq!(coupon.accrual_fraction(end), 0.5);
+        assert_eq!(coupon.clamped_accrued_days(end + 10), 182);
+        assert_eq!(coupon.accrual_fraction(end + 10), 0.5);
+        // while for a coupon still to be paid the period is over
+        assert_eq!(Coupon::accrued_days(&coupon, end), 182);
+        assert_eq!(Coupon::accrued_days(&coupon, end + 10), 0);
+    }
+
     #[test]
     fn test_settlement_date_accruals() {
         let today = Date::new(4, January, 2023);
@@ -279,7 +350,7 @@ mod test {
             .build();
 
         for cf in leg {
-            let expected = calendar.advance_by_days(
+            let expected = calendar.advance_by_units(
                 cf.accrual_end_date,
                 -2,
                 Days,
@@ -392,7 +463,7 @@ mod test {
             leg.len()
         );
 
-        let expected_ref_starts = vec![
+        let expected_ref_starts = [
             Date::new(25, March, 2017),
             Date::new(25, September, 2017),
             Date::new(25, March, 2018),
@@ -402,7 +473,7 @@ mod test {
             Date::new(25, March, 2020),
             Date::new(25, September, 2020),
         ];
-        let expected_ref_ends = vec![
+        let expected_ref_ends = [
             Date::new(25, September, 2017),
             Date::new(25, March, 2018),
             Date::new(25, September, 2018),
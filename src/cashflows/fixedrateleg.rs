@@ -194,12 +194,12 @@ impl FixedRateLeg {
         let start = self.schedule[0];
         let end = self.schedule[1];
         let payment_date =
-            payment_calendar.advance_by_days(end, payment_lag, Days, payment_adjustment, false);
+            payment_calendar.advance_by_units(end, payment_lag, Days, payment_adjustment, false);
         let interest_rate = &self.coupon_rates[0];
         let nominal = self.notionals[0];
         let ex_coupon_date = self.make_ex_coupon_date(payment_date);
         let ref_date = if self.schedule.has_is_regular() && !self.schedule.is_regular(1) {
-            self.schedule.calendar().advance_by_period(
+            self.schedule.calendar().advance(
                 end,
                 -self.schedule.tenor(),
                 self.schedule.business_day_convention(),
@@ -208,11 +208,10 @@ impl FixedRateLeg {
         } else {
             start
         };
-        let first_dc = if self.first_period_dc.is_none() {
-            &interest_rate.daycounter
-        } else {
-            self.first_period_dc.as_ref().unwrap()
-        };
+        let first_dc = self
+            .first_period_dc
+            .as_ref()
+            .unwrap_or(&interest_rate.daycounter);
         let r = InterestRate::new(
             interest_rate.rate,
             first_dc.clone(),
@@ -241,7 +240,7 @@ impl FixedRateLeg {
     ) -> FixedRateCoupon {
         let end = self.schedule[i];
         let payment_date =
-            payment_calendar.advance_by_days(end, payment_lag, Days, payment_adjustment, false);
+            payment_calendar.advance_by_units(end, payment_lag, Days, payment_adjustment, false);
         let ex_coupon_date = self.make_ex_coupon_date(payment_date);
         let rate = if (i - 1) < self.coupon_rates.len() {
             &self.coupon_rates[i - 1]
@@ -277,7 +276,7 @@ impl FixedRateLeg {
         let end = self.schedule[n - 1];
 
         let payment_date =
-            payment_calendar.advance_by_days(end, payment_lag, Days, payment_adjustment, false);
+            payment_calendar.advance_by_units(end, payment_lag, Days, payment_adjustment, false);
         let ex_coupon_date = self.make_ex_coupon_date(payment_date);
 
         let interest_rate = if (n - 2) < self.coupon_rates.len() {
@@ -290,11 +289,10 @@ impl FixedRateLeg {
         } else {
             self.notionals[self.notionals.len() - 1]
         };
-        let last_dc = if self.last_period_dc.is_none() {
-            &interest_rate.daycounter
-        } else {
-            self.last_period_dc.as_ref().unwrap()
-        };
+        let last_dc = self
+            .last_period_dc
+            .as_ref()
+            .unwrap_or(&interest_rate.daycounter);
         let r = InterestRate::new(
             interest_rate.rate,
             last_dc.clone(),
@@ -313,7 +311,7 @@ impl FixedRateLeg {
                 Some(ex_coupon_date),
             )
         } else {
-            let ref_date = self.schedule.calendar().advance_by_period(
+            let ref_date = self.schedule.calendar().advance(
                 start,
                 self.schedule.tenor(),
                 self.schedule.business_day_convention(),
@@ -333,27 +331,26 @@ impl FixedRateLeg {
     }
 
     fn make_ex_coupon_date(&self, payment_date: Date) -> Date {
-        if self.ex_coupon_period.is_some() {
-            let ex_coupon_period = self.ex_coupon_period.unwrap();
+        if let Some(ex_coupon_period) = self.ex_coupon_period {
             let ex_coupon_adjustment = self.ex_coupon_adjustment.unwrap_or_else(|| {
                 panic!(
                     "ex-coupon period is {:?}, but ex-coupon adjustment has not been set",
-                    self.ex_coupon_period.unwrap()
+                    ex_coupon_period
                 )
             });
             let ex_coupon_end_of_month = self.ex_coupon_end_of_month.unwrap_or_else(|| {
                 panic!(
                     "ex-coupon period is {:?}, but ex-coupon end of month has not been set",
-                    self.ex_coupon_period.unwrap()
+                    ex_coupon_period
                 )
             });
             let ex_coupon_calendar = self.ex_coupon_calendar.as_ref().unwrap_or_else(|| {
                 panic!(
                     "ex-coupon period is {:?}, but ex-coupon calendar has not been set",
-                    self.ex_coupon_period.unwrap()
+                    ex_coupon_period
                 )
             });
-            ex_coupon_calendar.advance_by_period(
+            ex_coupon_calendar.advance(
                 payment_date,
                 -ex_coupon_period,
                 ex_coupon_adjustment,
@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    indexes::iborindex::IborIndex,
+    types::{Rate, Real, Spread},
+};
+
+use super::{cashflow::CashFlow, coupon::Coupon};
+
+/// Coupon paying a Libor-type index, i.e. `gearing * fixing + spread`.
+///
+/// The index fixing is forecast as the par rate over the accrual period, read from the
+/// forwarding term structure of the index. Fixings that are already known are taken from the
+/// fixing history of the index instead.
+#[derive(Clone)]
+pub struct IborCoupon {
+    pub payment_date: Date,
+    pub nominal: Real,
+    pub accrual_start_date: Date,
+    pub accrual_end_date: Date,
+    pub ref_period_start: Date,
+    pub ref_period_end: Date,
+    pub day_counter: DayCounter,
+    pub index: Rc<IborIndex>,
+    pub gearing: Real,
+    pub spread: Spread,
+}
+
+impl IborCoupon {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        payment_date: Date,
+        nominal: Real,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        index: Rc<IborIndex>,
+        gearing: Real,
+        spread: Spread,
+        day_counter: DayCounter,
+        ref_period_start: Option<Date>,
+        ref_period_end: Option<Date>,
+    ) -> Self {
+        Self {
+            payment_date,
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            ref_period_start: ref_period_start.unwrap_or_default(),
+            ref_period_end: ref_period_end.unwrap_or_default(),
+            day_counter,
+            index,
+            gearing,
+            spread,
+        }
+    }
+
+    /// Date at which the index fixes, i.e. the fixing days of the index before the start of
+    /// the accrual period
+    pub fn fixing_date(&self) -> Date {
+        self.index.fixing_date(&self.accrual_start_date)
+    }
+
+    /// Fixing of the underlying index: the published one if the fixing date is past (see
+    /// [IborIndex::known_fixing]), otherwise forecast as the forward rate over the accrual period
+    pub fn index_fixing(&self) -> Rate {
+        if let Some(fixing) = self.index.known_fixing(&self.fixing_date()) {
+            return fixing;
+        }
+        let curve = self.index.forwarding_term_structure.current_link();
+        let start_discount = curve.discount_from_date(&self.accrual_start_date, false);
+        let end_discount = curve.discount_from_date(&self.accrual_end_date, false);
+        let t = self
+            .index
+            .day_counter
+            .year_fraction_simple(&self.accrual_start_date, &self.accrual_end_date);
+        (start_discount / end_discount - 1.0) / t
+    }
+}
+
+impl CashFlow for IborCoupon {
+    fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+
+    fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+
+    fn accrued_amount(&self, date: Date) -> Real {
+        self.nominal * self.rate() * self.accrued_period(date)
+    }
+
+    fn amount(&self) -> Real {
+        self.nominal * self.rate() * self.accrual_period()
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+
+    fn date(&self) -> Date {
+        self.payment_date
+    }
+
+    fn reference_period_start(&self) -> Date {
+        self.ref_period_start
+    }
+
+    fn reference_period_end(&self) -> Date {
+        self.ref_period_end
+    }
+}
+
+impl Coupon for IborCoupon {
+    fn day_counter(&self) -> &DayCounter {
+        &self.day_counter
+    }
+
+    fn nominal(&self) -> Real {
+        self.nominal
+    }
+
+    fn rate(&self) -> Rate {
+        self.gearing * self.index_fixing() + self.spread
+    }
+}
@@ -0,0 +1,250 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::{self, *},
+        calendar::Calendar,
+        daycounter::DayCounter,
+        schedule::Schedule,
+        timeunit::TimeUnit::Days,
+    },
+    indexes::iborindex::IborIndex,
+    types::{Integer, Real, Size, Spread},
+};
+
+use super::iborcoupon::IborCoupon;
+
+/// Helper for building a sequence of [IborCoupon] instances
+pub struct IborLeg {
+    pub schedule: Schedule,
+    pub index: Rc<IborIndex>,
+    pub notionals: Vec<Real>,
+    pub payment_day_counter: Option<DayCounter>, // index day counter
+    pub payment_calendar: Option<Calendar>,
+    pub payment_adjustment: Option<BusinessDayConvention>, // Following
+    pub payment_lag: Option<Integer>,                      // 0
+    pub gearings: Vec<Real>,                               // 1.0
+    pub spreads: Vec<Spread>,                              // 0.0
+}
+
+impl IborLeg {
+    /// Construct an [IborLeg] from the mandatory parameters
+    pub fn new(schedule: Schedule, index: Rc<IborIndex>) -> Self {
+        Self {
+            schedule,
+            index,
+            notionals: vec![],
+            payment_day_counter: None,
+            payment_calendar: None,
+            payment_adjustment: None,
+            payment_lag: None,
+            gearings: vec![],
+            spreads: vec![],
+        }
+    }
+
+    pub fn with_notional(mut self, notional: Real) -> Self {
+        self.notionals = vec![notional];
+        self
+    }
+
+    pub fn with_notionals(mut self, notionals: Vec<Real>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    pub fn with_payment_day_counter(mut self, daycounter: DayCounter) -> Self {
+        self.payment_day_counter = Some(daycounter);
+        self
+    }
+
+    pub fn with_payment_calendar(mut self, calendar: Calendar) -> Self {
+        self.payment_calendar = Some(calendar);
+        self
+    }
+
+    pub fn with_payment_adjustment(mut self, convention: BusinessDayConvention) -> Self {
+        self.payment_adjustment = Some(convention);
+        self
+    }
+
+    /// Payment of each coupon the given number of business days after the end of its accrual
+    /// period
+    pub fn with_payment_lag(mut self, lag: Integer) -> Self {
+        self.payment_lag = Some(lag);
+        self
+    }
+
+    pub fn with_gearing(mut self, gearing: Real) -> Self {
+        self.gearings = vec![gearing];
+        self
+    }
+
+    pub fn with_gearings(mut self, gearings: Vec<Real>) -> Self {
+        self.gearings = gearings;
+        self
+    }
+
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spreads = vec![spread];
+        self
+    }
+
+    pub fn with_spreads(mut self, spreads: Vec<Spread>) -> Self {
+        self.spreads = spreads;
+        self
+    }
+
+    /// Build a leg of floating rate coupons, one per schedule period
+    pub fn build(self) -> Vec<IborCoupon> {
+        assert!(!self.notionals.is_empty(), "No notionals given");
+
+        let payment_calendar = self
+            .payment_calendar
+            .as_ref()
+            .unwrap_or_else(|| self.schedule.calendar());
+        let payment_adjustment = self.payment_adjustment.unwrap_or(Following);
+        let payment_lag = self.payment_lag.unwrap_or(0);
+        let day_counter = self
+            .payment_day_counter
+            .as_ref()
+            .unwrap_or(&self.index.day_counter);
+
+        (1..self.schedule.size())
+            .map(|i| {
+                let start = self.schedule[i - 1];
+                let end = self.schedule[i];
+                let payment_date = payment_calendar.advance_by_units(
+                    end,
+                    payment_lag,
+                    Days,
+                    payment_adjustment,
+                    false,
+                );
+                IborCoupon::new(
+                    payment_date,
+                    value_for_period(&self.notionals, i - 1, 0.0),
+                    start,
+                    end,
+                    self.index.clone(),
+                    value_for_period(&self.gearings, i - 1, 1.0),
+                    value_for_period(&self.spreads, i - 1, 0.0),
+                    day_counter.clone(),
+                    Some(start),
+                    Some(end),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Value for the i-th period; the last given value applies to the remaining periods
+pub(crate) fn value_for_period(values: &[Real], i: Size, default: Real) -> Real {
+    if values.is_empty() {
+        default
+    } else {
+        values[i.min(values.len() - 1)]
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::{cash_flows::npv, cashflow::CashFlow, fixedrateleg::FixedRateLeg},
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedule::Schedule,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Days, Months, Years},
+        },
+        handle::Handle,
+        indexes::iborindex::IborIndex,
+        rates::compounding::Compounding::{Continuous, Simple},
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::IborLeg;
+
+    #[test]
+    fn test_payment_lag() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(6, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            curve.clone(),
+        ));
+        let start = Date::new(17, January, 2024);
+        let schedule = || -> Schedule {
+            ScheduleBuilder::new(
+                PricingContext::new(today),
+                start,
+                start + Period::new(3, Years),
+                Period::new(6, Months),
+                Target::new(),
+            )
+            .with_convention(ModifiedFollowing)
+            .build()
+        };
+        let calendar = Target::new();
+        let discount = curve.current_link();
+
+        let floating = IborLeg::new(schedule(), index.clone())
+            .with_notional(100.0)
+            .build();
+        let lagged = IborLeg::new(schedule(), index)
+            .with_notional(100.0)
+            .with_payment_lag(2)
+            .build();
+        for (c, l) in floating.iter().zip(&lagged) {
+            assert_eq!(c.date(), c.accrual_end_date());
+            let expected =
+                calendar.advance_by_units(c.accrual_end_date(), 2, Days, Following, false);
+            assert_eq!(l.date(), expected);
+            // the coupon amounts only depend on the accrual periods
+            assert_eq!(l.amount(), c.amount());
+        }
+        let npv_floating = npv(&floating, discount.as_ref(), false, today, today);
+        let npv_lagged = npv(&lagged, discount.as_ref(), false, today, today);
+        assert!(npv_lagged < npv_floating);
+        assert!((npv_lagged - npv_floating).abs() / npv_floating < 1.0e-3);
+
+        let fixed = |lag| {
+            FixedRateLeg::new(schedule(), vec![100.0], vec![])
+                .with_coupon_rate(0.03, DayCounter::actual360(), Simple, Annual)
+                .with_payment_lag(lag)
+                .build()
+        };
+        let (fixed, fixed_lagged) = (fixed(0), fixed(2));
+        for (c, l) in fixed.iter().zip(&fixed_lagged) {
+            let expected = calendar.advance_by_units(c.date(), 2, Days, Following, false);
+            assert_eq!(l.date(), expected);
+        }
+        let npv_fixed = npv(&fixed, discount.as_ref(), false, today, today);
+        let npv_fixed_lagged = npv(&fixed_lagged, discount.as_ref(), false, today, today);
+        assert!(npv_fixed_lagged < npv_fixed);
+        assert!((npv_fixed_lagged - npv_fixed).abs() / npv_fixed < 1.0e-3);
+    }
+}
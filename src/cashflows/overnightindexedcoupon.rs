@@ -0,0 +1,597 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::Preceding, calendar::Calendar, date::Date,
+        daycounter::DayCounter, timeunit::TimeUnit::Days,
+    },
+    indexes::overnightindex::OvernightIndex,
+    types::{Integer, Natural, Rate, Real, Spread},
+};
+
+use super::{cashflow::CashFlow, coupon::Coupon};
+
+/// Coupon paying the daily compounded fixings of an overnight index, i.e.
+/// `gearing * compounded rate + spread`.
+///
+/// The accrual period is split at the business days of the index fixing calendar. Published
+/// fixings are used where available and the others are forecast from the forwarding curve of
+/// the index. Two market conventions are supported:
+/// - lookback: each fixing is observed the given number of business days earlier;
+/// - lockout: the fixing of the last given number of days is frozen at the value observed
+///   just before.
+///
+/// With a lookback, the observation shift convention also weights each fixing by the length of
+/// its observation period rather than of its overnight period.
+#[derive(Clone)]
+pub struct OvernightIndexedCoupon {
+    pub payment_date: Date,
+    pub nominal: Real,
+    pub accrual_start_date: Date,
+    pub accrual_end_date: Date,
+    pub day_counter: DayCounter,
+    pub index: Rc<OvernightIndex>,
+    pub gearing: Real,
+    pub spread: Spread,
+    pub lookback_days: Natural,
+    pub lockout_days: Natural,
+    pub observation_shift: bool,
+}
+
+impl OvernightIndexedCoupon {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        payment_date: Date,
+        nominal: Real,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        index: Rc<OvernightIndex>,
+        gearing: Real,
+        spread: Spread,
+        day_counter: DayCounter,
+    ) -> Self {
+        assert!(
+            accrual_start_date < accrual_end_date,
+            "accrual start date ({:?}) must be earlier than accrual end date ({:?})",
+            accrual_start_date,
+            accrual_end_date
+        );
+        Self {
+            payment_date,
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            day_counter,
+            index,
+            gearing,
+            spread,
+            lookback_days: 0,
+            lockout_days: 0,
+            observation_shift: false,
+        }
+    }
+
+    pub fn with_lookback_days(mut self, lookback_days: Natural) -> Self {
+        self.lookback_days = lookback_days;
+        self
+    }
+
+    pub fn with_lockout_days(mut self, lockout_days: Natural) -> Self {
+        self.lockout_days = lockout_days;
+        self
+    }
+
+    pub fn with_observation_shift(mut self, observation_shift: bool) -> Self {
+        self.observation_shift = observation_shift;
+        self
+    }
+
+    /// Dates splitting the accrual period into overnight periods: the accrual start date, the
+    /// fixing calendar business days within the period and the accrual end date
+    pub fn value_dates(&self) -> Vec<Date> {
+        overnight_value_dates(
+            self.accrual_start_date,
+            self.accrual_end_date,
+            self.index.fixing_calendar(),
+        )
+    }
+
+    /// Dates at which the fixings for each overnight period are observed
+    pub fn fixing_dates(&self) -> Vec<Date> {
+        let value_dates = self.value_dates();
+        value_dates[..value_dates.len() - 1]
+            .iter()
+            .map(|d| self.observation_date(d))
+            .collect()
+    }
+
+    /// Dates delimiting the periods over which each fixing accrues: the value dates, or with
+    /// an observation shift the observation dates
+    pub fn interest_dates(&self) -> Vec<Date> {
+        let value_dates = self.value_dates();
+        if self.observation_shift {
+            value_dates
+                .iter()
+                .map(|d| self.observation_date(d))
+                .collect()
+        } else {
+            value_dates
+        }
+    }
+
+    fn observation_date(&self, value_date: &Date) -> Date {
+        let shift = self.index.ibor_index.fixing_days + self.lookback_days as Integer;
+        self.index
+            .fixing_calendar()
+            .advance_by_units(*value_date, -shift, Days, Preceding, false)
+    }
+
+    /// Fixings applied to each overnight period, after the lockout
+    pub fn index_fixings(&self) -> Vec<Rate> {
+        let mut fixings: Vec<Rate> = self
+            .fixing_dates()
+            .iter()
+            .map(|d| self.index.fixing(d))
+            .collect();
+        let n = fixings.len();
+        let lockout = self.lockout_days as usize;
+        assert!(
+            lockout < n,
+            "lockout days ({}) must be fewer than the number of fixings ({})",
+            lockout,
+            n
+        );
+        let locked = fixings[n - lockout - 1];
+        fixings[n - lockout..].fill(locked);
+        fixings
+    }
+
+    /// Compounded overnight rate over the accrual period
+    pub fn index_fixing(&self) -> Rate {
+        let fixings: Vec<(Date, Rate)> = self
+            .interest_dates()
+            .into_iter()
+            .zip(self.index_fixings().into_iter().chain([0.0]))
+            .collect();
+        compounded_overnight_rate(&fixings, self.index.day_counter(), false, 0.0)
+            .expect("no overnight period to compound")
+    }
+}
+
+impl CashFlow for OvernightIndexedCoupon {
+    fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+
+    fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+
+    fn accrued_amount(&self, date: Date) -> Real {
+        self.nominal * self.rate() * self.accrued_period(date)
+    }
+
+    fn amount(&self) -> Real {
+        self.nominal * self.rate() * self.accrual_period()
+    }
+
+    fn as_coupon(&self) -> Option<&dyn Coupon> {
+        Some(self)
+    }
+
+    fn date(&self) -> Date {
+        self.payment_date
+    }
+
+    fn reference_period_start(&self) -> Date {
+        self.accrual_start_date
+    }
+
+    fn reference_period_end(&self) -> Date {
+        self.accrual_end_date
+    }
+}
+
+impl Coupon for OvernightIndexedCoupon {
+    fn day_counter(&self) -> &DayCounter {
+        &self.day_counter
+    }
+
+    fn nominal(&self) -> Real {
+        self.nominal
+    }
+
+    fn rate(&self) -> Rate {
+        self.gearing * self.index_fixing() + self.spread
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Fixings observed for each overnight period from `start_date` to `end_date`, given the
+/// published fixings by fixing date, as expected by [compounded_overnight_rate].
+///
+/// The period is split at the business days of `calendar`, and the fixing of each overnight
+/// period is observed `lookback_days` business days before its start. With
+/// `observation_shift`, each fixing is dated by its observation date, so that it is weighted by
+/// the length of its observation period, i.e. the calendar is shifted back as a whole, rather
+/// than by the length of its overnight period.
+///
+/// Returns `None` if a required fixing is missing.
+pub fn overnight_fixings(
+    published: &[(Date, Rate)],
+    start_date: Date,
+    end_date: Date,
+    calendar: &Calendar,
+    lookback_days: Natural,
+    observation_shift: bool,
+) -> Option<Vec<(Date, Rate)>> {
+    assert!(
+        start_date < end_date,
+        "start date ({:?}) must be earlier than end date ({:?})",
+        start_date,
+        end_date
+    );
+    let published: HashMap<Date, Rate> = published.iter().copied().collect();
+    let shift = |d: &Date| {
+        calendar.advance_by_units(*d, -(lookback_days as Integer), Days, Preceding, false)
+    };
+    let value_dates = overnight_value_dates(start_date, end_date, calendar);
+    let (end, value_dates) = value_dates.split_last().expect("no value dates");
+    let mut fixings = value_dates
+        .iter()
+        .map(|d| {
+            let fixing_date = shift(d);
+            let date = if observation_shift { fixing_date } else { *d };
+            published.get(&fixing_date).map(|fixing| (date, *fixing))
+        })
+        .collect::<Option<Vec<(Date, Rate)>>>()?;
+    let end = if observation_shift { shift(end) } else { *end };
+    fixings.push((end, 0.0));
+    Some(fixings)
+}
+
+/// Simple rate over the period compounding the given overnight fixings.
+///
+/// The rate of `fixings[i]` accrues from its date to the date of `fixings[i + 1]`, with the
+/// given day counter; the last entry only gives the end of the period and its rate is not used.
+/// If `include_spread` the spread is added to each fixing before compounding, otherwise to the
+/// compounded rate.
+///
+/// Returns `None` if fewer than two dates are given, i.e. there is no period to compound.
+pub fn compounded_overnight_rate(
+    fixings: &[(Date, Rate)],
+    day_counter: &DayCounter,
+    include_spread: bool,
+    spread: Spread,
+) -> Option<Rate> {
+    if fixings.len() < 2 {
+        return None;
+    }
+    let compound = fixings.windows(2).fold(1.0, |acc, period| {
+        let (start, rate) = period[0];
+        let rate = if include_spread { rate + spread } else { rate };
+        acc * (1.0 + rate * day_counter.year_fraction_simple(&start, &period[1].0))
+    });
+    let t = day_counter.year_fraction_simple(&fixings[0].0, &fixings[fixings.len() - 1].0);
+    let rate = (compound - 1.0) / t;
+    Some(if include_spread { rate } else { rate + spread })
+}
+
+/// Start date, business days within the period and end date
+fn overnight_value_dates(start_date: Date, end_date: Date, calendar: &Calendar) -> Vec<Date> {
+    let mut dates = vec![start_date];
+    let mut d = calendar.nth_business_day(&start_date, 1);
+    while d < end_date {
+        dates.push(d);
+        d = calendar.nth_business_day(&d, 1);
+    }
+    dates.push(end_date);
+    dates
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::{target::Target, unitedstates::UnitedStates},
+            months::Month::*,
+        },
+        handle::Handle,
+        indexes::overnightindex::OvernightIndex,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::Real,
+    };
+
+    use super::{compounded_overnight_rate, overnight_fixings, CashFlow, OvernightIndexedCoupon};
+
+    fn index(today: Date) -> OvernightIndex {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.04,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        OvernightIndex::new("ESTR", 0, Target::new(), DayCounter::actual360(), curve)
+    }
+
+    #[test]
+    fn test_compounded_rate() {
+        // Monday 15 to Monday 22 January 2024: 5 overnight periods, the last one over the
+        // weekend, all fixed by the end of the period
+        let start = Date::new(15, January, 2024);
+        let end = Date::new(22, January, 2024);
+        let mut index = index(end);
+        let fixings = [0.0390, 0.0391, 0.0392, 0.0393, 0.0394];
+        for (i, fixing) in fixings.iter().enumerate() {
+            index.add_fixing(start + i as i32, *fixing);
+        }
+        let coupon = OvernightIndexedCoupon::new(
+            end,
+            1_000_000.0,
+            start,
+            end,
+            Rc::new(index),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        );
+
+        let expected_value_dates: Vec<Date> = (15..=19)
+            .map(|d| Date::new(d, January, 2024))
+            .chain([end])
+            .collect();
+        assert_eq!(coupon.value_dates(), expected_value_dates);
+        assert_eq!(coupon.index_fixings(), fixings.to_vec());
+
+        let compound = fixings[..4]
+            .iter()
+            .fold(1.0, |acc, f| acc * (1.0 + f / 360.0))
+            * (1.0 + fixings[4] * 3.0 / 360.0);
+        let expected = (compound - 1.0) * 360.0 / 7.0;
+        assert!((coupon.index_fixing() - expected).abs() < 1.0e-15);
+        assert!((coupon.amount() - 1_000_000.0 * expected * 7.0 / 360.0).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_lookback_and_lockout() {
+        let start = Date::new(15, January, 2024);
+        let end = Date::new(22, January, 2024);
+        let mut index = index(end);
+        // published from Monday 8 January
+        let fixings: Vec<Real> = (0..10).map(|i| 0.0380 + 0.0001 * i as Real).collect();
+        let fixing_dates: Vec<Date> = [8, 9, 10, 11, 12, 15, 16, 17, 18, 19]
+            .iter()
+            .map(|d| Date::new(*d, January, 2024))
+            .collect();
+        for (date, fixing) in fixing_dates.iter().zip(fixings.iter()) {
+            index.add_fixing(*date, *fixing);
+        }
+        let coupon = OvernightIndexedCoupon::new(
+            end,
+            1_000_000.0,
+            start,
+            end,
+            Rc::new(index),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        )
+        .with_lookback_days(2)
+        .with_lockout_days(2);
+
+        // each period observes the fixing two business days earlier
+        assert_eq!(coupon.fixing_dates(), fixing_dates[3..8].to_vec());
+        // and the last two are frozen at the third one
+        assert_eq!(
+            coupon.index_fixings(),
+            vec![fixings[3], fixings[4], fixings[5], fixings[5], fixings[5]]
+        );
+    }
+
+    #[test]
+    fn test_forecast_fixings() {
+        let today = Date::new(15, January, 2024);
+        let coupon = OvernightIndexedCoupon::new(
+            Date::new(15, February, 2024),
+            1.0,
+            today,
+            Date::new(15, February, 2024),
+            Rc::new(index(today)),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        );
+        // with no published fixings, compounding the forecasts gives back the forward rate
+        // over the whole period
+        let df = |d: &Date| (-0.04 * (*d - today) as Real / 365.0).exp();
+        let expected = (df(&today) / df(&Date::new(15, February, 2024)) - 1.0) * 360.0 / 31.0;
+        assert!((coupon.index_fixing() - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_partly_fixed_period() {
+        // valued on Wednesday 17 January: the first two fixings are published, today's is not
+        // yet and is forecast along with the later ones
+        let start = Date::new(15, January, 2024);
+        let today = Date::new(17, January, 2024);
+        let end = Date::new(22, January, 2024);
+        let mut index = index(today);
+        index.add_fixing(start, 0.0390);
+        index.add_fixing(start + 1, 0.0391);
+        let forecast = index.fixing(&today);
+        assert_eq!(index.fixing(&(start + 1)), 0.0391);
+        let coupon = OvernightIndexedCoupon::new(
+            end,
+            1.0,
+            start,
+            end,
+            Rc::new(index),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        );
+        let fixings = coupon.index_fixings();
+        assert_eq!(fixings[..3], [0.0390, 0.0391, forecast]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing ESTR1D Actual/360 fixing for")]
+    fn test_missing_past_fixing() {
+        let start = Date::new(15, January, 2024);
+        let end = Date::new(22, January, 2024);
+        let mut index = index(end);
+        index.add_fixing(start, 0.0390);
+        let coupon = OvernightIndexedCoupon::new(
+            end,
+            1.0,
+            start,
+            end,
+            Rc::new(index),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        );
+        coupon.index_fixing();
+    }
+
+    #[test]
+    fn test_compounded_overnight_rate() {
+        // SOFR-like fixings over Monday 3 to Monday 10 July 2023, Tuesday 4 July being a
+        // holiday
+        let calendar = UnitedStates::government_bond();
+        let dc = DayCounter::actual360();
+        let fixings = [
+            (Date::new(29, June, 2023), 0.0509),
+            (Date::new(30, June, 2023), 0.0509),
+            (Date::new(3, July, 2023), 0.0508),
+            (Date::new(5, July, 2023), 0.0506),
+            (Date::new(6, July, 2023), 0.0507),
+            (Date::new(7, July, 2023), 0.0506),
+        ];
+        let start = Date::new(3, July, 2023);
+        let end = Date::new(10, July, 2023);
+        let rate = |lookback, shift, include_spread, spread| {
+            let observed =
+                overnight_fixings(&fixings, start, end, &calendar, lookback, shift).unwrap();
+            compounded_overnight_rate(&observed, &dc, include_spread, spread).unwrap()
+        };
+        let compounded = |periods: [(Real, Real); 4]| {
+            let compound = periods
+                .iter()
+                .fold(1.0, |acc, (r, days)| acc * (1.0 + r * days / 360.0));
+            (compound - 1.0) * 360.0 / 7.0
+        };
+
+        // over the holiday and the weekend the fixing applies to several days
+        let expected = compounded([(0.0508, 2.0), (0.0506, 1.0), (0.0507, 1.0), (0.0506, 3.0)]);
+        assert!((rate(0, false, false, 0.0) - expected).abs() < 1.0e-15);
+
+        // a spread is added to the result, or compounded with the fixings
+        assert!((rate(0, false, false, 0.001) - expected - 0.001).abs() < 1.0e-15);
+        assert!(rate(0, false, true, 0.001) > expected + 0.001);
+
+        // two day lookback: the fixings of 29 June to 5 July, weighted by the interest periods
+        let expected = compounded([(0.0509, 2.0), (0.0509, 1.0), (0.0508, 1.0), (0.0506, 3.0)]);
+        assert!((rate(2, false, false, 0.0) - expected).abs() < 1.0e-15);
+
+        // with observation shift the weights are those of the observation periods, from
+        // Thursday 29 June to Thursday 6 July
+        let expected = compounded([(0.0509, 1.0), (0.0509, 3.0), (0.0508, 2.0), (0.0506, 1.0)]);
+        assert!((rate(2, true, false, 0.0) - expected).abs() < 1.0e-15);
+
+        // a three day lookback needs the fixing of 28 June
+        assert!(overnight_fixings(&fixings, start, end, &calendar, 3, false).is_none());
+        assert!(compounded_overnight_rate(&fixings[..1], &dc, false, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_published_sofr_index() {
+        // the first week of SOFR publication, Monday 2 to Monday 9 April 2018, over which the
+        // SOFR Index published by the New York Fed went from 1.00000000 to 1.00034365
+        let fixings = [
+            (Date::new(2, April, 2018), 0.0180),
+            (Date::new(3, April, 2018), 0.0183),
+            (Date::new(4, April, 2018), 0.0174),
+            (Date::new(5, April, 2018), 0.0175),
+            (Date::new(6, April, 2018), 0.0175),
+        ];
+        let start = Date::new(2, April, 2018);
+        let end = Date::new(9, April, 2018);
+        let calendar = UnitedStates::government_bond();
+        let observed = overnight_fixings(&fixings, start, end, &calendar, 0, false).unwrap();
+        let rate =
+            compounded_overnight_rate(&observed, &DayCounter::actual360(), false, 0.0).unwrap();
+        let expected = (1.00034365 / 1.00000000 - 1.0) * 360.0 / 7.0;
+        // the index is published with 8 decimals
+        assert!(
+            (rate - expected).abs() < 0.5e-8 * 360.0 / 7.0,
+            "calculated {}, expected {}",
+            rate,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_observation_shift() {
+        let start = Date::new(15, January, 2024);
+        let end = Date::new(22, January, 2024);
+        let mut index = index(end);
+        let fixing_dates: Vec<Date> = [11, 12, 15, 16, 17, 18]
+            .iter()
+            .map(|d| Date::new(*d, January, 2024))
+            .collect();
+        for (i, date) in fixing_dates.iter().enumerate() {
+            index.add_fixing(*date, 0.0390 + 0.0001 * i as Real);
+        }
+        let coupon = OvernightIndexedCoupon::new(
+            end,
+            1_000_000.0,
+            start,
+            end,
+            Rc::new(index),
+            1.0,
+            0.0,
+            DayCounter::actual360(),
+        )
+        .with_lookback_days(2);
+        let shifted = coupon.clone().with_observation_shift(true);
+
+        // the same fixings, weighted by the observation periods from Thursday 11 to Thursday
+        // 18 January: the weekend now falls in the first period instead of the last one
+        assert_eq!(shifted.fixing_dates(), coupon.fixing_dates());
+        assert_eq!(coupon.interest_dates(), coupon.value_dates());
+        assert_eq!(shifted.interest_dates(), fixing_dates);
+        let compounded = |periods: [(Real, Real); 5]| {
+            let compound = periods
+                .iter()
+                .fold(1.0, |acc, (r, days)| acc * (1.0 + r * days / 360.0));
+            (compound - 1.0) * 360.0 / 7.0
+        };
+        let expected = compounded([
+            (0.0390, 1.0),
+            (0.0391, 3.0),
+            (0.0392, 1.0),
+            (0.0393, 1.0),
+            (0.0394, 1.0),
+        ]);
+        assert!((shifted.index_fixing() - expected).abs() < 1.0e-15);
+        let expected = compounded([
+            (0.0390, 1.0),
+            (0.0391, 1.0),
+            (0.0392, 1.0),
+            (0.0393, 1.0),
+            (0.0394, 3.0),
+        ]);
+        assert!((coupon.index_fixing() - expected).abs() < 1.0e-15);
+    }
+}
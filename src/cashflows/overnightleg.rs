@@ -0,0 +1,253 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::{self, *},
+        calendar::Calendar,
+        daycounter::DayCounter,
+        schedule::Schedule,
+        timeunit::TimeUnit::Days,
+    },
+    indexes::overnightindex::OvernightIndex,
+    types::{Integer, Natural, Real, Spread},
+};
+
+use super::{iborleg::value_for_period, overnightindexedcoupon::OvernightIndexedCoupon};
+
+/// Helper for building a sequence of [OvernightIndexedCoupon] instances
+pub struct OvernightLeg {
+    pub schedule: Schedule,
+    pub index: Rc<OvernightIndex>,
+    pub notionals: Vec<Real>,
+    pub payment_day_counter: Option<DayCounter>, // index day counter
+    pub payment_calendar: Option<Calendar>,
+    pub payment_adjustment: Option<BusinessDayConvention>, // Following
+    pub payment_lag: Option<Integer>,                      // 0
+    pub gearings: Vec<Real>,                               // 1.0
+    pub spreads: Vec<Spread>,                              // 0.0
+    pub lookback_days: Option<Natural>,                    // 0
+    pub lockout_days: Option<Natural>,                     // 0
+    pub observation_shift: Option<bool>,                   // false
+}
+
+impl OvernightLeg {
+    /// Construct an [OvernightLeg] from the mandatory parameters
+    pub fn new(schedule: Schedule, index: Rc<OvernightIndex>) -> Self {
+        Self {
+            schedule,
+            index,
+            notionals: vec![],
+            payment_day_counter: None,
+            payment_calendar: None,
+            payment_adjustment: None,
+            payment_lag: None,
+            gearings: vec![],
+            spreads: vec![],
+            lookback_days: None,
+            lockout_days: None,
+            observation_shift: None,
+        }
+    }
+
+    pub fn with_notional(mut self, notional: Real) -> Self {
+        self.notionals = vec![notional];
+        self
+    }
+
+    pub fn with_notionals(mut self, notionals: Vec<Real>) -> Self {
+        self.notionals = notionals;
+        self
+    }
+
+    pub fn with_payment_day_counter(mut self, daycounter: DayCounter) -> Self {
+        self.payment_day_counter = Some(daycounter);
+        self
+    }
+
+    pub fn with_payment_calendar(mut self, calendar: Calendar) -> Self {
+        self.payment_calendar = Some(calendar);
+        self
+    }
+
+    pub fn with_payment_adjustment(mut self, convention: BusinessDayConvention) -> Self {
+        self.payment_adjustment = Some(convention);
+        self
+    }
+
+    /// Payment of each coupon the given number of business days after the end of its accrual
+    /// period
+    pub fn with_payment_lag(mut self, lag: Integer) -> Self {
+        self.payment_lag = Some(lag);
+        self
+    }
+
+    pub fn with_gearing(mut self, gearing: Real) -> Self {
+        self.gearings = vec![gearing];
+        self
+    }
+
+    pub fn with_gearings(mut self, gearings: Vec<Real>) -> Self {
+        self.gearings = gearings;
+        self
+    }
+
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spreads = vec![spread];
+        self
+    }
+
+    pub fn with_spreads(mut self, spreads: Vec<Spread>) -> Self {
+        self.spreads = spreads;
+        self
+    }
+
+    /// Observe each fixing the given number of business days earlier
+    pub fn with_lookback_days(mut self, lookback_days: Natural) -> Self {
+        self.lookback_days = Some(lookback_days);
+        self
+    }
+
+    /// Freeze the fixings of the last given number of days of each coupon
+    pub fn with_lockout_days(mut self, lockout_days: Natural) -> Self {
+        self.lockout_days = Some(lockout_days);
+        self
+    }
+
+    /// Weight each fixing by the length of its observation period, with the lookback
+    pub fn with_observation_shift(mut self, observation_shift: bool) -> Self {
+        self.observation_shift = Some(observation_shift);
+        self
+    }
+
+    /// Build a leg of overnight indexed coupons, one per schedule period
+    pub fn build(self) -> Vec<OvernightIndexedCoupon> {
+        assert!(!self.notionals.is_empty(), "No notionals given");
+
+        let payment_calendar = self
+            .payment_calendar
+            .as_ref()
+            .unwrap_or_else(|| self.schedule.calendar());
+        let payment_adjustment = self.payment_adjustment.unwrap_or(Following);
+        let payment_lag = self.payment_lag.unwrap_or(0);
+        let day_counter = self
+            .payment_day_counter
+            .as_ref()
+            .unwrap_or_else(|| self.index.day_counter());
+
+        (1..self.schedule.size())
+            .map(|i| {
+                let start = self.schedule[i - 1];
+                let end = self.schedule[i];
+                let payment_date = payment_calendar.advance_by_units(
+                    end,
+                    payment_lag,
+                    Days,
+                    payment_adjustment,
+                    false,
+                );
+                OvernightIndexedCoupon::new(
+                    payment_date,
+                    value_for_period(&self.notionals, i - 1, 0.0),
+                    start,
+                    end,
+                    self.index.clone(),
+                    value_for_period(&self.gearings, i - 1, 1.0),
+                    value_for_period(&self.spreads, i - 1, 0.0),
+                    day_counter.clone(),
+                )
+                .with_lookback_days(self.lookback_days.unwrap_or(0))
+                .with_lockout_days(self.lockout_days.unwrap_or(0))
+                .with_observation_shift(self.observation_shift.unwrap_or(false))
+            })
+            .collect()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::cashflow::CashFlow,
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedule::Schedule,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Days, Months, Years},
+        },
+        handle::Handle,
+        indexes::overnightindex::OvernightIndex,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::OvernightLeg;
+
+    #[test]
+    fn test_overnight_leg() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Rc::new(OvernightIndex::new(
+            "ESTR",
+            0,
+            Target::new(),
+            DayCounter::actual360(),
+            curve,
+        ));
+        let start = Date::new(17, January, 2024);
+        let schedule = || -> Schedule {
+            ScheduleBuilder::new(
+                PricingContext::new(today),
+                start,
+                start + Period::new(1, Years),
+                Period::new(3, Months),
+                Target::new(),
+            )
+            .with_convention(ModifiedFollowing)
+            .build()
+        };
+        let calendar = Target::new();
+
+        let leg = OvernightLeg::new(schedule(), index.clone())
+            .with_notional(100.0)
+            .with_spread(0.001)
+            .build();
+        let lagged = OvernightLeg::new(schedule(), index)
+            .with_notionals(vec![100.0, 75.0])
+            .with_spread(0.001)
+            .with_payment_lag(2)
+            .with_lookback_days(2)
+            .with_observation_shift(true)
+            .build();
+        assert_eq!(leg.len(), 4);
+        assert_eq!(lagged.len(), 4);
+        for (c, l) in leg.iter().zip(&lagged) {
+            assert_eq!(c.date(), c.accrual_end_date);
+            let expected = calendar.advance_by_units(c.accrual_end_date, 2, Days, Following, false);
+            assert_eq!(l.date(), expected);
+            assert_eq!(c.spread, 0.001);
+            assert_eq!(c.day_counter, DayCounter::actual360());
+            assert_eq!(c.lookback_days, 0);
+            assert_eq!(l.lookback_days, 2);
+            assert!(!c.observation_shift);
+            assert!(l.observation_shift);
+        }
+        assert_eq!(lagged[0].nominal, 100.0);
+        assert!(lagged[1..].iter().all(|c| c.nominal == 75.0));
+    }
+}
@@ -1 +1,2 @@
 pub mod pricing_context;
+pub mod shared_pricing_context;
@@ -0,0 +1,237 @@
+use std::sync::{Arc, Mutex, RwLock, Weak};
+
+use crate::{datetime::date::Date, patterns::observable::Observer};
+
+use super::pricing_context::PricingContext;
+
+/// [PricingContext] which can be shared between threads, e.g. to price a portfolio in parallel
+/// with a consistent evaluation date.
+///
+/// Cloning a `SharedPricingContext` does not copy the context: all the clones refer to the same
+/// underlying [PricingContext], so that a change of evaluation date made through one of them is
+/// seen by all the others. Readers never observe a partially updated context.
+///
+/// Observers registered with the context are notified after each change of evaluation date;
+/// they must be `Send + Sync` since the change may happen on any thread. Plain market data such
+/// as [FlatForward](crate::termstructures::flatforward::FlatForward),
+/// [SimpleQuote](crate::quotes::simplequote::SimpleQuote) or a
+/// [DayCounter](crate::datetime::daycounter::DayCounter) is `Send + Sync` and can be shared
+/// between threads, but [Handle](crate::handle::Handle)s are single-threaded: each thread wraps
+/// the data it uses in its own handles.
+#[derive(Clone)]
+pub struct SharedPricingContext {
+    inner: Arc<SharedState>,
+}
+
+struct SharedState {
+    context: RwLock<PricingContext>,
+    observers: Mutex<Vec<Weak<dyn Observer + Send + Sync>>>,
+}
+
+impl SharedPricingContext {
+    pub fn new(eval_date: Date) -> Self {
+        Self::from(PricingContext::new(eval_date))
+    }
+
+    /// Copy of the current context
+    pub fn context(&self) -> PricingContext {
+        *self
+            .inner
+            .context
+            .read()
+            .expect("pricing context lock poisoned")
+    }
+
+    /// Current evaluation date
+    pub fn eval_date(&self) -> Date {
+        self.context().eval_date
+    }
+
+    /// Changes the evaluation date for all the clones of this context and notifies the
+    /// observers, unless the date is unchanged
+    pub fn set_eval_date(&self, eval_date: Date) {
+        {
+            let mut context = self
+                .inner
+                .context
+                .write()
+                .expect("pricing context lock poisoned");
+            if context.eval_date == eval_date {
+                return;
+            }
+            context.eval_date = eval_date;
+        }
+        // notify without holding any lock, so that observers can read the context
+        let observers: Vec<Arc<dyn Observer + Send + Sync>> = {
+            let mut observers = self.observers();
+            observers.retain(|o| o.strong_count() > 0);
+            observers.iter().filter_map(|o| o.upgrade()).collect()
+        };
+        for observer in observers {
+            observer.update();
+        }
+    }
+
+    /// Register an observer of the evaluation date. Observers are held through weak
+    /// references, and registering the same observer more than once has no effect.
+    pub fn register_observer(&self, observer: Arc<dyn Observer + Send + Sync>) {
+        let observer = Arc::downgrade(&observer);
+        let mut observers = self.observers();
+        if !observers.iter().any(|o| Weak::ptr_eq(o, &observer)) {
+            observers.push(observer);
+        }
+    }
+
+    /// Unregister an observer, if it was registered
+    pub fn unregister_observer(&self, observer: &Arc<dyn Observer + Send + Sync>) {
+        let observer = Arc::downgrade(observer);
+        self.observers().retain(|o| !Weak::ptr_eq(o, &observer));
+    }
+
+    fn observers(&self) -> std::sync::MutexGuard<'_, Vec<Weak<dyn Observer + Send + Sync>>> {
+        self.inner
+            .observers
+            .lock()
+            .expect("pricing context observers lock poisoned")
+    }
+}
+
+impl From<PricingContext> for SharedPricingContext {
+    fn from(context: PricingContext) -> Self {
+        Self {
+            inner: Arc::new(SharedState {
+                context: RwLock::new(context),
+                observers: Mutex::new(vec![]),
+            }),
+        }
+    }
+}
+
+impl Default for SharedPricingContext {
+    fn default() -> Self {
+        Self::from(PricingContext::default())
+    }
+}
+
+impl std::fmt::Debug for SharedPricingContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedPricingContext")
+            .field(&self.context())
+            .finish()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::{
+        rc::Rc,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+            period::Period, timeunit::TimeUnit::Months,
+        },
+        handle::Handle,
+        indexes::ibor::euribor::Euribor,
+        instruments::{
+            forwardrateagreement::{ForwardRateAgreement, Position},
+            instrument::Instrument,
+        },
+        patterns::observable::Observer,
+        quotes::simplequote::SimpleQuote,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::Real,
+    };
+
+    use super::SharedPricingContext;
+
+    #[derive(Default)]
+    struct Counter {
+        updates: AtomicUsize,
+    }
+
+    impl Observer for Counter {
+        fn update(&self) {
+            self.updates.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// NPV of a long 3x6 FRA struck at 2.5% on a flat 3% curve, starting from the evaluation
+    /// date. The instrument and its market data are built on the calling thread, as they are
+    /// not shared.
+    fn price_fra(context: &SharedPricingContext) -> Real {
+        let today = context.eval_date();
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Rc::new(Euribor::new(Period::new(3, Months), curve.clone()));
+        let fra = ForwardRateAgreement::new(
+            today + Period::new(3, Months),
+            today + Period::new(6, Months),
+            Position::Long,
+            0.025,
+            1_000_000.0,
+            index,
+            curve,
+        );
+        fra.npv()
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedPricingContext>();
+        assert_send_sync::<FlatForward>();
+        assert_send_sync::<SimpleQuote>();
+        assert_send_sync::<DayCounter>();
+
+        let today = Date::new(15, January, 2024);
+        let context = SharedPricingContext::new(today);
+        let expected = price_fra(&context);
+        assert!(expected > 0.0);
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let context = context.clone();
+                thread::spawn(move || (context.eval_date(), price_fra(&context)))
+            })
+            .collect();
+        for handle in handles {
+            let (eval_date, price) = handle.join().unwrap();
+            assert_eq!(eval_date, today);
+            assert_eq!(price, expected);
+        }
+
+        // clones share the same context, and observers are notified of changes
+        let counter = Arc::new(Counter::default());
+        let observer: Arc<dyn Observer + Send + Sync> = counter.clone();
+        context.register_observer(observer.clone());
+        let clone = context.clone();
+        let tomorrow = Date::new(16, January, 2024);
+        thread::spawn(move || clone.set_eval_date(tomorrow))
+            .join()
+            .unwrap();
+        assert_eq!(context.eval_date(), tomorrow);
+        assert_eq!(counter.updates.load(Ordering::SeqCst), 1);
+
+        // setting the same date again is not a change
+        context.set_eval_date(tomorrow);
+        assert_eq!(counter.updates.load(Ordering::SeqCst), 1);
+        context.unregister_observer(&observer);
+        context.set_eval_date(today);
+        assert_eq!(counter.updates.load(Ordering::SeqCst), 1);
+    }
+}
@@ -4,6 +4,7 @@ pub mod businessdayconvention;
 pub mod calendar;
 pub mod date;
 pub mod dategenerationrule;
+pub mod daterange;
 pub mod daycounter;
 pub mod daycounters;
 pub mod frequency;
@@ -11,6 +12,7 @@ pub mod holiday;
 pub mod holidays;
 pub mod imm;
 pub mod months;
+pub mod parseerror;
 pub mod period;
 pub mod schedule;
 pub mod schedulebuilder;
@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use crate::datetime::parseerror::{normalise_name, ParseError};
+
 /// Business Day conventions.
 /// These conventions specify the algorithm used to adjust a date in case it is not a valid
 /// business day.
@@ -24,3 +28,86 @@ pub enum BusinessDayConvention {
     /// business days are equally far away, default to following business day.
     Nearest,
 }
+
+// -------------------------------------------------------------------------------------------------
+
+impl fmt::Display for BusinessDayConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BusinessDayConvention::Following => "Following",
+            BusinessDayConvention::ModifiedFollowing => "ModifiedFollowing",
+            BusinessDayConvention::Preceding => "Preceding",
+            BusinessDayConvention::ModifiedPreceding => "ModifiedPreceding",
+            BusinessDayConvention::Unadjusted => "Unadjusted",
+            BusinessDayConvention::HalfMonthModifiedFollowing => "HalfMonthModifiedFollowing",
+            BusinessDayConvention::Nearest => "Nearest",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parses the names of the variants, ignoring case, spaces, hyphens and underscores.
+impl FromStr for BusinessDayConvention {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalise_name(s).as_str() {
+            "following" => Ok(BusinessDayConvention::Following),
+            "modifiedfollowing" => Ok(BusinessDayConvention::ModifiedFollowing),
+            "preceding" => Ok(BusinessDayConvention::Preceding),
+            "modifiedpreceding" => Ok(BusinessDayConvention::ModifiedPreceding),
+            "unadjusted" => Ok(BusinessDayConvention::Unadjusted),
+            "halfmonthmodifiedfollowing" => Ok(BusinessDayConvention::HalfMonthModifiedFollowing),
+            "nearest" => Ok(BusinessDayConvention::Nearest),
+            _ => Err(ParseError::new("business day convention", s)),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::BusinessDayConvention;
+
+    #[test]
+    fn test_display_and_from_str() {
+        let variants = [
+            BusinessDayConvention::Following,
+            BusinessDayConvention::ModifiedFollowing,
+            BusinessDayConvention::Preceding,
+            BusinessDayConvention::ModifiedPreceding,
+            BusinessDayConvention::Unadjusted,
+            BusinessDayConvention::HalfMonthModifiedFollowing,
+            BusinessDayConvention::Nearest,
+        ];
+        for v in variants {
+            assert_eq!(v.to_string().parse::<BusinessDayConvention>(), Ok(v));
+            assert_eq!(
+                v.to_string()
+                    .to_uppercase()
+                    .parse::<BusinessDayConvention>(),
+                Ok(v)
+            );
+            assert_eq!(
+                v.to_string()
+                    .to_lowercase()
+                    .parse::<BusinessDayConvention>(),
+                Ok(v)
+            );
+        }
+        assert_eq!(
+            "Modified Following".parse::<BusinessDayConvention>(),
+            Ok(BusinessDayConvention::ModifiedFollowing)
+        );
+        assert_eq!(
+            "half-month modified following".parse::<BusinessDayConvention>(),
+            Ok(BusinessDayConvention::HalfMonthModifiedFollowing)
+        );
+        let err = "Folowing".parse::<BusinessDayConvention>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown business day convention: 'Folowing'"
+        );
+    }
+}
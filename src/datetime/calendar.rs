@@ -1,10 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use crate::types::{Integer, Natural, Size};
 
 use crate::datetime::{
-    businessdayconvention::BusinessDayConvention, date::Date, period::Period, timeunit::TimeUnit,
-    weekday::Weekday, Day, Year,
+    businessdayconvention::BusinessDayConvention,
+    date::{check_year, Date},
+    months::Month,
+    period::Period,
+    timeunit::TimeUnit,
+    weekday::Weekday,
+    Day, SerialNumber, Year,
 };
 
 use super::holiday::Holiday;
@@ -16,11 +21,32 @@ use super::holiday::Holiday;
 ///
 /// A calendar should be defined for specific exchange holiday schedule or for general country
 /// holiday schedule.
+///
+/// Business days on which the market closes early, e.g. the US bond market on the day after
+/// Thanksgiving, can be marked as early closes; they remain business days.
 #[derive(Clone)]
 pub struct Calendar {
     holiday: Holiday,
     added_holidays: HashSet<Date>,
     removed_holidays: HashSet<Date>,
+    early_close_days: HashSet<Date>,
+}
+
+/// Two calendars are equal if they have the same name, the same added and removed holidays and
+/// the same early closes.
+impl PartialEq for Calendar {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+            && self.added_holidays == other.added_holidays
+            && self.removed_holidays == other.removed_holidays
+            && self.early_close_days == other.early_close_days
+    }
+}
+
+impl std::fmt::Debug for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 impl Calendar {
@@ -29,6 +55,7 @@ impl Calendar {
             holiday,
             added_holidays: HashSet::new(),
             removed_holidays: HashSet::new(),
+            early_close_days: HashSet::new(),
         }
     }
 
@@ -52,8 +79,9 @@ impl Calendar {
         self.removed_holidays.clear();
     }
 
-    /// Returns `true` iff the date is a business day for the given market.
-    fn is_business_day(&self, date: &Date) -> bool {
+    /// Returns `true` iff the date is a business day for the given market, taking into account
+    /// any added or removed holidays.
+    pub fn is_business_day(&self, date: &Date) -> bool {
         if !self.added_holidays.is_empty() && self.added_holidays.contains(date) {
             return false;
         }
@@ -63,9 +91,10 @@ impl Calendar {
         self.holiday.is_business_day(date)
     }
 
-    /// Returns `true` iff the date is a holiday for the given market
+    /// Returns `true` iff the date is a holiday for the given market, taking into account
+    /// any added or removed holidays.
     pub fn is_holiday(&self, date: &Date) -> bool {
-        !self.holiday.is_business_day(date)
+        !self.is_business_day(date)
     }
 
     /// Returns `true` iff the weekday is part of the weekend for the given market.    
@@ -106,8 +135,79 @@ impl Calendar {
         }
     }
 
-    /// Returns the holidays between two dates.
+    /// Returns the set of early close days marked on the given calendar
+    pub fn early_close_days(&self) -> &HashSet<Date> {
+        &self.early_close_days
+    }
+
+    /// Marks a date as an early close, i.e. a business day with a shortened session.
+    ///
+    /// The date stays a business day; marking a holiday has no effect until it is removed from
+    /// the holidays.
+    pub fn add_early_close(&mut self, date: Date) {
+        self.early_close_days.insert(date);
+    }
+
+    /// Removes the early close mark from a date
+    pub fn remove_early_close(&mut self, date: Date) {
+        self.early_close_days.remove(&date);
+    }
+
+    /// Returns `true` iff the date is a business day marked as an early close
+    pub fn is_early_close(&self, date: &Date) -> bool {
+        !self.early_close_days.is_empty()
+            && self.early_close_days.contains(date)
+            && self.is_business_day(date)
+    }
+
+    /// Returns the early closes between two dates, both included, in increasing order
+    pub fn early_close_list(&self, from: Date, to: Date) -> Vec<Date> {
+        assert!(
+            to >= from,
+            "'from' date ({:?}) must be equal or earlier than 'to' date ({:?})",
+            from,
+            to
+        );
+        let mut result: Vec<Date> = self
+            .early_close_days
+            .iter()
+            .filter(|d| **d >= from && **d <= to && self.is_business_day(d))
+            .copied()
+            .collect();
+        result.sort();
+        result
+    }
+
+    /// Returns the holidays between two dates, both included, together with the early closes
+    /// in the same range when `include_early_closes` is set.
+    pub fn holiday_list_with_early_closes(
+        &self,
+        from: Date,
+        to: Date,
+        include_weekends: bool,
+        include_early_closes: bool,
+    ) -> (Vec<Date>, Vec<Date>) {
+        let holidays = self.holiday_list(from, to, include_weekends);
+        let early_closes = if include_early_closes {
+            self.early_close_list(from, to)
+        } else {
+            vec![]
+        };
+        (holidays, early_closes)
+    }
+
+    /// Returns the holidays between two dates, both included.
+    ///
+    /// The dates are unique and returned in increasing order. Weekends are only listed if
+    /// `include_weekends` is true.
     pub fn holiday_list(&self, from: Date, to: Date, include_weekends: bool) -> Vec<Date> {
+        if from == to {
+            return if self.is_listed_holiday(&from, include_weekends) {
+                vec![from]
+            } else {
+                vec![]
+            };
+        }
         assert!(
             to >= from,
             "'from' date ({:?}) must be equal or earlier than 'to' date ({:?})",
@@ -117,7 +217,7 @@ impl Calendar {
         let mut result = vec![];
         let mut d = from;
         while d <= to {
-            if self.is_holiday(&d) && (include_weekends || !self.is_weekend(d.weekday())) {
+            if self.is_listed_holiday(&d, include_weekends) {
                 result.push(d);
             }
             d += 1;
@@ -125,6 +225,81 @@ impl Calendar {
         result
     }
 
+    /// Returns the holidays between two dates, both included, computing each calendar year in
+    /// parallel.
+    ///
+    /// The result is identical to [Calendar::holiday_list].
+    #[cfg(feature = "rayon")]
+    pub fn holiday_list_parallel(&self, from: Date, to: Date, include_weekends: bool) -> Vec<Date> {
+        use rayon::prelude::*;
+
+        assert!(
+            to >= from,
+            "'from' date ({:?}) must be equal or earlier than 'to' date ({:?})",
+            from,
+            to
+        );
+        (from.year()..=to.year())
+            .into_par_iter()
+            .map(|year| {
+                let start = from.max(Date::new(1, Month::January, year));
+                let end = to.min(Date::new(31, Month::December, year));
+                self.holiday_list(start, end, include_weekends)
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Returns the holidays of the years from `from_year` to `to_year`, both included, grouped
+    /// by year and in increasing order within each year. Weekends are not listed.
+    pub fn holidays_by_year(&self, from_year: Year, to_year: Year) -> BTreeMap<Year, Vec<Date>> {
+        assert!(
+            to_year >= from_year,
+            "'from' year ({}) must be equal or earlier than 'to' year ({})",
+            from_year,
+            to_year
+        );
+        (from_year..=to_year)
+            .map(|year| {
+                let holidays = self.holiday_list(
+                    Date::new(1, Month::January, year),
+                    Date::new(31, Month::December, year),
+                    false,
+                );
+                (year, holidays)
+            })
+            .collect()
+    }
+
+    /// Returns the number of holidays between two dates, both included.
+    ///
+    /// This is the same as `holiday_list(from, to, include_weekends).len()`, without building
+    /// the list.
+    pub fn holiday_count(&self, from: Date, to: Date, include_weekends: bool) -> Size {
+        if from == to {
+            return self.is_listed_holiday(&from, include_weekends) as Size;
+        }
+        assert!(
+            to >= from,
+            "'from' date ({:?}) must be equal or earlier than 'to' date ({:?})",
+            from,
+            to
+        );
+        let mut count = 0;
+        let mut d = from;
+        while d <= to {
+            if self.is_listed_holiday(&d, include_weekends) {
+                count += 1;
+            }
+            d += 1;
+        }
+        count
+    }
+
+    fn is_listed_holiday(&self, date: &Date, include_weekends: bool) -> bool {
+        self.is_holiday(date) && (include_weekends || !self.is_weekend(date.weekday()))
+    }
+
     /// Returns the business days between two dates.
     pub fn business_day_list(&self, from: Date, to: Date) -> Vec<Date> {
         assert!(
@@ -144,6 +319,38 @@ impl Calendar {
         result
     }
 
+    /// Returns the next `n` business days on or after `start`.
+    ///
+    /// `start` is only part of the result if `include_start` is true and it is a business day.
+    pub fn next_business_days(&self, start: &Date, n: Size, include_start: bool) -> Vec<Date> {
+        let mut result = Vec::with_capacity(n);
+        let mut d = *start;
+        if !include_start {
+            d += 1;
+        }
+        while result.len() < n {
+            if self.is_business_day(&d) {
+                result.push(d);
+            }
+            d += 1;
+        }
+        result
+    }
+
+    /// Returns the `n`-th business day after `start`, `start` itself excluded.
+    pub fn nth_business_day(&self, start: &Date, n: Size) -> Date {
+        assert!(n > 0, "the number of business days must be positive");
+        let mut d = *start;
+        let mut count = 0;
+        while count < n {
+            d += 1;
+            if self.is_business_day(&d) {
+                count += 1;
+            }
+        }
+        d
+    }
+
     /// Adjusts a non-business day to the appropriate near business day using the
     /// [BusinessDayConvention::Following]
     pub fn adjust_with_following(&self, date: Date) -> Date {
@@ -213,7 +420,7 @@ impl Calendar {
         unit: TimeUnit,
         end_of_month: bool,
     ) -> Date {
-        self.advance_by_days(
+        self.advance_by_units(
             date,
             n,
             unit,
@@ -222,8 +429,18 @@ impl Calendar {
         )
     }
 
-    /// Advances the given date by the given number of business days and returns the result.
-    pub fn advance_by_days(
+    /// Advances the given date by `n` units and returns the result.
+    ///
+    /// * For [TimeUnit::Days], `n` counts business days, skipping holidays; the result is a
+    ///   business day and `convention` is not used, except that for `n == 0` the date is
+    ///   adjusted.
+    /// * For weeks, months and years the calendar period is added first and the resulting date
+    ///   adjusted with `convention`.
+    /// * End-of-month rule: for months and years, if `end_of_month` is set and `date` is the
+    ///   last business day of its month, the result is the last business day of the target
+    ///   month, e.g. the 28th of February 2023 advanced by one month gives the 31st of March
+    ///   rather than the 28th.
+    pub fn advance_by_units(
         &self,
         date: Date,
         n: Integer,
@@ -270,6 +487,19 @@ impl Calendar {
         }
     }
 
+    /// Advances the given date by the given number of units; same as [Calendar::advance_by_units]
+    #[deprecated(note = "use advance_by_units instead, which takes any time unit")]
+    pub fn advance_by_days(
+        &self,
+        date: Date,
+        n: Integer,
+        unit: TimeUnit,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Date {
+        self.advance_by_units(date, n, unit, convention, end_of_month)
+    }
+
     /// Advances the given date as specified by the given period using
     /// [BusinessDayConvention::Following]
     pub fn advance_by_period_with_following(
@@ -278,10 +508,25 @@ impl Calendar {
         period: Period,
         end_of_month: bool,
     ) -> Date {
-        self.advance_by_period(date, period, BusinessDayConvention::Following, end_of_month)
+        self.advance(date, period, BusinessDayConvention::Following, end_of_month)
     }
 
     /// Advances the given date as specified by the given period and returns the result.
+    ///
+    /// The period is applied as by [Calendar::advance_by_units], including its end-of-month
+    /// rule.
+    pub fn advance(
+        &self,
+        date: Date,
+        period: Period,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Date {
+        self.advance_by_units(date, period.length, period.unit, convention, end_of_month)
+    }
+
+    /// Advances the given date as specified by the given period; same as [Calendar::advance]
+    #[deprecated(note = "use advance instead")]
     pub fn advance_by_period(
         &self,
         date: Date,
@@ -289,7 +534,7 @@ impl Calendar {
         convention: BusinessDayConvention,
         end_of_month: bool,
     ) -> Date {
-        self.advance_by_days(date, period.length, period.unit, convention, end_of_month)
+        self.advance(date, period, convention, end_of_month)
     }
 
     /// Calculates the number of business days between two given dates and returns the result.
@@ -343,22 +588,38 @@ impl Calendar {
     }
 }
 
+/// Returns the day of the year of Easter Monday, in the western (Gregorian) calendar
 pub fn easter_monday(year: Year) -> Day {
-    assert!(
-        (1900..2200).contains(&year),
-        "Year must be in the range (1900, 2200)"
-    );
-    // NOTE: guaranteed not to panic due to the assertion above;
+    check_year(year);
+    // NOTE: guaranteed not to panic due to the check above;
     let idx: Size = (year - 1900).try_into().unwrap();
     EASTER_MONDAYS[idx]
 }
 
+/// Returns the date of Easter Sunday in the given year, in the western (Gregorian) calendar
+pub fn easter_sunday(year: Year) -> Date {
+    // day of the year is 1-based, and Easter Sunday is the day before Easter Monday
+    Date::new(1, Month::January, year) + (easter_monday(year) as SerialNumber - 2)
+}
+
+/// Returns the date of Good Friday, two days before Easter Sunday
+pub fn good_friday(year: Year) -> Date {
+    easter_sunday(year) - 2
+}
+
+/// Returns the date of Ash Wednesday, 46 days before Easter Sunday
+pub fn ash_wednesday(year: Year) -> Date {
+    easter_sunday(year) - 46
+}
+
+/// Returns the date of Corpus Christi, 60 days after Easter Sunday
+pub fn corpus_christi(year: Year) -> Date {
+    easter_sunday(year) + 60
+}
+
 pub fn easter_monday_orthodox(year: Year) -> Day {
-    assert!(
-        (1900..2200).contains(&year),
-        "Year must be in the range (1900, 2200)"
-    );
-    // NOTE: guaranteed not to panic due to the assertion above;
+    check_year(year);
+    // NOTE: guaranteed not to panic due to the check above;
     let idx: Size = (year - 1900).try_into().unwrap();
     ORTHODOX_EASTER_MONDAYS[idx]
 }
@@ -436,13 +697,18 @@ const ORTHODOX_EASTER_MONDAYS: [Natural; 300] = [
 #[cfg(test)]
 mod test {
     use crate::datetime::{
+        businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
         date::Date,
-        holidays::{brazil::Brazil, target::Target},
+        holidays::{brazil::Brazil, target::Target, unitedstates::UnitedStates},
         months::Month,
         period::Period,
         timeunit::TimeUnit,
+        weekday::Weekday,
+        Day, Year,
     };
 
+    use super::{ash_wednesday, corpus_christi, easter_monday, easter_sunday, good_friday};
+
     #[test]
     fn test_end_of_month() {
         // any calendar should be ok
@@ -474,6 +740,305 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_easter_dates() {
+        assert_eq!(easter_sunday(2024), Date::new(31, Month::March, 2024));
+        assert_eq!(good_friday(2024), Date::new(29, Month::March, 2024));
+        assert_eq!(ash_wednesday(2024), Date::new(14, Month::February, 2024));
+        assert_eq!(corpus_christi(2024), Date::new(30, Month::May, 2024));
+        assert_eq!(easter_sunday(2023), Date::new(9, Month::April, 2023));
+
+        for year in 1901..2200 {
+            let easter = easter_sunday(year);
+            assert_eq!(easter.weekday(), Weekday::Sunday);
+            assert_eq!(good_friday(year).weekday(), Weekday::Friday);
+            assert_eq!(ash_wednesday(year).weekday(), Weekday::Wednesday);
+            assert_eq!(corpus_christi(year).weekday(), Weekday::Thursday);
+            assert_eq!(easter, computus(year), "Easter Sunday of {}", year);
+            assert_eq!((easter + 1).day_of_year(), easter_monday(year));
+        }
+    }
+
+    /// Anonymous Gregorian algorithm (Meeus/Jones/Butcher) for Easter Sunday
+    fn computus(year: Year) -> Date {
+        let a = year % 19;
+        let b = year / 100;
+        let c = year % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+        let month = if month == 3 {
+            Month::March
+        } else {
+            Month::April
+        };
+        Date::new(day as Day, month, year)
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_holiday_list_parallel() {
+        let mut c = Target::new();
+        c.add_holiday(Date::new(27, Month::December, 2023));
+        let from = Date::new(1, Month::January, 1980);
+        let to = Date::new(31, Month::December, 2050);
+        for include_weekends in [false, true] {
+            assert_eq!(
+                c.holiday_list_parallel(from, to, include_weekends),
+                c.holiday_list(from, to, include_weekends)
+            );
+        }
+        // ranges not aligned on years
+        let from = Date::new(15, Month::April, 1995);
+        let to = Date::new(2, Month::January, 2001);
+        assert_eq!(
+            c.holiday_list_parallel(from, to, false),
+            c.holiday_list(from, to, false)
+        );
+        assert_eq!(
+            c.holiday_list_parallel(to, to, true),
+            c.holiday_list(to, to, true)
+        );
+    }
+
+    #[test]
+    fn test_holiday_count() {
+        let c = Target::new();
+        let from = Date::new(1, Month::January, 2023);
+        let to = Date::new(31, Month::December, 2023);
+        for include_weekends in [false, true] {
+            assert_eq!(
+                c.holiday_count(from, to, include_weekends),
+                c.holiday_list(from, to, include_weekends).len()
+            );
+        }
+        // Good Friday, Easter Monday, Labour Day, Christmas and Boxing Day, New Year's Day
+        // falling on a Sunday
+        assert_eq!(c.holiday_count(from, to, false), 5);
+        assert_eq!(c.holiday_count(from, to, true), 5 + 105);
+
+        // single day ranges
+        assert_eq!(c.holiday_count(from, from, false), 0);
+        assert_eq!(c.holiday_list(from, from, true), vec![from]);
+        let christmas = Date::new(25, Month::December, 2023);
+        assert_eq!(c.holiday_count(christmas, christmas, false), 1);
+    }
+
+    #[test]
+    fn test_added_and_removed_holidays() {
+        let mut c = Target::new();
+        let christmas = Date::new(25, Month::December, 2023);
+        let business_day = Date::new(27, Month::December, 2023);
+        assert!(!c.is_business_day(&christmas));
+        assert!(c.is_business_day(&business_day));
+
+        c.remove_holiday(christmas);
+        c.add_holiday(business_day);
+        assert!(c.is_business_day(&christmas));
+        assert!(!c.is_holiday(&christmas));
+        assert!(!c.is_business_day(&business_day));
+        assert!(c.is_holiday(&business_day));
+        assert_eq!(c.adjust_with_following(christmas), christmas);
+        assert_eq!(c.adjust_with_following(business_day), business_day + 1);
+
+        c.reset_added_and_removed_holidays();
+        assert!(!c.is_business_day(&christmas));
+        assert!(c.is_business_day(&business_day));
+    }
+
+    #[test]
+    fn test_early_close() {
+        let mut c = UnitedStates::government_bond();
+        // the day after Thanksgiving 2004
+        let date = Date::new(26, Month::November, 2004);
+        assert!(!c.is_early_close(&date));
+        c.add_early_close(date);
+        assert!(c.is_business_day(&date));
+        assert!(c.is_early_close(&date));
+        assert!(!c.is_early_close(&(date + 3)));
+
+        let from = Date::new(1, Month::November, 2004);
+        let to = Date::new(30, Month::November, 2004);
+        assert_eq!(c.early_close_list(from, to), vec![date]);
+        let (holidays, early_closes) = c.holiday_list_with_early_closes(from, to, false, true);
+        assert_eq!(holidays, c.holiday_list(from, to, false));
+        assert!(!holidays.contains(&date));
+        assert_eq!(early_closes, vec![date]);
+        let (_, early_closes) = c.holiday_list_with_early_closes(from, to, false, false);
+        assert!(early_closes.is_empty());
+
+        // a holiday is never an early close
+        c.add_holiday(date);
+        assert!(!c.is_early_close(&date));
+        assert!(c.early_close_list(from, to).is_empty());
+        c.remove_holiday(date);
+        assert!(c.is_early_close(&date));
+
+        assert!(c != UnitedStates::government_bond());
+        c.remove_early_close(date);
+        assert!(!c.is_early_close(&date));
+        assert!(c == UnitedStates::government_bond());
+    }
+
+    #[test]
+    fn test_calendar_equality() {
+        let c1 = Target::new();
+        let mut c2 = Target::new();
+        assert_eq!(c1, c2);
+        assert_ne!(c1, Brazil::new());
+        assert_eq!(format!("{:?}", c1), c1.name());
+
+        let holiday = Date::new(27, Month::December, 2023);
+        c2.add_holiday(holiday);
+        assert_ne!(c1, c2);
+        c2.reset_added_and_removed_holidays();
+        assert_eq!(c1, c2);
+        c2.remove_holiday(Date::new(25, Month::December, 2023));
+        assert_ne!(c1, c2);
+    }
+
+    #[test]
+    fn test_next_business_days() {
+        let mut c = Target::new();
+        // Labour Day, Wednesday 1 May 2024, is a TARGET holiday
+        let monday = Date::new(29, Month::April, 2024);
+        assert_eq!(
+            c.next_business_days(&monday, 4, true),
+            vec![
+                monday,
+                Date::new(30, Month::April, 2024),
+                Date::new(2, Month::May, 2024),
+                Date::new(3, Month::May, 2024),
+            ]
+        );
+        assert_eq!(
+            c.next_business_days(&monday, 4, false),
+            vec![
+                Date::new(30, Month::April, 2024),
+                Date::new(2, Month::May, 2024),
+                Date::new(3, Month::May, 2024),
+                Date::new(6, Month::May, 2024),
+            ]
+        );
+        assert!(c.next_business_days(&monday, 0, true).is_empty());
+        // a holiday start date is skipped even when included
+        let holiday = Date::new(1, Month::May, 2024);
+        assert_eq!(
+            c.next_business_days(&holiday, 1, true),
+            vec![Date::new(2, Month::May, 2024)]
+        );
+
+        assert_eq!(
+            c.nth_business_day(&monday, 1),
+            Date::new(30, Month::April, 2024)
+        );
+        assert_eq!(
+            c.nth_business_day(&monday, 2),
+            Date::new(2, Month::May, 2024)
+        );
+        assert_eq!(
+            c.nth_business_day(&monday, 4),
+            Date::new(6, Month::May, 2024)
+        );
+
+        // added and removed holidays are honoured
+        c.add_holiday(Date::new(2, Month::May, 2024));
+        c.remove_holiday(holiday);
+        assert_eq!(
+            c.next_business_days(&monday, 4, false),
+            vec![
+                Date::new(30, Month::April, 2024),
+                holiday,
+                Date::new(3, Month::May, 2024),
+                Date::new(6, Month::May, 2024),
+            ]
+        );
+        assert_eq!(
+            c.nth_business_day(&monday, 3),
+            Date::new(3, Month::May, 2024)
+        );
+    }
+
+    #[test]
+    fn test_advance() {
+        let c = Target::new();
+        // Tuesday 28 February 2023 is the last business day of the month
+        let date = Date::new(28, Month::February, 2023);
+        let month = Period::new(1, TimeUnit::Months);
+        assert_eq!(
+            c.advance(date, month, Following, true),
+            Date::new(31, Month::March, 2023)
+        );
+        assert_eq!(
+            c.advance(date, month, Following, false),
+            Date::new(28, Month::March, 2023)
+        );
+        assert_eq!(
+            c.advance_by_units(date, 1, TimeUnit::Months, Following, true),
+            c.advance(date, month, Following, true)
+        );
+        // in 2024 the 28th is not the end of February, so that the rule does not apply
+        let date = Date::new(28, Month::February, 2024);
+        assert_eq!(
+            c.advance(date, month, Following, true),
+            Date::new(28, Month::March, 2024)
+        );
+
+        // the end of month lands on the last business day: 30 June 2024 is a Sunday
+        let date = Date::new(31, Month::May, 2024);
+        assert_eq!(
+            c.advance(date, month, ModifiedFollowing, true),
+            Date::new(28, Month::June, 2024)
+        );
+
+        // business days skip holidays, e.g. Labour Day on Wednesday 1 May 2024
+        let date = Date::new(30, Month::April, 2024);
+        assert_eq!(
+            c.advance_by_units(date, 1, TimeUnit::Days, Following, false),
+            Date::new(2, Month::May, 2024)
+        );
+        assert_eq!(
+            c.advance_by_units(
+                Date::new(2, Month::May, 2024),
+                -1,
+                TimeUnit::Days,
+                Following,
+                false
+            ),
+            date
+        );
+        assert_eq!(
+            c.advance_by_units(
+                Date::new(1, Month::May, 2024),
+                0,
+                TimeUnit::Days,
+                Following,
+                false
+            ),
+            Date::new(2, Month::May, 2024)
+        );
+
+        // the former names are kept as aliases
+        #[allow(deprecated)]
+        let (by_days, by_period) = (
+            c.advance_by_days(date, 1, TimeUnit::Months, Following, true),
+            c.advance_by_period(date, month, Following, true),
+        );
+        assert_eq!(
+            by_days,
+            c.advance_by_units(date, 1, TimeUnit::Months, Following, true)
+        );
+        assert_eq!(by_period, c.advance(date, month, Following, true));
+    }
+
     #[allow(unused)]
     #[test]
     fn test_business_days_between() {
@@ -1,5 +1,5 @@
 use std::{
-    fmt::Debug,
+    fmt::{self, Debug},
     hash::Hash,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
@@ -38,7 +38,7 @@ impl Default for Date {
 
 impl PartialOrd for Date {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.serial_number.partial_cmp(&other.serial_number)
+        Some(self.cmp(other))
     }
 }
 
@@ -74,7 +74,7 @@ impl Add<SerialNumber> for Date {
     }
 }
 
-impl<'a> Add<SerialNumber> for &'a Date {
+impl Add<SerialNumber> for &Date {
     type Output = Date;
 
     fn add(self, rhs: SerialNumber) -> Self::Output {
@@ -100,7 +100,7 @@ impl Sub<SerialNumber> for Date {
     }
 }
 
-impl<'a> Sub<SerialNumber> for &'a Date {
+impl Sub<SerialNumber> for &Date {
     type Output = Date;
 
     fn sub(self, rhs: SerialNumber) -> Self::Output {
@@ -126,7 +126,7 @@ impl Add<Period> for Date {
     }
 }
 
-impl<'a> Add<&'a Period> for &'a Date {
+impl Add<&Period> for &Date {
     type Output = Date;
 
     fn add(self, rhs: &Period) -> Self::Output {
@@ -134,7 +134,7 @@ impl<'a> Add<&'a Period> for &'a Date {
     }
 }
 
-impl<'a> Add<Period> for &'a Date {
+impl Add<Period> for &Date {
     type Output = Date;
 
     fn add(self, rhs: Period) -> Self::Output {
@@ -156,7 +156,7 @@ impl Sub<Period> for Date {
     }
 }
 
-impl<'a> Sub<Period> for &'a Date {
+impl Sub<Period> for &Date {
     type Output = Date;
 
     fn sub(self, rhs: Period) -> Self::Output {
@@ -180,7 +180,7 @@ impl Sub for Date {
     }
 }
 
-impl<'a> Sub for &'a Date {
+impl Sub for &Date {
     type Output = SerialNumber;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -193,10 +193,7 @@ impl<'a> Sub for &'a Date {
 impl Date {
     /// Create a new [Date] from day, [Month] and year.
     pub fn new(d: Day, m: Month, y: Year) -> Self {
-        assert!(
-            (1900..2200).contains(&y),
-            "Year must be in the range [1900, 2200)"
-        );
+        check_year(y);
         assert!(
             (Into::<Integer>::into(m) > 0 && Into::<Integer>::into(m) < 13),
             "Month is outside [January, December] range"
@@ -214,13 +211,24 @@ impl Date {
         let year_offset = Date::year_offset(y);
         let serial_number = 1 + d + month_offset + year_offset;
         let date_time = Utc.with_ymd_and_hms(y, m.into(), d, 0, 0, 0).unwrap();
-        Date {
+        let date = Date {
             date_time,
             // NOTE: If this conversion fails, we'll just panic as that's the best course
             // of action.
             serial_number: Integer::try_from(serial_number)
                 .unwrap_or_else(|_| panic!("date serial number ({}) out of range", serial_number)),
-        }
+        };
+        // the serial number is computed independently of chrono; both must give the same day
+        debug_assert_eq!(
+            Date::min_date().date_time + Duration::days(date.serial_number as BigInteger - 2),
+            date.date_time,
+            "serial number {} inconsistent with {}-{}-{}",
+            date.serial_number,
+            y,
+            Into::<Integer>::into(m),
+            d
+        );
+        date
     }
 
     /// Create a [Date] taking a serial number as given by Applix or Excel.
@@ -250,17 +258,63 @@ impl Date {
     }
 
     pub fn todays_date() -> Date {
-        let now = Utc::now();
-        let d = now.day();
-        let m = now.month();
-        let y = now.year();
-        Date::new(d, m.into(), y)
+        Date::from_chrono(Utc::now())
+    }
+
+    /// Creates a [Date] from the calendar day of a `chrono` date-time; the time of day is
+    /// discarded.
+    ///
+    /// Panics if the day is outside the range of [Date::min_date] to [Date::max_date].
+    pub fn from_chrono(date_time: DateTime<Utc>) -> Date {
+        Date::new(date_time.day(), date_time.month().into(), date_time.year())
+    }
+
+    /// Returns the date as a `chrono` date-time at midnight UTC.
+    pub fn to_chrono(&self) -> DateTime<Utc> {
+        self.date_time
     }
 
     pub fn serial_number(&self) -> SerialNumber {
         self.serial_number
     }
 
+    /// Returns the serial number of the date as used by spreadsheets.
+    ///
+    /// The internal serial number counts days from 1899-12-30, as Applix and LibreOffice do:
+    /// 1900-01-01 is serial 2. Excel instead counts from 1899-12-31 but wrongly treats 1900 as
+    /// a leap year, so that its serials match the internal ones from 1900-03-01 onwards and are
+    /// one less before that.
+    ///
+    /// With `excel_leap_year_bug` set the result is the Excel serial, with the non-existent
+    /// 1900-02-29 as serial 60; otherwise it is the actual number of days since 1899-12-31,
+    /// which is one less than the Excel serial for dates after 1900-02-28.
+    pub fn to_excel_serial(&self, excel_leap_year_bug: bool) -> SerialNumber {
+        if excel_leap_year_bug && self.serial_number > EXCEL_PHANTOM_LEAP_DAY {
+            self.serial_number
+        } else {
+            self.serial_number - 1
+        }
+    }
+
+    /// Creates a [Date] from a spreadsheet serial number; the inverse of
+    /// [Date::to_excel_serial].
+    ///
+    /// Panics if `excel_leap_year_bug` is set and `serial_number` is 60, which Excel maps to
+    /// the non-existent 1900-02-29.
+    pub fn from_excel_serial(serial_number: SerialNumber, excel_leap_year_bug: bool) -> Self {
+        if excel_leap_year_bug {
+            assert!(
+                serial_number != EXCEL_PHANTOM_LEAP_DAY,
+                "Excel serial number {} is the non-existent 29 February 1900",
+                EXCEL_PHANTOM_LEAP_DAY
+            );
+            if serial_number > EXCEL_PHANTOM_LEAP_DAY {
+                return Date::from_serial(serial_number);
+            }
+        }
+        Date::from_serial(serial_number + 1)
+    }
+
     pub fn weekday(&self) -> Weekday {
         let w = self.serial_number % 7;
         Weekday::from(w)
@@ -315,6 +369,44 @@ impl Date {
         (d2 - d1).into()
     }
 
+    /// Exact difference between two dates as a [Period] in days, i.e. `d2 - d1` days.
+    pub fn period_between(d1: &Date, d2: &Date) -> Period {
+        Period::new(d2 - d1, TimeUnit::Days)
+    }
+
+    /// Approximate difference between two dates as a normalised [Period].
+    ///
+    /// With `n = d2 - d1` days, the following rules are tried in order:
+    ///
+    /// 1. whole years: with `y = round(n / 365)` years, if `y != 0` and `n` differs from
+    ///    `365 * y` by at most the number of leap days `y` years can contain, i.e.
+    ///    `ceil(|y| / 4)`, the result is `y` years;
+    /// 2. whole months: with `m = round(n / (365 / 12))` months, if `m != 0` and `n` differs from
+    ///    `m * 365 / 12` by at most 3 days (enough to absorb the difference between calendar
+    ///    months, from 28 to 31 days, and their average length), the result is `m` months,
+    ///    normalised to years when `m` is a multiple of 12;
+    /// 3. otherwise the exact number of days, normalised to weeks when `n` is a multiple of 7.
+    ///
+    /// For example, 182 days gives 6M, 366 days gives 1Y and 730 days gives 2Y.
+    pub fn approximate_period_between(d1: &Date, d2: &Date) -> Period {
+        let days = d2 - d1;
+        let n = days as Time;
+
+        let years = (n / 365.0).round();
+        let leap_days = (years.abs() / 4.0).ceil();
+        if years != 0.0 && (n - 365.0 * years).abs() <= leap_days {
+            return Period::new(years as Integer, TimeUnit::Years);
+        }
+
+        let average_month = 365.0 / 12.0;
+        let months = (n / average_month).round();
+        if months != 0.0 && (n - months * average_month).abs() <= 3.0 {
+            return Period::new(months as Integer, TimeUnit::Months).normalised();
+        }
+
+        Period::new(days, TimeUnit::Days).normalised()
+    }
+
     /// n-th given weekday in the given month and year
     /// E.g., the 4th Thursday of March, 1998 was March 26th, 1998.
     ///
@@ -332,12 +424,35 @@ impl Date {
         Date::new(d, m, y)
     }
 
+    /// Third Wednesday of the given month and year, i.e. the IMM date of that month
+    pub fn third_wednesday(m: Month, y: Year) -> Date {
+        Date::nth_weekday(3, Weekday::Wednesday, m, y)
+    }
+
+    /// Third Wednesday of the month of the given date
+    pub fn third_wednesday_of(date: &Date) -> Date {
+        Date::third_wednesday(date.month(), date.year())
+    }
+
+    /// Returns the first date on or after this date falling on the given weekday.
+    /// E.g., the next Friday on or after Saturday, June 3rd, 2023 is Friday, June 9th, 2023.
+    pub fn next_weekday(&self, day_of_week: Weekday) -> Date {
+        let wd: Integer = self.weekday().into();
+        let target: Integer = day_of_week.into();
+        self + (target - wd + 7) % 7
+    }
+
+    /// Returns the last date on or before this date falling on the given weekday.
+    /// E.g., the previous Friday on or before Monday, June 5th, 2023 is Friday, June 2nd, 2023.
+    pub fn previous_weekday(&self, day_of_week: Weekday) -> Date {
+        let wd: Integer = self.weekday().into();
+        let target: Integer = day_of_week.into();
+        self - (wd - target + 7) % 7
+    }
+
     pub fn is_leap(y: Year) -> bool {
-        assert!(
-            (1900..2200).contains(&y),
-            "Year must be in the range (1900, 2200)"
-        );
-        // NOTE: guaranteed not to panic due to the assertion above;
+        check_year(y);
+        // NOTE: guaranteed not to panic due to the check above;
         let idx: Size = (y - 1900).try_into().unwrap();
         YEAR_IS_LEAP[idx]
     }
@@ -363,64 +478,128 @@ impl Date {
     }
 
     fn year_offset(y: Year) -> Natural {
-        assert!(
-            (1900..2200).contains(&y),
-            "Year must be in the range (1900, 2200)"
-        );
-        // NOTE: guaranteed not to panic due to the assertion above;
+        check_year(y);
+        // NOTE: guaranteed not to panic due to the check above;
         let idx: Size = (y - 1900).try_into().unwrap();
         YEAR_OFFSET[idx]
     }
 
+    /// Returns true if the date can be advanced by the given period, i.e. if the result falls
+    /// within [[Date::min_date], [Date::max_date]] and the time unit is one of days, weeks,
+    /// months or years.
+    pub fn can_advance(&self, period: Period) -> bool {
+        self.checked_advance(period.length, period.unit).is_some()
+    }
+
+    /// Advance the date by the given period, returning an error rather than panicking if the
+    /// time unit is not one of days, weeks, months or years, or if the result falls outside
+    /// [[Date::min_date], [Date::max_date]].
+    ///
+    /// Dates carry no time of day, so intraday units such as [TimeUnit::Hours] are not
+    /// supported.
+    pub fn try_advance(&self, period: Period) -> Result<Date, AdvanceError> {
+        if !matches!(
+            period.unit,
+            TimeUnit::Days | TimeUnit::Weeks | TimeUnit::Months | TimeUnit::Years
+        ) {
+            return Err(AdvanceError::UnsupportedTimeUnit(period.unit));
+        }
+        self.checked_advance(period.length, period.unit)
+            .ok_or(AdvanceError::OutOfRange {
+                date: *self,
+                period,
+            })
+    }
+
     /// Advance the date by the given amount of time units
     fn advance(&self, n: Integer, unit: TimeUnit) -> Date {
+        self.try_advance(Period::new(n, unit))
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Advance the date by the given amount of time units, returning `None` if the result is
+    /// out of range or the time unit is not supported
+    fn checked_advance(&self, n: Integer, unit: TimeUnit) -> Option<Date> {
         match unit {
-            TimeUnit::Days => self + n,
-            TimeUnit::Weeks => self + 7 * n,
+            TimeUnit::Days => self.checked_add_days(n),
+            TimeUnit::Weeks => self.checked_add_days(n.checked_mul(7)?),
             TimeUnit::Months => {
                 let mut d = self.day_of_month();
                 let m_ordinal: Integer = self.month().into();
-                let mut m = m_ordinal + n;
-                let mut y = self.year();
-                while m > 12 {
-                    m -= 12;
-                    y += 1;
-                }
-                while m < 1 {
-                    m += 12;
-                    y -= 1;
+                // months since January of year 0
+                let months = (self.year() * 12 + m_ordinal - 1).checked_add(n)?;
+                let y = months.div_euclid(12);
+                let m = months.rem_euclid(12) + 1;
+                if !(MINIMUM_YEAR..=MAXIMUM_YEAR).contains(&y) {
+                    return None;
                 }
-                assert!(
-                    (1900..2200).contains(&y),
-                    "Year must be in the range (1900, 2199)"
-                );
                 let length = Date::month_length(m.into(), Date::is_leap(y));
                 if d > length {
                     d = length;
                 }
-                Date::new(d, m.into(), y)
+                Some(Date::new(d, m.into(), y))
             }
             TimeUnit::Years => {
                 let mut d = self.day_of_month();
                 let m = self.month();
                 // we need to be able to subtract years in case `n` is negative
-                let y = self.year() + n;
-                assert!(
-                    (1900..2200).contains(&y),
-                    "Year must be in the range (1900, 2199)"
-                );
+                let y = self.year().checked_add(n)?;
+                if !(MINIMUM_YEAR..=MAXIMUM_YEAR).contains(&y) {
+                    return None;
+                }
                 if d == 29 && m == Month::February && !Date::is_leap(y) {
                     d = 28;
                 }
-                Date::new(d, m, y)
+                Some(Date::new(d, m, y))
             }
-            other => panic!("Invalid time unit: {:?}", other),
+            _ => None,
         }
     }
+
+    fn checked_add_days(&self, n: SerialNumber) -> Option<Date> {
+        let serial_number = self.serial_number.checked_add(n)?;
+        (MINIMUM_SERIAL_NUMBER..=MAXIMUM_SERIAL_NUMBER)
+            .contains(&serial_number)
+            .then(|| Date::from_serial(serial_number))
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
 
+/// Reasons for which a [Date] cannot be advanced by a [Period]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvanceError {
+    /// Only days, weeks, months and years are supported
+    UnsupportedTimeUnit(TimeUnit),
+    /// The resulting date is outside [[Date::min_date], [Date::max_date]]
+    OutOfRange { date: Date, period: Period },
+}
+
+impl fmt::Display for AdvanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdvanceError::UnsupportedTimeUnit(unit) => write!(
+                f,
+                "Invalid time unit: {:?}; dates can only be advanced by days, weeks, months or \
+                 years",
+                unit
+            ),
+            AdvanceError::OutOfRange { date, period } => write!(
+                f,
+                "{:?} advanced by {:?} falls outside the allowed range [{:?}, {:?}]",
+                date,
+                period,
+                Date::min_date(),
+                Date::max_date()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AdvanceError {}
+
+// -------------------------------------------------------------------------------------------------
+
 const YEAR_IS_LEAP: [bool; 301] = [
     // Note that in the Quantlib C++ implementation, 1900 is taken as a leap year because
     // that's how Excel treats it (it's a bug in Excel). However, we correct that here,
@@ -514,6 +693,23 @@ const YEAR_OFFSET: [Natural; 301] = [
     109573,
 ];
 
+/// Excel serial number of 1900-02-29, a date which doesn't exist
+const EXCEL_PHANTOM_LEAP_DAY: SerialNumber = 60;
+
+const MINIMUM_YEAR: Year = 1900;
+const MAXIMUM_YEAR: Year = 2199;
+
+/// Panics with a descriptive message if the year is outside the range of supported years
+pub(crate) fn check_year(y: Year) {
+    assert!(
+        (MINIMUM_YEAR..=MAXIMUM_YEAR).contains(&y),
+        "year ({}) outside the allowed range [{}, {}]",
+        y,
+        MINIMUM_YEAR,
+        MAXIMUM_YEAR
+    );
+}
+
 const MINIMUM_SERIAL_NUMBER: SerialNumber = 2; // Jan 1st, 1900
 const MAXIMUM_SERIAL_NUMBER: SerialNumber = 109574; // Dec 31st, 2199
 
@@ -523,11 +719,95 @@ const MAXIMUM_SERIAL_NUMBER: SerialNumber = 109574; // Dec 31st, 2199
 mod test {
     use std::collections::HashSet;
 
+    use chrono::{Datelike, TimeZone, Utc};
+
     use crate::types::Integer;
 
     use crate::datetime::{date::Month, period::Period, timeunit::TimeUnit, weekday::Weekday};
 
-    use super::Date;
+    use super::{AdvanceError, Date};
+
+    #[test]
+    fn test_next_and_previous_weekday() {
+        let saturday = Date::new(3, Month::June, 2023);
+        assert_eq!(saturday.weekday(), Weekday::Saturday);
+        assert_eq!(
+            saturday.next_weekday(Weekday::Monday),
+            Date::new(5, Month::June, 2023)
+        );
+        assert_eq!(saturday.next_weekday(Weekday::Saturday), saturday);
+        assert_eq!(
+            saturday.next_weekday(Weekday::Friday),
+            Date::new(9, Month::June, 2023)
+        );
+
+        let monday = Date::new(5, Month::June, 2023);
+        assert_eq!(
+            monday.previous_weekday(Weekday::Friday),
+            Date::new(2, Month::June, 2023)
+        );
+        assert_eq!(monday.previous_weekday(Weekday::Monday), monday);
+        assert_eq!(
+            monday.previous_weekday(Weekday::Tuesday),
+            Date::new(30, Month::May, 2023)
+        );
+
+        // the result always falls on the requested weekday, within a week of the date
+        let mut d = Date::new(1, Month::January, 2023);
+        while d < Date::new(1, Month::February, 2023) {
+            for w in 1..=7 {
+                let w = Weekday::from(w);
+                let next = d.next_weekday(w);
+                let previous = d.previous_weekday(w);
+                assert_eq!(next.weekday(), w);
+                assert_eq!(previous.weekday(), w);
+                assert!(next >= d && next - d < 7);
+                assert!(previous <= d && d - previous < 7);
+            }
+            d += 1;
+        }
+    }
+
+    #[test]
+    fn test_period_between() {
+        let d1 = Date::new(15, Month::January, 2023);
+        let d2 = d1 + 182;
+        assert_eq!(
+            Date::period_between(&d1, &d2),
+            Period::new(182, TimeUnit::Days)
+        );
+        assert_eq!(
+            Date::period_between(&d2, &d1),
+            Period::new(-182, TimeUnit::Days)
+        );
+        assert_eq!(
+            Date::period_between(&d1, &d1),
+            Period::new(0, TimeUnit::Days)
+        );
+    }
+
+    #[test]
+    fn test_approximate_period_between() {
+        let d1 = Date::new(15, Month::January, 2023);
+        let cases = [
+            (182, Period::new(6, TimeUnit::Months)),
+            (730, Period::new(2, TimeUnit::Years)),
+            (731, Period::new(2, TimeUnit::Years)),
+            (365, Period::new(1, TimeUnit::Years)),
+            (28, Period::new(1, TimeUnit::Months)),
+            (91, Period::new(3, TimeUnit::Months)),
+            (14, Period::new(2, TimeUnit::Weeks)),
+            (45, Period::new(45, TimeUnit::Days)),
+            (0, Period::new(0, TimeUnit::Days)),
+        ];
+        for (days, expected) in cases {
+            let period = Date::approximate_period_between(&d1, &(d1 + days));
+            assert_eq!(period.length, expected.length, "{} days", days);
+            assert_eq!(period.unit, expected.unit, "{} days", days);
+        }
+        let period = Date::approximate_period_between(&(d1 + 182), &d1);
+        assert_eq!((period.length, period.unit), (-6, TimeUnit::Months));
+    }
 
     #[test]
     fn test_new_date() {
@@ -546,6 +826,85 @@ mod test {
         assert!(date.end_of_month().is_end_of_month());
     }
 
+    #[test]
+    fn test_chrono_round_trip() {
+        for (d, m, y, serial) in [
+            (1, Month::March, 1900, 61),
+            (29, Month::February, 2000, 36585),
+            (31, Month::December, 2199, 109574),
+        ] {
+            let date = Date::new(d, m, y);
+            assert_eq!(date.serial_number(), serial);
+            let date_time = date.to_chrono();
+            assert_eq!(
+                (date_time.day(), date_time.month(), date_time.year()),
+                (d, m.into(), y)
+            );
+            assert_eq!(Date::from_chrono(date_time), date);
+        }
+        // the time of day is dropped
+        let noon = Utc.with_ymd_and_hms(2000, 2, 29, 12, 30, 0).unwrap();
+        assert_eq!(
+            Date::from_chrono(noon),
+            Date::new(29, Month::February, 2000)
+        );
+    }
+
+    #[test]
+    fn test_third_wednesday() {
+        let expected = Date::new(16, Month::March, 2016);
+        assert_eq!(Date::third_wednesday(Month::March, 2016), expected);
+        assert_eq!(
+            Date::third_wednesday(Month::March, 2016).weekday(),
+            Weekday::Wednesday
+        );
+        for day in [1, 16, 31] {
+            assert_eq!(
+                Date::third_wednesday_of(&Date::new(day, Month::March, 2016)),
+                expected
+            );
+        }
+        // the month starting on a Wednesday has its third one on the 15th
+        assert_eq!(
+            Date::third_wednesday(Month::January, 2025),
+            Date::new(15, Month::January, 2025)
+        );
+        assert_eq!(
+            Date::third_wednesday(Month::February, 2025),
+            Date::new(19, Month::February, 2025)
+        );
+    }
+
+    #[test]
+    fn test_excel_serial() {
+        // before 1 March 1900 the Excel serial is the actual day count
+        let date = Date::new(28, Month::February, 1900);
+        assert_eq!(date.to_excel_serial(true), 59);
+        assert_eq!(date.to_excel_serial(false), 59);
+        assert_eq!(Date::from_excel_serial(59, true), date);
+        assert_eq!(Date::from_excel_serial(59, false), date);
+
+        // after it, Excel counts the non-existent 29 February 1900
+        let date = Date::new(1, Month::March, 1900);
+        assert_eq!(date.serial_number(), 61);
+        assert_eq!(date.to_excel_serial(true), 61);
+        assert_eq!(date.to_excel_serial(false), 60);
+        assert_eq!(Date::from_excel_serial(61, true), date);
+        assert_eq!(Date::from_excel_serial(60, false), date);
+
+        let date = Date::new(15, Month::January, 2024);
+        assert_eq!(date.to_excel_serial(true), 45306);
+        assert_eq!(date.to_excel_serial(false), 45305);
+        assert_eq!(Date::from_excel_serial(45306, true), date);
+        assert_eq!(Date::from_excel_serial(45305, false), date);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-existent 29 February 1900")]
+    fn test_excel_phantom_leap_day() {
+        Date::from_excel_serial(60, true);
+    }
+
     #[test]
     fn test_from_serial() {
         let date = Date::from_serial(39448);
@@ -642,6 +1001,89 @@ mod test {
         assert_eq!(d - p, Date::new(1, Month::January, 1998));
     }
 
+    #[test]
+    fn test_can_advance() {
+        let max_date = Date::max_date();
+        let min_date = Date::min_date();
+        assert!(max_date.can_advance(Period::new(0, TimeUnit::Days)));
+        assert!(!max_date.can_advance(Period::new(1, TimeUnit::Days)));
+        assert!(!max_date.can_advance(Period::new(1, TimeUnit::Weeks)));
+        assert!(!max_date.can_advance(Period::new(1, TimeUnit::Months)));
+        assert!(!max_date.can_advance(Period::new(1, TimeUnit::Years)));
+        assert!(max_date.can_advance(Period::new(-1, TimeUnit::Years)));
+        assert!(!min_date.can_advance(Period::new(-1, TimeUnit::Days)));
+        assert!(!min_date.can_advance(Period::new(-1, TimeUnit::Months)));
+        assert!(min_date.can_advance(Period::new(1, TimeUnit::Months)));
+        assert!(!min_date.can_advance(Period::new(1, TimeUnit::Hours)));
+        assert!(!min_date.can_advance(Period::new(Integer::MAX, TimeUnit::Weeks)));
+
+        let d = Date::new(15, Month::December, 2199);
+        assert!(d.can_advance(Period::new(16, TimeUnit::Days)));
+        assert!(!d.can_advance(Period::new(17, TimeUnit::Days)));
+        assert!(!d.can_advance(Period::new(1, TimeUnit::Months)));
+        assert_eq!(
+            Date::new(30, Month::November, 2199) + Period::new(1, TimeUnit::Months),
+            Date::new(30, Month::December, 2199)
+        );
+        assert_eq!(
+            Date::new(15, Month::January, 1900) - Period::new(14, TimeUnit::Days),
+            min_date
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "advanced by 1M falls outside the allowed range")]
+    fn test_advance_months_past_max_date() {
+        let _ = Date::new(15, Month::December, 2199) + Period::new(1, TimeUnit::Months);
+    }
+
+    #[test]
+    #[should_panic(expected = "advanced by 1Y falls outside the allowed range")]
+    fn test_advance_years_past_max_date() {
+        let _ = Date::new(1, Month::January, 2199) + Period::new(1, TimeUnit::Years);
+    }
+
+    #[test]
+    #[should_panic(expected = "advanced by -1W falls outside the allowed range")]
+    fn test_advance_weeks_before_min_date() {
+        let _ = Date::new(3, Month::January, 1900) - Period::new(1, TimeUnit::Weeks);
+    }
+
+    #[test]
+    fn test_try_advance() {
+        let d = Date::new(31, Month::January, 2024);
+        assert_eq!(
+            d.try_advance(Period::new(1, TimeUnit::Months)),
+            Ok(Date::new(29, Month::February, 2024))
+        );
+        for unit in [TimeUnit::Hours, TimeUnit::Minutes, TimeUnit::Seconds] {
+            assert_eq!(
+                d.try_advance(Period::new(1, unit)),
+                Err(AdvanceError::UnsupportedTimeUnit(unit))
+            );
+        }
+        let period = Period::new(1, TimeUnit::Days);
+        assert_eq!(
+            Date::max_date().try_advance(period),
+            Err(AdvanceError::OutOfRange {
+                date: Date::max_date(),
+                period
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid time unit: Hours")]
+    fn test_advance_by_hours() {
+        let _ = Date::new(15, Month::January, 2024) + Period::new(6, TimeUnit::Hours);
+    }
+
+    #[test]
+    #[should_panic(expected = "year (2200) outside the allowed range [1900, 2199]")]
+    fn test_new_date_out_of_range() {
+        let _ = Date::new(1, Month::January, 2200);
+    }
+
     #[test]
     fn test_days_between() {
         let d1 = Date::new(1, Month::January, 2022);
@@ -1,3 +1,7 @@
+use std::{fmt, str::FromStr};
+
+use crate::datetime::parseerror::{normalise_name, ParseError};
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DateGenerationRule {
     /// Backward from termination date to effective date
@@ -26,3 +30,84 @@ pub enum DateGenerationRule {
     /// Credit derivatives standard rule since December 20th, 2015.
     CDS2015,
 }
+
+// -------------------------------------------------------------------------------------------------
+
+impl fmt::Display for DateGenerationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DateGenerationRule::Backward => "Backward",
+            DateGenerationRule::Forward => "Forward",
+            DateGenerationRule::Zero => "Zero",
+            DateGenerationRule::ThirdWednesday => "ThirdWednesday",
+            DateGenerationRule::ThirdWednesdayInclusive => "ThirdWednesdayInclusive",
+            DateGenerationRule::Twentieth => "Twentieth",
+            DateGenerationRule::TwentiethIMM => "TwentiethIMM",
+            DateGenerationRule::OldCDS => "OldCDS",
+            DateGenerationRule::CDS => "CDS",
+            DateGenerationRule::CDS2015 => "CDS2015",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Case-insensitive; spaces, hyphens and underscores in the name are ignored.
+impl FromStr for DateGenerationRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalise_name(s).as_str() {
+            "backward" => Ok(DateGenerationRule::Backward),
+            "forward" => Ok(DateGenerationRule::Forward),
+            "zero" => Ok(DateGenerationRule::Zero),
+            "thirdwednesday" => Ok(DateGenerationRule::ThirdWednesday),
+            "thirdwednesdayinclusive" => Ok(DateGenerationRule::ThirdWednesdayInclusive),
+            "twentieth" => Ok(DateGenerationRule::Twentieth),
+            "twentiethimm" => Ok(DateGenerationRule::TwentiethIMM),
+            "oldcds" => Ok(DateGenerationRule::OldCDS),
+            "cds" => Ok(DateGenerationRule::CDS),
+            "cds2015" => Ok(DateGenerationRule::CDS2015),
+            _ => Err(ParseError::new("date generation rule", s)),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::DateGenerationRule;
+
+    #[test]
+    fn test_display_and_from_str() {
+        let variants = [
+            DateGenerationRule::Backward,
+            DateGenerationRule::Forward,
+            DateGenerationRule::Zero,
+            DateGenerationRule::ThirdWednesday,
+            DateGenerationRule::ThirdWednesdayInclusive,
+            DateGenerationRule::Twentieth,
+            DateGenerationRule::TwentiethIMM,
+            DateGenerationRule::OldCDS,
+            DateGenerationRule::CDS,
+            DateGenerationRule::CDS2015,
+        ];
+        for v in variants {
+            assert_eq!(v.to_string().parse::<DateGenerationRule>(), Ok(v));
+            assert_eq!(
+                v.to_string().to_uppercase().parse::<DateGenerationRule>(),
+                Ok(v)
+            );
+            assert_eq!(
+                v.to_string().to_lowercase().parse::<DateGenerationRule>(),
+                Ok(v)
+            );
+        }
+        assert_eq!(
+            "Third Wednesday".parse::<DateGenerationRule>(),
+            Ok(DateGenerationRule::ThirdWednesday)
+        );
+        let err = "Sideways".parse::<DateGenerationRule>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown date generation rule: 'Sideways'");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    datetime::{date::Date, period::Period, timeunit::TimeUnit::Days},
+    types::Integer,
+};
+
+/// Dates from `from` to `to`, both included, `step` apart.
+///
+/// The n-th date is `from + n * step`, rather than the previous date plus `step`, so that a
+/// monthly range starting on 31 January goes through 29 February and 31 March. Iteration stops
+/// at the first date after `to`, or if the next date would fall after [Date::max_date].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: Date,
+    pub to: Date,
+    pub step: Period,
+    steps: Integer,
+}
+
+impl DateRange {
+    pub fn new(from: Date, to: Date, step: Period) -> Self {
+        assert!(
+            step.length > 0,
+            "step ({:?}) must be a positive period",
+            step
+        );
+        Self {
+            from,
+            to,
+            step,
+            steps: 0,
+        }
+    }
+
+    /// Daily range from `from` to `to`, both included
+    pub fn daily(from: Date, to: Date) -> Self {
+        Self::new(from, to, Period::new(1, Days))
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let offset = self.step.checked_mul(self.steps)?;
+        let date = self.from.try_advance(offset).ok()?;
+        if date > self.to {
+            return None;
+        }
+        self.steps += 1;
+        Some(date)
+    }
+}
+
+/// Daily range between the two dates, both included
+impl From<(Date, Date)> for DateRange {
+    fn from((from, to): (Date, Date)) -> Self {
+        DateRange::daily(from, to)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{
+        date::Date,
+        months::Month::*,
+        period::Period,
+        timeunit::TimeUnit::{Days, Months, Weeks},
+    };
+
+    use super::DateRange;
+
+    #[test]
+    fn test_monthly_range() {
+        let dates: Vec<Date> = DateRange::new(
+            Date::new(15, January, 2024),
+            Date::new(15, June, 2024),
+            Period::new(1, Months),
+        )
+        .collect();
+        assert_eq!(dates.len(), 6);
+        assert_eq!(dates[0], Date::new(15, January, 2024));
+        assert_eq!(dates[5], Date::new(15, June, 2024));
+
+        // month ends are kept where possible
+        let dates: Vec<Date> = DateRange::new(
+            Date::new(31, January, 2024),
+            Date::new(30, April, 2024),
+            Period::new(1, Months),
+        )
+        .collect();
+        assert_eq!(
+            dates,
+            vec![
+                Date::new(31, January, 2024),
+                Date::new(29, February, 2024),
+                Date::new(31, March, 2024),
+                Date::new(30, April, 2024),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_daily_and_weekly_ranges() {
+        let from = Date::new(15, January, 2024);
+        let to = Date::new(21, January, 2024);
+        assert_eq!(DateRange::from((from, to)).count(), 7);
+        assert_eq!(DateRange::daily(from, from).count(), 1);
+        assert_eq!(DateRange::daily(to, from).count(), 0);
+
+        // the end date is not included unless reached exactly
+        let dates: Vec<Date> = DateRange::new(from, to, Period::new(2, Weeks)).collect();
+        assert_eq!(dates, vec![from]);
+
+        // stops at the maximum date
+        let dates: Vec<Date> =
+            DateRange::new(Date::max_date() - 2, Date::max_date(), Period::new(2, Days)).collect();
+        assert_eq!(dates, vec![Date::max_date() - 2, Date::max_date()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a positive period")]
+    fn test_non_positive_step() {
+        DateRange::new(
+            Date::new(15, January, 2024),
+            Date::new(15, June, 2024),
+            Period::new(0, Days),
+        );
+    }
+}
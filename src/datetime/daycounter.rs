@@ -1,18 +1,22 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 use crate::types::{Integer, Time};
 
 use super::{
+    calendar::Calendar,
     date::Date,
     daycounters::{
         actual360::Actual360,
+        actual365fixed::Actual365Fixed,
         actual366::Actual366,
         actualactual::{self, ActualActual},
+        business252::Business252,
         one::One,
         simple::Simple,
         thirty360::{self, Thirty360, Thiry360Convention, EU, ISDA, IT, NASD, US},
         thirty365::Thirty365,
     },
+    parseerror::normalise_name,
     schedule::Schedule,
 };
 
@@ -23,8 +27,12 @@ pub enum DayCounter {
     ActualActual(ActualActual),
     /// Actual/360 day count convention, also known as "Act/360", or "A/360".
     Actual360(Actual360),
+    /// Actual/365 (Fixed) day count convention, also known as "Act/365 (Fixed)", or "A/365F".
+    Actual365Fixed(Actual365Fixed),
     /// Actual/366 day count convention, also known as "Act/366".
     Actual366(Actual366),
+    /// Business/252 day count convention, counting the business days of a calendar
+    Business252(Business252),
     /// 1/1 day count convention
     One(One),
     /// Simple day counter for reproducing theoretical calculations.
@@ -40,7 +48,9 @@ impl Debug for DayCounter {
         match self {
             Self::ActualActual(dc) => write!(f, "{}", dc.name()),
             Self::Actual360(dc) => write!(f, "{}", dc.name()),
+            Self::Actual365Fixed(dc) => write!(f, "{}", dc.name()),
             Self::Actual366(dc) => write!(f, "{}", dc.name()),
+            Self::Business252(dc) => write!(f, "{}", dc.name()),
             Self::One(dc) => write!(f, "{}", dc.name()),
             Self::Simple(dc) => write!(f, "{}", dc.name()),
             Self::Thirty360(dc) => write!(f, "{}", dc.name()),
@@ -54,7 +64,9 @@ impl PartialEq for DayCounter {
         match (self, other) {
             (Self::ActualActual(l0), Self::ActualActual(r0)) => l0.name() == r0.name(),
             (Self::Actual360(l0), Self::Actual360(r0)) => l0.name() == r0.name(),
+            (Self::Actual365Fixed(l0), Self::Actual365Fixed(r0)) => l0.name() == r0.name(),
             (Self::Actual366(l0), Self::Actual366(r0)) => l0.name() == r0.name(),
+            (Self::Business252(l0), Self::Business252(r0)) => l0.calendar() == r0.calendar(),
             (Self::One(l0), Self::One(r0)) => l0.name() == r0.name(),
             (Self::Simple(l0), Self::Simple(r0)) => l0.name() == r0.name(),
             (Self::Thirty360(l0), Self::Thirty360(r0)) => l0.name() == r0.name(),
@@ -64,12 +76,102 @@ impl PartialEq for DayCounter {
     }
 }
 
+/// Error returned by [DayCounter::from_name] for names which do not identify a day counter on
+/// their own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownDayCounter {
+    /// The name which could not be resolved
+    pub name: String,
+    /// How to build the day counter instead, when the name is known but the convention needs
+    /// more data than the name carries
+    pub hint: Option<&'static str>,
+}
+
+impl fmt::Display for UnknownDayCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.hint {
+            Some(hint) => write!(
+                f,
+                "day counter '{}' cannot be built by name: {}",
+                self.name, hint
+            ),
+            None => write!(f, "unknown day counter: '{}'", self.name),
+        }
+    }
+}
+
+impl std::error::Error for UnknownDayCounter {}
+
+/// Error returned by [DayCounter::try_year_fractions] for day counters whose year fractions
+/// depend on the reference period of each coupon, e.g. Actual/Actual (ISMA) without a schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferencePeriodRequired {
+    /// Name of the day counter
+    pub name: String,
+}
+
+impl fmt::Display for ReferencePeriodRequired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} year fractions need a reference period, use DayCounter::year_fraction \
+             or DayCounter::actual_actual_isma(schedule)",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for ReferencePeriodRequired {}
+
 impl DayCounter {
+    /// Returns the day counter with the given name, e.g. "Actual/360", "Actual/365 (Fixed)",
+    /// "30/360" or "Actual/Actual (ISDA)".
+    ///
+    /// Names are matched ignoring case and blanks, and the names returned by [DayCounter::name]
+    /// are accepted. "30/360" on its own is the bond basis and "30E/360" the Eurobond basis.
+    /// Actual/Actual (ISMA) and 30E/360 (ISDA) need a schedule and a termination date
+    /// respectively: for them an error points to the constructor to use.
+    pub fn from_name(name: &str) -> Result<DayCounter, UnknownDayCounter> {
+        let error = |hint| UnknownDayCounter {
+            name: name.to_string(),
+            hint,
+        };
+        match normalise_name(name).as_str() {
+            "actual/360" | "act/360" | "a/360" => Ok(DayCounter::actual360()),
+            "actual/360(inc)" => Ok(DayCounter::Actual360(Actual360::with_last_day(true))),
+            "actual/365(fixed)" | "act/365(fixed)" | "a/365f" | "actual/365fixed" => {
+                Ok(DayCounter::actual365_fixed())
+            }
+            "actual/366" | "act/366" => Ok(DayCounter::Actual366(Actual366::new())),
+            "actual/actual(isda)" | "act/act(isda)" | "actual/actual" | "act/act" => {
+                Ok(DayCounter::actual_actual_isda())
+            }
+            "actual/actual(afb)" | "act/act(afb)" => Ok(DayCounter::actual_actual_afb()),
+            "actual/actual(isma)" | "act/act(isma)" | "actual/actual(icma)" | "act/act(icma)" => {
+                Err(error(Some(
+                    "it needs the coupon schedule, use DayCounter::actual_actual_isma(schedule)",
+                )))
+            }
+            "30/360" | "30/360(bondbasis)" => Ok(DayCounter::bond_basis()),
+            "30/360(us)" => Ok(DayCounter::usa()),
+            "30/360(italian)" => Ok(DayCounter::italian()),
+            "30/360(nasd)" => Ok(DayCounter::nasd()),
+            "30e/360" | "30e/360(eurobondbasis)" => Ok(DayCounter::euro_bond_basis()),
+            "30e/360(isda)" => Err(error(Some(
+                "it needs the termination date, use DayCounter::isda(termination_date)",
+            ))),
+            "30/365" => Ok(DayCounter::Thirty365(Thirty365::new())),
+            "1/1" => Ok(DayCounter::One(One::new())),
+            "simple" => Ok(DayCounter::simple()),
+            _ => Err(error(None)),
+        }
+    }
+
     /// Return an instance of an Actual/Actual ISMA day counter
     pub fn actual_actual_isma(schedule: Schedule) -> DayCounter {
         DayCounter::ActualActual(ActualActual {
             convention: super::daycounters::actualactual::ActualActualConvention::ISMA(Box::new(
-                actualactual::ISMA { schedule },
+                actualactual::ISMA::new(schedule),
             )),
         })
     }
@@ -106,6 +208,11 @@ impl DayCounter {
         DayCounter::Actual360(Actual360::new())
     }
 
+    /// Return an instance of an [Actual365Fixed] day counter
+    pub fn actual365_fixed() -> DayCounter {
+        DayCounter::Actual365Fixed(Actual365Fixed::new())
+    }
+
     /// Return an instance of a [Simple] day counter
     pub fn simple() -> DayCounter {
         DayCounter::Simple(Simple::new())
@@ -168,12 +275,20 @@ impl DayCounter {
         })
     }
 
+    /// Return an instance of a [Business252] day counter counting the business days of the
+    /// given calendar, e.g. the Brazilian one
+    pub fn business252(calendar: Calendar) -> DayCounter {
+        DayCounter::Business252(Business252::new(calendar))
+    }
+
     /// Return the name of the day counter
     pub fn name(&self) -> String {
         match self {
             DayCounter::ActualActual(dc) => dc.name(),
             DayCounter::Actual360(dc) => dc.name(),
+            DayCounter::Actual365Fixed(dc) => dc.name(),
             DayCounter::Actual366(dc) => dc.name(),
+            DayCounter::Business252(dc) => dc.name(),
             DayCounter::One(dc) => dc.name(),
             DayCounter::Simple(dc) => dc.name(),
             DayCounter::Thirty360(dc) => dc.name(),
@@ -186,7 +301,9 @@ impl DayCounter {
         match self {
             DayCounter::ActualActual(dc) => dc.day_count(d1, d2),
             DayCounter::Actual360(dc) => dc.day_count(d1, d2),
+            DayCounter::Actual365Fixed(dc) => dc.day_count(d1, d2),
             DayCounter::Actual366(dc) => dc.day_count(d1, d2),
+            DayCounter::Business252(dc) => dc.day_count(d1, d2),
             DayCounter::One(dc) => dc.day_count(d1, d2),
             DayCounter::Simple(dc) => dc.day_count(d1, d2),
             DayCounter::Thirty360(dc) => dc.day_count(d1, d2),
@@ -207,13 +324,61 @@ impl DayCounter {
                 dc.year_fraction(d1, d2, ref_period_start, ref_period_end)
             }
             DayCounter::Actual360(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
+            DayCounter::Actual365Fixed(dc) => {
+                dc.year_fraction(d1, d2, ref_period_start, ref_period_end)
+            }
             DayCounter::Actual366(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
+            DayCounter::Business252(dc) => {
+                dc.year_fraction(d1, d2, ref_period_start, ref_period_end)
+            }
             DayCounter::One(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
             DayCounter::Simple(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
             DayCounter::Thirty360(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
             DayCounter::Thirty365(dc) => dc.year_fraction(d1, d2, ref_period_start, ref_period_end),
         }
     }
+
+    /// Returns the period between two dates as a fraction of year, without reference period.
+    ///
+    /// This is the form needed by all day counters except Actual/Actual ISMA, which may use
+    /// the reference period of the coupon; for that one, use [DayCounter::year_fraction].
+    pub fn year_fraction_simple(&self, d1: &Date, d2: &Date) -> Time {
+        self.year_fraction(d1, d2, &Date::default(), &Date::default())
+    }
+
+    /// Returns the time from the `reference` date to `date`, i.e. the year fraction between
+    /// them.
+    pub fn time_from_reference(&self, reference: &Date, date: &Date) -> Time {
+        self.year_fraction_simple(reference, date)
+    }
+
+    /// Returns the times from the `reference` date to each of the `dates`.
+    ///
+    /// Panics for day counters needing a reference period; see [DayCounter::try_year_fractions].
+    pub fn year_fractions(&self, reference: &Date, dates: &[Date]) -> Vec<Time> {
+        self.try_year_fractions(reference, dates)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the times from the `reference` date to each of the `dates`, or an error for the
+    /// Actual/Actual (ISMA) convention without a schedule, whose year fractions are only
+    /// meaningful within a coupon period.
+    pub fn try_year_fractions(
+        &self,
+        reference: &Date,
+        dates: &[Date],
+    ) -> Result<Vec<Time>, ReferencePeriodRequired> {
+        if let DayCounter::ActualActual(ActualActual {
+            convention: actualactual::ActualActualConvention::OldISMA(_),
+        }) = self
+        {
+            return Err(ReferencePeriodRequired { name: self.name() });
+        }
+        Ok(dates
+            .iter()
+            .map(|date| self.time_from_reference(reference, date))
+            .collect())
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -225,7 +390,7 @@ mod test {
         types::Integer,
     };
 
-    use super::DayCounter;
+    use super::{DayCounter, ReferencePeriodRequired, UnknownDayCounter};
 
     #[test]
     pub fn test_thirty360() {
@@ -233,7 +398,7 @@ mod test {
         let d2 = Date::new(30, June, 2022);
         let d3 = Date::new(1, December, 2022);
         let d4 = Date::new(31, December, 2022);
-        let date_pairs = vec![(d1, d2), (d3, d4)];
+        let date_pairs = [(d1, d2), (d3, d4)];
         let expected = [179, 30];
         let usa = DayCounter::usa();
         for (i, dp) in date_pairs.iter().enumerate() {
@@ -246,6 +411,116 @@ mod test {
         }
     }
 
+    #[test]
+    pub fn test_actual365_fixed() {
+        let dc = DayCounter::actual365_fixed();
+        assert_eq!(dc.name(), "Actual/365 (Fixed)");
+        let d1 = Date::new(1, January, 2024);
+        let d2 = Date::new(1, January, 2025);
+        assert_eq!(dc.day_count(&d1, &d2), 366);
+        assert_eq!(dc.year_fraction_simple(&d1, &d2), 366.0 / 365.0);
+    }
+
+    #[test]
+    pub fn test_year_fraction_simple() {
+        let d1 = Date::new(15, February, 2023);
+        let dates = [
+            Date::new(15, February, 2023),
+            Date::new(28, February, 2024),
+            Date::new(31, December, 2027),
+        ];
+        for dc in [DayCounter::actual365_fixed(), DayCounter::actual360()] {
+            for d2 in dates.iter() {
+                let expected = dc.year_fraction(&d1, d2, &Date::default(), &Date::default());
+                assert_eq!(dc.year_fraction_simple(&d1, d2), expected);
+                assert_eq!(dc.time_from_reference(&d1, d2), expected);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_year_fractions() {
+        let reference = Date::new(15, February, 2023);
+        let dates = [
+            Date::new(15, February, 2023),
+            Date::new(28, February, 2024),
+            Date::new(31, December, 2027),
+            Date::new(1, January, 2020),
+        ];
+        let dc = DayCounter::actual365_fixed();
+        let times = dc.year_fractions(&reference, &dates);
+        assert_eq!(times.len(), dates.len());
+        for (t, d) in times.iter().zip(dates.iter()) {
+            assert_eq!(*t, dc.year_fraction_simple(&reference, d));
+        }
+        assert!(dc.year_fractions(&reference, &[]).is_empty());
+
+        assert_eq!(
+            DayCounter::actual_actual_old_isma().try_year_fractions(&reference, &dates),
+            Err(ReferencePeriodRequired {
+                name: "Actual/Actual (ISMA)".into()
+            })
+        );
+        assert!(DayCounter::actual_actual_isda()
+            .try_year_fractions(&reference, &dates)
+            .is_ok());
+    }
+
+    #[test]
+    pub fn test_from_name() {
+        let names = [
+            "Actual/360",
+            "Actual/365 (Fixed)",
+            "30/360 (Bond Basis)",
+            "30/360 (US)",
+            "30E/360 (Eurobond Basis)",
+            "Actual/Actual (ISDA)",
+            "Actual/Actual (AFB)",
+            "1/1",
+        ];
+        for name in names {
+            let dc = DayCounter::from_name(name).unwrap();
+            assert_eq!(dc.name(), name);
+        }
+        assert_eq!(
+            DayCounter::from_name("30/360").unwrap(),
+            DayCounter::bond_basis()
+        );
+        assert_eq!(
+            DayCounter::from_name("30E/360").unwrap(),
+            DayCounter::european()
+        );
+        assert_eq!(
+            DayCounter::from_name("act/365 (fixed)").unwrap(),
+            DayCounter::actual365_fixed()
+        );
+        assert_eq!(
+            DayCounter::from_name("A/360").unwrap(),
+            DayCounter::actual360()
+        );
+    }
+
+    #[test]
+    pub fn test_from_name_errors() {
+        let error = DayCounter::from_name("Actual/Actual (ISMA)").unwrap_err();
+        assert!(error.hint.is_some());
+        assert!(
+            error.to_string().contains("actual_actual_isma(schedule)"),
+            "{}",
+            error
+        );
+
+        let error = DayCounter::from_name("Actual/364").unwrap_err();
+        assert_eq!(
+            error,
+            UnknownDayCounter {
+                name: "Actual/364".into(),
+                hint: None
+            }
+        );
+        assert_eq!(error.to_string(), "unknown day counter: 'Actual/364'");
+    }
+
     fn day_count(dc: &DayCounter, d1: &Date, d2: &Date) -> Integer {
         dc.day_count(d1, d2)
     }
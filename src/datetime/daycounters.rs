@@ -1,6 +1,8 @@
 pub mod actual360;
+pub mod actual365fixed;
 pub mod actual366;
 pub mod actualactual;
+pub mod business252;
 pub mod one;
 pub mod simple;
 pub mod thirty360;
@@ -0,0 +1,33 @@
+use crate::{
+    datetime::date::Date,
+    types::{Integer, Time},
+};
+
+/// Actual/365 (Fixed) day count convention, also known as "Act/365 (Fixed)", "A/365F", or
+/// "A/365".
+#[derive(Clone, Copy, Default)]
+pub struct Actual365Fixed {}
+
+impl Actual365Fixed {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn name(&self) -> String {
+        "Actual/365 (Fixed)".into()
+    }
+
+    pub fn day_count(&self, d1: &Date, d2: &Date) -> Integer {
+        d2 - d1
+    }
+
+    pub fn year_fraction(
+        &self,
+        d1: &Date,
+        d2: &Date,
+        _ref_period_start: &Date,
+        _ref_period_end: &Date,
+    ) -> Time {
+        Date::days_between(d1, d2) / 365.0
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use crate::{
     datetime::{
         date::Date, months::Month::*, period::Period, schedule::Schedule, timeunit::TimeUnit::*,
@@ -75,7 +77,7 @@ impl ActualActual {
 
     /// Create an instance of [ActualActualIsma] day counter
     pub fn actual_actual_isma(schedule: Schedule) -> ISMA {
-        ISMA { schedule }
+        ISMA::new(schedule)
     }
 
     /// Create an instance of [ActualActualOldIsma] day counter
@@ -86,12 +88,30 @@ impl ActualActual {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Actual/Actual (ISMA) day counter, with the reference periods given by the coupon schedule.
+///
+/// The coupon dates, completed with the quasi-coupon dates of irregular first and last periods,
+/// are worked out from the schedule on first use and cached. The schedule is not exposed
+/// mutably, so that it cannot be replaced after construction and the cache stays valid.
 #[derive(Clone)]
 pub struct ISMA {
-    pub schedule: Schedule,
+    schedule: Schedule,
+    quasi_payment_dates: OnceLock<Vec<Date>>,
 }
 
 impl ISMA {
+    pub fn new(schedule: Schedule) -> Self {
+        Self {
+            schedule,
+            quasi_payment_dates: OnceLock::new(),
+        }
+    }
+
+    /// The coupon schedule giving the reference periods
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
     pub fn name(&self) -> String {
         "Actual/Actual (ISMA)".into()
     }
@@ -100,7 +120,13 @@ impl ISMA {
         d2 - d1
     }
 
-    #[allow(clippy::comparison_chain)]
+    /// Returns the period between two dates as a fraction of year.
+    ///
+    /// When both `ref_period_start` and `ref_period_end` are given, they are taken as the
+    /// reference (coupon) period and the calculation is done as in
+    /// [ISMA::year_fraction_with_reference_period]. Otherwise the reference periods are derived
+    /// from the schedule.
+    #[allow(clippy::comparison_chain, clippy::only_used_in_recursion)]
     pub fn year_fraction(
         &self,
         d1: &Date,
@@ -114,7 +140,18 @@ impl ISMA {
             return -self.year_fraction(d2, d1, ref_period_start, ref_period_end);
         }
 
-        let coupon_dates = self.get_list_of_period_dates_including_quasi_payments();
+        if ref_period_start != &Date::default() && ref_period_end != &Date::default() {
+            return self.year_fraction_with_reference_period(
+                d1,
+                d2,
+                ref_period_start,
+                ref_period_end,
+            );
+        }
+
+        let coupon_dates = self
+            .quasi_payment_dates
+            .get_or_init(|| self.get_list_of_period_dates_including_quasi_payments());
 
         let first_date = coupon_dates
             .iter()
@@ -151,6 +188,82 @@ impl ISMA {
         year_fraction_sum
     }
 
+    /// Returns the period between two dates as a fraction of year, given the reference period
+    /// of the coupon they belong to, without reconstructing the periods from the schedule.
+    ///
+    /// For a long first coupon (`d1 < ref_period_start`) or a long last coupon
+    /// (`d2 > ref_period_end`), the notional periods before or after the reference period are
+    /// obtained by moving by the schedule tenor, with its business day convention and end of
+    /// month rule.
+    pub fn year_fraction_with_reference_period(
+        &self,
+        d1: &Date,
+        d2: &Date,
+        ref_period_start: &Date,
+        ref_period_end: &Date,
+    ) -> Time {
+        if d1 == d2 {
+            return 0.0;
+        } else if d2 < d1 {
+            return -self.year_fraction_with_reference_period(
+                d2,
+                d1,
+                ref_period_start,
+                ref_period_end,
+            );
+        }
+        assert!(
+            ref_period_end > ref_period_start,
+            "Invalid reference period, start: {:?}, end: {:?}",
+            ref_period_start,
+            ref_period_end
+        );
+
+        if d1 < ref_period_start {
+            // long first coupon, the previous period is notional
+            let previous_start = self.schedule.calendar().advance(
+                *ref_period_start,
+                -self.schedule.tenor(),
+                self.schedule.business_day_convention(),
+                self.schedule.end_of_month(),
+            );
+            let split = d2.min(ref_period_start);
+            return self.year_fraction_with_reference_period(
+                d1,
+                split,
+                &previous_start,
+                ref_period_start,
+            ) + self.year_fraction_with_reference_period(
+                split,
+                d2,
+                ref_period_start,
+                ref_period_end,
+            );
+        }
+        if d2 > ref_period_end {
+            // long last coupon, the next period is notional
+            let next_end = self.schedule.calendar().advance(
+                *ref_period_end,
+                self.schedule.tenor(),
+                self.schedule.business_day_convention(),
+                self.schedule.end_of_month(),
+            );
+            let split = d1.max(ref_period_end);
+            return self.year_fraction_with_reference_period(
+                d1,
+                split,
+                ref_period_start,
+                ref_period_end,
+            ) + self.year_fraction_with_reference_period(
+                split,
+                d2,
+                ref_period_end,
+                &next_end,
+            );
+        }
+        self.year_fraction_with_reference_dates(d1, d2, ref_period_start, ref_period_end)
+    }
+
     // -------------------------------------------------------------------------------------------------
 
     fn get_list_of_period_dates_including_quasi_payments(&self) -> Vec<Date> {
@@ -160,7 +273,7 @@ impl ISMA {
 
         if !self.schedule.has_is_regular() || !self.schedule.is_regular(1) {
             let first_coupon = self.schedule[1];
-            let notional_first_coupon = self.schedule.calendar().advance_by_period(
+            let notional_first_coupon = self.schedule.calendar().advance(
                 first_coupon,
                 -self.schedule.tenor(),
                 self.schedule.business_day_convention(),
@@ -170,7 +283,7 @@ impl ISMA {
             new_dates[0] = notional_first_coupon;
             // long first coupon
             if notional_first_coupon > issue_date {
-                let prior_notional_coupon = self.schedule.calendar().advance_by_period(
+                let prior_notional_coupon = self.schedule.calendar().advance(
                     notional_first_coupon,
                     -self.schedule.tenor(),
                     self.schedule.business_day_convention(),
@@ -182,7 +295,7 @@ impl ISMA {
         }
 
         if !self.schedule.has_is_regular() || !self.schedule.is_regular(self.schedule.size() - 1) {
-            let notional_last_coupon = self.schedule.calendar().advance_by_period(
+            let notional_last_coupon = self.schedule.calendar().advance(
                 self.schedule[self.schedule.size() - 2],
                 self.schedule.tenor(),
                 self.schedule.business_day_convention(),
@@ -190,7 +303,7 @@ impl ISMA {
             );
             new_dates[self.schedule.size() - 1] = notional_last_coupon;
             if notional_last_coupon < *self.schedule.end_date() {
-                let next_notional_coupon = self.schedule.calendar().advance_by_period(
+                let next_notional_coupon = self.schedule.calendar().advance(
                     notional_last_coupon,
                     self.schedule.tenor(),
                     self.schedule.business_day_convention(),
@@ -252,6 +365,7 @@ impl OldISMA {
         d2 - d1
     }
 
+    #[allow(clippy::only_used_in_recursion)]
     pub fn year_fraction(
         &self,
         d1: &Date,
@@ -383,6 +497,7 @@ impl ISDA {
         d2 - d1
     }
 
+    #[allow(clippy::only_used_in_recursion)]
     pub fn year_fraction(
         &self,
         d1: &Date,
@@ -424,6 +539,7 @@ impl AFB {
         d2 - d1
     }
 
+    #[allow(clippy::only_used_in_recursion)]
     pub fn year_fraction(
         &self,
         d1: &Date,
@@ -496,6 +612,8 @@ mod test {
         let d2 = Date::new(30, June, 2000);
 
         let expected = 152.0 / (182.0 * 2.0);
+        // short last coupon, with a notional end of the reference period
+        let reference_period = (Date::new(30, January, 2000), Date::new(30, July, 2000));
 
         do_test_actual_actual_isma(
             end_of_month,
@@ -506,6 +624,7 @@ mod test {
             penultimate_coupon_date,
             d1,
             d2,
+            reference_period,
             expected,
         );
 
@@ -521,6 +640,8 @@ mod test {
         let d2 = Date::new(30, April, 2000);
 
         let expected = 91.0 / (91.0 * 4.0) + 61.0 / (92.0 * 4.0);
+        // long last coupon, the reference period is the first quarter of it
+        let reference_period = (Date::new(30, November, 1999), Date::new(29, February, 2000));
 
         do_test_actual_actual_isma(
             end_of_month,
@@ -531,6 +652,7 @@ mod test {
             penultimate_coupon_date,
             d1,
             d2,
+            reference_period,
             expected,
         );
 
@@ -546,6 +668,7 @@ mod test {
         let d2 = Date::new(30, April, 2000);
 
         let expected = 91.0 / (91.0 * 4.0) + 61.0 / (90.0 * 4.0);
+        let reference_period = (Date::new(30, November, 1999), Date::new(29, February, 2000));
 
         do_test_actual_actual_isma(
             end_of_month,
@@ -556,6 +679,7 @@ mod test {
             penultimate_coupon_date,
             d1,
             d2,
+            reference_period,
             expected,
         );
     }
@@ -570,6 +694,7 @@ mod test {
         penultimate_coupon_date: Date,
         d1: Date,
         d2: Date,
+        reference_period: (Date, Date),
         expected: Real,
     ) {
         let schedule = ScheduleBuilder::new(
@@ -586,7 +711,30 @@ mod test {
         .build();
 
         let day_counter = ActualActual::actual_actual_isma(schedule);
+        assert!(day_counter.quasi_payment_dates.get().is_none());
         let calculated = day_counter.year_fraction(&d1, &d2, &Date::default(), &Date::default());
+        // the quasi-coupon dates are cached on first use, and later calls give the same result
+        assert_eq!(
+            day_counter.quasi_payment_dates.get(),
+            Some(&day_counter.get_list_of_period_dates_including_quasi_payments())
+        );
+        let cached = day_counter.year_fraction(&d1, &d2, &Date::default(), &Date::default());
+        assert_eq!(cached, calculated);
+        // the caller-supplied reference period must give the same result
+        let (ref_period_start, ref_period_end) = reference_period;
+        let calculated_with_reference_period =
+            day_counter.year_fraction(&d1, &d2, &ref_period_start, &ref_period_end);
+        assert!(
+            (calculated_with_reference_period - calculated).abs() <= 1.0e-12,
+            "period: {:?} to {:?}, reference period {:?} to {:?}, \
+             calculated: {}, expected: {}",
+            d1,
+            d2,
+            ref_period_start,
+            ref_period_end,
+            calculated_with_reference_period,
+            calculated,
+        );
 
         assert!(
             (calculated - expected).abs() <= 1.0e-10,
@@ -786,6 +934,103 @@ mod test {
         do_test_actual_actual(test_cases);
     }
 
+    #[test]
+    fn test_actual_actual_afb_leap_years() {
+        let afb = |d1: Date, d2: Date, expected: Real| {
+            ActualActualTestCase::new(
+                DayCounter::actual_actual_afb(),
+                d1,
+                d2,
+                Date::default(),
+                Date::default(),
+                expected,
+            )
+        };
+        let test_cases = vec![
+            // whole years straddling 29 February, with 29 February early or late in the period
+            afb(
+                Date::new(1, November, 2003),
+                Date::new(1, November, 2004),
+                1.0,
+            ),
+            afb(
+                Date::new(15, January, 2004),
+                Date::new(15, January, 2005),
+                1.0,
+            ),
+            // stubs containing 29 February are counted over 366 days
+            afb(
+                Date::new(1, November, 2003),
+                Date::new(1, May, 2004),
+                182.0 / 366.0,
+            ),
+            afb(
+                Date::new(15, January, 2004),
+                Date::new(15, March, 2004),
+                60.0 / 366.0,
+            ),
+            afb(
+                Date::new(1, November, 2003),
+                Date::new(1, May, 2005),
+                1.0 + 182.0 / 366.0,
+            ),
+            afb(
+                Date::new(15, January, 2004),
+                Date::new(15, March, 2006),
+                2.0 + 60.0 / 366.0,
+            ),
+            // stubs in a leap year not containing 29 February are counted over 365 days
+            afb(
+                Date::new(1, March, 2004),
+                Date::new(1, February, 2005),
+                337.0 / 365.0,
+            ),
+            afb(
+                Date::new(1, January, 2004),
+                Date::new(28, February, 2004),
+                58.0 / 365.0,
+            ),
+            // 28 February to 29 February is a whole year
+            afb(
+                Date::new(28, February, 2003),
+                Date::new(29, February, 2004),
+                1.0,
+            ),
+            afb(
+                Date::new(29, February, 2004),
+                Date::new(28, February, 2005),
+                1.0,
+            ),
+            // reversed periods
+            afb(
+                Date::new(1, May, 2004),
+                Date::new(1, November, 2003),
+                -182.0 / 366.0,
+            ),
+            afb(
+                Date::new(15, January, 2005),
+                Date::new(15, January, 2004),
+                -1.0,
+            ),
+        ];
+        for test_case in &test_cases {
+            let calculated = test_case.daycounter.year_fraction(
+                &test_case.d1,
+                &test_case.d2,
+                &test_case.ref_period_start,
+                &test_case.ref_period_end,
+            );
+            assert!(
+                (calculated - test_case.expected).abs() <= 1.0e-12,
+                "period: {:?} to {:?}, calculated: {}, expected: {}",
+                test_case.d1,
+                test_case.d2,
+                calculated,
+                test_case.expected,
+            );
+        }
+    }
+
     fn do_test_actual_actual(test_cases: Vec<ActualActualTestCase>) {
         for test_case in test_cases {
             let calculated = test_case.daycounter.year_fraction(
@@ -0,0 +1,162 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::datetime::{calendar::Calendar, date::Date, Year};
+use crate::types::{Integer, Time};
+
+/// Business/252 day count convention, used for Brazilian fixed income.
+///
+/// The day count is the number of business days of the calendar in `[d1, d2)` and the year
+/// fraction is that count divided by 252. The business days of each whole month are counted
+/// once and cached, so that long periods don't need to check every single date; clones share
+/// the same cache.
+#[derive(Clone)]
+pub struct Business252 {
+    calendar: Calendar,
+    month_cache: Arc<Mutex<HashMap<(Year, Integer), Integer>>>,
+}
+
+impl Business252 {
+    pub fn new(calendar: Calendar) -> Self {
+        Self {
+            calendar,
+            month_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("Business/252({})", self.calendar.name())
+    }
+
+    /// Returns the calendar whose business days are counted
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    pub fn day_count(&self, d1: &Date, d2: &Date) -> Integer {
+        if d1 > d2 {
+            return -self.day_count(d2, d1);
+        }
+        let last_month_start = Date::new(1, d2.month(), d2.year());
+        if *d1 >= last_month_start {
+            return self.business_days(*d1, *d2);
+        }
+        // the rest of the first month, the whole months in between and the start of the last
+        let mut month_start = d1.end_of_month() + 1;
+        let mut count = self.business_days(*d1, month_start);
+        while month_start < last_month_start {
+            count += self.month_business_days(month_start);
+            month_start = month_start.end_of_month() + 1;
+        }
+        count + self.business_days(last_month_start, *d2)
+    }
+
+    pub fn year_fraction(
+        &self,
+        d1: &Date,
+        d2: &Date,
+        _ref_period_start: &Date,
+        _ref_period_end: &Date,
+    ) -> Time {
+        self.day_count(d1, d2) as Time / 252.0
+    }
+
+    /// Business days in `[from, to)`
+    fn business_days(&self, from: Date, to: Date) -> Integer {
+        self.calendar.business_days_between(from, to, true, false)
+    }
+
+    /// Business days in the month starting on `month_start`
+    fn month_business_days(&self, month_start: Date) -> Integer {
+        let key = (month_start.year(), month_start.month().into());
+        let mut cache = self.month_cache.lock().unwrap();
+        *cache
+            .entry(key)
+            .or_insert_with(|| self.business_days(month_start, month_start.end_of_month() + 1))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{
+        date::Date, daycounter::DayCounter, holidays::brazil::Brazil, months::Month::*,
+    };
+
+    use super::Business252;
+
+    #[test]
+    fn test_business252() {
+        let test_dates = [
+            Date::new(1, February, 2002),
+            Date::new(4, February, 2002),
+            Date::new(16, May, 2003),
+            Date::new(17, December, 2003),
+            Date::new(17, December, 2004),
+            Date::new(19, December, 2005),
+            Date::new(2, January, 2006),
+            Date::new(13, March, 2006),
+            Date::new(15, May, 2006),
+            Date::new(17, March, 2006),
+            Date::new(15, May, 2006),
+            Date::new(26, July, 2006),
+            Date::new(28, June, 2007),
+            Date::new(16, September, 2009),
+            Date::new(26, July, 2016),
+        ];
+
+        // from the QuantLib test suite
+        let expected = [
+            0.0039682539683,
+            1.2738095238095,
+            0.6031746031746,
+            0.9960317460317,
+            1.0000000000000,
+            0.0396825396825,
+            0.1904761904762,
+            0.1666666666667,
+            -0.1507936507937,
+            0.1507936507937,
+            0.2023809523810,
+            0.912698412698,
+            2.214285714286,
+            6.84126984127,
+        ];
+
+        let dc = Business252::new(Brazil::new());
+        assert_eq!(dc.name(), "Business/252(Brazil)");
+        for i in 1..test_dates.len() {
+            let calculated = dc.year_fraction(
+                &test_dates[i - 1],
+                &test_dates[i],
+                &Date::default(),
+                &Date::default(),
+            );
+            assert!(
+                (calculated - expected[i - 1]).abs() < 1.0e-12,
+                "from {:?} to {:?}: calculated: {}, expected: {}",
+                test_dates[i - 1],
+                test_dates[i],
+                calculated,
+                expected[i - 1]
+            );
+            // the cached month counts agree with counting every date
+            let brute_force =
+                dc.calendar()
+                    .business_days_between(test_dates[i - 1], test_dates[i], true, false);
+            assert_eq!(
+                dc.day_count(&test_dates[i - 1], &test_dates[i]),
+                brute_force
+            );
+        }
+
+        let dc = DayCounter::business252(Brazil::new());
+        assert_eq!(dc, DayCounter::business252(Brazil::new()));
+        assert_ne!(dc, DayCounter::business252(Brazil::exchange()));
+        let t = dc.year_fraction_simple(&test_dates[13], &test_dates[14]);
+        assert!((t - expected[13]).abs() < 1.0e-12);
+    }
+}
@@ -49,7 +49,7 @@ mod test {
 
     #[test]
     fn test_one_day_counter() {
-        let periods = vec![
+        let periods = [
             Period::new(3, Months),
             Period::new(6, Months),
             Period::new(1, Years),
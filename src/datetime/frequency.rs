@@ -1,4 +1,9 @@
-use crate::types::{Integer, Real};
+use std::{fmt, str::FromStr};
+
+use crate::{
+    datetime::parseerror::{normalise_name, ParseError},
+    types::{Integer, Real},
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Frequency {
@@ -58,3 +63,91 @@ impl From<Frequency> for Real {
         (f as Integer) as Real
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Frequency::NoFrequency => "NoFrequency",
+            Frequency::Once => "Once",
+            Frequency::Annual => "Annual",
+            Frequency::Semiannual => "Semiannual",
+            Frequency::EveryFourthMonth => "EveryFourthMonth",
+            Frequency::Quarterly => "Quarterly",
+            Frequency::Bimonthly => "Bimonthly",
+            Frequency::Monthly => "Monthly",
+            Frequency::EveryFourthWeek => "EveryFourthWeek",
+            Frequency::Biweekly => "Biweekly",
+            Frequency::Weekly => "Weekly",
+            Frequency::Daily => "Daily",
+            Frequency::OtherFrequency => "OtherFrequency",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Accepts the variant names in any case, with or without separators, e.g. "Semi-Annual".
+impl FromStr for Frequency {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalise_name(s).as_str() {
+            "nofrequency" => Ok(Frequency::NoFrequency),
+            "once" => Ok(Frequency::Once),
+            "annual" => Ok(Frequency::Annual),
+            "semiannual" => Ok(Frequency::Semiannual),
+            "everyfourthmonth" => Ok(Frequency::EveryFourthMonth),
+            "quarterly" => Ok(Frequency::Quarterly),
+            "bimonthly" => Ok(Frequency::Bimonthly),
+            "monthly" => Ok(Frequency::Monthly),
+            "everyfourthweek" => Ok(Frequency::EveryFourthWeek),
+            "biweekly" => Ok(Frequency::Biweekly),
+            "weekly" => Ok(Frequency::Weekly),
+            "daily" => Ok(Frequency::Daily),
+            "otherfrequency" => Ok(Frequency::OtherFrequency),
+            _ => Err(ParseError::new("frequency", s)),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::Frequency;
+
+    #[test]
+    fn test_display_and_from_str() {
+        let variants = [
+            Frequency::NoFrequency,
+            Frequency::Once,
+            Frequency::Annual,
+            Frequency::Semiannual,
+            Frequency::EveryFourthMonth,
+            Frequency::Quarterly,
+            Frequency::Bimonthly,
+            Frequency::Monthly,
+            Frequency::EveryFourthWeek,
+            Frequency::Biweekly,
+            Frequency::Weekly,
+            Frequency::Daily,
+            Frequency::OtherFrequency,
+        ];
+        for v in variants {
+            assert_eq!(v.to_string().parse::<Frequency>(), Ok(v));
+            assert_eq!(v.to_string().to_uppercase().parse::<Frequency>(), Ok(v));
+            assert_eq!(v.to_string().to_lowercase().parse::<Frequency>(), Ok(v));
+        }
+        assert_eq!(
+            "Semi-Annual".parse::<Frequency>(),
+            Ok(Frequency::Semiannual)
+        );
+        assert_eq!(
+            "every fourth week".parse::<Frequency>(),
+            Ok(Frequency::EveryFourthWeek)
+        );
+        let err = "Fortnightly".parse::<Frequency>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown frequency: 'Fortnightly'");
+    }
+}
@@ -1,11 +1,13 @@
 use crate::types::{Natural, Size};
 
 use super::{
-    date::Date,
+    date::{check_year, Date},
     holidays::{
         brazil::{BrazilExchange, BrazilSettlement},
+        germany::{Eurex, Euwax, FrankfurtStockExchange, GermanySettlement, Xetra},
         italy::{ItalyExchange, ItalySettlement},
         japan::Japan,
+        jointcalendar::JointCalendar,
         nilholiday::NilHoliday,
         switzerland::Switzerland,
         target::Target,
@@ -20,13 +22,19 @@ use super::{
     Day, Year,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Holiday {
     BrazilExchange(BrazilExchange),
     BrazilSettlement(BrazilSettlement),
+    Eurex(Eurex),
+    Euwax(Euwax),
+    FrankfurtStockExchange(FrankfurtStockExchange),
+    GermanySettlement(GermanySettlement),
+    Xetra(Xetra),
     ItalyExchange(ItalyExchange),
     ItalySettlement(ItalySettlement),
     Japan(Japan),
+    JointCalendar(JointCalendar),
     NilHoliday(NilHoliday),
     Switzerland(Switzerland),
     Target(Target),
@@ -50,9 +58,15 @@ impl Holiday {
         match self {
             Holiday::BrazilExchange(h) => h.name(),
             Holiday::BrazilSettlement(h) => h.name(),
+            Holiday::Eurex(h) => h.name(),
+            Holiday::Euwax(h) => h.name(),
+            Holiday::FrankfurtStockExchange(h) => h.name(),
+            Holiday::GermanySettlement(h) => h.name(),
+            Holiday::Xetra(h) => h.name(),
             Holiday::ItalyExchange(h) => h.name(),
             Holiday::ItalySettlement(h) => h.name(),
             Holiday::Japan(h) => h.name(),
+            Holiday::JointCalendar(h) => h.name(),
             Holiday::NilHoliday(h) => h.name(),
             Holiday::Switzerland(h) => h.name(),
             Holiday::Target(h) => h.name(),
@@ -74,9 +88,15 @@ impl Holiday {
         match self {
             Holiday::BrazilExchange(h) => h.is_business_day(date),
             Holiday::BrazilSettlement(h) => h.is_business_day(date),
+            Holiday::Eurex(h) => h.is_business_day(date),
+            Holiday::Euwax(h) => h.is_business_day(date),
+            Holiday::FrankfurtStockExchange(h) => h.is_business_day(date),
+            Holiday::GermanySettlement(h) => h.is_business_day(date),
+            Holiday::Xetra(h) => h.is_business_day(date),
             Holiday::ItalyExchange(h) => h.is_business_day(date),
             Holiday::ItalySettlement(h) => h.is_business_day(date),
             Holiday::Japan(h) => h.is_business_day(date),
+            Holiday::JointCalendar(h) => h.is_business_day(date),
             Holiday::NilHoliday(h) => h.is_business_day(date),
             Holiday::Switzerland(h) => h.is_business_day(date),
             Holiday::Target(h) => h.is_business_day(date),
@@ -98,9 +118,15 @@ impl Holiday {
         match self {
             Holiday::BrazilExchange(h) => h.is_weekend(weekday),
             Holiday::BrazilSettlement(h) => h.is_weekend(weekday),
+            Holiday::Eurex(h) => h.is_weekend(weekday),
+            Holiday::Euwax(h) => h.is_weekend(weekday),
+            Holiday::FrankfurtStockExchange(h) => h.is_weekend(weekday),
+            Holiday::GermanySettlement(h) => h.is_weekend(weekday),
+            Holiday::Xetra(h) => h.is_weekend(weekday),
             Holiday::ItalyExchange(h) => h.is_weekend(weekday),
             Holiday::ItalySettlement(h) => h.is_weekend(weekday),
             Holiday::Japan(h) => h.is_weekend(weekday),
+            Holiday::JointCalendar(h) => h.is_weekend(weekday),
             Holiday::NilHoliday(h) => h.is_weekend(weekday),
             Holiday::Switzerland(h) => h.is_weekend(weekday),
             Holiday::Target(h) => h.is_weekend(weekday),
@@ -119,21 +145,15 @@ impl Holiday {
 }
 
 pub fn easter_monday(year: Year) -> Day {
-    assert!(
-        (1900..2200).contains(&year),
-        "Year must be in the range (1900, 2200)"
-    );
-    // NOTE: guaranteed not to panic due to the assertion above;
+    check_year(year);
+    // NOTE: guaranteed not to panic due to the check above;
     let idx: Size = (year - 1900).try_into().unwrap();
     EASTER_MONDAYS[idx]
 }
 
 pub fn easter_monday_orthodox(year: Year) -> Day {
-    assert!(
-        (1900..2200).contains(&year),
-        "Year must be in the range (1900, 2200)"
-    );
-    // NOTE: guaranteed not to panic due to the assertion above;
+    check_year(year);
+    // NOTE: guaranteed not to panic due to the check above;
     let idx: Size = (year - 1900).try_into().unwrap();
     ORTHODOX_EASTER_MONDAYS[idx]
 }
@@ -1,6 +1,8 @@
 pub mod brazil;
+pub mod germany;
 pub mod italy;
 pub mod japan;
+pub mod jointcalendar;
 pub mod nilholiday;
 pub mod switzerland;
 pub mod target;
@@ -55,6 +55,11 @@ impl Brazil {
         BrazilSettlement::new()
     }
 
+    /// Create an instance of the [BrazilSettlement] calendar
+    pub fn settlement() -> Calendar {
+        BrazilSettlement::new()
+    }
+
     /// Create an instance of the [BrazilExchange] calendar
     pub fn exchange() -> Calendar {
         BrazilExchange::new()
@@ -204,3 +209,67 @@ impl BrazilExchange {
         self.weekend.is_weekend(weekday)
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{date::Date, months::Month::*};
+
+    use super::Brazil;
+
+    #[test]
+    fn test_brazil_settlement() {
+        let c = Brazil::settlement();
+        assert_eq!(c.name(), "Brazil");
+        // Carnival Monday and Tuesday
+        assert!(c.is_holiday(&Date::new(20, February, 2023)));
+        assert!(c.is_holiday(&Date::new(21, February, 2023)));
+        // Ash Wednesday is a business day
+        assert!(!c.is_holiday(&Date::new(22, February, 2023)));
+        // Passion of Christ
+        assert!(c.is_holiday(&Date::new(7, April, 2023)));
+        // Corpus Christi
+        assert!(c.is_holiday(&Date::new(8, June, 2023)));
+        assert!(c.is_holiday(&Date::new(30, May, 2024)));
+        // Our Lady of Aparecida
+        assert!(c.is_holiday(&Date::new(12, October, 2023)));
+        // Sao Paulo City Day is only an exchange holiday
+        assert!(!c.is_holiday(&Date::new(25, January, 2023)));
+
+        let from = Date::new(1, January, 2023);
+        let to = Date::new(31, December, 2023);
+        assert_eq!(
+            c.holiday_list(from, to, false),
+            vec![
+                Date::new(20, February, 2023),
+                Date::new(21, February, 2023),
+                Date::new(7, April, 2023),
+                Date::new(21, April, 2023),
+                Date::new(1, May, 2023),
+                Date::new(8, June, 2023),
+                Date::new(7, September, 2023),
+                Date::new(12, October, 2023),
+                Date::new(2, November, 2023),
+                Date::new(15, November, 2023),
+                Date::new(25, December, 2023),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_brazil_exchange() {
+        let c = Brazil::exchange();
+        assert_eq!(c.name(), "BOVESPA");
+        assert!(c.is_holiday(&Date::new(21, February, 2023)));
+        assert!(c.is_holiday(&Date::new(8, June, 2023)));
+        // Sao Paulo City Day
+        assert!(c.is_holiday(&Date::new(25, January, 2023)));
+        // Black Consciousness Day, since 2007
+        assert!(c.is_holiday(&Date::new(20, November, 2023)));
+        assert!(!c.is_holiday(&Date::new(20, November, 2006)));
+        // last business day of the year
+        assert!(c.is_holiday(&Date::new(29, December, 2023)));
+        assert!(c.is_holiday(&Date::new(24, December, 2024)));
+    }
+}
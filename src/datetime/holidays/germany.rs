@@ -0,0 +1,392 @@
+use std::fmt::Debug;
+
+use crate::datetime::{
+    calendar::{easter_monday, Calendar},
+    date::Date,
+    holiday,
+    months::Month::*,
+    weekday::Weekday,
+    weekend::{Weekend, WesternWeekend},
+};
+
+// -------------------------------------------------------------------------------------------------
+
+/// German calendars: settlement, Frankfurt stock exchange, Xetra, Eurex and Euwax
+#[derive(Clone)]
+pub struct Germany {}
+
+impl Germany {
+    #[allow(clippy::new_ret_no_self)]
+    /// The default calendar is the [GermanySettlement] calendar
+    pub fn new() -> Calendar {
+        GermanySettlement::new()
+    }
+
+    /// Create an instance of [FrankfurtStockExchange] calendar
+    pub fn frankfurt_stock_exchange() -> Calendar {
+        FrankfurtStockExchange::new()
+    }
+
+    /// Create an instance of [Xetra] calendar
+    pub fn xetra() -> Calendar {
+        Xetra::new()
+    }
+
+    /// Create an instance of [Eurex] calendar
+    pub fn eurex() -> Calendar {
+        Eurex::new()
+    }
+
+    /// Create an instance of [Euwax] calendar
+    pub fn euwax() -> Calendar {
+        Euwax::new()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// German settlement calendar
+///
+/// Holidays are the federal ones, regional holidays such as Epiphany being left out:
+/// * Saturdays
+/// * Sundays
+/// * New Year's Day, January 1st
+/// * Good Friday
+/// * Easter Monday
+/// * Ascension Thursday
+/// * Whit Monday
+/// * Corpus Christi
+/// * Labour Day, May 1st
+/// * National Day, October 3rd
+/// * Christmas Eve, December 24th
+/// * Christmas, December 25th
+/// * Boxing Day, December 26th
+#[derive(Clone, Copy)]
+pub struct GermanySettlement {
+    pub weekend: Weekend,
+}
+
+impl Debug for GermanySettlement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl GermanySettlement {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Calendar {
+        Calendar::new(holiday::Holiday::GermanySettlement(Self {
+            weekend: Weekend::WesternWeekend(WesternWeekend {}),
+        }))
+    }
+
+    pub fn name(&self) -> String {
+        "German settlement".into()
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        let w = date.weekday();
+        let d = date.day_of_month();
+        let dd = date.day_of_year();
+        let m = date.month();
+        let y = date.year();
+        let em = easter_monday(y);
+
+        if self.is_weekend(w)
+            // New Year's Day
+            || (d == 1 && m == January)
+            // Good Friday
+            || (dd == em-3)
+            // Easter Monday
+            || (dd == em)
+            // Ascension Thursday
+            || (dd == em+38)
+            // Whit Monday
+            || (dd == em+49)
+            // Corpus Christi
+            || (dd == em+59)
+            // Labour Day
+            || (d == 1 && m == May)
+            // National Day
+            || (d == 3 && m == October)
+            // Christmas Eve
+            || (d == 24 && m == December)
+            // Christmas
+            || (d == 25 && m == December)
+            // Boxing Day
+            || (d == 26 && m == December)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.is_weekend(weekday)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Frankfurt stock exchange calendar
+///
+/// Holidays:
+/// * Saturdays
+/// * Sundays
+/// * New Year's Day, January 1st
+/// * Good Friday
+/// * Easter Monday
+/// * Labour Day, May 1st
+/// * Christmas Eve, December 24th
+/// * Christmas, December 25th
+/// * Boxing Day, December 26th
+#[derive(Clone, Copy)]
+pub struct FrankfurtStockExchange {
+    pub weekend: Weekend,
+}
+
+impl Debug for FrankfurtStockExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FrankfurtStockExchange {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Calendar {
+        Calendar::new(holiday::Holiday::FrankfurtStockExchange(Self {
+            weekend: Weekend::WesternWeekend(WesternWeekend {}),
+        }))
+    }
+
+    pub fn name(&self) -> String {
+        "Frankfurt stock exchange".into()
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        !self.is_weekend(date.weekday()) && !is_exchange_holiday(date)
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.is_weekend(weekday)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Xetra calendar, with the same holidays as the [FrankfurtStockExchange]
+#[derive(Clone, Copy)]
+pub struct Xetra {
+    pub weekend: Weekend,
+}
+
+impl Debug for Xetra {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Xetra {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Calendar {
+        Calendar::new(holiday::Holiday::Xetra(Self {
+            weekend: Weekend::WesternWeekend(WesternWeekend {}),
+        }))
+    }
+
+    pub fn name(&self) -> String {
+        "Xetra".into()
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        !self.is_weekend(date.weekday()) && !is_exchange_holiday(date)
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.is_weekend(weekday)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Eurex calendar
+///
+/// Holidays are those of the [FrankfurtStockExchange] plus New Year's Eve, December 31st.
+#[derive(Clone, Copy)]
+pub struct Eurex {
+    pub weekend: Weekend,
+}
+
+impl Debug for Eurex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Eurex {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Calendar {
+        Calendar::new(holiday::Holiday::Eurex(Self {
+            weekend: Weekend::WesternWeekend(WesternWeekend {}),
+        }))
+    }
+
+    pub fn name(&self) -> String {
+        "Eurex".into()
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        let new_years_eve = date.day_of_month() == 31 && date.month() == December;
+        !self.is_weekend(date.weekday()) && !is_exchange_holiday(date) && !new_years_eve
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.is_weekend(weekday)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Euwax calendar
+///
+/// Holidays are those of the [FrankfurtStockExchange] plus Whit Monday.
+#[derive(Clone, Copy)]
+pub struct Euwax {
+    pub weekend: Weekend,
+}
+
+impl Debug for Euwax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Euwax {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Calendar {
+        Calendar::new(holiday::Holiday::Euwax(Self {
+            weekend: Weekend::WesternWeekend(WesternWeekend {}),
+        }))
+    }
+
+    pub fn name(&self) -> String {
+        "Euwax".into()
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        let whit_monday = date.day_of_year() == easter_monday(date.year()) + 49;
+        !self.is_weekend(date.weekday()) && !is_exchange_holiday(date) && !whit_monday
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.is_weekend(weekday)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Holidays shared by the German exchanges, i.e. those of the [FrankfurtStockExchange], weekends
+/// aside
+fn is_exchange_holiday(date: &Date) -> bool {
+    let d = date.day_of_month();
+    let dd = date.day_of_year();
+    let m = date.month();
+    let em = easter_monday(date.year());
+
+    // New Year's Day
+    (d == 1 && m == January)
+        // Good Friday
+        || (dd == em - 3)
+        // Easter Monday
+        || (dd == em)
+        // Labour Day
+        || (d == 1 && m == May)
+        // Christmas Eve
+        || (d == 24 && m == December)
+        // Christmas
+        || (d == 25 && m == December)
+        // Boxing Day
+        || (d == 26 && m == December)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::calendar::Calendar;
+    use crate::datetime::date::Date;
+    use crate::datetime::months::Month::*;
+
+    use super::Germany;
+
+    fn check_holidays(c: &Calendar, expected_hol: &[Date]) {
+        let hol = c.holiday_list(
+            Date::new(1, January, 2004),
+            Date::new(31, December, 2004),
+            false,
+        );
+
+        assert!(
+            hol.len() == expected_hol.len(),
+            "there were {} expected holidays, while there are {} calculated holidays for {:?}",
+            expected_hol.len(),
+            hol.len(),
+            c
+        );
+
+        for i in 0..expected_hol.len() {
+            assert!(
+                hol[i] == expected_hol[i],
+                "expected holiday was {:?} while calculated holiday is {:?}",
+                expected_hol[i],
+                hol[i]
+            );
+        }
+    }
+
+    #[test]
+    fn test_settlement() {
+        // Labour Day, National Day, Christmas and Boxing Day fall on weekends in 2004
+        let expected_hol = [
+            Date::new(1, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            Date::new(20, May, 2004),
+            Date::new(31, May, 2004),
+            Date::new(10, June, 2004),
+            Date::new(24, December, 2004),
+        ];
+        check_holidays(&Germany::new(), &expected_hol);
+    }
+
+    #[test]
+    fn test_exchanges() {
+        let expected_hol = [
+            Date::new(1, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            Date::new(24, December, 2004),
+        ];
+        check_holidays(&Germany::frankfurt_stock_exchange(), &expected_hol);
+        check_holidays(&Germany::xetra(), &expected_hol);
+
+        let expected_hol = [
+            Date::new(1, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            Date::new(24, December, 2004),
+            Date::new(31, December, 2004),
+        ];
+        check_holidays(&Germany::eurex(), &expected_hol);
+
+        let expected_hol = [
+            Date::new(1, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            Date::new(31, May, 2004),
+            Date::new(24, December, 2004),
+        ];
+        check_holidays(&Germany::euwax(), &expected_hol);
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Debug;
+
+use crate::datetime::{calendar::Calendar, date::Date, holiday, weekday::Weekday};
+
+/// Joint calendar
+///
+/// Combines the holidays of the given calendars: a date is a business day only if it is a
+/// business day for every one of them, e.g. London and New York for the value dates of USD Libor.
+#[derive(Clone)]
+pub struct JointCalendar {
+    pub calendars: Vec<Calendar>,
+}
+
+impl Debug for JointCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl JointCalendar {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(calendars: Vec<Calendar>) -> Calendar {
+        assert!(!calendars.is_empty(), "no calendars given");
+        Calendar::new(holiday::Holiday::JointCalendar(Self { calendars }))
+    }
+
+    pub fn name(&self) -> String {
+        let names: Vec<String> = self.calendars.iter().map(|c| c.name()).collect();
+        format!("JoinHolidays({})", names.join(", "))
+    }
+
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        self.calendars.iter().all(|c| c.is_business_day(date))
+    }
+
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.calendars.iter().any(|c| c.is_weekend(weekday))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{
+        date::Date,
+        holidays::{unitedkingdom::UnitedKingdom, unitedstates::UnitedStates},
+        months::Month::{July, May},
+        weekday::Weekday,
+    };
+
+    use super::JointCalendar;
+
+    #[test]
+    fn test_joint_calendar() {
+        let calendar = JointCalendar::new(vec![
+            UnitedKingdom::exchange(),
+            UnitedStates::libor_impact(),
+        ]);
+        assert_eq!(
+            calendar.name(),
+            "JoinHolidays(London stock exchange, US with Libor impact)"
+        );
+        // Independence Day is a holiday in New York only
+        assert!(calendar.is_holiday(&Date::new(4, July, 2023)));
+        // the early May bank holiday is a holiday in London only
+        assert!(calendar.is_holiday(&Date::new(1, May, 2023)));
+        assert!(calendar.is_business_day(&Date::new(5, July, 2023)));
+        assert!(calendar.is_weekend(Weekday::Saturday));
+        assert!(!calendar.is_weekend(Weekday::Monday));
+    }
+}
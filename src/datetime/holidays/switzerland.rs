@@ -9,6 +9,21 @@ use crate::datetime::{
     weekend::{Weekend, WesternWeekend},
 };
 
+/// Swiss calendar
+///
+/// Holidays:
+/// * Saturdays
+/// * Sundays
+/// * New Year's Day, January 1st
+/// * Berchtoldstag, January 2nd
+/// * Good Friday
+/// * Easter Monday
+/// * Ascension Day
+/// * Whit Monday
+/// * Labour Day, May 1st
+/// * National Day, August 1st
+/// * Christmas, December 25th
+/// * St. Stephen's Day, December 26th
 #[derive(Clone, Copy)]
 pub struct Switzerland {
     weekend: Weekend,
@@ -71,3 +86,50 @@ impl Switzerland {
         self.weekend.is_weekend(weekday)
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::date::Date;
+    use crate::datetime::months::Month::*;
+
+    use super::Switzerland;
+
+    #[test]
+    fn test_switzerland() {
+        // Labour Day, National Day, Christmas and St. Stephen's Day fall on weekends in 2004
+        let expected_hol = [
+            Date::new(1, January, 2004),
+            Date::new(2, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            Date::new(20, May, 2004),
+            Date::new(31, May, 2004),
+        ];
+
+        let c = Switzerland::new();
+
+        let hol = c.holiday_list(
+            Date::new(1, January, 2004),
+            Date::new(31, December, 2004),
+            false,
+        );
+
+        assert!(
+            hol.len() == expected_hol.len(),
+            "there were {} expected holidays, while there are {} calculated holidays",
+            expected_hol.len(),
+            hol.len()
+        );
+
+        for i in 0..expected_hol.len() {
+            assert!(
+                hol[i] == expected_hol[i],
+                "expected holiday was {:?} while calculated holiday is {:?}",
+                expected_hol[i],
+                hol[i]
+            );
+        }
+    }
+}
@@ -78,3 +78,113 @@ impl Target {
         self.weekend.is_weekend(weekday)
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::date::Date;
+    use crate::datetime::months::Month::*;
+
+    use super::Target;
+
+    #[test]
+    fn test_target() {
+        let expected_hol = vec![
+            Date::new(1, January, 1999),
+            Date::new(31, December, 1999),
+            //
+            Date::new(21, April, 2000),
+            Date::new(24, April, 2000),
+            Date::new(1, May, 2000),
+            Date::new(25, December, 2000),
+            Date::new(26, December, 2000),
+            //
+            Date::new(1, January, 2001),
+            Date::new(13, April, 2001),
+            Date::new(16, April, 2001),
+            Date::new(1, May, 2001),
+            Date::new(25, December, 2001),
+            Date::new(26, December, 2001),
+            Date::new(31, December, 2001),
+            //
+            Date::new(1, January, 2002),
+            Date::new(29, March, 2002),
+            Date::new(1, April, 2002),
+            Date::new(1, May, 2002),
+            Date::new(25, December, 2002),
+            Date::new(26, December, 2002),
+            //
+            Date::new(1, January, 2003),
+            Date::new(18, April, 2003),
+            Date::new(21, April, 2003),
+            Date::new(1, May, 2003),
+            Date::new(25, December, 2003),
+            Date::new(26, December, 2003),
+            //
+            Date::new(1, January, 2004),
+            Date::new(9, April, 2004),
+            Date::new(12, April, 2004),
+            //
+            Date::new(25, March, 2005),
+            Date::new(28, March, 2005),
+            Date::new(26, December, 2005),
+            //
+            Date::new(14, April, 2006),
+            Date::new(17, April, 2006),
+            Date::new(1, May, 2006),
+            Date::new(25, December, 2006),
+            Date::new(26, December, 2006),
+        ];
+
+        let c = Target::new();
+
+        let hol = c.holiday_list(
+            Date::new(1, January, 1999),
+            Date::new(31, December, 2006),
+            false,
+        );
+
+        assert!(
+            hol.len() == expected_hol.len(),
+            "there were {} expected holidays, while there are {} calculated holidays",
+            expected_hol.len(),
+            hol.len()
+        );
+
+        for i in 0..expected_hol.len() {
+            assert!(
+                hol[i] == expected_hol[i],
+                "expected holiday was {:?} while calculated holiday is {:?}",
+                expected_hol[i],
+                hol[i]
+            );
+        }
+    }
+
+    #[test]
+    fn test_target_before_2000() {
+        let c = Target::new();
+        // only New Year's Day, Christmas and the 1998 year-end closure
+        let hol = c.holiday_list(
+            Date::new(1, January, 1998),
+            Date::new(31, December, 1998),
+            false,
+        );
+        assert_eq!(
+            hol,
+            vec![
+                Date::new(1, January, 1998),
+                Date::new(25, December, 1998),
+                Date::new(31, December, 1998),
+            ]
+        );
+        // Good Friday, Easter Monday, Labour Day and December 26th are business days
+        assert!(c.is_business_day(&Date::new(10, April, 1998)));
+        assert!(c.is_business_day(&Date::new(13, April, 1998)));
+        assert!(c.is_business_day(&Date::new(1, May, 1998)));
+        assert!(c.is_business_day(&Date::new(26, December, 1997)));
+        // no year-end closure in 2000
+        assert!(c.is_business_day(&Date::new(29, December, 2000)));
+    }
+}
@@ -616,7 +616,7 @@ mod test {
         }
 
         // before Uniform Monday Holiday Act
-        let expected_hol = vec![
+        let expected_hol = [
             Date::new(2, January, 1961),
             Date::new(22, February, 1961),
             Date::new(30, May, 1961),
@@ -648,6 +648,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_holidays_by_year() {
+        let c = UnitedStates::settlement();
+        let by_year = c.holidays_by_year(2004, 2005);
+        assert_eq!(
+            by_year.keys().copied().collect::<Vec<_>>(),
+            vec![2004, 2005]
+        );
+
+        // New Year's Day 2005, a Saturday, is observed on Friday 31 December 2004
+        assert_eq!(by_year[&2004].len(), 11);
+        assert_eq!(by_year[&2004][0], Date::new(1, January, 2004));
+        assert_eq!(by_year[&2004][10], Date::new(31, December, 2004));
+        assert_eq!(by_year[&2005].len(), 9);
+        assert_eq!(by_year[&2005][0], Date::new(17, January, 2005));
+        assert_eq!(by_year[&2005][8], Date::new(26, December, 2005));
+
+        let all: Vec<Date> = by_year.into_values().flatten().collect();
+        let expected = c.holiday_list(
+            Date::new(1, January, 2004),
+            Date::new(31, December, 2005),
+            false,
+        );
+        assert_eq!(all, expected);
+    }
+
     #[test]
     fn test_government_bond() {
         let expected_hol = vec![
@@ -175,7 +175,7 @@ impl IMM {
             }
         }
 
-        let mut result = Date::nth_weekday(3, Wednesday, m, y);
+        let mut result = Date::third_wednesday(m, y);
         if &result <= ref_date {
             result = self.next_date(&Date::new(22, m, y), main_cycle);
         }
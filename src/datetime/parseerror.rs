@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Error returned when a date-related value, such as a convention or a frequency, cannot be
+/// parsed from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What was being parsed, e.g. "business day convention"
+    pub kind: &'static str,
+    /// The text which could not be parsed
+    pub input: String,
+}
+
+impl ParseError {
+    pub fn new(kind: &'static str, input: &str) -> Self {
+        Self {
+            kind,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {}: '{}'", self.kind, self.input)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Lower-case `name` and drop the separators, so that e.g. "Modified Following",
+/// "modified-following" and "ModifiedFollowing" all compare equal.
+pub(crate) fn normalise_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -1,14 +1,20 @@
 use std::{
+    fmt::Debug,
     hash::Hash,
-    ops::{AddAssign, Div, DivAssign, Mul, Neg, SubAssign}, fmt::Debug,
+    ops::{AddAssign, Div, DivAssign, Mul, Neg, SubAssign},
+    str::FromStr,
 };
 
 use crate::types::{Integer, Real};
 
 use crate::datetime::{
-    frequency::Frequency, frequency::Frequency::*, timeunit::TimeUnit, timeunit::TimeUnit::*,
+    frequency::Frequency, frequency::Frequency::*, parseerror::ParseError, timeunit::TimeUnit,
+    timeunit::TimeUnit::*,
 };
 
+/// Error returned when a [Period] cannot be parsed from a tenor string
+pub type ParsePeriodError = ParseError;
+
 #[derive(Clone, Copy, Eq)]
 /// Provides a period represented by a length and [TimeUnit].
 pub struct Period {
@@ -36,16 +42,28 @@ impl Debug for Period {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Two periods are equal when they represent exactly the same length of time, i.e. when their
+/// normalised forms coincide: `12M == 1Y` and `14D == 2W`, but `30D != 1M` and `365D != 1Y`,
+/// since the number of days in a month or year depends on the date the period is applied to.
+/// All zero-length periods are equal.
+///
+/// Note that equality is stricter than the ordering: periods which cannot be ordered exactly,
+/// such as `4W` and `1M`, are never equal even though [PartialOrd] gives them an order based on
+/// their bounds in days.
 impl PartialEq for Period {
     fn eq(&self, other: &Self) -> bool {
-        !(self < other || other < self)
+        let p1 = self.normalised();
+        let p2 = other.normalised();
+        p1.length == p2.length && p1.unit == p2.unit
     }
 }
 
+/// Hashes the normalised period, consistently with [PartialEq].
 impl Hash for Period {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.length.hash(state);
-        self.unit.hash(state);
+        let p = self.normalised();
+        p.length.hash(state);
+        p.unit.hash(state);
     }
 }
 
@@ -256,10 +274,16 @@ impl Mul<Integer> for Period {
     type Output = Self;
 
     fn mul(self, rhs: Integer) -> Self::Output {
-        Self {
-            length: rhs * self.length,
-            unit: self.unit,
-        }
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| panic!("{:?} * {} overflows", self, rhs))
+    }
+}
+
+impl Mul<Period> for Integer {
+    type Output = Period;
+
+    fn mul(self, rhs: Period) -> Self::Output {
+        rhs * self
     }
 }
 
@@ -271,8 +295,20 @@ impl Period {
         Self { length, unit }
     }
 
+    /// Multiply the length of this [Period] by `n`, returning `None` if the result overflows.
+    pub fn checked_mul(self, n: Integer) -> Option<Period> {
+        self.length
+            .checked_mul(n)
+            .map(|length| Period::new(length, self.unit))
+    }
+
     /// Return the [Frequency] that corresponds to this [Period].
+    ///
+    /// Negative periods have no corresponding frequency and give [Frequency::NoFrequency].
     pub fn frequency(&self) -> Frequency {
+        if self.length < 0 {
+            return NoFrequency;
+        }
         if self.length == 0 {
             if self.unit == Years {
                 return Once;
@@ -316,7 +352,11 @@ impl Period {
         }
     }
 
-    /// Normalise length and units
+    /// Normalise length and units.
+    ///
+    /// A zero length period becomes `0` days, whatever its unit. Otherwise months are converted
+    /// to years and days to weeks when the length is an exact multiple, keeping the sign, e.g.
+    /// `-24M` becomes `-2Y` and `-7D` becomes `-1W`. Intraday units are left unchanged.
     pub fn normalise(&mut self) {
         if self.length == 0 {
             self.unit = Days;
@@ -335,9 +375,8 @@ impl Period {
                     }
                 }
 
-                Weeks => {}
-                Years => {}
-                other => panic!("Invalid timeunit: {:?}", other),
+                Weeks | Years => {}
+                Hours | Minutes | Seconds | Milliseconds | Microseconds => {}
             }
         }
     }
@@ -419,11 +458,93 @@ impl Period {
 
 // -------------------------------------------------------------------------------------------------
 
+/// Parses market tenors such as "1W", "3M", "10Y" or "2D", in any case and with an optional
+/// sign. Compound tenors such as "1Y6M" or "1W3D" are accepted as long as their units can be
+/// added, i.e. years with months or weeks with days.
+impl FromStr for Period {
+    type Err = ParsePeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new("period", s);
+        let trimmed = s.trim();
+        let (sign, mut rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        if rest.is_empty() {
+            return Err(error());
+        }
+        let mut result: Option<Period> = None;
+        while !rest.is_empty() {
+            let digits = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(error)?;
+            let length: Integer = rest[..digits].parse().map_err(|_| error())?;
+            let mut chars = rest[digits..].chars();
+            let unit = match chars.next().map(|c| c.to_ascii_uppercase()) {
+                Some('D') => Days,
+                Some('W') => Weeks,
+                Some('M') => Months,
+                Some('Y') => Years,
+                _ => return Err(error()),
+            };
+            rest = chars.as_str();
+            let period = Period::new(sign * length, unit);
+            result = match result {
+                None => Some(period),
+                Some(mut total) => {
+                    let compatible = matches!(
+                        (total.unit, unit),
+                        (Years | Months, Years | Months) | (Weeks | Days, Weeks | Days)
+                    );
+                    if !compatible {
+                        return Err(error());
+                    }
+                    total += period;
+                    Some(total)
+                }
+            };
+        }
+        result.ok_or_else(error)
+    }
+}
+
+/// Parses each of the given tenors, e.g. to define the pillars of a curve.
+///
+/// The first tenor which cannot be parsed is returned as the error.
+pub fn tenor_grid(tenors: &[&str]) -> Result<Vec<Period>, ParsePeriodError> {
+    tenors.iter().map(|tenor| tenor.parse()).collect()
+}
+
+/// Usual pillars of a deposit and swap curve, from one week to thirty years, in increasing
+/// order.
+pub fn standard_deposit_swap_grid() -> Vec<Period> {
+    let mut grid = vec![
+        Period::new(1, Weeks),
+        Period::new(1, Months),
+        Period::new(3, Months),
+        Period::new(6, Months),
+    ];
+    grid.extend(
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 15, 20, 25, 30]
+            .into_iter()
+            .map(|n| Period::new(n, Years)),
+    );
+    grid
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[cfg(test)]
 mod test {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+    };
+
     use crate::datetime::{frequency::Frequency::*, timeunit::TimeUnit::*};
 
-    use super::Period;
+    use crate::types::Integer;
+
+    use super::{standard_deposit_swap_grid, tenor_grid, Period};
 
     #[test]
     fn test_from_frequency() {
@@ -513,6 +634,11 @@ mod test {
         assert_eq!(p.frequency(), Annual);
         let p = Period::new(2, Years);
         assert_eq!(p.frequency(), OtherFrequency);
+
+        let p = Period::new(-3, Months);
+        assert_eq!(p.frequency(), NoFrequency);
+        let p = Period::new(-1, Years);
+        assert_eq!(p.frequency(), NoFrequency);
     }
 
     #[test]
@@ -550,6 +676,33 @@ mod test {
         p.normalise();
         assert_eq!(p.length, 4);
         assert_eq!(p.unit, Years);
+
+        // negative lengths normalise symmetrically
+        let mut p = Period::new(-24, Months);
+        p.normalise();
+        assert_eq!(p.length, -2);
+        assert_eq!(p.unit, Years);
+
+        let mut p = Period::new(-7, Days);
+        p.normalise();
+        assert_eq!(p.length, -1);
+        assert_eq!(p.unit, Weeks);
+
+        let p = Period::new(-5, Months).normalised();
+        assert_eq!(p.length, -5);
+        assert_eq!(p.unit, Months);
+
+        // zero length periods are always 0 days
+        for unit in [Days, Weeks, Months, Years, Hours, Minutes, Seconds] {
+            let p = Period::new(0, unit).normalised();
+            assert_eq!(p.length, 0);
+            assert_eq!(p.unit, Days);
+        }
+
+        // intraday units are left alone
+        let p = Period::new(60, Minutes).normalised();
+        assert_eq!(p.length, 60);
+        assert_eq!(p.unit, Minutes);
     }
 
     #[test]
@@ -673,6 +826,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_multiplication() {
+        let two_months = Period::new(2, Months);
+        assert_eq!(two_months * 3, Period::new(6, Months));
+        assert_eq!(3 * two_months, Period::new(6, Months));
+        assert_eq!((3 * two_months).unit, Months);
+
+        // negative multipliers, as used when generating schedules backwards
+        let periods = 4;
+        assert_eq!(
+            Period::new(3, Months) * (-periods),
+            Period::new(-12, Months)
+        );
+        assert_eq!(-periods * Period::new(1, Years), Period::new(-4, Years));
+        assert_eq!(Period::new(-1, Weeks) * -2, Period::new(2, Weeks));
+
+        assert_eq!(two_months.checked_mul(3), Some(Period::new(6, Months)));
+        assert_eq!(Period::new(Integer::MAX / 2 + 1, Days).checked_mul(2), None);
+        assert_eq!(Period::new(Integer::MIN, Days).checked_mul(-1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_multiplication_overflow() {
+        let _ = Period::new(Integer::MAX, Months) * 2;
+    }
+
     #[test]
     fn test_weekday_algebra() {
         let two_weeks = Period::new(2, Weeks);
@@ -799,4 +979,81 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1W".parse::<Period>(), Ok(Period::new(1, Weeks)));
+        assert_eq!("3m".parse::<Period>(), Ok(Period::new(3, Months)));
+        assert_eq!(" 10Y ".parse::<Period>(), Ok(Period::new(10, Years)));
+        assert_eq!("-2D".parse::<Period>(), Ok(Period::new(-2, Days)));
+        let p = "1Y6M".parse::<Period>().unwrap();
+        assert_eq!((p.length, p.unit), (18, Months));
+        let p = "1W3D".parse::<Period>().unwrap();
+        assert_eq!((p.length, p.unit), (10, Days));
+
+        for invalid in ["", "-", "Y", "3", "3X", "1Y2D", "1.5Y", "M3"] {
+            let error = invalid.parse::<Period>().unwrap_err();
+            assert_eq!(error.kind, "period");
+            assert_eq!(error.input, invalid);
+        }
+    }
+
+    #[test]
+    fn test_tenor_grid() {
+        let grid =
+            tenor_grid(&["1W", "2W", "1M", "3M", "6M", "9M", "1Y", "18M", "2Y", "30Y"]).unwrap();
+        assert_eq!(grid.len(), 10);
+        assert_eq!(grid[7], Period::new(18, Months));
+        assert!(grid.windows(2).all(|w| w[0] < w[1]), "{:?}", grid);
+
+        let error = tenor_grid(&["1M", "oops", "2Y"]).unwrap_err();
+        assert_eq!(error.input, "oops");
+
+        let standard = standard_deposit_swap_grid();
+        assert_eq!(standard.first(), Some(&Period::new(1, Weeks)));
+        assert_eq!(standard.last(), Some(&Period::new(30, Years)));
+        assert!(standard.windows(2).all(|w| w[0] < w[1]), "{:?}", standard);
+        let parsed = tenor_grid(&["1W", "1M", "3M", "6M", "1Y", "2Y", "3Y"]).unwrap();
+        assert_eq!(parsed, standard[..7]);
+    }
+
+    #[test]
+    fn test_equality_and_hash() {
+        let hash = |p: Period| {
+            let mut hasher = DefaultHasher::new();
+            p.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let equal = [
+            (Period::new(12, Months), Period::new(1, Years)),
+            (Period::new(-24, Months), Period::new(-2, Years)),
+            (Period::new(14, Days), Period::new(2, Weeks)),
+            (Period::new(0, Months), Period::new(0, Days)),
+            (Period::new(3, Months), Period::new(3, Months)),
+        ];
+        for (p1, p2) in equal {
+            assert_eq!(p1, p2);
+            assert_eq!(hash(p1), hash(p2), "{:?} and {:?}", p1, p2);
+        }
+
+        let different = [
+            (Period::new(30, Days), Period::new(1, Months)),
+            (Period::new(4, Weeks), Period::new(1, Months)),
+            (Period::new(365, Days), Period::new(1, Years)),
+            (Period::new(13, Months), Period::new(1, Years)),
+        ];
+        for (p1, p2) in different {
+            assert_ne!(p1, p2);
+        }
+
+        let set: HashSet<Period> = [
+            Period::new(12, Months),
+            Period::new(1, Years),
+            Period::new(6, Months),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
 }
@@ -1,20 +1,28 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::ops::Index;
 
 use crate::context::pricing_context::PricingContext;
-use crate::maths::bounds::lower_bound;
+use crate::maths::bounds::{lower_bound, upper_bound};
 use crate::types::{Integer, Size};
 
 use crate::datetime::{
-    businessdayconvention::BusinessDayConvention, calendar::Calendar, date::Date,
-    dategenerationrule::DateGenerationRule, holidays::nilholiday::NilHoliday, imm::IMM,
-    period::Period, timeunit::TimeUnit::*, weekday::Weekday::*,
+    businessdayconvention::BusinessDayConvention,
+    calendar::Calendar,
+    date::Date,
+    dategenerationrule::DateGenerationRule,
+    holidays::nilholiday::NilHoliday,
+    imm::IMM,
+    period::Period,
+    timeunit::TimeUnit::{self, *},
 };
 
 /// Payment Schedule
 #[derive(Clone)]
 pub struct Schedule {
     pricing_context: PricingContext,
+    // effective and termination dates as given to the constructor, kept for regeneration
+    effective_date: Date,
+    termination_date: Date,
     dates: Vec<Date>,
     calendar: Calendar,
     convention: BusinessDayConvention,
@@ -25,6 +33,8 @@ pub struct Schedule {
     is_regular: Vec<bool>,
     first_date: Date,
     next_to_last_date: Date,
+    // false for schedules built from an explicit list of dates
+    rule_based: bool,
 }
 
 /// Convenient indexing operation on the Schedule.
@@ -40,23 +50,169 @@ impl Index<Size> for Schedule {
 
 impl Debug for Schedule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Schedule[")?;
-        for d in self.dates() {
-            writeln!(
+        writeln!(
+            f,
+            "Schedule[tenor: {:?}, calendar: {}, convention: {:?}, rule: {}, end of month: {}]",
+            self.tenor,
+            self.calendar.name(),
+            self.convention,
+            self.rule,
+            self.end_of_month
+        )?;
+        write!(f, "{}", self.to_table())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Reasons for which a rule based schedule cannot be generated
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleError {
+    /// The termination date is the null date
+    NullTerminationDate,
+    /// The effective date is the null date and cannot be inferred
+    NullEffectiveDate,
+    /// The effective date is to be inferred from an evaluation date which is not earlier than
+    /// the termination date
+    EvaluationDateAfterTermination {
+        eval_date: Date,
+        termination_date: Date,
+    },
+    /// The effective date is not earlier than the termination date
+    EffectiveDateAfterTermination {
+        effective_date: Date,
+        termination_date: Date,
+    },
+    /// The tenor is negative; a zero tenor is allowed and gives a [DateGenerationRule::Zero]
+    /// schedule
+    NegativeTenor(Period),
+    /// The first date is not in (effective date, termination date]
+    FirstDateOutOfRange {
+        first_date: Date,
+        effective_date: Date,
+        termination_date: Date,
+    },
+    /// The next to last date is not in [effective date, termination date)
+    NextToLastDateOutOfRange {
+        next_to_last_date: Date,
+        effective_date: Date,
+        termination_date: Date,
+    },
+    /// A first or next to last date given with the [DateGenerationRule::ThirdWednesday] rule is
+    /// not an IMM date
+    NotImmDate(Date),
+    /// The date generation rule doesn't allow the given feature, e.g. a first date or the
+    /// end-of-month convention
+    IncompatibleRule {
+        rule: DateGenerationRule,
+        feature: &'static str,
+    },
+    /// The generated schedule has a single date, together with the parameters it was generated
+    /// from
+    DegenerateSchedule {
+        date: Date,
+        seed_date: Date,
+        exit_date: Date,
+        effective_date: Date,
+        first_date: Date,
+        next_to_last_date: Date,
+        termination_date: Date,
+        rule: DateGenerationRule,
+        end_of_month: bool,
+    },
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::NullTerminationDate => write!(f, "null termination date"),
+            ScheduleError::NullEffectiveDate => write!(f, "null effective date"),
+            ScheduleError::EvaluationDateAfterTermination {
+                eval_date,
+                termination_date,
+            } => write!(
                 f,
-                "\t{:04}-{:02}-{:02},",
-                d.year(),
-                d.month() as Integer,
-                d.day_of_month()
-            )?;
+                "eval date ({:?}) is >= termination date ({:?})",
+                eval_date, termination_date
+            ),
+            ScheduleError::EffectiveDateAfterTermination {
+                effective_date,
+                termination_date,
+            } => write!(
+                f,
+                "effective date ({:?}) later than or equal to termination date ({:?})",
+                effective_date, termination_date
+            ),
+            ScheduleError::NegativeTenor(tenor) => {
+                write!(f, "negative tenor ({:?}) not allowed", tenor)
+            }
+            ScheduleError::FirstDateOutOfRange {
+                first_date,
+                effective_date,
+                termination_date,
+            } => write!(
+                f,
+                "first date ({:?}) out of effective-termination date range ({:?}, {:?}]",
+                first_date, effective_date, termination_date
+            ),
+            ScheduleError::NextToLastDateOutOfRange {
+                next_to_last_date,
+                effective_date,
+                termination_date,
+            } => write!(
+                f,
+                "next to last date ({:?}) out of effective-termination date range [{:?}, {:?})",
+                next_to_last_date, effective_date, termination_date
+            ),
+            ScheduleError::NotImmDate(date) => write!(f, "{:?} is not an IMM date", date),
+            ScheduleError::IncompatibleRule { rule, feature } => write!(
+                f,
+                "{} incompatible with {:?} date generation rule",
+                feature, rule
+            ),
+            ScheduleError::DegenerateSchedule {
+                date,
+                seed_date,
+                exit_date,
+                effective_date,
+                first_date,
+                next_to_last_date,
+                termination_date,
+                rule,
+                end_of_month,
+            } => write!(
+                f,
+                "degenerate single date ({:?}) schedule\
+                 \n seed date: {:?}\
+                 \n exit date: {:?}\
+                 \n effective date: {:?}\
+                 \n first date: {:?}\
+                 \n next to last date: {:?}\
+                 \n termination date: {:?}\
+                 \n generation rule: {:?}\
+                 \n end of month: {:?}",
+                date,
+                seed_date,
+                exit_date,
+                effective_date,
+                first_date,
+                next_to_last_date,
+                termination_date,
+                rule,
+                end_of_month
+            ),
         }
-        writeln!(f, "]")?;
-        Ok(())
     }
 }
 
+impl std::error::Error for ScheduleError {}
+
+// -------------------------------------------------------------------------------------------------
+
 impl Schedule {
     /// Rule based constructor
+    ///
+    /// Panics if the schedule cannot be generated; see [Schedule::try_new].
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         pricing_context: PricingContext,
@@ -71,9 +227,42 @@ impl Schedule {
         first: Date,
         next_to_last: Date,
     ) -> Self {
+        Schedule::try_new(
+            pricing_context,
+            effective_date,
+            termination_date,
+            tenor,
+            calendar,
+            convention,
+            termination_date_convention,
+            date_generation_rule,
+            eom,
+            first,
+            next_to_last,
+        )
+        .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Rule based constructor, returning an error if the schedule cannot be generated
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        pricing_context: PricingContext,
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        calendar: Calendar,
+        convention: BusinessDayConvention,
+        termination_date_convention: BusinessDayConvention,
+        date_generation_rule: DateGenerationRule,
+        eom: bool, // end of month
+        first: Date,
+        next_to_last: Date,
+    ) -> Result<Self, ScheduleError> {
         let eval_date = &pricing_context.eval_date;
         let mut result = Self {
             pricing_context,
+            effective_date,
+            termination_date,
             dates: vec![],
             calendar,
             convention,
@@ -96,13 +285,12 @@ impl Schedule {
             } else {
                 next_to_last
             },
+            rule_based: true,
         };
         // sanity checks
-        assert!(
-            termination_date != Date::default(),
-            "Termination date cannot be the null Date: {:?}",
-            Date::default()
-        );
+        if termination_date == Date::default() {
+            return Err(ScheduleError::NullTerminationDate);
+        }
 
         // in many cases (e.g. non-expired bonds) the effective date is not
         // really necessary. In these cases a decent placeholder is enough
@@ -111,113 +299,93 @@ impl Schedule {
             && first == Date::default()
             && date_generation_rule == DateGenerationRule::Backward
         {
-            assert!(
-                eval_date < &termination_date,
-                "eval date ({:?}) is >= termination date ({:?})",
-                eval_date,
-                termination_date
-            );
-            if next_to_last != Date::default() {
-                let date_diff_serial = &next_to_last - eval_date;
-                let y = date_diff_serial / 366 + 1;
-                effective_date = next_to_last - Period::new(y, Years);
+            if eval_date >= &termination_date {
+                return Err(ScheduleError::EvaluationDateAfterTermination {
+                    eval_date: *eval_date,
+                    termination_date,
+                });
             }
-        } else {
-            assert!(
-                effective_date != Date::default(),
-                "null effective date: {:?}",
-                effective_date
-            );
+            // go back in whole years from the next-to-last or termination date until before the
+            // evaluation date
+            effective_date = if next_to_last != Date::default() {
+                let y = (&next_to_last - eval_date) / 366 + 1;
+                next_to_last - Period::new(y, Years)
+            } else {
+                let y = (&termination_date - eval_date) / 366 + 1;
+                termination_date - Period::new(y, Years)
+            };
+        } else if effective_date == Date::default() {
+            return Err(ScheduleError::NullEffectiveDate);
         }
 
-        assert!(
-            effective_date < termination_date,
-            "effective date ({:?}) later than or equal to termination date ({:?})",
-            effective_date,
-            termination_date
-        );
+        if effective_date >= termination_date {
+            return Err(ScheduleError::EffectiveDateAfterTermination {
+                effective_date,
+                termination_date,
+            });
+        }
 
         if tenor.length == 0 {
             result.rule = DateGenerationRule::Zero;
-        } else {
-            assert!(
-                tenor.length > 0,
-                "non positive tenor ({:?}) not allowed",
-                tenor
-            );
+        } else if tenor.length < 0 {
+            return Err(ScheduleError::NegativeTenor(tenor));
         }
 
         if result.first_date != Date::default() {
             match result.rule {
                 DateGenerationRule::Backward | DateGenerationRule::Forward => {
-                    assert!(
-                        result.first_date > effective_date && result.first_date <= termination_date,
-                        "first date ({:?}) out of effective-termination date range ({:?}, {:?}]",
-                        result.first_date,
-                        effective_date,
-                        termination_date
-                    );
+                    if result.first_date <= effective_date || result.first_date > termination_date {
+                        return Err(ScheduleError::FirstDateOutOfRange {
+                            first_date: result.first_date,
+                            effective_date,
+                            termination_date,
+                        });
+                    }
                     // we should ensure that the above condition is still
                     // verified after adjustment
                 }
                 DateGenerationRule::ThirdWednesday => {
                     let imm = IMM::new(pricing_context);
-                    assert!(
-                        imm.is_imm_date(&result.first_date, false),
-                        "first date ({:?}) is not an IMM date",
-                        result.first_date
-                    );
+                    if !imm.is_imm_date(&result.first_date, false) {
+                        return Err(ScheduleError::NotImmDate(result.first_date));
+                    }
                 }
-                DateGenerationRule::Zero
-                | DateGenerationRule::Twentieth
-                | DateGenerationRule::TwentiethIMM
-                | DateGenerationRule::OldCDS
-                | DateGenerationRule::CDS
-                | DateGenerationRule::CDS2015 => {
-                    panic!(
-                        "first date incompatible with {:?} date generation rule",
-                        result.rule
-                    )
+                rule => {
+                    return Err(ScheduleError::IncompatibleRule {
+                        rule,
+                        feature: "first date",
+                    })
                 }
-                other => panic!("Invalid date generation rule {:?}", other),
             }
         }
 
         if result.next_to_last_date != Date::default() {
             match result.rule {
                 DateGenerationRule::Backward | DateGenerationRule::Forward => {
-                    assert!(
-                        result.next_to_last_date >= effective_date
-                            && result.next_to_last_date < termination_date,
-                        "next to last date ({:?}) out of effective-termination date range \
-                         ({:?}, {:?}]",
-                        result.next_to_last_date,
-                        effective_date,
-                        termination_date
-                    );
+                    if result.next_to_last_date < effective_date
+                        || result.next_to_last_date >= termination_date
+                    {
+                        return Err(ScheduleError::NextToLastDateOutOfRange {
+                            next_to_last_date: result.next_to_last_date,
+                            effective_date,
+                            termination_date,
+                        });
+                    }
                     // we should ensure that the above condition is still
                     // verified after adjustment
                 }
                 DateGenerationRule::ThirdWednesday => {
                     let imm = IMM::new(pricing_context);
-                    assert!(
-                        imm.is_imm_date(&result.next_to_last_date, false),
-                        "next to last date ({:?}) is not an IMM date",
-                        result.next_to_last_date
-                    );
+                    if !imm.is_imm_date(&result.next_to_last_date, false) {
+                        return Err(ScheduleError::NotImmDate(result.next_to_last_date));
+                    }
                 }
-                DateGenerationRule::Zero
-                | DateGenerationRule::Twentieth
-                | DateGenerationRule::TwentiethIMM
-                | DateGenerationRule::OldCDS
-                | DateGenerationRule::CDS
-                | DateGenerationRule::CDS2015 => {
-                    panic!(
-                        "next to last date incompatible with {:?} date generation rule",
-                        result.rule
-                    )
+                rule => {
+                    return Err(ScheduleError::IncompatibleRule {
+                        rule,
+                        feature: "next to last date",
+                    })
                 }
-                other => panic!("Invalid date generation rule {:?}", other),
             }
         }
 
@@ -239,12 +407,8 @@ impl Schedule {
                 if result.next_to_last_date != Date::default() {
                     result.dates.insert(0, result.next_to_last_date); // add to front
                     let period = result.tenor * (-periods);
-                    let temp = nil_holiday.advance_by_period(
-                        seed,
-                        period,
-                        result.convention,
-                        result.end_of_month,
-                    );
+                    let temp =
+                        nil_holiday.advance(seed, period, result.convention, result.end_of_month);
                     if temp != result.next_to_last_date {
                         result.is_regular.insert(0, false);
                     } else {
@@ -258,12 +422,8 @@ impl Schedule {
                 }
                 loop {
                     let period = result.tenor * (-periods);
-                    let temp = nil_holiday.advance_by_period(
-                        seed,
-                        period,
-                        result.convention,
-                        result.end_of_month,
-                    );
+                    let temp =
+                        nil_holiday.advance(seed, period, result.convention, result.end_of_month);
                     if temp < exit_date {
                         if result.first_date != Date::default()
                             && (result
@@ -308,12 +468,11 @@ impl Schedule {
             | DateGenerationRule::CDS2015
             | DateGenerationRule::Forward => {
                 // non-forward rule
-                if result.rule != DateGenerationRule::Forward {
-                    assert!(
-                        !result.end_of_month,
-                        "end of month convention incompatible with {:?} date generation rule",
-                        result.rule
-                    );
+                if result.rule != DateGenerationRule::Forward && result.end_of_month {
+                    return Err(ScheduleError::IncompatibleRule {
+                        rule: result.rule,
+                        feature: "end of month convention",
+                    });
                 }
                 // CDS rules
                 if result.rule == DateGenerationRule::CDS
@@ -334,12 +493,7 @@ impl Schedule {
                 if result.first_date != Date::default() {
                     result.dates.push(result.first_date);
                     let period = result.tenor * periods;
-                    let temp = nil_holiday.advance_by_period(
-                        seed,
-                        period,
-                        convention,
-                        result.end_of_month,
-                    );
+                    let temp = nil_holiday.advance(seed, period, convention, result.end_of_month);
                     if temp != result.first_date {
                         result.is_regular.push(false);
                     } else {
@@ -379,12 +533,7 @@ impl Schedule {
 
                 loop {
                     let period = result.tenor * periods;
-                    let temp = nil_holiday.advance_by_period(
-                        seed,
-                        period,
-                        convention,
-                        result.end_of_month,
-                    );
+                    let temp = nil_holiday.advance(seed, period, convention, result.end_of_month);
                     if temp > exit_date {
                         if result.next_to_last_date != Date::default()
                             && (result
@@ -438,16 +587,11 @@ impl Schedule {
         // adjustments
         if result.rule == DateGenerationRule::ThirdWednesday {
             for i in 1..result.dates.len() - 1 {
-                result.dates[i] = Date::nth_weekday(
-                    3,
-                    Wednesday,
-                    result.dates[i].month(),
-                    result.dates[i].year(),
-                );
+                result.dates[i] = Date::third_wednesday_of(&result.dates[i]);
             }
         } else if result.rule == DateGenerationRule::ThirdWednesdayInclusive {
             for d in result.dates.iter_mut() {
-                *d = Date::nth_weekday(3, Wednesday, d.month(), d.year());
+                *d = Date::third_wednesday_of(d);
             }
         }
 
@@ -528,30 +672,96 @@ impl Schedule {
             result.is_regular.remove(0); // this is expensive
         }
 
+        if result.dates.len() <= 1 {
+            return Err(ScheduleError::DegenerateSchedule {
+                date: result.dates[0],
+                seed_date: seed,
+                exit_date,
+                effective_date,
+                first_date: first,
+                next_to_last_date: next_to_last,
+                termination_date,
+                rule: result.rule,
+                end_of_month: result.end_of_month,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Constructor from an explicit list of dates.
+    ///
+    /// The dates are used as given, without any adjustment or generation; the remaining
+    /// arguments only describe the schedule to the code consuming it. `is_regular` is either
+    /// empty or gives, for each period, whether it is a regular one.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_dates(
+        pricing_context: PricingContext,
+        dates: Vec<Date>,
+        calendar: Calendar,
+        convention: BusinessDayConvention,
+        termination_date_convention: BusinessDayConvention,
+        tenor: Period,
+        rule: DateGenerationRule,
+        end_of_month: bool,
+        is_regular: Vec<bool>,
+    ) -> Self {
+        assert!(!dates.is_empty(), "no dates given");
         assert!(
-            result.dates.len() > 1,
-            "Degenerate single date ({:?}) schedule \
-                 \n seed date: {:?} \
-                 \n exit date: {:?} \
-                 \n effective date: {:?} \
-                 \n first date: {:?} \
-                 \n next to last date: {:?} \
-                 \n termination date: {:?} \
-                 \n generation rule: {:?} \
-                 \n end of month: {:?}
-                 ",
-            result.dates[0],
-            seed,
-            exit_date,
-            effective_date,
-            first,
-            next_to_last,
-            termination_date,
-            result.rule,
-            result.end_of_month
+            dates.windows(2).all(|w| w[0] < w[1]),
+            "dates must be sorted in strictly increasing order: {:?}",
+            dates
+        );
+        assert!(
+            is_regular.is_empty() || is_regular.len() == dates.len() - 1,
+            "is_regular size ({}) must be zero or equal to the number of dates minus 1 ({})",
+            is_regular.len(),
+            dates.len() - 1
         );
+        Self {
+            pricing_context,
+            effective_date: dates[0],
+            termination_date: dates[dates.len() - 1],
+            dates,
+            calendar,
+            convention,
+            termination_date_convention,
+            tenor,
+            rule,
+            end_of_month,
+            is_regular,
+            first_date: Date::default(),
+            next_to_last_date: Date::default(),
+            rule_based: false,
+        }
+    }
 
-        result
+    /// Regenerate the schedule as of the evaluation date of the given context, with the same
+    /// rule, tenor, calendar, conventions and dates as given when the schedule was built.
+    ///
+    /// Only schedules built without an effective date are affected by the evaluation date: with
+    /// the [DateGenerationRule::Backward] rule, their effective date is inferred from the
+    /// evaluation date. Schedules with an explicit effective date are regenerated unchanged.
+    /// Schedules built from explicit dates keep their dates.
+    pub fn rebuild_with_context(&self, new_context: PricingContext) -> Schedule {
+        if !self.rule_based {
+            let mut result = self.clone();
+            result.pricing_context = new_context;
+            return result;
+        }
+        Schedule::new(
+            new_context,
+            self.effective_date,
+            self.termination_date,
+            self.tenor,
+            self.calendar.clone(),
+            self.convention,
+            self.termination_date_convention,
+            self.rule,
+            self.end_of_month,
+            self.first_date,
+            self.next_to_last_date,
+        )
     }
 
     pub fn business_day_convention(&self) -> BusinessDayConvention {
@@ -582,6 +792,11 @@ impl Schedule {
         self.tenor
     }
 
+    /// Return the [DateGenerationRule] the dates were generated with
+    pub fn rule(&self) -> DateGenerationRule {
+        self.rule
+    }
+
     /// Return the first date that is bigger than `ref_date` in the schedule.
     pub fn next_date(&self, ref_date: &Date) -> Date {
         let i = self.lower_bound(ref_date);
@@ -616,6 +831,63 @@ impl Schedule {
         self.is_regular[i - 1]
     }
 
+    /// Return the dates as a table with one row per date, giving its index, weekday and whether
+    /// the period ending on it is regular; the first row has no period, and the flag is "-" if
+    /// the schedule doesn't know which periods are regular.
+    ///
+    /// ```text
+    ///   #  date        weekday    regular
+    ///   0  2024-01-15  Monday
+    ///   1  2024-07-15  Monday     true
+    /// ```
+    pub fn to_table(&self) -> String {
+        let mut table = format!(
+            "{:>3}  {:<10}  {:<9}  {}\n",
+            "#", "date", "weekday", "regular"
+        );
+        for (i, d) in self.dates.iter().enumerate() {
+            let regular = if i == 0 {
+                String::new()
+            } else if self.has_is_regular() {
+                self.is_regular(i).to_string()
+            } else {
+                "-".to_string()
+            };
+            let row = format!(
+                "{:>3}  {:04}-{:02}-{:02}  {:<9}  {}",
+                i,
+                d.year(),
+                d.month() as Integer,
+                d.day_of_month(),
+                format!("{:?}", d.weekday()),
+                regular
+            );
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+
+    /// Whether the first or the last period of the schedule is irregular, i.e. a stub
+    pub fn has_stub(&self) -> bool {
+        self.has_is_regular() && (!self.is_regular(1) || !self.is_regular(self.is_regular.len()))
+    }
+
+    /// Whether the first period is a short stub: irregular, and starting after the date one
+    /// tenor before its end
+    pub fn first_period_is_short(&self) -> bool {
+        self.has_is_regular() && !self.is_regular(1) && self.dates[0] > self.dates[1] - self.tenor
+    }
+
+    /// Whether the last period is a short stub: irregular, and ending before the date one tenor
+    /// after its start
+    pub fn last_period_is_short(&self) -> bool {
+        let n = self.dates.len();
+        self.has_is_regular()
+            && !self.is_regular(n - 1)
+            && self.dates[n - 1] < self.dates[n - 2] + self.tenor
+    }
+
     /// Check whether the schedule has been constructed or not.
     pub fn empty(&self) -> bool {
         self.dates.is_empty()
@@ -645,11 +917,10 @@ impl Schedule {
 
         if truncation_date > &result.dates[0] {
             // remove earlier dates
-            while &result.dates[0] < truncation_date {
-                result.dates.remove(0);
-                if !result.is_regular.is_empty() {
-                    result.is_regular.remove(0);
-                }
+            let first = lower_bound(&result.dates, *truncation_date);
+            result.dates.drain(..first);
+            if !result.is_regular.is_empty() {
+                result.is_regular.drain(..first);
             }
             // add truncation date if missing
             if *truncation_date != result.dates[0] {
@@ -664,6 +935,7 @@ impl Schedule {
             if &result.first_date <= truncation_date {
                 result.first_date = Date::default();
             }
+            result.effective_date = result.dates[0];
         }
         result
     }
@@ -682,13 +954,13 @@ impl Schedule {
 
         if truncation_date < &result.dates[result.dates.len() - 1] {
             // remove later dates
-            let mut idx = result.dates.len() - 1;
-            while &result.dates[idx] > truncation_date {
-                result.dates.pop();
-                idx = result.dates.len() - 1;
-                if !result.is_regular.is_empty() {
-                    result.is_regular.pop();
-                }
+            let last = upper_bound(&result.dates, *truncation_date);
+            let removed = result.dates.len() - last;
+            result.dates.truncate(last);
+            if !result.is_regular.is_empty() {
+                result
+                    .is_regular
+                    .truncate(result.is_regular.len() - removed);
             }
             // add truncation date if missing
             if truncation_date != &result.dates[result.dates.len() - 1] {
@@ -702,11 +974,42 @@ impl Schedule {
             if &result.first_date >= truncation_date {
                 result.first_date = Date::default();
             }
+            result.termination_date = result.dates[result.dates.len() - 1];
         }
 
         result
     }
 
+    /// Return a schedule with every date advanced by `n` units on the calendar of the schedule,
+    /// e.g. payment dates two business days after the accrual dates, or ex-coupon dates a week
+    /// before them.
+    ///
+    /// Business days are counted for [TimeUnit::Days], and the resulting dates are adjusted with
+    /// `convention` otherwise. The shifted schedule keeps the description and regularity of the
+    /// original one, but its dates are no longer generated from its rule, as for
+    /// [Schedule::from_dates]. Panics if two dates are shifted onto the same one.
+    pub fn shifted(&self, n: Integer, unit: TimeUnit, convention: BusinessDayConvention) -> Self {
+        let dates = self
+            .dates
+            .iter()
+            .map(|d| {
+                self.calendar
+                    .advance_by_units(*d, n, unit, convention, self.end_of_month)
+            })
+            .collect();
+        Self::from_dates(
+            self.pricing_context,
+            dates,
+            self.calendar.clone(),
+            self.convention,
+            self.termination_date_convention,
+            self.tenor,
+            self.rule,
+            self.end_of_month,
+            self.is_regular.clone(),
+        )
+    }
+
     fn lower_bound(&self, ref_date: &Date) -> Size {
         let d = if ref_date == &Date::default() {
             self.pricing_context.eval_date
@@ -741,7 +1044,9 @@ pub fn previous_twentieth(d: &Date, rule: DateGenerationRule) -> Date {
     result
 }
 
-fn next_twentieth(d: &Date, rule: DateGenerationRule) -> Date {
+/// Helper function for returning the date on or after date `d` that is the 20th of
+/// the month and observes the given date generation `rule` if it is relevant.
+pub fn next_twentieth(d: &Date, rule: DateGenerationRule) -> Date {
     let mut result = Date::new(20, d.month(), d.year());
     if &result < d {
         result += Period::new(1, Months);
@@ -774,6 +1079,8 @@ mod test {
     use crate::datetime::{
         businessdayconvention::BusinessDayConvention,
         date::Date,
+        dategenerationrule::DateGenerationRule,
+        daterange::DateRange,
         frequency::Frequency,
         holidays::{japan::Japan, target::Target, unitedstates::UnitedStates},
         months::Month::*,
@@ -781,7 +1088,7 @@ mod test {
         timeunit::TimeUnit::*,
     };
 
-    use super::Schedule;
+    use super::{Schedule, ScheduleError};
 
     #[test]
     fn test_next_date() {
@@ -1013,6 +1320,109 @@ mod test {
         check_dates(&s, &expected);
     }
 
+    #[test]
+    fn test_to_table() {
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(31, August, 1996),
+            Date::new(15, September, 1997),
+            Period::new(6, Months),
+            UnitedStates::government_bond(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .forwards()
+        .with_end_of_month(true)
+        .build();
+
+        let table = s.to_table();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), s.size() + 1);
+        assert_eq!(lines[0], "  #  date        weekday    regular");
+        assert_eq!(lines[1], "  0  1996-08-31  Saturday");
+        assert_eq!(lines[2], "  1  1997-02-28  Friday     true");
+        assert_eq!(lines[4], "  3  1997-09-15  Monday     false");
+        assert!(format!("{:?}", s).ends_with(&table));
+
+        // no regularity information for schedules built from dates
+        let s = Schedule::from_dates(
+            pricing_context(),
+            vec![Date::new(15, January, 2024), Date::new(15, July, 2024)],
+            Target::new(),
+            BusinessDayConvention::Unadjusted,
+            BusinessDayConvention::Unadjusted,
+            Period::new(6, Months),
+            DateGenerationRule::Forward,
+            false,
+            vec![],
+        );
+        assert!(s.to_table().ends_with("  1  2024-07-15  Monday     -\n"));
+    }
+
+    #[test]
+    fn test_stub_periods() {
+        // forwards: the last period, from 31 August to 15 September 1997, is a short stub
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(31, August, 1996),
+            Date::new(15, September, 1997),
+            Period::new(6, Months),
+            UnitedStates::government_bond(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .forwards()
+        .with_end_of_month(true)
+        .build();
+        assert!(s.has_stub());
+        assert!(s.last_period_is_short());
+        assert!(!s.first_period_is_short());
+
+        // backwards: the stub is at the front, from 22 to 31 August 1996
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(22, August, 1996),
+            Date::new(31, August, 1997),
+            Period::new(6, Months),
+            UnitedStates::government_bond(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .backwards()
+        .with_end_of_month(true)
+        .build();
+        assert!(s.has_stub());
+        assert!(s.first_period_is_short());
+        assert!(!s.last_period_is_short());
+
+        // a long first period: the first regular date is skipped
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(15, January, 2024),
+            Date::new(15, June, 2026),
+            Period::new(6, Months),
+            Target::new(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .backwards()
+        .with_first_date(Date::new(15, December, 2024))
+        .build();
+        assert!(s.has_stub());
+        assert!(!s.first_period_is_short());
+        assert!(!s.last_period_is_short());
+
+        // regular schedule
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(15, January, 2024),
+            Date::new(15, January, 2026),
+            Period::new(6, Months),
+            Target::new(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .build();
+        assert!(!s.has_stub());
+        assert!(!s.first_period_is_short());
+        assert!(!s.last_period_is_short());
+    }
+
     #[test]
     fn test_backward_dates_with_eom_adjustment() {
         // Testing that the first date is not adjusted for EOM "
@@ -1218,6 +1628,160 @@ mod test {
         check_dates(&s, &expected);
     }
 
+    #[test]
+    fn test_rebuild_with_context() {
+        let termination_date = Date::new(15, June, 2030);
+        let next_to_last_date = Date::new(15, December, 2029);
+        let build = |effective_date: Date| {
+            ScheduleBuilder::new(
+                PricingContext::new(Date::new(1, January, 2024)),
+                effective_date,
+                termination_date,
+                Period::new(6, Months),
+                Target::new(),
+            )
+            .with_convention(BusinessDayConvention::Unadjusted)
+            .with_next_to_last_date(next_to_last_date)
+            .backwards()
+            .build()
+        };
+
+        // the effective date is inferred from the evaluation date
+        let s = build(Date::default());
+        assert_eq!(s.start_date(), &Date::new(15, December, 2023));
+        let rebuilt = s.rebuild_with_context(PricingContext::new(Date::new(1, January, 2026)));
+        assert_eq!(rebuilt.start_date(), &Date::new(15, December, 2025));
+        assert_eq!(rebuilt.end_date(), s.end_date());
+        assert_eq!(rebuilt.size(), s.size() - 4);
+        assert_eq!(&rebuilt.dates()[..], &s.dates()[4..]);
+
+        // explicit effective dates do not depend on the evaluation date
+        let s = build(Date::new(15, June, 2024));
+        let rebuilt = s.rebuild_with_context(PricingContext::new(Date::new(1, January, 2026)));
+        assert_eq!(rebuilt.dates(), s.dates());
+    }
+
+    #[test]
+    fn test_rebuild_with_context_without_next_to_last_date() {
+        let termination_date = Date::new(15, June, 2030);
+        let s = ScheduleBuilder::new(
+            PricingContext::new(Date::new(1, January, 2024)),
+            Date::default(),
+            termination_date,
+            Period::new(6, Months),
+            Target::new(),
+        )
+        .with_convention(BusinessDayConvention::Unadjusted)
+        .backwards()
+        .build();
+
+        // whole years back from the termination date, to the last one before the evaluation date
+        assert_eq!(s.start_date(), &Date::new(15, June, 2023));
+        assert_eq!(s.end_date(), &termination_date);
+        assert_eq!(s.size(), 15);
+
+        let rebuilt = s.rebuild_with_context(PricingContext::new(Date::new(1, January, 2026)));
+        assert_eq!(rebuilt.start_date(), &Date::new(15, June, 2025));
+        assert_eq!(&rebuilt.dates()[..], &s.dates()[4..]);
+
+        // an evaluation date just after an anniversary moves the start forward by one year
+        let rebuilt = s.rebuild_with_context(PricingContext::new(Date::new(16, June, 2026)));
+        assert_eq!(rebuilt.start_date(), &Date::new(15, June, 2026));
+    }
+
+    #[test]
+    fn test_from_dates() {
+        let dates = vec![
+            Date::new(17, January, 2024),
+            Date::new(15, March, 2024),
+            Date::new(3, July, 2024),
+            Date::new(20, December, 2024),
+            Date::new(30, June, 2025),
+        ];
+        let s = Schedule::from_dates(
+            pricing_context(),
+            dates.clone(),
+            Target::new(),
+            BusinessDayConvention::Unadjusted,
+            BusinessDayConvention::Unadjusted,
+            Period::new(6, Months),
+            DateGenerationRule::Forward,
+            false,
+            vec![false, true, true, false],
+        );
+        assert_eq!(s.size(), 5);
+        assert_eq!(s.start_date(), &dates[0]);
+        assert_eq!(s.end_date(), &dates[4]);
+        for (i, d) in s.dates().iter().enumerate() {
+            assert_eq!(d, &dates[i]);
+            assert_eq!(&s[i], &dates[i]);
+        }
+        assert_eq!(s.next_date(&Date::new(1, April, 2024)), dates[2]);
+        assert!(!s.is_regular(1));
+        assert!(s.is_regular(2));
+
+        // the dates are kept as given, whatever the evaluation date
+        let rebuilt = s.rebuild_with_context(PricingContext::new(Date::new(1, January, 2025)));
+        assert_eq!(rebuilt.dates(), dates);
+        let truncated = s.after(&Date::new(15, March, 2024));
+        assert_eq!(truncated.dates(), dates[1..].to_vec());
+    }
+
+    #[test]
+    fn test_shifted() {
+        let calendar = Target::new();
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(15, January, 2024),
+            Date::new(15, January, 2025),
+            Period::new(3, Months),
+            calendar.clone(),
+        )
+        .with_convention(BusinessDayConvention::ModifiedFollowing)
+        .build();
+
+        let payments = s.shifted(2, Days, BusinessDayConvention::Following);
+        assert_eq!(payments.size(), s.size());
+        for (accrual, payment) in s.dates().iter().zip(payments.dates().iter()) {
+            assert!(calendar.is_business_day(payment));
+            // exactly two business days later, however many holidays in between
+            let business_days = DateRange::daily(*accrual + 1, *payment)
+                .filter(|d| calendar.is_business_day(d))
+                .count();
+            assert_eq!(business_days, 2, "{:?} shifted to {:?}", accrual, payment);
+        }
+        // from Monday to Wednesday
+        assert_eq!(payments[1], Date::new(17, April, 2024));
+        assert_eq!(s[2], Date::new(15, July, 2024));
+        assert_eq!(payments[2], Date::new(17, July, 2024));
+        assert_eq!(payments.is_regular(1), s.is_regular(1));
+        assert_eq!(payments.calendar(), s.calendar());
+
+        // ex-coupon dates a week before
+        let ex_dates = s.shifted(-1, Weeks, BusinessDayConvention::Preceding);
+        assert_eq!(ex_dates[1], Date::new(8, April, 2024));
+        assert_eq!(
+            s.shifted(0, Days, BusinessDayConvention::Following).dates(),
+            s.dates()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "dates must be sorted in strictly increasing order")]
+    fn test_from_unsorted_dates() {
+        Schedule::from_dates(
+            pricing_context(),
+            vec![Date::new(15, March, 2024), Date::new(17, January, 2024)],
+            Target::new(),
+            BusinessDayConvention::Unadjusted,
+            BusinessDayConvention::Unadjusted,
+            Period::new(6, Months),
+            DateGenerationRule::Forward,
+            false,
+            vec![],
+        );
+    }
+
     #[test]
     fn test_truncation() {
         let s = ScheduleBuilder::new(
@@ -1325,6 +1889,212 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_after_and_until_on_long_daily_schedule() {
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(1, December, 2022),
+            Date::new(1, December, 2032),
+            Period::new(1, Days),
+            Target::new(),
+        )
+        .forwards()
+        .build();
+        assert!(s.size() > 2500);
+
+        let truncation_dates = [
+            Date::new(1, December, 2022),
+            Date::new(3, December, 2022), // Saturday, not in the schedule
+            Date::new(15, June, 2027),
+            Date::new(25, December, 2030), // TARGET holiday
+            Date::new(29, November, 2032),
+        ];
+        for truncation_date in truncation_dates.iter() {
+            let after = s.after(truncation_date);
+            let expected = after_reference(&s, truncation_date);
+            assert_eq!(after.dates, expected.dates);
+            assert_eq!(after.is_regular, expected.is_regular);
+            assert_eq!(
+                after.termination_date_convention,
+                expected.termination_date_convention
+            );
+
+            if truncation_date > s.start_date() {
+                let until = s.until(truncation_date);
+                let expected = until_reference(&s, truncation_date);
+                assert_eq!(until.dates, expected.dates);
+                assert_eq!(until.is_regular, expected.is_regular);
+            }
+        }
+    }
+
+    /// Element by element implementation of [Schedule::after], used as reference
+    fn after_reference(s: &Schedule, truncation_date: &Date) -> Schedule {
+        let mut result = s.clone();
+        if truncation_date > &result.dates[0] {
+            while &result.dates[0] < truncation_date {
+                result.dates.remove(0);
+                if !result.is_regular.is_empty() {
+                    result.is_regular.remove(0);
+                }
+            }
+            if *truncation_date != result.dates[0] {
+                result.dates.insert(0, *truncation_date);
+                result.is_regular.insert(0, false);
+                result.termination_date_convention = BusinessDayConvention::Unadjusted;
+            }
+        }
+        result
+    }
+
+    /// Element by element implementation of [Schedule::until], used as reference
+    fn until_reference(s: &Schedule, truncation_date: &Date) -> Schedule {
+        let mut result = s.clone();
+        if truncation_date < &result.dates[result.dates.len() - 1] {
+            while &result.dates[result.dates.len() - 1] > truncation_date {
+                result.dates.pop();
+                if !result.is_regular.is_empty() {
+                    result.is_regular.pop();
+                }
+            }
+            if truncation_date != &result.dates[result.dates.len() - 1] {
+                result.dates.push(*truncation_date);
+                result.is_regular.push(false);
+                result.termination_date_convention = BusinessDayConvention::Unadjusted;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_third_wednesday_schedule() {
+        let s = ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(5, January, 2016),
+            Date::new(5, January, 2017),
+            Period::new(3, Months),
+            Target::new(),
+        )
+        .with_rule(DateGenerationRule::ThirdWednesdayInclusive)
+        .build();
+        assert_eq!(s.dates[0], Date::new(20, January, 2016));
+        for d in s.dates.iter() {
+            assert_eq!(*d, Date::third_wednesday_of(d));
+        }
+    }
+
+    #[test]
+    fn test_try_new_errors() {
+        let try_new = |effective: Date,
+                       termination: Date,
+                       rule: DateGenerationRule,
+                       first: Date|
+         -> Result<Schedule, ScheduleError> {
+            Schedule::try_new(
+                pricing_context(),
+                effective,
+                termination,
+                Period::new(1, Months),
+                Target::new(),
+                BusinessDayConvention::Following,
+                BusinessDayConvention::Following,
+                rule,
+                false,
+                first,
+                Date::default(),
+            )
+        };
+
+        // both dates fall on the same weekend and are rolled to the same Monday
+        let err = try_new(
+            Date::new(18, November, 2023),
+            Date::new(19, November, 2023),
+            DateGenerationRule::Forward,
+            Date::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ScheduleError::DegenerateSchedule {
+                date: Date::new(20, November, 2023),
+                seed_date: Date::new(18, November, 2023),
+                exit_date: Date::new(19, November, 2023),
+                effective_date: Date::new(18, November, 2023),
+                first_date: Date::default(),
+                next_to_last_date: Date::default(),
+                termination_date: Date::new(19, November, 2023),
+                rule: DateGenerationRule::Forward,
+                end_of_month: false,
+            }
+        );
+        let message = err.to_string();
+        assert!(message.starts_with("degenerate single date"));
+        assert!(message.contains("\n termination date: "));
+        assert!(message.contains("\n generation rule: Forward"));
+
+        assert_eq!(
+            try_new(
+                Date::new(15, January, 2024),
+                Date::new(15, January, 2025),
+                DateGenerationRule::Backward,
+                Date::new(15, March, 2025)
+            )
+            .unwrap_err(),
+            ScheduleError::FirstDateOutOfRange {
+                first_date: Date::new(15, March, 2025),
+                effective_date: Date::new(15, January, 2024),
+                termination_date: Date::new(15, January, 2025),
+            }
+        );
+
+        assert_eq!(
+            try_new(
+                Date::new(15, January, 2024),
+                Date::new(15, January, 2025),
+                DateGenerationRule::ThirdWednesday,
+                Date::new(15, March, 2024)
+            )
+            .unwrap_err(),
+            ScheduleError::NotImmDate(Date::new(15, March, 2024))
+        );
+
+        assert_eq!(
+            try_new(
+                Date::new(15, January, 2024),
+                Date::new(15, January, 2025),
+                DateGenerationRule::Zero,
+                Date::new(15, March, 2024)
+            )
+            .unwrap_err(),
+            ScheduleError::IncompatibleRule {
+                rule: DateGenerationRule::Zero,
+                feature: "first date",
+            }
+        );
+
+        assert!(try_new(
+            Date::new(15, January, 2024),
+            Date::new(15, January, 2025),
+            DateGenerationRule::Backward,
+            Date::new(15, March, 2024)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of effective-termination date range")]
+    fn test_new_panics_on_error() {
+        ScheduleBuilder::new(
+            pricing_context(),
+            Date::new(15, January, 2024),
+            Date::new(15, January, 2025),
+            Period::new(1, Months),
+            Target::new(),
+        )
+        .with_first_date(Date::new(15, March, 2025))
+        .build();
+    }
+
     fn pricing_context() -> PricingContext {
         PricingContext {
             eval_date: Date::new(1, December, 2022),
@@ -2,16 +2,17 @@ use crate::context::pricing_context::PricingContext;
 
 use crate::datetime::{
     businessdayconvention::BusinessDayConvention, calendar::Calendar, date::Date,
-    dategenerationrule::DateGenerationRule, period::Period,
+    dategenerationrule::DateGenerationRule, frequency::Frequency, period::Period,
 };
 
-use super::schedule::Schedule;
+use super::schedule::{Schedule, ScheduleError};
 
 /// Schedule Builder
 pub struct ScheduleBuilder {
     pricing_context: PricingContext,
     effective_date: Date,
     termination_date: Date,
+    maturity: Option<Period>,
     tenor: Period,
     calendar: Calendar,
     convention: Option<BusinessDayConvention>,
@@ -35,6 +36,7 @@ impl ScheduleBuilder {
             pricing_context,
             effective_date,
             termination_date,
+            maturity: None,
             tenor,
             calendar,
             convention: None,
@@ -46,6 +48,36 @@ impl ScheduleBuilder {
         }
     }
 
+    /// Construct the Builder from the overall maturity of the deal (e.g. 5Y) instead of its
+    /// termination date.
+    ///
+    /// The termination date is computed when the schedule is built, by advancing the effective
+    /// date by `maturity` on the calendar with the termination date convention.
+    pub fn new_with_maturity(
+        pricing_context: PricingContext,
+        effective_date: Date,
+        maturity: Period,
+        tenor: Period,
+        calendar: Calendar,
+    ) -> Self {
+        Self {
+            maturity: Some(maturity),
+            ..Self::new(
+                pricing_context,
+                effective_date,
+                Date::default(),
+                tenor,
+                calendar,
+            )
+        }
+    }
+
+    /// Set the tenor from a coupon frequency, e.g. [Frequency::Quarterly] for a 3M tenor
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.tenor = Period::from(frequency);
+        self
+    }
+
     /// Set business day convention
     pub fn with_convention(mut self, convention: BusinessDayConvention) -> Self {
         self.convention = Some(convention);
@@ -98,28 +130,44 @@ impl ScheduleBuilder {
     }
 
     /// Build the [Schedule]
+    ///
+    /// Panics if the schedule cannot be generated; see [ScheduleBuilder::try_build].
     pub fn build(self) -> Schedule {
-        let convention = if self.convention.is_some() {
-            self.convention.unwrap()
-        } else {
-            BusinessDayConvention::Following
-        };
-        let termination_date_convention = if self.termination_date_convention.is_some() {
-            self.termination_date_convention.unwrap()
+        self.try_build().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Build the [Schedule], or return the reason why it cannot be generated.
+    ///
+    /// A zero tenor is valid and gives a [DateGenerationRule::Zero] schedule made of the
+    /// effective and termination dates only, whatever the rule set on the builder; a negative
+    /// tenor is rejected with [ScheduleError::NegativeTenor] before any date is computed.
+    pub fn try_build(self) -> Result<Schedule, ScheduleError> {
+        if self.tenor.length < 0 {
+            return Err(ScheduleError::NegativeTenor(self.tenor));
+        }
+        let convention = self.convention.unwrap_or(BusinessDayConvention::Following);
+        // Unadjusted as per ISDA specification
+        let termination_date_convention = self.termination_date_convention.unwrap_or(convention);
+        let date_generation_rule = if self.tenor.length == 0 {
+            DateGenerationRule::Zero
         } else {
-            // Unadjusted as per ISDA specification
-            convention
+            self.date_generation_rule
+                .unwrap_or(DateGenerationRule::Backward)
         };
-        let date_generation_rule = if self.date_generation_rule.is_some() {
-            self.date_generation_rule.unwrap()
-        } else {
-            DateGenerationRule::Backward
+        let termination_date = match self.maturity {
+            Some(maturity) => self.calendar.advance(
+                self.effective_date,
+                maturity,
+                termination_date_convention,
+                self.end_of_month,
+            ),
+            None => self.termination_date,
         };
 
-        Schedule::new(
+        Schedule::try_new(
             self.pricing_context,
             self.effective_date,
-            self.termination_date,
+            termination_date,
             self.tenor,
             self.calendar,
             convention,
@@ -131,3 +179,79 @@ impl ScheduleBuilder {
         )
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::context::pricing_context::PricingContext;
+    use crate::datetime::{
+        businessdayconvention::BusinessDayConvention, date::Date,
+        dategenerationrule::DateGenerationRule, frequency::Frequency, holidays::target::Target,
+        months::Month::*, period::Period, schedule::ScheduleError, timeunit::TimeUnit::*,
+    };
+
+    use super::ScheduleBuilder;
+
+    #[test]
+    fn test_schedule_from_maturity() {
+        let pricing_context = PricingContext {
+            eval_date: Date::new(15, March, 2023),
+        };
+        let effective_date = Date::new(15, March, 2023);
+
+        let explicit = ScheduleBuilder::new(
+            pricing_context,
+            effective_date,
+            Date::new(15, March, 2028),
+            Period::new(3, Months),
+            Target::new(),
+        )
+        .with_convention(BusinessDayConvention::ModifiedFollowing)
+        .build();
+        let computed = ScheduleBuilder::new_with_maturity(
+            pricing_context,
+            effective_date,
+            Period::new(5, Years),
+            Period::new(1, Years),
+            Target::new(),
+        )
+        .with_frequency(Frequency::Quarterly)
+        .with_convention(BusinessDayConvention::ModifiedFollowing)
+        .build();
+
+        assert_eq!(computed.size(), 21);
+        assert_eq!(computed.dates(), explicit.dates());
+        assert_eq!(computed.tenor(), Period::new(3, Months));
+        assert_eq!(computed.end_date(), &Date::new(15, March, 2028));
+    }
+
+    #[test]
+    fn test_zero_and_negative_tenors() {
+        let pricing_context = PricingContext::new(Date::new(15, March, 2023));
+        let effective_date = Date::new(15, March, 2023);
+        let termination_date = Date::new(15, March, 2028);
+        let builder = |tenor: Period| {
+            ScheduleBuilder::new(
+                pricing_context,
+                effective_date,
+                termination_date,
+                tenor,
+                Target::new(),
+            )
+            .with_convention(BusinessDayConvention::ModifiedFollowing)
+            .forwards()
+        };
+
+        let schedule = builder(Period::new(0, Days)).build();
+        assert_eq!(schedule.rule(), DateGenerationRule::Zero);
+        assert_eq!(schedule.dates(), vec![effective_date, termination_date]);
+
+        let tenor = Period::new(-6, Months);
+        assert_eq!(
+            builder(tenor).try_build().unwrap_err(),
+            ScheduleError::NegativeTenor(tenor)
+        );
+        assert!(builder(Period::new(6, Months)).try_build().is_ok());
+    }
+}
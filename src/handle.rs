@@ -0,0 +1,176 @@
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+
+use crate::patterns::observable::{Observable, Observer};
+
+/// Shared handle to an object, typically a piece of market data such as a quote or a curve.
+///
+/// All copies of a handle share the same link, so that relinking it through a
+/// [RelinkableHandle] is seen by every instrument or engine holding a copy. This allows, for
+/// instance, the pricing of the same instrument under several scenarios by relinking its
+/// discount curve.
+pub struct Handle<T: ?Sized> {
+    link: Rc<Link<T>>,
+}
+
+/// Link shared by all the copies of a handle
+struct Link<T: ?Sized> {
+    value: RefCell<Option<Rc<T>>>,
+    observable: Observable,
+}
+
+impl<T: ?Sized> Link<T> {
+    fn new(value: Option<Rc<T>>) -> Self {
+        Self {
+            value: RefCell::new(value),
+            observable: Observable::new(),
+        }
+    }
+}
+
+impl<T: ?Sized> Handle<T> {
+    /// Create a handle linked to the given object
+    pub fn new(value: Rc<T>) -> Self {
+        Self {
+            link: Rc::new(Link::new(Some(value))),
+        }
+    }
+
+    /// Create a handle which is not linked to any object
+    pub fn empty() -> Self {
+        Self {
+            link: Rc::new(Link::new(None)),
+        }
+    }
+
+    /// Returns true if the handle is not linked to any object
+    pub fn is_empty(&self) -> bool {
+        self.link.value.borrow().is_none()
+    }
+
+    /// Returns the object the handle is currently linked to.
+    ///
+    /// Panics if the handle is empty.
+    pub fn current_link(&self) -> Rc<T> {
+        self.link
+            .value
+            .borrow()
+            .as_ref()
+            .expect("empty Handle cannot be dereferenced")
+            .clone()
+    }
+
+    /// Register an observer to be notified when the handle is relinked.
+    ///
+    /// Changes of the linked object itself are not forwarded; observers interested in those
+    /// must register with the object as well.
+    pub fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.link.observable.register_observer(observer);
+    }
+}
+
+impl<T: ?Sized> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            link: self.link.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> Default for Handle<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Handle which can be relinked to a different object.
+///
+/// Handles obtained from it (by [Clone] or [RelinkableHandle::handle]) follow the new link.
+pub struct RelinkableHandle<T: ?Sized> {
+    handle: Handle<T>,
+}
+
+impl<T: ?Sized> RelinkableHandle<T> {
+    pub fn new(value: Rc<T>) -> Self {
+        Self {
+            handle: Handle::new(value),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            handle: Handle::empty(),
+        }
+    }
+
+    /// Link all the copies of this handle to the given object and notify their observers
+    pub fn link_to(&self, value: Rc<T>) {
+        *self.handle.link.value.borrow_mut() = Some(value);
+        self.handle.link.observable.notify_observers();
+    }
+
+    /// Return a (non relinkable) handle sharing the link of this one
+    pub fn handle(&self) -> Handle<T> {
+        self.handle.clone()
+    }
+}
+
+impl<T: ?Sized> Clone for RelinkableHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for RelinkableHandle<T> {
+    type Target = Handle<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::quotes::{quote::Quote, simplequote::SimpleQuote};
+
+    use super::{Handle, RelinkableHandle};
+
+    #[test]
+    fn test_handle() {
+        let handle: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(1.0)));
+        assert!(!handle.is_empty());
+        assert_eq!(handle.current_link().value(), 1.0);
+
+        let handle: Handle<dyn Quote> = Handle::empty();
+        assert!(handle.is_empty());
+    }
+
+    #[test]
+    fn test_relinkable_handle() {
+        let relinkable: RelinkableHandle<dyn Quote> = RelinkableHandle::empty();
+        let handle = relinkable.handle();
+        assert!(handle.is_empty());
+
+        relinkable.link_to(Rc::new(SimpleQuote::new(1.0)));
+        assert!(!handle.is_empty());
+        assert_eq!(handle.current_link().value(), 1.0);
+
+        relinkable.link_to(Rc::new(SimpleQuote::new(2.0)));
+        assert_eq!(handle.current_link().value(), 2.0);
+        assert_eq!(relinkable.current_link().value(), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty Handle cannot be dereferenced")]
+    fn test_empty_handle() {
+        let handle: Handle<dyn Quote> = Handle::empty();
+        handle.current_link();
+    }
+}
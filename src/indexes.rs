@@ -0,0 +1,4 @@
+pub mod ibor;
+pub mod iborindex;
+pub mod overnightindex;
+pub mod zeroinflationindex;
@@ -0,0 +1,5 @@
+pub mod estr;
+pub mod euribor;
+pub mod sofr;
+pub mod sonia;
+pub mod usdlibor;
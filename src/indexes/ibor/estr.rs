@@ -0,0 +1,71 @@
+use crate::{
+    datetime::{daycounter::DayCounter, holidays::target::Target},
+    handle::Handle,
+    indexes::overnightindex::OvernightIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+};
+
+/// ESTR index
+///
+/// Euro short-term rate (€STR), computed by the ECB from the unsecured overnight borrowing of
+/// euro area banks. There is one fixing for each TARGET business day, with Actual/360 day count
+/// and no fixing days.
+#[derive(Clone)]
+pub struct Estr {}
+
+impl Estr {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(forwarding_term_structure: Handle<dyn YieldTermStructure>) -> OvernightIndex {
+        OvernightIndex::new(
+            "ESTR",
+            0,
+            Target::new(),
+            DayCounter::actual360(),
+            forwarding_term_structure,
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual,
+            holidays::target::Target, months::Month::*,
+        },
+        handle::Handle,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::Estr;
+
+    #[test]
+    fn test_estr() {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, January, 2024),
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Estr::new(curve);
+        assert_eq!(index.name(), "ESTR Actual/360");
+        assert_eq!(index.fixing_calendar(), &Target::new());
+        assert_eq!(index.day_counter(), &DayCounter::actual360());
+        assert_eq!(index.ibor_index.fixing_days, 0);
+        // no fixings on 1 May, a TARGET holiday
+        assert!(!index
+            .fixing_calendar()
+            .is_business_day(&Date::new(1, May, 2024)));
+        let fixing_date = Date::new(30, April, 2024);
+        assert_eq!(
+            index.fixing_calendar().nth_business_day(&fixing_date, 1),
+            Date::new(2, May, 2024)
+        );
+    }
+}
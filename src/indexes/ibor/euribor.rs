@@ -0,0 +1,91 @@
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+        daycounter::DayCounter,
+        holidays::target::Target,
+        period::Period,
+        timeunit::TimeUnit,
+    },
+    handle::Handle,
+    indexes::iborindex::IborIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+};
+
+/// Euribor index
+///
+/// Fixed two TARGET business days before the start of the deposit, with Actual/360 day count.
+/// Tenors in days or weeks are adjusted with the Following convention, tenors in months or
+/// years with Modified Following and the end-of-month rule.
+#[derive(Clone)]
+pub struct Euribor {}
+
+impl Euribor {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        tenor: Period,
+        forwarding_term_structure: Handle<dyn YieldTermStructure>,
+    ) -> IborIndex {
+        let (convention, end_of_month) = match tenor.unit {
+            TimeUnit::Days | TimeUnit::Weeks => (Following, false),
+            _ => (ModifiedFollowing, true),
+        };
+        IborIndex::new(
+            "Euribor",
+            tenor,
+            2,
+            Target::new(),
+            convention,
+            end_of_month,
+            DayCounter::actual360(),
+            forwarding_term_structure,
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            timeunit::TimeUnit::{Months, Weeks},
+        },
+        handle::Handle,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::Euribor;
+
+    #[test]
+    fn test_euribor() {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, January, 2024),
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Euribor::new(Period::new(6, Months), curve.clone());
+        assert_eq!(index.name(), "Euribor6M Actual/360");
+        assert_eq!(index.tenor, Period::new(6, Months));
+        assert_eq!(index.fixing_calendar, Target::new());
+        assert_eq!(index.day_counter, DayCounter::actual360());
+        assert_eq!(index.fixing_days, 2);
+        assert_eq!(index.convention, ModifiedFollowing);
+        assert!(index.end_of_month);
+
+        let index = Euribor::new(Period::new(1, Weeks), curve);
+        assert_eq!(index.convention, Following);
+        assert!(!index.end_of_month);
+    }
+}
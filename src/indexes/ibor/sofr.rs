@@ -0,0 +1,72 @@
+use crate::{
+    datetime::{daycounter::DayCounter, holidays::unitedstates::UnitedStates},
+    handle::Handle,
+    indexes::overnightindex::OvernightIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+};
+
+/// SOFR index
+///
+/// Secured Overnight Financing Rate, the cost of overnight borrowing collateralised by US
+/// Treasuries. Fixed on US government bond market business days with Actual/360 day count; the
+/// Federal Reserve Bank of New York publishes each rate on the following business day, but it
+/// applies to the day it was observed, hence no fixing days.
+#[derive(Clone)]
+pub struct Sofr {}
+
+impl Sofr {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(forwarding_term_structure: Handle<dyn YieldTermStructure>) -> OvernightIndex {
+        OvernightIndex::new(
+            "SOFR",
+            0,
+            UnitedStates::government_bond(),
+            DayCounter::actual360(),
+            forwarding_term_structure,
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual,
+            holidays::unitedstates::UnitedStates, months::Month::*, period::Period,
+            timeunit::TimeUnit::Days,
+        },
+        handle::Handle,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::Sofr;
+
+    #[test]
+    fn test_sofr() {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, January, 2024),
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Sofr::new(curve);
+        assert_eq!(index.name(), "SOFR Actual/360");
+        assert_eq!(index.fixing_calendar(), &UnitedStates::government_bond());
+        assert_eq!(index.day_counter(), &DayCounter::actual360());
+        assert_eq!(index.ibor_index.fixing_days, 0);
+        assert_eq!(index.ibor_index.tenor, Period::new(1, Days));
+        // Independence Day falls on a Thursday in 2024
+        let fixing_date = Date::new(3, July, 2024);
+        assert_eq!(index.value_date(&fixing_date), fixing_date);
+        assert_eq!(
+            index.fixing_calendar().nth_business_day(&fixing_date, 1),
+            Date::new(5, July, 2024)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    datetime::{daycounter::DayCounter, holidays::unitedkingdom::UnitedKingdom},
+    handle::Handle,
+    indexes::overnightindex::OvernightIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+};
+
+/// SONIA index
+///
+/// Sterling Overnight Index Average, administered by the Bank of England and based on unsecured
+/// overnight sterling deposits. Fixed on the UK settlement calendar, without fixing days, and,
+/// unlike the US dollar and euro rates, accrues on an Actual/365 (Fixed) basis.
+#[derive(Clone)]
+pub struct Sonia {}
+
+impl Sonia {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(forwarding_term_structure: Handle<dyn YieldTermStructure>) -> OvernightIndex {
+        OvernightIndex::new(
+            "SONIA",
+            0,
+            UnitedKingdom::new(),
+            DayCounter::actual365_fixed(),
+            forwarding_term_structure,
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual,
+            holidays::unitedkingdom::UnitedKingdom, months::Month::*,
+        },
+        handle::Handle,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::Sonia;
+
+    #[test]
+    fn test_sonia() {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, January, 2024),
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Sonia::new(curve);
+        assert_eq!(index.name(), "SONIA Actual/365 (Fixed)");
+        assert_eq!(index.fixing_calendar(), &UnitedKingdom::new());
+        assert_eq!(index.day_counter(), &DayCounter::actual365_fixed());
+        assert_eq!(index.ibor_index.fixing_days, 0);
+        // the late May bank holiday
+        let fixing_date = Date::new(24, May, 2024);
+        assert_eq!(
+            index.fixing_calendar().nth_business_day(&fixing_date, 1),
+            Date::new(28, May, 2024)
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+        daycounter::DayCounter,
+        holidays::{
+            jointcalendar::JointCalendar, unitedkingdom::UnitedKingdom, unitedstates::UnitedStates,
+        },
+        period::Period,
+        timeunit::TimeUnit,
+    },
+    handle::Handle,
+    indexes::iborindex::IborIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+};
+
+/// USD Libor index
+///
+/// Fixed in London two business days before the start of the deposit, with Actual/360 day
+/// count. The value and maturity dates must be business days in both London and New York. As for Euribor, short tenors use the Following convention and tenors in months or
+/// years Modified Following with the end-of-month rule.
+#[derive(Clone)]
+pub struct UsdLibor {}
+
+impl UsdLibor {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        tenor: Period,
+        forwarding_term_structure: Handle<dyn YieldTermStructure>,
+    ) -> IborIndex {
+        let (convention, end_of_month) = match tenor.unit {
+            TimeUnit::Days | TimeUnit::Weeks => (Following, false),
+            _ => (ModifiedFollowing, true),
+        };
+        IborIndex::new(
+            "USDLibor",
+            tenor,
+            2,
+            UnitedKingdom::exchange(),
+            convention,
+            end_of_month,
+            DayCounter::actual360(),
+            forwarding_term_structure,
+        )
+        .with_value_calendar(JointCalendar::new(vec![
+            UnitedKingdom::exchange(),
+            UnitedStates::libor_impact(),
+        ]))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual,
+            holidays::unitedkingdom::UnitedKingdom, months::Month::*, period::Period,
+            timeunit::TimeUnit::Months,
+        },
+        handle::Handle,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::UsdLibor;
+
+    #[test]
+    fn test_dates_across_us_holidays() {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, June, 2023),
+            0.05,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = UsdLibor::new(Period::new(1, Months), curve);
+        assert_eq!(index.name(), "USDLibor1M Actual/360");
+        assert_eq!(index.fixing_calendar, UnitedKingdom::exchange());
+
+        // two London days after the fixing is Independence Day, a New York holiday only
+        let value_date = index.value_date(&Date::new(30, June, 2023));
+        assert_eq!(value_date, Date::new(5, July, 2023));
+
+        // one month after the value date is Thanksgiving, a New York holiday only
+        let value_date = index.value_date(&Date::new(19, October, 2023));
+        assert_eq!(value_date, Date::new(23, October, 2023));
+        assert_eq!(
+            index.maturity_date(&value_date),
+            Date::new(24, November, 2023)
+        );
+        assert_eq!(index.fixing_date(&value_date), Date::new(19, October, 2023));
+    }
+}
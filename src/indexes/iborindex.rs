@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::{self, Following, Preceding},
+        calendar::Calendar,
+        date::Date,
+        daycounter::DayCounter,
+        period::Period,
+        timeunit::TimeUnit,
+    },
+    handle::Handle,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Integer, Rate},
+};
+
+/// Base class for Inter-Bank-Offered-Rate indexes, e.g. Libor or Euribor.
+///
+/// Forward fixings are forecast from the attached forwarding term structure, while past ones
+/// are read from the fixings published so far. Today is taken as the reference date of the
+/// forwarding term structure.
+#[derive(Clone)]
+pub struct IborIndex {
+    pub family_name: String,
+    pub tenor: Period,
+    pub fixing_days: Integer,
+    pub fixing_calendar: Calendar,
+    /// Calendar of the value and maturity dates; the fixing calendar unless set otherwise
+    pub value_calendar: Calendar,
+    pub convention: BusinessDayConvention,
+    pub end_of_month: bool,
+    pub day_counter: DayCounter,
+    pub forwarding_term_structure: Handle<dyn YieldTermStructure>,
+    fixings: HashMap<Date, Rate>,
+}
+
+impl IborIndex {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        family_name: &str,
+        tenor: Period,
+        fixing_days: Integer,
+        fixing_calendar: Calendar,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        day_counter: DayCounter,
+        forwarding_term_structure: Handle<dyn YieldTermStructure>,
+    ) -> Self {
+        Self {
+            family_name: family_name.into(),
+            tenor,
+            fixing_days,
+            value_calendar: fixing_calendar.clone(),
+            fixing_calendar,
+            convention,
+            end_of_month,
+            day_counter,
+            forwarding_term_structure,
+            fixings: HashMap::new(),
+        }
+    }
+
+    /// Use the given calendar, e.g. a joint calendar, for the value and maturity dates while
+    /// still fixing on the fixing calendar
+    pub fn with_value_calendar(mut self, value_calendar: Calendar) -> Self {
+        self.value_calendar = value_calendar;
+        self
+    }
+
+    /// Return the name of the index, e.g. "Euribor3M Actual/360"
+    pub fn name(&self) -> String {
+        let unit = match self.tenor.unit {
+            TimeUnit::Days => "D",
+            TimeUnit::Weeks => "W",
+            TimeUnit::Months => "M",
+            TimeUnit::Years => "Y",
+            other => panic!("Invalid index tenor unit: {:?}", other),
+        };
+        format!(
+            "{}{}{} {}",
+            self.family_name,
+            self.tenor.length,
+            unit,
+            self.day_counter.name()
+        )
+    }
+
+    /// Date on which the deposit starting at `value_date` is fixed, the fixing days of the
+    /// index before it
+    pub fn fixing_date(&self, value_date: &Date) -> Date {
+        self.fixing_calendar.advance_by_units(
+            *value_date,
+            -self.fixing_days,
+            TimeUnit::Days,
+            Preceding,
+            false,
+        )
+    }
+
+    /// Start date of the deposit fixed at `fixing_date`, the fixing days of the index after it,
+    /// moved to the next business day of the value calendar
+    pub fn value_date(&self, fixing_date: &Date) -> Date {
+        let date = self.fixing_calendar.advance_by_units(
+            *fixing_date,
+            self.fixing_days,
+            TimeUnit::Days,
+            Following,
+            false,
+        );
+        self.value_calendar.adjust(date, Following)
+    }
+
+    /// End date of the deposit starting at `value_date`: the tenor of the index later, adjusted
+    /// with its convention and end-of-month rule
+    pub fn maturity_date(&self, value_date: &Date) -> Date {
+        self.value_calendar
+            .advance(*value_date, self.tenor, self.convention, self.end_of_month)
+    }
+
+    /// Store the fixing published on the given fixing date
+    pub fn add_fixing(&mut self, fixing_date: Date, value: Rate) {
+        self.fixings.insert(fixing_date, value);
+    }
+
+    /// Returns the fixing published on the given date, if any
+    pub fn past_fixing(&self, fixing_date: &Date) -> Option<Rate> {
+        self.fixings.get(fixing_date).copied()
+    }
+
+    /// Returns the published fixing to be used at `fixing_date`, or `None` if it is to be
+    /// forecast.
+    ///
+    /// Fixings before today must have been published, and a missing one is an error. The
+    /// fixing of today is used if already published, and forecast otherwise.
+    pub fn known_fixing(&self, fixing_date: &Date) -> Option<Rate> {
+        let today = self
+            .forwarding_term_structure
+            .current_link()
+            .reference_date();
+        if *fixing_date > today {
+            return None;
+        }
+        let fixing = self.past_fixing(fixing_date);
+        assert!(
+            fixing.is_some() || *fixing_date == today,
+            "Missing {} fixing for {:?}",
+            self.name(),
+            fixing_date
+        );
+        fixing
+    }
+
+    /// Returns the fixing at the given date: the published one for past dates, otherwise the
+    /// one forecast from the forwarding term structure
+    pub fn fixing(&self, fixing_date: &Date) -> Rate {
+        self.known_fixing(fixing_date).unwrap_or_else(|| {
+            let curve = self.forwarding_term_structure.current_link();
+            self.forecast_fixing(fixing_date, curve.as_ref())
+        })
+    }
+
+    /// Fixing at `fixing_date` forecast from the given curve, i.e. the simple forward rate
+    /// `(P(value) / P(maturity) - 1) / tau` over the deposit period
+    pub fn forecast_fixing(&self, fixing_date: &Date, curve: &dyn YieldTermStructure) -> Rate {
+        let value_date = self.value_date(fixing_date);
+        let maturity_date = self.maturity_date(&value_date);
+        self.forecast_fixing_between(&value_date, &maturity_date, curve)
+    }
+
+    /// Simple forward rate of the index from `value_date` to `maturity_date` forecast from the
+    /// given curve, with `tau` measured by the index day counter. Used by instruments and
+    /// indexes whose accrual period is not the deposit period of a fixing date.
+    pub fn forecast_fixing_between(
+        &self,
+        value_date: &Date,
+        maturity_date: &Date,
+        curve: &dyn YieldTermStructure,
+    ) -> Rate {
+        let tau = self
+            .day_counter
+            .year_fraction_simple(value_date, maturity_date);
+        assert!(
+            tau > 0.0,
+            "non positive time ({}) between {:?} and {:?}",
+            tau,
+            value_date,
+            maturity_date
+        );
+        let value_discount = curve.discount_from_date(value_date, false);
+        let maturity_discount = curve.discount_from_date(maturity_date, false);
+        (value_discount / maturity_discount - 1.0) / tau
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+            period::Period, timeunit::TimeUnit::Months,
+        },
+        handle::Handle,
+        indexes::ibor::euribor::Euribor,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::Real,
+    };
+
+    #[test]
+    fn test_dates_and_forecast() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Euribor::new(Period::new(3, Months), curve.clone());
+
+        // Thursday fixing, value on the following Monday
+        let fixing_date = Date::new(11, April, 2024);
+        let value_date = index.value_date(&fixing_date);
+        assert_eq!(value_date, Date::new(15, April, 2024));
+        assert_eq!(index.fixing_date(&value_date), fixing_date);
+        let maturity_date = index.maturity_date(&value_date);
+        assert_eq!(maturity_date, Date::new(15, July, 2024));
+
+        // Good Friday and Easter Monday are skipped
+        let fixing_date = Date::new(28, March, 2024);
+        assert_eq!(index.value_date(&fixing_date), Date::new(3, April, 2024));
+        assert_eq!(index.fixing_date(&Date::new(3, April, 2024)), fixing_date);
+
+        // simple forward over the 91 days of the deposit
+        let fixing_date = Date::new(11, April, 2024);
+        let expected = ((0.03 * 91.0 / 365.0 as Real).exp() - 1.0) * 360.0 / 91.0;
+        let forecast = index.forecast_fixing(&fixing_date, curve.current_link().as_ref());
+        assert!((forecast - expected).abs() < 1.0e-15);
+
+        // ... and over any other period, e.g. 30 days
+        let end = Date::new(15, May, 2024);
+        let expected = ((0.03 * 30.0 / 365.0 as Real).exp() - 1.0) * 360.0 / 30.0;
+        let forecast =
+            index.forecast_fixing_between(&value_date, &end, curve.current_link().as_ref());
+        assert!((forecast - expected).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn test_past_fixings() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let mut index = Euribor::new(Period::new(3, Months), curve.clone());
+        let past = Date::new(11, January, 2024);
+        index.add_fixing(past, 0.039);
+        assert_eq!(index.past_fixing(&past), Some(0.039));
+        assert_eq!(index.fixing(&past), 0.039);
+
+        // today's fixing is forecast until published
+        let forecast = index.forecast_fixing(&today, curve.current_link().as_ref());
+        assert_eq!(index.known_fixing(&today), None);
+        assert_eq!(index.fixing(&today), forecast);
+        index.add_fixing(today, 0.04);
+        assert_eq!(index.fixing(&today), 0.04);
+
+        // future fixings are always forecast
+        let future = Date::new(11, April, 2024);
+        index.add_fixing(future, 0.05);
+        assert_eq!(index.known_fixing(&future), None);
+        assert_ne!(index.fixing(&future), 0.05);
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing Euribor3M Actual/360 fixing for")]
+    fn test_missing_past_fixing() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Euribor::new(Period::new(3, Months), curve);
+        index.fixing(&Date::new(11, January, 2024));
+    }
+}
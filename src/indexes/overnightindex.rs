@@ -0,0 +1,90 @@
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::Following, calendar::Calendar, date::Date,
+        daycounter::DayCounter, period::Period, timeunit::TimeUnit::Days,
+    },
+    handle::Handle,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Integer, Rate},
+};
+
+use super::iborindex::IborIndex;
+
+/// Overnight rate index, e.g. SOFR or ESTR.
+///
+/// This is an [IborIndex] with a one business day tenor, which also keeps the fixings
+/// published so far. Fixings up to today must have been published, later ones are forecast from
+/// the forwarding term structure.
+#[derive(Clone)]
+pub struct OvernightIndex {
+    pub ibor_index: IborIndex,
+}
+
+impl OvernightIndex {
+    pub fn new(
+        family_name: &str,
+        fixing_days: Integer,
+        fixing_calendar: Calendar,
+        day_counter: DayCounter,
+        forwarding_term_structure: Handle<dyn YieldTermStructure>,
+    ) -> Self {
+        Self {
+            ibor_index: IborIndex::new(
+                family_name,
+                Period::new(1, Days),
+                fixing_days,
+                fixing_calendar,
+                Following,
+                false,
+                day_counter,
+                forwarding_term_structure,
+            ),
+        }
+    }
+
+    /// Return the name of the index, e.g. "SOFR Actual/360"
+    pub fn name(&self) -> String {
+        format!(
+            "{} {}",
+            self.ibor_index.family_name,
+            self.ibor_index.day_counter.name()
+        )
+    }
+
+    pub fn fixing_calendar(&self) -> &Calendar {
+        &self.ibor_index.fixing_calendar
+    }
+
+    pub fn day_counter(&self) -> &DayCounter {
+        &self.ibor_index.day_counter
+    }
+
+    /// Store the fixing published on the given fixing date
+    pub fn add_fixing(&mut self, fixing_date: Date, value: Rate) {
+        self.ibor_index.add_fixing(fixing_date, value);
+    }
+
+    /// Returns the fixing published on the given date, if any
+    pub fn past_fixing(&self, fixing_date: &Date) -> Option<Rate> {
+        self.ibor_index.past_fixing(fixing_date)
+    }
+
+    /// Date on which the rate fixed at `fixing_date` starts accruing
+    pub fn value_date(&self, fixing_date: &Date) -> Date {
+        self.ibor_index.value_date(fixing_date)
+    }
+
+    /// Returns the fixing at the given date: the published one for past dates, see
+    /// [IborIndex::known_fixing], otherwise the simple forward rate from the value date to the
+    /// next business day.
+    pub fn fixing(&self, fixing_date: &Date) -> Rate {
+        if let Some(fixing) = self.ibor_index.known_fixing(fixing_date) {
+            return fixing;
+        }
+        let start = self.value_date(fixing_date);
+        let end = self.fixing_calendar().nth_business_day(&start, 1);
+        let curve = self.ibor_index.forwarding_term_structure.current_link();
+        self.ibor_index
+            .forecast_fixing_between(&start, &end, curve.as_ref())
+    }
+}
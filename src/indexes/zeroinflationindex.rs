@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::{
+    datetime::{date::Date, frequency::Frequency, period::Period, timeunit::TimeUnit::Months},
+    types::{Integer, Real},
+};
+
+/// Base class for zero inflation indices, e.g. a CPI.
+///
+/// Fixings are index levels published once per period of the index frequency, e.g. monthly or
+/// quarterly, stored against the first day of the period they refer to. No inflation term
+/// structure is attached, so only historical fixings can be returned.
+#[derive(Debug, Clone)]
+pub struct ZeroInflationIndex {
+    pub family_name: String,
+    pub region: String,
+    pub frequency: Frequency,
+    pub availability_lag: Period,
+    /// If true, fixings are interpolated linearly between monthly levels; otherwise the level
+    /// of the reference month is used as is (flat).
+    pub interpolated: bool,
+    fixings: HashMap<Date, Real>,
+}
+
+impl ZeroInflationIndex {
+    pub fn new(
+        family_name: &str,
+        region: &str,
+        frequency: Frequency,
+        availability_lag: Period,
+        interpolated: bool,
+    ) -> Self {
+        let periods_per_year: Integer = frequency.into();
+        assert!(
+            (1..=12).contains(&periods_per_year) && 12 % periods_per_year == 0,
+            "frequency {:?} not allowed for an inflation index",
+            frequency
+        );
+        Self {
+            family_name: family_name.into(),
+            region: region.into(),
+            frequency,
+            availability_lag,
+            interpolated,
+            fixings: HashMap::new(),
+        }
+    }
+
+    /// Return the name of the index, e.g. "EU HICP"
+    pub fn name(&self) -> String {
+        format!("{} {}", self.region, self.family_name)
+    }
+
+    /// Store the index level for the period of the given date
+    pub fn add_fixing(&mut self, date: Date, value: Real) {
+        self.fixings.insert(self.period_start(date), value);
+    }
+
+    /// Returns the index level published for the period of the given date
+    pub fn monthly_fixing(&self, date: &Date) -> Real {
+        let reference = self.period_start(*date);
+        *self
+            .fixings
+            .get(&reference)
+            .unwrap_or_else(|| panic!("Missing {} fixing for {:?}", self.name(), reference))
+    }
+
+    /// Returns the index fixing at the given date.
+    ///
+    /// The reference period is obtained by moving `date` back by the availability lag. For
+    /// interpolated indices the result is linear between the level of the reference period and
+    /// the one of the following period, weighted by how far `date` is into its own period; e.g.
+    /// 13/31 for the 14th of May with a monthly index.
+    pub fn fixing(&self, date: &Date) -> Real {
+        let reference = self.period_start(date - self.availability_lag);
+        let value = self.monthly_fixing(&reference);
+        let start = self.period_start(*date);
+        if !self.interpolated || *date == start {
+            return value;
+        }
+        let next_value = self.monthly_fixing(&(reference + self.period_length()));
+        let end = start + self.period_length();
+        let weight = (*date - start) as Real / (end - start) as Real;
+        value + weight * (next_value - value)
+    }
+
+    /// Length of the period covered by one fixing, e.g. 3 months for a quarterly index
+    fn period_length(&self) -> Period {
+        let periods_per_year: Integer = self.frequency.into();
+        Period::new(12 / periods_per_year, Months)
+    }
+
+    /// First day of the fixing period containing the given date
+    fn period_start(&self, date: Date) -> Date {
+        let months = self.period_length().length;
+        let month: Integer = date.month().into();
+        let first_month = (month - 1) / months * months + 1;
+        Date::new(1, first_month.into(), date.year())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{
+        date::Date,
+        frequency::Frequency::{Monthly, Quarterly, Weekly},
+        months::Month::*,
+        period::Period,
+        timeunit::TimeUnit::Months,
+    };
+
+    use super::ZeroInflationIndex;
+
+    fn index(interpolated: bool) -> ZeroInflationIndex {
+        let mut index =
+            ZeroInflationIndex::new("CPI", "UK", Monthly, Period::new(3, Months), interpolated);
+        index.add_fixing(Date::new(1, January, 2023), 126.4);
+        index.add_fixing(Date::new(1, February, 2023), 127.9);
+        index.add_fixing(Date::new(1, March, 2023), 128.9);
+        index
+    }
+
+    #[test]
+    fn test_flat_fixing() {
+        let index = index(false);
+        assert_eq!(index.name(), "UK CPI");
+        // the availability lag of 3 months moves April back to January
+        assert_eq!(index.fixing(&Date::new(1, April, 2023)), 126.4);
+        assert_eq!(index.fixing(&Date::new(20, April, 2023)), 126.4);
+        assert_eq!(index.fixing(&Date::new(15, May, 2023)), 127.9);
+    }
+
+    #[test]
+    fn test_interpolated_fixing() {
+        let index = index(true);
+        assert_eq!(index.fixing(&Date::new(1, May, 2023)), 127.9);
+        // 14 May is 13 days into a 31 day month, February and March are interpolated
+        let fixing = index.fixing(&Date::new(14, May, 2023));
+        let expected = 127.9 + 13.0 / 31.0 * (128.9 - 127.9);
+        assert!((fixing - expected).abs() < 1.0e-12);
+        // the weight depends on the month of the date, not on the reference month
+        let fixing = index.fixing(&Date::new(16, April, 2023));
+        let expected = 126.4 + 15.0 / 30.0 * (127.9 - 126.4);
+        assert!((fixing - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_quarterly_fixing() {
+        let mut index =
+            ZeroInflationIndex::new("CPI", "AU", Quarterly, Period::new(3, Months), true);
+        index.add_fixing(Date::new(15, February, 2023), 128.0);
+        index.add_fixing(Date::new(1, April, 2023), 129.5);
+        // May is in the second quarter, moved back to the first one by the lag
+        assert_eq!(index.fixing(&Date::new(1, April, 2023)), 128.0);
+        let fixing = index.fixing(&Date::new(1, May, 2023));
+        let expected = 128.0 + 30.0 / 91.0 * (129.5 - 128.0);
+        assert!((fixing - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "not allowed for an inflation index")]
+    fn test_weekly_frequency() {
+        ZeroInflationIndex::new("CPI", "UK", Weekly, Period::new(3, Months), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing UK CPI fixing")]
+    fn test_missing_fixing() {
+        let index = index(true);
+        index.fixing(&Date::new(14, June, 2023));
+    }
+}
@@ -1,6 +1,16 @@
+pub mod amortizingfixedratebond;
 pub mod bond;
+pub mod capfloor;
 pub mod creditdefaultswap;
+pub mod exercise;
 pub mod fixedratebond;
+pub mod forwardrateagreement;
 pub mod instrument;
+pub mod overnightindexedswap;
+pub mod payoffs;
 pub mod stock;
+pub mod swap;
+pub mod swaption;
+pub mod vanillaoption;
+pub mod vanillaswap;
 pub mod zerocouponbond;
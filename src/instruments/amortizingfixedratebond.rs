@@ -0,0 +1,230 @@
+use std::{fmt::Debug, rc::Rc};
+
+use crate::{
+    cashflows::{
+        cashflow::{CashFlow, CashFlowLeg},
+        fixedrateleg::FixedRateLeg,
+    },
+    datetime::{
+        businessdayconvention::BusinessDayConvention, calendar::Calendar, date::Date,
+        daycounter::DayCounter, frequency::Frequency::*, schedule::Schedule,
+    },
+    pricingengines::bond::bondfunctions,
+    rates::{compounding::Compounding::Simple, interestrate::InterestRate},
+    types::{Integer, Rate, Real},
+};
+
+use super::bond::{calculate_notionals_from_cashflows, calculate_redemptions, Bond};
+
+/// Amortizing fixed-rate bond
+///
+/// The outstanding notional is given for each coupon period; coupons accrue on the notional of
+/// their period, and each decrease of the notional is repaid at the end of the period.
+pub struct AmortizingFixedRateBond {
+    pub settlement_days: Integer,
+    pub calendar: Calendar,
+    pub maturity_date: Date,
+    pub issue_date: Date,
+    pub notionals: Vec<Real>,
+    pub notional_schedule: Vec<Date>,
+    pub cashflows: CashFlowLeg,
+    pub redemptions: CashFlowLeg,
+}
+
+impl Debug for AmortizingFixedRateBond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AFRB/{}-{:02}-{:02}",
+            self.maturity_date.year(),
+            self.maturity_date.month() as Integer,
+            self.maturity_date.day_of_month(),
+        )
+    }
+}
+
+impl AmortizingFixedRateBond {
+    pub fn new(
+        settlement_days: Integer,
+        notionals: Vec<Real>,
+        schedule: Schedule,
+        coupons: Vec<Rate>,
+        accrual_daycounter: DayCounter,
+    ) -> Self {
+        Self::new_with_options(
+            settlement_days,
+            notionals,
+            schedule,
+            coupons,
+            accrual_daycounter,
+            None,
+            None,
+        )
+    }
+
+    /// The `notionals` are the outstanding notionals of the coupon periods, in order; if there
+    /// are fewer notionals than periods, the last one applies to the remaining periods.
+    pub fn new_with_options(
+        settlement_days: Integer,
+        notionals: Vec<Real>,
+        schedule: Schedule,
+        coupons: Vec<Rate>,
+        accrual_daycounter: DayCounter,
+        payment_convention: Option<BusinessDayConvention>,
+        issue_date: Option<Date>,
+    ) -> Self {
+        assert!(!notionals.is_empty(), "no notionals given");
+        let calendar = schedule.calendar().clone();
+        let coupon_rates = coupons
+            .into_iter()
+            .map(|c| InterestRate::new(c, accrual_daycounter.clone(), Simple, Annual))
+            .collect();
+        let coupons = FixedRateLeg::new(schedule.clone(), notionals, coupon_rates)
+            .with_payment_adjustment(payment_convention.unwrap_or(BusinessDayConvention::Following))
+            .with_payment_calendar(calendar.clone())
+            .build();
+
+        let (notionals, notional_schedule) = calculate_notionals_from_cashflows(&coupons);
+        let redemptions = calculate_redemptions(&notionals, &notional_schedule, &[100.0]);
+
+        let mut cashflows = CashFlowLeg::new();
+        for c in coupons.iter() {
+            cashflows.push(Rc::new(c.clone()));
+        }
+        for r in redemptions.iter() {
+            cashflows.push(r.clone());
+        }
+        // principal repayments follow the coupons paid on the same date (stable sort)
+        cashflows.sort_by_key(|a| a.date());
+
+        Self {
+            settlement_days,
+            calendar,
+            maturity_date: *schedule.end_date(),
+            issue_date: issue_date.unwrap_or_default(),
+            notionals,
+            notional_schedule,
+            cashflows,
+            redemptions,
+        }
+    }
+}
+
+impl Bond for AmortizingFixedRateBond {
+    fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    fn cashflows(&self) -> &CashFlowLeg {
+        &self.cashflows
+    }
+
+    fn issue_date(&self) -> Date {
+        self.issue_date
+    }
+
+    fn maturity_date(&self) -> Date {
+        bondfunctions::maturity_date(&self.cashflows)
+    }
+
+    fn notional_schedule(&self) -> &Vec<Date> {
+        &self.notional_schedule
+    }
+
+    fn notionals(&self) -> &Vec<Real> {
+        &self.notionals
+    }
+
+    fn settlement_days(&self) -> Integer {
+        self.settlement_days
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing, date::Date,
+            daycounter::DayCounter, frequency::Frequency::Annual, holidays::target::Target,
+            months::Month::*, period::Period, schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::Years,
+        },
+        instruments::bond::Bond,
+        rates::compounding::Compounding,
+        types::Real,
+    };
+
+    use super::AmortizingFixedRateBond;
+
+    #[test]
+    fn test_linear_amortizer() {
+        let today = Date::new(15, January, 2024);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            today,
+            today + Period::new(4, Years),
+            Period::new(1, Years),
+            Target::new(),
+        )
+        .with_convention(ModifiedFollowing)
+        .build();
+        let notionals = vec![100.0, 75.0, 50.0, 25.0];
+        let bond = AmortizingFixedRateBond::new(
+            2,
+            notionals.clone(),
+            schedule.clone(),
+            vec![0.05],
+            DayCounter::bond_basis(),
+        );
+
+        let coupons: Vec<_> = bond
+            .cashflows()
+            .iter()
+            .filter_map(|cf| cf.as_coupon())
+            .collect();
+        assert_eq!(coupons.len(), 4);
+        for (i, coupon) in coupons.iter().enumerate() {
+            assert_eq!(coupon.nominal(), notionals[i]);
+            assert!(
+                (coupon.amount() - notionals[i] * 0.05 * coupon.accrual_period()).abs() < 1e-12
+            );
+            assert_eq!(coupon.date(), schedule.dates()[i + 1]);
+        }
+
+        // principal is repaid as the notional decreases
+        assert_eq!(bond.redemptions.len(), 4);
+        for (r, date) in bond.redemptions.iter().zip(&schedule.dates()[1..]) {
+            assert_eq!(r.amount(), 25.0);
+            assert_eq!(r.date(), *date);
+        }
+        let principal: Real = bond.redemptions.iter().map(|r| r.amount()).sum();
+        assert_eq!(principal, 100.0);
+        assert_eq!(bond.notional(today), 100.0);
+        assert_eq!(bond.notional(Date::new(1, June, 2025)), 75.0);
+        assert_eq!(bond.notional(Date::new(1, June, 2027)), 25.0);
+        assert_eq!(bond.notional(Date::new(1, June, 2028)), 0.0);
+
+        // prices are quoted per 100 of outstanding notional
+        let settlement_date = Date::new(1, June, 2025);
+        let dc = DayCounter::bond_basis();
+        let clean_price = bond.clean_price_from_yield(
+            0.04,
+            dc.clone(),
+            Compounding::Compounded,
+            Annual,
+            settlement_date,
+        );
+        assert!(clean_price > 100.0);
+        let bond_yield = bond.bond_yield(
+            clean_price,
+            dc,
+            Compounding::Compounded,
+            Annual,
+            settlement_date,
+        );
+        assert!((bond_yield - 0.04).abs() < 1e-8, "yield: {}", bond_yield);
+    }
+}
@@ -1,12 +1,19 @@
+use std::rc::Rc;
+
 use crate::{
-    cashflows::cashflow::CashFlowLeg,
-    datetime::{
-        calendar::Calendar, date::Date, daycounter::DayCounter, frequency::Frequency,
-        timeunit::TimeUnit::Days,
+    cashflows::{
+        cash_flows,
+        cashflow::{CashFlow, CashFlowLeg},
+        coupon::Coupon,
+        simplecashflow::{AmortizingPayment, Redemption},
     },
-    maths::bounds::lower_bound,
+    datetime::{calendar::Calendar, date::Date, daycounter::DayCounter, frequency::Frequency},
+    handle::Handle,
+    instruments::instrument::{settlement_date, InstrumentResults},
+    maths::{bounds::lower_bound, comparison::close},
     pricingengines::bond::bondfunctions,
     rates::{compounding::Compounding, interestrate::InterestRate},
+    termstructures::yieldtermstructure::YieldTermStructure,
     types::{Integer, Rate, Real, Size},
 };
 
@@ -26,11 +33,7 @@ pub trait Bond {
         if current_notional == 0.0 {
             return 0.0;
         }
-        bondfunctions::accrued_amount(
-            self.cashflows(),
-            self.notional(date),
-            date,
-        )
+        bondfunctions::accrued_amount(self.cashflows(), self.notional(date), date)
     }
 
     /// Calculate the yield given a (clean) price and settlement date.
@@ -43,11 +46,17 @@ pub trait Bond {
         settlement_date: Date,
     ) -> Rate {
         self.bond_yield_with_options(
-            clean_price, daycounter, compounding, frequency, settlement_date,
-            None, None, None
+            clean_price,
+            daycounter,
+            compounding,
+            frequency,
+            settlement_date,
+            None,
+            None,
+            None,
         )
     }
-    
+
     #[allow(clippy::too_many_arguments)]
     /// Calculate the yield given a (clean) price and settlement date.
     fn bond_yield_with_options(
@@ -61,36 +70,16 @@ pub trait Bond {
         max_evaluations: Option<Size>,
         guess: Option<Real>,
     ) -> Rate {
-        assert!(
-            self.is_tradeable(settlement_date),
-            "Non tradeable at {:?}, (maturity being {:?})",
-            settlement_date,
-            self.maturity_date()
-        );
-
-        let accuracy = accuracy.unwrap_or(1.0e-8);
-        let max_evaluations = max_evaluations.unwrap_or(100);
-        let guess = guess.unwrap_or(0.05);
-
-        let current_notional = self.notional(settlement_date);
-        if current_notional == 0.0 {
-            return 0.0;
-        }
-
-        let accrued_amount = self.accrued_amount(settlement_date);
-        let mut dirty_price = clean_price + accrued_amount;
-        dirty_price /= 100.0 / self.notional(settlement_date);
-
-        bondfunctions::bond_yield(
-            self.cashflows(),
-            dirty_price,
+        bondfunctions::yield_from_clean_price(
+            self,
+            clean_price,
             daycounter,
             compounding,
             frequency,
             settlement_date,
-            accuracy,
-            max_evaluations,
-            guess,
+            accuracy.unwrap_or(1.0e-8),
+            max_evaluations.unwrap_or(100),
+            guess.unwrap_or(0.05),
         )
     }
 
@@ -122,6 +111,73 @@ pub trait Bond {
         todo!()
     }
 
+    /// Clean price as of the settlement date, discounting each cash flow on the given curve
+    fn clean_price_from_curve(
+        &self,
+        discount_curve: &dyn YieldTermStructure,
+        settlement_date: Date,
+    ) -> Real {
+        self.dirty_price_from_curve(discount_curve, settlement_date)
+            - self.accrued_amount(settlement_date)
+    }
+
+    /// Dirty price as of the settlement date, discounting each cash flow on the given curve
+    fn dirty_price_from_curve(
+        &self,
+        discount_curve: &dyn YieldTermStructure,
+        settlement_date: Date,
+    ) -> Real {
+        let current_notional = self.notional(settlement_date);
+        if current_notional == 0.0 {
+            return 0.0;
+        }
+        bondfunctions::dirty_price_from_curve(
+            current_notional,
+            self.cashflows(),
+            discount_curve,
+            settlement_date,
+        )
+    }
+
+    /// Present value, as of the reference date of the curve, of the cash flows paid after the
+    /// settlement date, each discounted on the given curve rather than with a single yield.
+    ///
+    /// Flows paid on the settlement date are only included if `include_settlement_date_flows`
+    /// is set. Unlike the prices, the result is an amount, not a percentage of the notional.
+    fn npv(
+        &self,
+        discount_curve: &dyn YieldTermStructure,
+        settlement_date: Date,
+        include_settlement_date_flows: bool,
+    ) -> Real {
+        cash_flows::npv(
+            self.cashflows(),
+            discount_curve,
+            include_settlement_date_flows,
+            settlement_date,
+            Date::default(),
+        )
+    }
+
+    /// Yield corresponding to the clean price implied by the given curve
+    fn bond_yield_from_curve(
+        &self,
+        discount_curve: &dyn YieldTermStructure,
+        daycounter: DayCounter,
+        compounding: Compounding,
+        frequency: Frequency,
+        settlement_date: Date,
+    ) -> Rate {
+        let clean_price = self.clean_price_from_curve(discount_curve, settlement_date);
+        self.bond_yield(
+            clean_price,
+            daycounter,
+            compounding,
+            frequency,
+            settlement_date,
+        )
+    }
+
     /// Clean price given a yield and settlement date
     fn clean_price_from_yield(
         &self,
@@ -144,19 +200,8 @@ pub trait Bond {
         frequency: Frequency,
         settlement_date: Date,
     ) -> Real {
-        assert!(
-            self.is_tradeable(settlement_date),
-            "Non tradeable at {:?}, (maturity being {:?})",
-            settlement_date,
-            self.maturity_date()
-        );
         let y = InterestRate::new(y, daycounter, compounding, frequency);
-        bondfunctions::dirty_price(
-            self.notional(settlement_date),
-            self.cashflows(),
-            &y,
-            settlement_date,
-        )
+        bondfunctions::dirty_price_from_yield(self, &y, settlement_date)
     }
 
     /// Returns whether this Bond still tradeable or not
@@ -190,26 +235,21 @@ pub trait Bond {
         let i = lower_bound(notional_schedule, date);
         if date < notional_schedule[i] {
             // no doubt about what to return
-            return self.notionals()[i - 1];
+            self.notionals()[i - 1]
         } else {
             // `date` is equal to a redemption date.
             // As per bond conventions, the payment has occurred; the bond already changed notional.
-            return self.notionals()[i];
+            self.notionals()[i]
         }
     }
 
     /// Return the notionals
     fn notionals(&self) -> &Vec<Real>;
 
-    /// Calculate the settlement date
+    /// Calculate the settlement date for the given evaluation date
     fn settlement_date(&self, date: Date) -> Date {
         // usually, the settlement is at T+n...
-        let settlement = self.calendar().advance_by_days_with_following(
-            date,
-            self.settlement_days(),
-            Days,
-            false,
-        );
+        let settlement = settlement_date(date, self.settlement_days(), self.calendar());
         // ...but the bond won't be traded until the issue date (if given.)
         if self.issue_date() == Date::default() {
             settlement
@@ -221,3 +261,87 @@ pub trait Bond {
     /// Return the number of settlement days
     fn settlement_days(&self) -> Integer;
 }
+
+/// Results of a bond discounted on the given curve: the NPV, as of the reference date of the
+/// curve, of the cash flows paid after the settlement date for that date, with the dirty and
+/// clean prices at settlement as additional results.
+pub(crate) fn discounting_results<B: Bond>(
+    bond: &B,
+    discount_curve: &Handle<dyn YieldTermStructure>,
+) -> InstrumentResults {
+    assert!(!discount_curve.is_empty(), "no discount curve given");
+    let discount_curve = discount_curve.current_link();
+    let valuation_date = discount_curve.reference_date();
+    let settlement_date = bond.settlement_date(valuation_date);
+    let npv = bond.npv(discount_curve.as_ref(), settlement_date, false);
+    InstrumentResults::new(npv, 0.0, valuation_date)
+        .with_additional_result(
+            "dirty_price",
+            bond.dirty_price_from_curve(discount_curve.as_ref(), settlement_date),
+        )
+        .with_additional_result(
+            "clean_price",
+            bond.clean_price_from_curve(discount_curve.as_ref(), settlement_date),
+        )
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Gather the notionals of a bond, and the dates at which they change, from its coupons.
+///
+/// The first date of the schedule is null and the last notional is zero, after redemption.
+pub(crate) fn calculate_notionals_from_cashflows<T: Coupon>(
+    coupons: &[T],
+) -> (Vec<Real>, Vec<Date>) {
+    let mut notionals: Vec<Real> = Vec::new();
+    let mut notional_schedule = vec![Date::default()];
+    let mut last_payment_date = Date::default();
+
+    for cf in coupons.iter() {
+        let notional = cf.nominal();
+        if notionals.is_empty() {
+            last_payment_date = cf.date();
+            notionals.push(notional);
+        } else if !close(notional, notionals[notionals.len() - 1]) {
+            notionals.push(cf.nominal());
+            notional_schedule.push(last_payment_date);
+            last_payment_date = cf.date();
+        } else {
+            last_payment_date = cf.date();
+        }
+    }
+    assert!(!notionals.is_empty(), "No coupons provided");
+    notionals.push(0.0);
+    notional_schedule.push(last_payment_date);
+
+    (notionals, notional_schedule)
+}
+
+/// Principal repayments for the changes of notional, as percentages of the amortized amounts.
+///
+/// The last repayment is a [Redemption], the earlier ones are [AmortizingPayment]s.
+pub(crate) fn calculate_redemptions(
+    notionals: &[Real],
+    notional_schedule: &[Date],
+    redemption_values: &[Real],
+) -> CashFlowLeg {
+    let mut redemptions = CashFlowLeg::new();
+    for i in 1..notional_schedule.len() {
+        let r = if i < redemption_values.len() {
+            redemption_values[i]
+        } else if !redemption_values.is_empty() {
+            redemption_values[redemption_values.len() - 1]
+        } else {
+            100.0
+        };
+        let amount = (r / 100.0) * (notionals[i - 1] - notionals[i]);
+        let payment: Rc<dyn CashFlow> = if i < notional_schedule.len() - 1 {
+            Rc::new(AmortizingPayment::new(amount, notional_schedule[i]))
+        } else {
+            Rc::new(Redemption::new(amount, notional_schedule[i]))
+        };
+        redemptions.push(payment);
+    }
+
+    redemptions
+}
@@ -0,0 +1,254 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{cashflow::CashFlow, iborcoupon::IborCoupon},
+    datetime::date::Date,
+    maths::solvers1d::{brent::Brent, solver1d::Solver1D},
+    pricingengines::pricingengine::{Arguments, PricingEngine, Results},
+    types::{Rate, Real},
+};
+
+use super::instrument::{Instrument, InstrumentCache, InstrumentResults};
+
+/// Type of a cap/floor instrument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapFloorType {
+    Cap,
+    Floor,
+    /// Long a cap and short a floor
+    Collar,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Cap, floor or collar on a leg of floating rate coupons
+///
+/// Each coupon of the leg gives rise to an optionlet (caplet or floorlet) on its index fixing,
+/// paid at the coupon payment date. When fewer cap or floor rates than coupons are given, the
+/// last rate applies to the remaining coupons.
+pub struct CapFloor {
+    pub cap_floor_type: CapFloorType,
+    pub floating_leg: Vec<IborCoupon>,
+    pub cap_rates: Vec<Rate>,
+    pub floor_rates: Vec<Rate>,
+    pub engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl CapFloor {
+    pub fn new(
+        cap_floor_type: CapFloorType,
+        floating_leg: Vec<IborCoupon>,
+        cap_rates: Vec<Rate>,
+        floor_rates: Vec<Rate>,
+        engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    ) -> Self {
+        assert!(!floating_leg.is_empty(), "empty floating leg given");
+        if cap_floor_type != CapFloorType::Floor {
+            assert!(!cap_rates.is_empty(), "no cap rates given");
+        }
+        if cap_floor_type != CapFloorType::Cap {
+            assert!(!floor_rates.is_empty(), "no floor rates given");
+        }
+        let n = floating_leg.len();
+        let cache = Rc::new(InstrumentCache::new());
+        engine.register_observer(cache.clone());
+        for coupon in &floating_leg {
+            coupon
+                .index
+                .forwarding_term_structure
+                .register_observer(cache.clone());
+        }
+        Self {
+            cap_floor_type,
+            floating_leg,
+            cap_rates: extend_rates(cap_rates, n),
+            floor_rates: extend_rates(floor_rates, n),
+            engine,
+            cache,
+        }
+    }
+
+    /// Cap with the same strike for all the coupons
+    pub fn cap(
+        floating_leg: Vec<IborCoupon>,
+        strike: Rate,
+        engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    ) -> Self {
+        Self::new(
+            CapFloorType::Cap,
+            floating_leg,
+            vec![strike],
+            vec![],
+            engine,
+        )
+    }
+
+    /// Floor with the same strike for all the coupons
+    pub fn floor(
+        floating_leg: Vec<IborCoupon>,
+        strike: Rate,
+        engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    ) -> Self {
+        Self::new(
+            CapFloorType::Floor,
+            floating_leg,
+            vec![],
+            vec![strike],
+            engine,
+        )
+    }
+
+    /// Collar, i.e. long a cap and short a floor, with the same strikes for all the coupons
+    pub fn collar(
+        floating_leg: Vec<IborCoupon>,
+        cap_strike: Rate,
+        floor_strike: Rate,
+        engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    ) -> Self {
+        Self::new(
+            CapFloorType::Collar,
+            floating_leg,
+            vec![cap_strike],
+            vec![floor_strike],
+            engine,
+        )
+    }
+
+    /// Floor strike for which the collar with the given cap strike, i.e. long the cap and short
+    /// the floor, is worth zero on the given engine.
+    ///
+    /// The collar value decreases with the floor strike; the root is searched with a Brent
+    /// solver between zero and the first of the cap strike and its doublings at which the
+    /// collar is worth less than zero, down to machine precision on the strike so that the
+    /// collar is worth zero to within rounding errors.
+    ///
+    /// The lower bracket is a zero floor strike, where a floor on a lognormal rate is worthless;
+    /// the engine is opaque here, so negative floor strikes, which can be meaningful with a
+    /// shifted-lognormal or normal volatility, are not searched. Panics if the collar is not
+    /// worth more than zero with a zero floor strike, e.g. because the cap itself is worthless.
+    pub fn zero_cost_collar_floor_strike(
+        floating_leg: Vec<IborCoupon>,
+        cap_strike: Rate,
+        engine: Rc<dyn PricingEngine<A = CapFloorArguments, R = CapFloorResults>>,
+    ) -> Rate {
+        let collar_npv = |floor_strike: Rate| {
+            Self::collar(
+                floating_leg.clone(),
+                cap_strike,
+                floor_strike,
+                engine.clone(),
+            )
+            .results()
+            .npv
+        };
+        let min_strike = 0.0;
+        let min_npv = collar_npv(min_strike);
+        assert!(
+            min_npv > 0.0,
+            "no zero-cost collar with cap strike {}: collar worth {} with a zero floor strike",
+            cap_strike,
+            min_npv
+        );
+        let mut max_strike = cap_strike.max(0.01);
+        for _ in 0..MAX_STRIKE_DOUBLINGS {
+            if collar_npv(max_strike) < 0.0 {
+                return Brent::default().solve_bracketed(
+                    collar_npv,
+                    |_| 0.0,
+                    f64::EPSILON,
+                    0.5 * (min_strike + max_strike),
+                    min_strike,
+                    max_strike,
+                );
+            }
+            max_strike *= 2.0;
+        }
+        panic!(
+            "no zero-cost collar with cap strike {}: collar still worth more than zero with a \
+             floor strike of {}",
+            cap_strike, max_strike
+        );
+    }
+
+    /// Start of the accrual period of the first coupon
+    pub fn start_date(&self) -> Date {
+        self.floating_leg
+            .iter()
+            .map(|c| c.accrual_start_date())
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// End of the accrual period of the last coupon
+    pub fn maturity_date(&self) -> Date {
+        self.floating_leg
+            .iter()
+            .map(|c| c.accrual_end_date())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Runs the pricing engine and returns all the results
+    pub fn results(&self) -> CapFloorResults {
+        self.engine.calculate(CapFloorArguments {
+            cap_floor_type: self.cap_floor_type,
+            floating_leg: self.floating_leg.clone(),
+            cap_rates: self.cap_rates.clone(),
+            floor_rates: self.floor_rates.clone(),
+        })
+    }
+}
+
+impl Instrument for CapFloor {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        let results = self.results();
+        InstrumentResults::new(results.npv, results.error_estimate, results.valuation_date)
+    }
+}
+
+/// Number of times the upper floor strike is doubled when bracketing a zero-cost collar
+const MAX_STRIKE_DOUBLINGS: usize = 20;
+
+fn extend_rates(mut rates: Vec<Rate>, n: usize) -> Vec<Rate> {
+    if let Some(&last) = rates.last() {
+        if rates.len() < n {
+            rates.resize(n, last);
+        }
+    }
+    rates
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Arguments for cap/floor calculation
+///
+/// The cap and floor rates are given per coupon; either may be empty when not relevant for
+/// the instrument type.
+#[derive(Clone)]
+pub struct CapFloorArguments {
+    pub cap_floor_type: CapFloorType,
+    pub floating_leg: Vec<IborCoupon>,
+    pub cap_rates: Vec<Rate>,
+    pub floor_rates: Vec<Rate>,
+}
+
+impl Arguments for CapFloorArguments {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Results from cap/floor calculation
+#[derive(Debug, Clone)]
+pub struct CapFloorResults {
+    pub npv: Real,
+    pub error_estimate: Real,
+    pub valuation_date: Date,
+    /// Value of each optionlet, in the order of the coupons of the floating leg
+    pub optionlet_prices: Vec<Real>,
+}
+
+impl Results for CapFloorResults {}
@@ -1,6 +1,12 @@
 use crate::datetime::{
-    date::Date, dategenerationrule::DateGenerationRule, months::Month::*, period::Period,
-    schedule::previous_twentieth, timeunit::TimeUnit::*,
+    businessdayconvention::BusinessDayConvention,
+    date::Date,
+    dategenerationrule::DateGenerationRule,
+    holidays::weekendsonly::WeekendsOnly,
+    months::Month::*,
+    period::Period,
+    schedule::{next_twentieth, previous_twentieth},
+    timeunit::TimeUnit::*,
 };
 
 // CDS Instrument
@@ -54,3 +60,92 @@ pub fn cds_maturity(trade_date: &Date, tenor: Period, rule: DateGenerationRule)
 
     maturity
 }
+
+/// Returns the CDS roll date on or before `date`, i.e. the 20th of March, June, September or
+/// December for the CDS date generation rules.
+pub fn cds_previous_twentieth(date: &Date, rule: DateGenerationRule) -> Date {
+    previous_twentieth(date, rule)
+}
+
+/// Returns the CDS roll date on or after `date`, i.e. the 20th of March, June, September or
+/// December for the CDS date generation rules.
+pub fn cds_next_twentieth(date: &Date, rule: DateGenerationRule) -> Date {
+    next_twentieth(date, rule)
+}
+
+/// Returns the start of accrual of a CDS traded on `trade_date`: the latest roll date whose
+/// following business day is not after the trade date, adjusted with the weekends-only calendar.
+///
+/// This is the start date of the schedule built by [crate::datetime::schedule::Schedule] for
+/// the same trade date and rule, with the `Following` convention.
+pub fn cds_effective_date(trade_date: &Date, rule: DateGenerationRule) -> Date {
+    assert!(
+        rule == DateGenerationRule::CDS2015 || rule == DateGenerationRule::CDS,
+        "cds_effective_date should only be used with date generation rule CDS2015 or CDS"
+    );
+    let calendar = WeekendsOnly::new();
+    let convention = BusinessDayConvention::Following;
+    let mut start = previous_twentieth(trade_date, rule);
+    if calendar.adjust(start, convention) > *trade_date {
+        start -= Period::new(3, Months);
+    }
+    calendar.adjust(start, convention)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{date::Date, dategenerationrule::DateGenerationRule, months::Month::*};
+
+    use super::{cds_effective_date, cds_next_twentieth, cds_previous_twentieth};
+
+    #[test]
+    fn test_cds_effective_date() {
+        let rule = DateGenerationRule::CDS2015;
+        for (trade_date, start) in [
+            (
+                Date::new(12, December, 2016),
+                Date::new(20, September, 2016),
+            ),
+            (Date::new(1, March, 2017), Date::new(20, December, 2016)),
+            (Date::new(20, March, 2017), Date::new(20, March, 2017)),
+            // 20 Sep 2015 is a Sunday, so the roll only happens on Monday 21st
+            (Date::new(19, September, 2015), Date::new(22, June, 2015)),
+            (Date::new(20, September, 2015), Date::new(22, June, 2015)),
+            (
+                Date::new(21, September, 2015),
+                Date::new(21, September, 2015),
+            ),
+        ] {
+            assert_eq!(
+                cds_effective_date(&trade_date, rule),
+                start,
+                "{:?}",
+                trade_date
+            );
+        }
+    }
+
+    #[test]
+    fn test_cds_twentieths() {
+        let rule = DateGenerationRule::CDS;
+        let d = Date::new(12, December, 2016);
+        assert_eq!(
+            cds_previous_twentieth(&d, rule),
+            Date::new(20, September, 2016)
+        );
+        assert_eq!(cds_next_twentieth(&d, rule), Date::new(20, December, 2016));
+        let d = Date::new(20, December, 2016);
+        assert_eq!(cds_previous_twentieth(&d, rule), d);
+        assert_eq!(cds_next_twentieth(&d, rule), d);
+        // without the CDS rules every month has a roll date
+        let d = Date::new(21, January, 2017);
+        let rule = DateGenerationRule::Twentieth;
+        assert_eq!(
+            cds_previous_twentieth(&d, rule),
+            Date::new(20, January, 2017)
+        );
+        assert_eq!(cds_next_twentieth(&d, rule), Date::new(20, February, 2017));
+    }
+}
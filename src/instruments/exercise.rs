@@ -0,0 +1,139 @@
+use crate::datetime::date::Date;
+
+/// Type of exercise allowed by an option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExerciseType {
+    /// Exercise only at expiry
+    European,
+    /// Exercise at any date between the earliest and the latest date
+    American,
+    /// Exercise at a discrete set of dates
+    Bermudan,
+}
+
+/// Exercise schedule of an option
+///
+/// * a European exercise holds the single expiry date,
+/// * an American exercise holds the earliest and the latest exercise dates,
+/// * a Bermudan exercise holds the sorted set of allowed exercise dates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub exercise_type: ExerciseType,
+    dates: Vec<Date>,
+}
+
+impl Exercise {
+    /// European exercise at the given expiry date
+    pub fn european(date: Date) -> Self {
+        Self {
+            exercise_type: ExerciseType::European,
+            dates: vec![date],
+        }
+    }
+
+    /// American exercise at any date between `earliest_date` and `latest_date` included
+    pub fn american(earliest_date: Date, latest_date: Date) -> Self {
+        assert!(
+            earliest_date <= latest_date,
+            "earliest > latest exercise date ({:?} > {:?})",
+            earliest_date,
+            latest_date
+        );
+        Self {
+            exercise_type: ExerciseType::American,
+            dates: vec![earliest_date, latest_date],
+        }
+    }
+
+    /// Bermudan exercise at the given dates, which must be sorted and unique
+    pub fn bermudan(dates: Vec<Date>) -> Self {
+        assert!(!dates.is_empty(), "no exercise date given");
+        assert!(
+            dates.windows(2).all(|w| w[0] < w[1]),
+            "exercise dates must be sorted and unique: {:?}",
+            dates
+        );
+        Self {
+            exercise_type: ExerciseType::Bermudan,
+            dates,
+        }
+    }
+
+    /// Returns the dates held by the exercise
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+
+    /// Returns the `index`-th exercise date
+    pub fn date(&self, index: usize) -> Date {
+        self.dates[index]
+    }
+
+    /// Returns the latest date at which the option can be exercised
+    pub fn last_date(&self) -> Date {
+        *self.dates.last().unwrap()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{date::Date, months::Month::*};
+
+    use super::{Exercise, ExerciseType};
+
+    #[test]
+    fn test_european_exercise() {
+        let expiry = Date::new(15, March, 2024);
+        let exercise = Exercise::european(expiry);
+        assert_eq!(exercise.exercise_type, ExerciseType::European);
+        assert_eq!(exercise.dates(), &[expiry]);
+        assert_eq!(exercise.last_date(), expiry);
+    }
+
+    #[test]
+    fn test_american_exercise() {
+        let earliest = Date::new(15, March, 2023);
+        let latest = Date::new(15, March, 2024);
+        let exercise = Exercise::american(earliest, latest);
+        assert_eq!(exercise.exercise_type, ExerciseType::American);
+        assert_eq!(exercise.date(0), earliest);
+        assert_eq!(exercise.last_date(), latest);
+    }
+
+    #[test]
+    #[should_panic(expected = "earliest > latest exercise date")]
+    fn test_american_exercise_inverted_dates() {
+        Exercise::american(Date::new(15, March, 2024), Date::new(15, March, 2023));
+    }
+
+    #[test]
+    fn test_bermudan_exercise() {
+        let dates = vec![
+            Date::new(15, March, 2023),
+            Date::new(15, September, 2023),
+            Date::new(15, March, 2024),
+        ];
+        let exercise = Exercise::bermudan(dates.clone());
+        assert_eq!(exercise.exercise_type, ExerciseType::Bermudan);
+        assert_eq!(exercise.dates(), dates.as_slice());
+        assert!(exercise.dates().windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(exercise.last_date(), Date::new(15, March, 2024));
+    }
+
+    #[test]
+    #[should_panic(expected = "exercise dates must be sorted and unique")]
+    fn test_bermudan_exercise_unsorted_dates() {
+        Exercise::bermudan(vec![
+            Date::new(15, September, 2023),
+            Date::new(15, March, 2023),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exercise dates must be sorted and unique")]
+    fn test_bermudan_exercise_duplicate_dates() {
+        Exercise::bermudan(vec![Date::new(15, March, 2023), Date::new(15, March, 2023)]);
+    }
+}
@@ -5,19 +5,22 @@ use crate::{
         cashflow::{CashFlow, CashFlowLeg},
         coupon::Coupon,
         fixedrateleg::FixedRateLeg,
-        simplecashflow::{AmortizingPayment, Redemption},
     },
     datetime::{
         businessdayconvention::BusinessDayConvention, calendar::Calendar, date::Date,
         daycounter::DayCounter, frequency::Frequency::*, period::Period, schedule::Schedule,
     },
-    maths::comparison::close,
+    handle::Handle,
     pricingengines::bond::bondfunctions,
     rates::{compounding::Compounding::Simple, interestrate::InterestRate},
-    types::{Integer, Real},
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Integer, Real, Size},
 };
 
-use super::bond::Bond;
+use super::{
+    bond::{calculate_notionals_from_cashflows, calculate_redemptions, discounting_results, Bond},
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+};
 
 /// Fixed-rate bond
 pub struct FixedRateBond {
@@ -29,6 +32,9 @@ pub struct FixedRateBond {
     pub notional_schedule: Vec<Date>,
     pub cashflows: CashFlowLeg,
     pub redemptions: CashFlowLeg,
+    /// Curve on which the cash flows are discounted when the bond is priced as an [Instrument]
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    cache: Rc<InstrumentCache>,
 }
 
 impl Debug for FixedRateBond {
@@ -131,10 +137,9 @@ impl FixedRateBond {
         let coupons = fixed_rate_coupon_builder.build();
 
         // Gather the notional information from the cashflows
-        let (notionals, notional_schedule) =
-            FixedRateBond::calculate_notionals_from_cashflows(&coupons);
+        let (notionals, notional_schedule) = calculate_notionals_from_cashflows(&coupons);
 
-        let redemptions = FixedRateBond::calculate_redemptions(
+        let redemptions = calculate_redemptions(
             &notionals,
             &notional_schedule,
             &[redemption.unwrap_or(100.0)], // redemption defaults to 100.0
@@ -170,58 +175,55 @@ impl FixedRateBond {
             notional_schedule,
             cashflows,
             redemptions,
+            discount_curve: Handle::empty(),
+            cache: Rc::new(InstrumentCache::new()),
         }
     }
 
-    fn calculate_notionals_from_cashflows<T: Coupon>(coupons: &[T]) -> (Vec<Real>, Vec<Date>) {
-        let mut notionals: Vec<Real> = Vec::new();
-        let mut notional_schedule = vec![Date::default()];
-        let mut last_payment_date = Date::default();
-
-        for cf in coupons.iter() {
-            let notional = cf.nominal();
-            if notionals.is_empty() {
-                last_payment_date = cf.date();
-                notionals.push(notional);
-            } else if !close(notional, notionals[notionals.len() - 1]) {
-                notionals.push(cf.nominal());
-                notional_schedule.push(last_payment_date);
-                last_payment_date = cf.date();
-            } else {
-                last_payment_date = cf.date();
-            }
-        }
-        assert!(!notionals.is_empty(), "No coupons provided");
-        notionals.push(0.0);
-        notional_schedule.push(last_payment_date);
+    /// Discount the cash flows on the given curve when the bond is priced as an [Instrument]
+    pub fn with_discount_curve(mut self, discount_curve: Handle<dyn YieldTermStructure>) -> Self {
+        discount_curve.register_observer(self.cache.clone());
+        self.discount_curve = discount_curve;
+        self
+    }
 
-        (notionals, notional_schedule)
+    /// Number of coupons, i.e. of cash flows other than the redemption
+    pub fn number_of_coupons(&self) -> Size {
+        self.coupons().count()
     }
 
-    fn calculate_redemptions(
-        notionals: &[Real],
-        notional_schedule: &[Date],
-        redemption_values: &[Real],
-    ) -> CashFlowLeg {
-        let mut redemptions = CashFlowLeg::new();
-        for i in 1..notional_schedule.len() {
-            let r = if i < redemption_values.len() {
-                redemption_values[i]
-            } else if !redemption_values.is_empty() {
-                redemption_values[redemption_values.len() - 1]
-            } else {
-                100.0
-            };
-            let amount = (r / 100.0) * (notionals[i - 1] - notionals[i]);
-            let payment: Rc<dyn CashFlow> = if i < notional_schedule.len() - 1 {
-                Rc::new(AmortizingPayment::new(amount, notional_schedule[i]))
-            } else {
-                Rc::new(Redemption::new(amount, notional_schedule[i]))
-            };
-            redemptions.push(payment);
-        }
+    /// Return the `i`-th coupon, counting from zero in order of payment
+    pub fn coupon(&self, i: Size) -> &dyn Coupon {
+        let n = self.number_of_coupons();
+        assert!(
+            i < n,
+            "coupon index ({}) must be less than the number of coupons ({})",
+            i,
+            n
+        );
+        self.coupons().nth(i).unwrap()
+    }
+
+    /// Return the redemption paid at maturity
+    pub fn redemption(&self) -> &dyn CashFlow {
+        self.redemptions
+            .last()
+            .expect("bond with no redemption")
+            .as_ref()
+    }
+
+    fn coupons(&self) -> impl Iterator<Item = &dyn Coupon> {
+        self.cashflows.iter().filter_map(|c| c.as_coupon())
+    }
+}
+
+impl Instrument for FixedRateBond {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
 
-        redemptions
+    fn perform_calculations(&self) -> InstrumentResults {
+        discounting_results(self, &self.discount_curve)
     }
 }
 
@@ -259,15 +261,27 @@ impl Bond for FixedRateBond {
 
 #[cfg(test)]
 mod test {
+    use std::rc::Rc;
+
     use crate::{
+        cashflows::cashflow::CashFlow,
         context::pricing_context::PricingContext,
         datetime::{
-            date::Date, daycounter::DayCounter, frequency::Frequency,
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing, date::Date,
+            daycounter::DayCounter, frequency::Frequency, holidays::target::Target,
             holidays::unitedstates::UnitedStates, months::Month::*, period::Period,
             schedulebuilder::ScheduleBuilder, timeunit::TimeUnit::*,
         },
+        handle::{Handle, RelinkableHandle},
         instruments::bond::Bond,
+        quotes::simplequote::SimpleQuote,
         rates::compounding::Compounding,
+        termstructures::{
+            flatforward::FlatForward,
+            piecewiseyieldcurve::PiecewiseYieldCurve,
+            ratehelpers::{DepositRateHelper, RateHelper},
+            yieldtermstructure::YieldTermStructure,
+        },
     };
 
     use super::FixedRateBond;
@@ -305,6 +319,7 @@ mod test {
             coupons,
             daycounter.clone(),
         ));
+        assert_eq!(bond.settlement_date(pricing_date), settlement);
 
         let clean_price = 99.0 + (18.0 + 3.0 / 4.0) / 32.0;
         let bond_yield =
@@ -317,4 +332,131 @@ mod test {
             bond_yield
         );
     }
+
+    #[test]
+    fn test_coupons_and_redemption() {
+        let pricing_date = Date::new(15, January, 2024);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(pricing_date),
+            pricing_date,
+            pricing_date + Period::new(3, Years),
+            Period::from(Frequency::Semiannual),
+            UnitedStates::government_bond(),
+        )
+        .build();
+        let bond = FixedRateBond::new(
+            1,
+            100.0,
+            schedule.clone(),
+            vec![0.04],
+            DayCounter::actual_actual_old_isma(),
+        );
+
+        assert_eq!(bond.number_of_coupons(), 6);
+        assert_eq!(bond.cashflows().len(), 7);
+        for i in 0..bond.number_of_coupons() {
+            let coupon = bond.coupon(i);
+            assert_eq!(coupon.accrual_start_date(), schedule[i]);
+            assert_eq!(coupon.accrual_end_date(), schedule[i + 1]);
+            assert!((coupon.amount() - 2.0).abs() < 1.0e-12);
+        }
+        assert_eq!(bond.redemption().amount(), 100.0);
+        assert_eq!(bond.redemption().date(), bond.coupon(5).date());
+        assert_eq!(bond.redemption().date(), bond.cashflows()[6].date());
+    }
+
+    #[test]
+    fn test_npv_from_curve() {
+        let today = Date::new(15, March, 2023);
+        let calendar = Target::new();
+        let helpers: Vec<Box<dyn RateHelper>> = [(3, 0.032), (6, 0.035), (9, 0.037), (12, 0.038)]
+            .iter()
+            .map(|&(months, rate)| {
+                Box::new(DepositRateHelper::new(
+                    Handle::new(Rc::new(SimpleQuote::new(rate))),
+                    today,
+                    Period::new(months, Months),
+                    0,
+                    &calendar,
+                    ModifiedFollowing,
+                    false,
+                    DayCounter::actual360(),
+                )) as Box<dyn RateHelper>
+            })
+            .collect();
+        let curve = PiecewiseYieldCurve::new(today, helpers, DayCounter::actual360(), None);
+
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            today,
+            today + Period::new(1, Years),
+            Period::from(Frequency::Quarterly),
+            calendar,
+        )
+        .build();
+        let bond = FixedRateBond::new(0, 100.0, schedule, vec![0.04], DayCounter::actual360());
+
+        let expected: f64 = bond
+            .cashflows()
+            .iter()
+            .map(|cf| cf.amount() * curve.discount_from_date(&cf.date(), false))
+            .sum();
+        let npv = bond.npv(&curve, today, false);
+        assert!((npv - expected).abs() < 1.0e-12);
+        let dirty_price = bond.dirty_price_from_curve(&curve, today);
+        assert!((dirty_price - expected).abs() < 1.0e-12);
+        assert_eq!(bond.clean_price_from_curve(&curve, today), dirty_price);
+
+        // on a coupon date the coupon is only counted when asked for
+        let coupon = bond.coupon(0);
+        let settlement = coupon.date();
+        let paid = coupon.amount() * curve.discount_from_date(&settlement, false);
+        let excluded = bond.npv(&curve, settlement, false);
+        let included = bond.npv(&curve, settlement, true);
+        assert!((excluded - (expected - paid)).abs() < 1.0e-12);
+        assert!((included - expected).abs() < 1.0e-12);
+        // prices are forward to the settlement date, and exclude the coupon
+        let dirty_price = bond.dirty_price_from_curve(&curve, settlement);
+        let forward = excluded / curve.discount_from_date(&settlement, false);
+        assert!((dirty_price - forward).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_cached_results() {
+        use crate::instruments::instrument::Instrument;
+
+        let today = Date::new(15, January, 2024);
+        let flat_curve = |rate: f64| -> Rc<dyn YieldTermStructure> {
+            Rc::new(FlatForward::new(
+                today,
+                rate,
+                DayCounter::actual365_fixed(),
+                Compounding::Continuous,
+                Frequency::Annual,
+            ))
+        };
+        let curve = RelinkableHandle::new(flat_curve(0.04));
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            today,
+            today + Period::new(2, Years),
+            Period::from(Frequency::Semiannual),
+            Target::new(),
+        )
+        .build();
+        let bond = FixedRateBond::new(0, 100.0, schedule, vec![0.04], DayCounter::actual360())
+            .with_discount_curve(curve.handle());
+
+        // the second call uses the cached results
+        let npv = Instrument::npv(&bond);
+        let expected = Bond::npv(&bond, curve.current_link().as_ref(), today, false);
+        assert!((npv - expected).abs() < 1.0e-12);
+        assert!(bond.cache().unwrap().is_calculated());
+        assert_eq!(Instrument::npv(&bond), npv);
+
+        // relinking the curve invalidates them
+        curve.link_to(flat_curve(0.05));
+        assert!(!bond.cache().unwrap().is_calculated());
+        assert!(Instrument::npv(&bond) < npv);
+    }
 }
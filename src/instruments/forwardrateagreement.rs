@@ -0,0 +1,254 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::date::Date,
+    handle::Handle,
+    indexes::iborindex::IborIndex,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Rate, Real},
+};
+
+use super::instrument::{Instrument, InstrumentCache, InstrumentResults};
+
+/// Position in a forward contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Receives the floating rate and pays the contracted rate
+    Long,
+    /// Pays the floating rate and receives the contracted rate
+    Short,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Forward rate agreement (FRA)
+///
+/// The contract fixes a rate for the period between the value date and the maturity date. At
+/// the value date, the difference between the index forward rate over the period and the
+/// contracted rate is settled, discounted back from maturity at the forward rate.
+pub struct ForwardRateAgreement {
+    pub value_date: Date,
+    pub maturity_date: Date,
+    pub position: Position,
+    pub strike_forward_rate: Rate,
+    pub notional: Real,
+    pub index: Rc<IborIndex>,
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl ForwardRateAgreement {
+    pub fn new(
+        value_date: Date,
+        maturity_date: Date,
+        position: Position,
+        strike_forward_rate: Rate,
+        notional: Real,
+        index: Rc<IborIndex>,
+        discount_curve: Handle<dyn YieldTermStructure>,
+    ) -> Self {
+        assert!(
+            value_date < maturity_date,
+            "value date ({:?}) must be earlier than maturity date ({:?})",
+            value_date,
+            maturity_date
+        );
+        assert!(notional > 0.0, "notional ({}) must be positive", notional);
+        let cache = Rc::new(InstrumentCache::new());
+        discount_curve.register_observer(cache.clone());
+        index
+            .forwarding_term_structure
+            .register_observer(cache.clone());
+        Self {
+            value_date,
+            maturity_date,
+            position,
+            strike_forward_rate,
+            notional,
+            index,
+            discount_curve,
+            cache,
+        }
+    }
+
+    /// Forward rate of the index between the value and maturity dates, simply compounded with
+    /// the index day counter
+    pub fn forward_rate(&self) -> Rate {
+        let curve = self.index.forwarding_term_structure.current_link();
+        self.index
+            .forecast_fixing_between(&self.value_date, &self.maturity_date, curve.as_ref())
+    }
+
+    /// Amount settled at the value date
+    pub fn forward_value(&self) -> Real {
+        let forward = self.forward_rate();
+        let tau = self.accrual_period();
+        let sign = match self.position {
+            Position::Long => 1.0,
+            Position::Short => -1.0,
+        };
+        sign * self.notional * (forward - self.strike_forward_rate) * tau / (1.0 + forward * tau)
+    }
+
+    /// Amount settled at the value date, discounted to the reference date of the discount curve
+    pub fn spot_value(&self) -> Real {
+        let discount = self
+            .discount_curve
+            .current_link()
+            .discount_from_date(&self.value_date, false);
+        self.forward_value() * discount
+    }
+
+    fn accrual_period(&self) -> Real {
+        self.index
+            .day_counter
+            .year_fraction_simple(&self.value_date, &self.maturity_date)
+    }
+}
+
+impl Instrument for ForwardRateAgreement {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        InstrumentResults::new(
+            self.spot_value(),
+            0.0,
+            self.discount_curve.current_link().reference_date(),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing, date::Date,
+            daycounter::DayCounter, frequency::Frequency::Annual, holidays::target::Target,
+            months::Month::*, period::Period, timeunit::TimeUnit::Months,
+        },
+        handle::{Handle, RelinkableHandle},
+        indexes::iborindex::IborIndex,
+        instruments::instrument::Instrument,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::{Rate, Real},
+    };
+
+    use super::{ForwardRateAgreement, Position};
+
+    #[test]
+    fn test_fra_at_the_forward_rate() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(3, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            curve.clone(),
+        ));
+        // 3x6 FRA
+        let value_date = Date::new(17, April, 2024);
+        let maturity_date = Date::new(17, July, 2024);
+        let make_fra = |position, strike| {
+            ForwardRateAgreement::new(
+                value_date,
+                maturity_date,
+                position,
+                strike,
+                1_000_000.0,
+                index.clone(),
+                curve.clone(),
+            )
+        };
+
+        let fra = make_fra(Position::Long, 0.03);
+        let tau = 91.0 / 360.0;
+        let t1 = (value_date - today) as Real / 365.0;
+        let t2 = (maturity_date - today) as Real / 365.0;
+        let expected_forward = ((0.03 * (t2 - t1)).exp() - 1.0) / tau;
+        assert!((fra.forward_rate() - expected_forward).abs() < 1.0e-15);
+        // the FRA covers the deposit period of the index fixing on its value date
+        assert_eq!(
+            fra.forward_rate(),
+            index.fixing(&index.fixing_date(&value_date))
+        );
+
+        // settlement amount, discounted at the forward rate
+        let expected_value =
+            1_000_000.0 * (expected_forward - 0.03) * tau / (1.0 + expected_forward * tau);
+        assert!((fra.forward_value() - expected_value).abs() < 1.0e-8);
+        assert!((fra.spot_value() - expected_value * (-0.03 * t1).exp()).abs() < 1.0e-8);
+        assert_eq!(fra.npv(), fra.spot_value());
+
+        // long and short positions have opposite values
+        let short = make_fra(Position::Short, 0.03);
+        assert!((short.npv() + fra.npv()).abs() < 1.0e-10);
+
+        // a FRA at the forward rate is worth nothing
+        for position in [Position::Long, Position::Short] {
+            let fra = make_fra(position, fra.forward_rate());
+            assert!(fra.npv().abs() < 1.0e-10, "{:?}: {}", position, fra.npv());
+        }
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let today = Date::new(15, January, 2024);
+        let flat_curve = |rate: Rate| -> Rc<dyn YieldTermStructure> {
+            Rc::new(FlatForward::new(
+                today,
+                rate,
+                DayCounter::actual365_fixed(),
+                Continuous,
+                Annual,
+            ))
+        };
+        let curve = RelinkableHandle::new(flat_curve(0.03));
+        let index = Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(3, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            curve.handle(),
+        ));
+        let fra = ForwardRateAgreement::new(
+            Date::new(17, April, 2024),
+            Date::new(17, July, 2024),
+            Position::Long,
+            0.03,
+            1_000_000.0,
+            index,
+            curve.handle(),
+        );
+
+        // the second call uses the cached results
+        let npv = fra.npv();
+        assert_eq!(fra.npv(), npv);
+        assert!(fra.cache().unwrap().is_calculated());
+
+        // relinking the curve invalidates them
+        curve.link_to(flat_curve(0.04));
+        assert!(!fra.cache().unwrap().is_calculated());
+        assert!(fra.npv() > npv);
+        assert!(fra.cache().unwrap().is_calculated());
+    }
+}
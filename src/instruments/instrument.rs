@@ -1,22 +1,124 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::currencies::currency::Currency;
+use crate::datetime::businessdayconvention::BusinessDayConvention::Following;
+use crate::datetime::calendar::Calendar;
 use crate::datetime::date::Date;
+use crate::datetime::timeunit::TimeUnit::Days;
+use crate::patterns::observable::Observer;
 use crate::pricingengines::pricingengine::Results;
-use crate::types::Real;
+use crate::types::{Integer, Real};
 
 // -------------------------------------------------------------------------------------------------
 
+/// Results of an instrument calculation
+///
+/// Besides the NPV, engine-specific quantities such as a fair rate or the NPV of each leg are
+/// stored by name in `additional_results`.
+#[derive(Clone)]
 pub struct InstrumentResults {
     pub npv: Real,
+    /// Currency of the NPV, if known
+    pub currency: Option<Rc<dyn Currency>>,
+    /// Error estimate on the NPV, when available (e.g. for Monte Carlo engines)
     pub error_estimate: Real,
     pub valuation_date: Date,
+    pub additional_results: HashMap<String, Real>,
+}
+
+impl InstrumentResults {
+    pub fn new(npv: Real, error_estimate: Real, valuation_date: Date) -> Self {
+        Self {
+            npv,
+            currency: None,
+            error_estimate,
+            valuation_date,
+            additional_results: HashMap::new(),
+        }
+    }
+
+    pub fn with_currency(mut self, currency: Rc<dyn Currency>) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn with_additional_result(mut self, name: &str, value: Real) -> Self {
+        self.additional_results.insert(name.to_string(), value);
+        self
+    }
+
+    /// Returns the additional result with the given name, if the engine provided it
+    pub fn additional_result(&self, name: &str) -> Option<Real> {
+        self.additional_results.get(name).copied()
+    }
 }
 
 impl Results for InstrumentResults {}
 
 // -------------------------------------------------------------------------------------------------
 
+/// Cache of the results of an instrument.
+///
+/// The cache is an [Observer]: once registered with the quotes, handles or curves the instrument
+/// depends on, any notification from them invalidates the cached results, and the next call to
+/// [Instrument::calculate] performs the calculations again.
+#[derive(Default)]
+pub struct InstrumentCache {
+    results: RefCell<Option<InstrumentResults>>,
+}
+
+impl InstrumentCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the cache holds up-to-date results
+    pub fn is_calculated(&self) -> bool {
+        self.results.borrow().is_some()
+    }
+
+    /// Discard the cached results
+    pub fn invalidate(&self) {
+        *self.results.borrow_mut() = None;
+    }
+
+    /// Returns the cached results, running `calculate` first if they are not up to date
+    pub fn get_or_calculate<F>(&self, calculate: F) -> InstrumentResults
+    where
+        F: FnOnce() -> InstrumentResults,
+    {
+        if let Some(results) = self.results.borrow().as_ref() {
+            return results.clone();
+        }
+        let results = calculate();
+        *self.results.borrow_mut() = Some(results.clone());
+        results
+    }
+}
+
+impl Observer for InstrumentCache {
+    fn update(&self) {
+        self.invalidate();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 pub trait Instrument {
+    /// Returns the results of the instrument.
+    ///
+    /// Instruments providing a [InstrumentCache] only perform the calculations when their inputs
+    /// changed since the last call; the others perform them on every call.
     fn calculate(&self) -> InstrumentResults {
-        self.perform_calculations()
+        match self.cache() {
+            Some(cache) => cache.get_or_calculate(|| self.perform_calculations()),
+            None => self.perform_calculations(),
+        }
+    }
+
+    /// Cache of the results, if the instrument keeps one
+    fn cache(&self) -> Option<&InstrumentCache> {
+        None
     }
 
     /// Returns the net present value of the instrument
@@ -25,6 +127,16 @@ pub trait Instrument {
         results.npv
     }
 
+    /// Returns the error estimate on the NPV
+    fn error_estimate(&self) -> Real {
+        self.calculate().error_estimate
+    }
+
+    /// Returns the additional result with the given name, if available
+    fn additional_result(&self, name: &str) -> Option<Real> {
+        self.calculate().additional_result(name)
+    }
+
     /// In case a pricing engine is **not** used, this method must be overridden to perform
     /// the actual calculations and set any needed results. In case a pricing engine is used, the
     /// default implementation can be used.
@@ -32,3 +144,51 @@ pub trait Instrument {
 }
 
 // -------------------------------------------------------------------------------------------------
+
+/// Resolve the settlement date of an instrument valued as of `eval_date`.
+///
+/// The evaluation date is first moved to a business day using the [Following] convention, and
+/// then advanced by `settlement_days` business days on the given `calendar`.
+pub fn settlement_date(eval_date: Date, settlement_days: Integer, calendar: &Calendar) -> Date {
+    let reference_date = calendar.adjust(eval_date, Following);
+    calendar.advance_by_units(reference_date, settlement_days, Days, Following, false)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::datetime::{date::Date, holidays::target::Target, months::Month::*};
+
+    use super::settlement_date;
+
+    #[test]
+    fn test_settlement_date_over_weekend() {
+        let calendar = Target::new();
+        // Thursday, 2 days settlement rolls over the weekend to Monday
+        let eval_date = Date::new(16, March, 2023);
+        assert_eq!(
+            settlement_date(eval_date, 2, &calendar),
+            Date::new(20, March, 2023)
+        );
+        // Saturday is first moved to Monday and then advanced to Wednesday
+        let eval_date = Date::new(18, March, 2023);
+        assert_eq!(
+            settlement_date(eval_date, 2, &calendar),
+            Date::new(22, March, 2023)
+        );
+    }
+
+    #[test]
+    fn test_settlement_date_zero_lag() {
+        let calendar = Target::new();
+        // Good Friday and Easter Monday are TARGET holidays
+        let eval_date = Date::new(7, April, 2023);
+        assert_eq!(
+            settlement_date(eval_date, 0, &calendar),
+            Date::new(11, April, 2023)
+        );
+        let eval_date = Date::new(12, April, 2023);
+        assert_eq!(settlement_date(eval_date, 0, &calendar), eval_date);
+    }
+}
@@ -0,0 +1,395 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{
+        cashflow::{CashFlow, CashFlowLeg},
+        fixedratecoupon::FixedRateCoupon,
+        fixedrateleg::FixedRateLeg,
+        overnightindexedcoupon::OvernightIndexedCoupon,
+        overnightleg::OvernightLeg,
+    },
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency, schedule::Schedule},
+    indexes::overnightindex::OvernightIndex,
+    pricingengines::pricingengine::PricingEngine,
+    rates::compounding::Compounding,
+    types::{Integer, Rate, Real, Spread},
+};
+
+use super::{
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+    swap::{SwapArguments, SwapResults, SwapType},
+};
+
+/// Overnight indexed swap: fixed rate vs the daily compounded fixings of an overnight index
+/// plus spread
+///
+/// Both legs share the same schedule and nominal; the fixed leg is the first one. A payer swap
+/// pays the fixed leg and receives the overnight one. Coupons of both legs are paid
+/// `payment_lag` business days after the end of their accrual period, on the schedule calendar.
+pub struct OvernightIndexedSwap {
+    pub swap_type: SwapType,
+    pub nominal: Real,
+    pub fixed_rate: Rate,
+    pub spread: Spread,
+    pub fixed_leg: Vec<FixedRateCoupon>,
+    pub overnight_leg: Vec<OvernightIndexedCoupon>,
+    pub engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl OvernightIndexedSwap {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        swap_type: SwapType,
+        nominal: Real,
+        schedule: Schedule,
+        fixed_rate: Rate,
+        fixed_day_count: DayCounter,
+        overnight_index: Rc<OvernightIndex>,
+        spread: Spread,
+        payment_lag: Integer,
+        engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+    ) -> Self {
+        let cache = Rc::new(InstrumentCache::new());
+        engine.register_observer(cache.clone());
+        overnight_index
+            .ibor_index
+            .forwarding_term_structure
+            .register_observer(cache.clone());
+        let overnight_leg = OvernightLeg::new(schedule.clone(), overnight_index)
+            .with_notional(nominal)
+            .with_spread(spread)
+            .with_payment_lag(payment_lag)
+            .build();
+        let fixed_leg = FixedRateLeg::new(schedule, vec![nominal], vec![])
+            .with_coupon_rate(
+                fixed_rate,
+                fixed_day_count,
+                Compounding::Simple,
+                Frequency::Annual,
+            )
+            .with_payment_lag(payment_lag)
+            .build();
+        Self {
+            swap_type,
+            nominal,
+            fixed_rate,
+            spread,
+            fixed_leg,
+            overnight_leg,
+            engine,
+            cache,
+        }
+    }
+
+    /// Arguments for the swap engine: the fixed leg followed by the overnight leg
+    pub fn arguments(&self) -> SwapArguments {
+        let fixed_leg: CashFlowLeg = self
+            .fixed_leg
+            .iter()
+            .map(|c| Rc::new(c.clone()) as Rc<dyn CashFlow>)
+            .collect();
+        let overnight_leg: CashFlowLeg = self
+            .overnight_leg
+            .iter()
+            .map(|c| Rc::new(c.clone()) as Rc<dyn CashFlow>)
+            .collect();
+        let payer = match self.swap_type {
+            SwapType::Payer => vec![-1.0, 1.0],
+            SwapType::Receiver => vec![1.0, -1.0],
+        };
+        SwapArguments {
+            legs: vec![fixed_leg, overnight_leg],
+            payer,
+            fixed_rate: Some(self.fixed_rate),
+            spread: Some(self.spread),
+        }
+    }
+
+    /// Runs the pricing engine and returns all the results
+    pub fn results(&self) -> SwapResults {
+        self.engine.calculate(self.arguments())
+    }
+
+    /// Start of the accrual period of the first coupon
+    pub fn start_date(&self) -> Date {
+        self.fixed_leg
+            .first()
+            .map(|c| c.accrual_start_date())
+            .unwrap_or_default()
+    }
+
+    /// End of the accrual period of the last coupon
+    pub fn maturity_date(&self) -> Date {
+        self.fixed_leg
+            .last()
+            .map(|c| c.accrual_end_date())
+            .unwrap_or_default()
+    }
+
+    /// NPV of the fixed leg, with the sign of the swap type
+    pub fn fixed_leg_npv(&self) -> Real {
+        self.results().leg_npv[0]
+    }
+
+    /// NPV of the overnight leg, with the sign of the swap type
+    pub fn overnight_leg_npv(&self) -> Real {
+        self.results().leg_npv[1]
+    }
+
+    /// Fixed rate giving a zero NPV, or `None` if the fixed leg has no coupon left to pay
+    pub fn fair_rate(&self) -> Option<Rate> {
+        self.results().fair_rate
+    }
+
+    /// Spread over the compounded overnight rate giving a zero NPV, or `None` if the overnight
+    /// leg has no coupon left to pay
+    pub fn fair_spread(&self) -> Option<Spread> {
+        self.results().fair_spread
+    }
+}
+
+impl Instrument for OvernightIndexedSwap {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        let results = self.results();
+        let mut instrument_results =
+            InstrumentResults::new(results.npv, results.error_estimate, results.valuation_date)
+                .with_additional_result("fixed_leg_npv", results.leg_npv[0])
+                .with_additional_result("overnight_leg_npv", results.leg_npv[1])
+                .with_additional_result("fixed_leg_bps", results.leg_bps[0])
+                .with_additional_result("overnight_leg_bps", results.leg_bps[1]);
+        if let Some(fair_rate) = results.fair_rate {
+            instrument_results = instrument_results.with_additional_result("fair_rate", fair_rate);
+        }
+        if let Some(fair_spread) = results.fair_spread {
+            instrument_results =
+                instrument_results.with_additional_result("fair_spread", fair_spread);
+        }
+        if let Some(currency) = results.currency {
+            instrument_results = instrument_results.with_currency(currency);
+        }
+        instrument_results
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::cashflow::CashFlow,
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::{Following, ModifiedFollowing},
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Days, Years},
+        },
+        handle::{Handle, RelinkableHandle},
+        indexes::overnightindex::OvernightIndex,
+        instruments::{
+            instrument::Instrument,
+            swap::{SwapArguments, SwapResults, SwapType},
+        },
+        pricingengines::{
+            pricingengine::PricingEngine, pricingengine_test_util::CountingEngine,
+            swap::discountingswapengine::DiscountingSwapEngine,
+        },
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::{Integer, Rate, Spread},
+    };
+
+    use super::OvernightIndexedSwap;
+
+    fn make_swap(swap_type: SwapType, fixed_rate: Rate, spread: Spread) -> OvernightIndexedSwap {
+        make_swap_on(
+            Date::new(15, January, 2024),
+            swap_type,
+            fixed_rate,
+            spread,
+            0,
+        )
+    }
+
+    /// Two-year swap starting on 17 January 2024, valued on `today`
+    fn make_swap_on(
+        today: Date,
+        swap_type: SwapType,
+        fixed_rate: Rate,
+        spread: Spread,
+        payment_lag: Integer,
+    ) -> OvernightIndexedSwap {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(flat_curve(today, 0.035));
+        let engine = Rc::new(DiscountingSwapEngine::new(curve.clone(), None));
+        make_swap_with(
+            today,
+            curve,
+            engine,
+            swap_type,
+            fixed_rate,
+            spread,
+            payment_lag,
+        )
+    }
+
+    fn flat_curve(today: Date, rate: Rate) -> Rc<dyn YieldTermStructure> {
+        Rc::new(FlatForward::new(
+            today,
+            rate,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        ))
+    }
+
+    /// Two-year swap starting on 17 January 2024, forecasting on `curve` and priced by `engine`
+    fn make_swap_with(
+        today: Date,
+        curve: Handle<dyn YieldTermStructure>,
+        engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+        swap_type: SwapType,
+        fixed_rate: Rate,
+        spread: Spread,
+        payment_lag: Integer,
+    ) -> OvernightIndexedSwap {
+        let index = Rc::new(OvernightIndex::new(
+            "ESTR",
+            0,
+            Target::new(),
+            DayCounter::actual360(),
+            curve.clone(),
+        ));
+        let start = Date::new(17, January, 2024);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            start,
+            start + Period::new(2, Years),
+            Period::new(1, Years),
+            Target::new(),
+        )
+        .with_convention(ModifiedFollowing)
+        .build();
+        OvernightIndexedSwap::new(
+            swap_type,
+            1_000_000.0,
+            schedule,
+            fixed_rate,
+            DayCounter::actual360(),
+            index,
+            spread,
+            payment_lag,
+            engine,
+        )
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let today = Date::new(15, January, 2024);
+        let curve = RelinkableHandle::new(flat_curve(today, 0.035));
+        let engine = Rc::new(CountingEngine::new(DiscountingSwapEngine::new(
+            curve.handle(),
+            None,
+        )));
+        let swap = make_swap_with(
+            today,
+            curve.handle(),
+            engine.clone(),
+            SwapType::Payer,
+            0.03,
+            0.0,
+            0,
+        );
+
+        // the second call uses the cached results
+        let npv = swap.npv();
+        assert_eq!(swap.npv(), npv);
+        assert_eq!(engine.calculations.get(), 1);
+
+        // relinking the curve invalidates them
+        curve.link_to(flat_curve(today, 0.04));
+        assert!(!swap.cache().unwrap().is_calculated());
+        assert!(swap.npv() > npv);
+        assert_eq!(engine.calculations.get(), 2);
+    }
+
+    #[test]
+    fn test_fair_rate() {
+        let swap = make_swap(SwapType::Payer, 0.03, 0.0);
+        assert_eq!(swap.fixed_leg.len(), 2);
+        assert_eq!(swap.overnight_leg.len(), 2);
+        assert!(swap.npv() > 0.0);
+
+        let fair_rate = swap.fair_rate().unwrap();
+        // on a flat curve discounting and forecasting alike, the compounded overnight rate
+        // is the simple forward rate over each period
+        let continuous: Rate = 0.035;
+        let simple = ((continuous * 366.0 / 365.0).exp() - 1.0) * 360.0 / 366.0;
+        assert!(
+            (fair_rate - simple).abs() < 1.0e-4,
+            "fair rate {} too far from {}",
+            fair_rate,
+            simple
+        );
+
+        for swap_type in [SwapType::Payer, SwapType::Receiver] {
+            let at_the_money = make_swap(swap_type, fair_rate, 0.0);
+            assert!(
+                at_the_money.npv().abs() < 1.0e-6,
+                "npv at the fair rate {}: {}",
+                fair_rate,
+                at_the_money.npv()
+            );
+        }
+
+        let fair_spread = swap.fair_spread().unwrap();
+        let at_the_money = make_swap(SwapType::Payer, 0.03, fair_spread);
+        assert!(at_the_money.npv().abs() < 1.0e-6);
+        assert_eq!(
+            swap.additional_result("overnight_leg_npv"),
+            Some(swap.overnight_leg_npv())
+        );
+    }
+
+    #[test]
+    fn test_payment_lag() {
+        let today = Date::new(15, January, 2024);
+        let swap = make_swap(SwapType::Payer, 0.03, 0.0);
+        let lagged = make_swap_on(today, SwapType::Payer, 0.03, 0.0, 2);
+        let calendar = Target::new();
+        for (c, l) in swap.overnight_leg.iter().zip(&lagged.overnight_leg) {
+            let expected = calendar.advance_by_units(c.date(), 2, Days, Following, false);
+            assert_eq!(l.date(), expected);
+        }
+        for (c, l) in swap.fixed_leg.iter().zip(&lagged.fixed_leg) {
+            let expected = calendar.advance_by_units(c.date(), 2, Days, Following, false);
+            assert_eq!(l.date(), expected);
+        }
+        // paying later on both legs barely moves the fair rate
+        let fair_rate = lagged.fair_rate().unwrap();
+        assert!((fair_rate - swap.fair_rate().unwrap()).abs() < 1.0e-5);
+        let at_the_money = make_swap_on(today, SwapType::Payer, fair_rate, 0.0, 2);
+        assert!(at_the_money.npv().abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_expired_swap() {
+        // all the coupons were paid by the valuation date
+        let swap = make_swap_on(Date::new(15, January, 2027), SwapType::Payer, 0.03, 0.0, 0);
+        assert_eq!(swap.npv(), 0.0);
+        assert!(swap.fair_rate().is_none());
+        assert!(swap.fair_spread().is_none());
+        assert!(swap.additional_result("fair_rate").is_none());
+    }
+}
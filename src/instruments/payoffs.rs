@@ -0,0 +1,251 @@
+use std::fmt;
+
+use crate::types::Real;
+
+/// Type of an option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionType {
+    Call = 1,
+    Put = -1,
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionType::Call => write!(f, "Call"),
+            OptionType::Put => write!(f, "Put"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Abstract base trait for option payoffs
+pub trait Payoff {
+    /// Name of the payoff, e.g. "Vanilla"
+    fn name(&self) -> String;
+
+    /// Payoff for the given price of the underlying
+    fn value(&self, price: Real) -> Real;
+
+    /// Description of the payoff, including its parameters
+    fn description(&self) -> String {
+        self.name()
+    }
+}
+
+/// Intermediate trait for payoffs based on a fixed strike and an option type
+pub trait StrikedTypePayoff: Payoff {
+    fn option_type(&self) -> OptionType;
+
+    fn strike(&self) -> Real;
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Plain-vanilla payoff, i.e. `max(S - K, 0)` for calls and `max(K - S, 0)` for puts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlainVanillaPayoff {
+    pub option_type: OptionType,
+    pub strike: Real,
+}
+
+impl PlainVanillaPayoff {
+    pub fn new(option_type: OptionType, strike: Real) -> Self {
+        Self {
+            option_type,
+            strike,
+        }
+    }
+}
+
+impl Payoff for PlainVanillaPayoff {
+    fn name(&self) -> String {
+        "Vanilla".to_string()
+    }
+
+    fn value(&self, price: Real) -> Real {
+        match self.option_type {
+            OptionType::Call => (price - self.strike).max(0.0),
+            OptionType::Put => (self.strike - price).max(0.0),
+        }
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {}, {} strike",
+            self.name(),
+            self.option_type,
+            self.strike
+        )
+    }
+}
+
+impl StrikedTypePayoff for PlainVanillaPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Binary cash-or-nothing payoff
+///
+/// Pays a fixed amount of cash if the option ends in the money, and nothing otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CashOrNothingPayoff {
+    pub option_type: OptionType,
+    pub strike: Real,
+    pub cash_payoff: Real,
+}
+
+impl CashOrNothingPayoff {
+    pub fn new(option_type: OptionType, strike: Real, cash_payoff: Real) -> Self {
+        Self {
+            option_type,
+            strike,
+            cash_payoff,
+        }
+    }
+}
+
+impl Payoff for CashOrNothingPayoff {
+    fn name(&self) -> String {
+        "CashOrNothing".to_string()
+    }
+
+    fn value(&self, price: Real) -> Real {
+        match self.option_type {
+            OptionType::Call if price > self.strike => self.cash_payoff,
+            OptionType::Put if price < self.strike => self.cash_payoff,
+            _ => 0.0,
+        }
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {}, {} strike, {} cash payoff",
+            self.name(),
+            self.option_type,
+            self.strike,
+            self.cash_payoff
+        )
+    }
+}
+
+impl StrikedTypePayoff for CashOrNothingPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Binary asset-or-nothing payoff
+///
+/// Pays the value of the underlying if the option ends in the money, and nothing otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AssetOrNothingPayoff {
+    pub option_type: OptionType,
+    pub strike: Real,
+}
+
+impl AssetOrNothingPayoff {
+    pub fn new(option_type: OptionType, strike: Real) -> Self {
+        Self {
+            option_type,
+            strike,
+        }
+    }
+}
+
+impl Payoff for AssetOrNothingPayoff {
+    fn name(&self) -> String {
+        "AssetOrNothing".to_string()
+    }
+
+    fn value(&self, price: Real) -> Real {
+        match self.option_type {
+            OptionType::Call if price > self.strike => price,
+            OptionType::Put if price < self.strike => price,
+            _ => 0.0,
+        }
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} {}, {} strike",
+            self.name(),
+            self.option_type,
+            self.strike
+        )
+    }
+}
+
+impl StrikedTypePayoff for AssetOrNothingPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{
+        AssetOrNothingPayoff, CashOrNothingPayoff, OptionType, Payoff, PlainVanillaPayoff,
+        StrikedTypePayoff,
+    };
+
+    #[test]
+    fn test_plain_vanilla_payoff() {
+        let call = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        assert_eq!(call.value(120.0), 20.0);
+        assert_eq!(call.value(100.0), 0.0);
+        assert_eq!(call.value(80.0), 0.0);
+
+        let put = PlainVanillaPayoff::new(OptionType::Put, 100.0);
+        assert_eq!(put.value(120.0), 0.0);
+        assert_eq!(put.value(80.0), 20.0);
+
+        assert_eq!(call.strike(), 100.0);
+        assert_eq!(put.option_type(), OptionType::Put);
+        assert_eq!(call.description(), "Vanilla Call, 100 strike");
+    }
+
+    #[test]
+    fn test_cash_or_nothing_payoff() {
+        let call = CashOrNothingPayoff::new(OptionType::Call, 100.0, 10.0);
+        assert_eq!(call.value(100.5), 10.0);
+        assert_eq!(call.value(150.0), 10.0);
+        assert_eq!(call.value(100.0), 0.0);
+        assert_eq!(call.value(90.0), 0.0);
+
+        let put = CashOrNothingPayoff::new(OptionType::Put, 100.0, 10.0);
+        assert_eq!(put.value(90.0), 10.0);
+        assert_eq!(put.value(110.0), 0.0);
+    }
+
+    #[test]
+    fn test_asset_or_nothing_payoff() {
+        let call = AssetOrNothingPayoff::new(OptionType::Call, 100.0);
+        assert_eq!(call.value(120.0), 120.0);
+        assert_eq!(call.value(80.0), 0.0);
+
+        let put = AssetOrNothingPayoff::new(OptionType::Put, 100.0);
+        assert_eq!(put.value(80.0), 80.0);
+        assert_eq!(put.value(120.0), 0.0);
+    }
+}
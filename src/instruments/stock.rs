@@ -1,29 +1,35 @@
-use std::sync::Arc;
+use std::rc::Rc;
 
-use crate::{datetime::date::Date, quotes::quote::Quote};
+use crate::{datetime::date::Date, handle::Handle, quotes::quote::Quote};
 use crate::{
-    instruments::instrument::{Instrument, InstrumentResults},
+    instruments::instrument::{Instrument, InstrumentCache, InstrumentResults},
     types::Real,
 };
 
 // Simple stock
 pub struct Stock {
-    pub quote: Arc<dyn Quote>,
+    pub quote: Handle<dyn Quote>,
+    cache: Rc<InstrumentCache>,
 }
 
 impl Stock {
-    pub fn new(quote: Arc<dyn Quote>) -> Self {
-        Self { quote }
+    pub fn new(quote: Handle<dyn Quote>) -> Self {
+        let cache = Rc::new(InstrumentCache::new());
+        quote.register_observer(cache.clone());
+        Self { quote, cache }
     }
 }
 
 impl Instrument for Stock {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
     fn perform_calculations(&self) -> InstrumentResults {
-        InstrumentResults {
-            npv: self.quote.value(),
-            error_estimate: Real::default(),
-            valuation_date: Date::default(),
-        }
+        let quote = self.quote.current_link();
+        // the handle may have been relinked since the last calculation
+        quote.register_observer(self.cache.clone());
+        InstrumentResults::new(quote.value(), Real::default(), Date::default())
     }
 }
 
@@ -31,10 +37,12 @@ impl Instrument for Stock {
 
 #[cfg(test)]
 mod test {
-    use std::sync::Arc;
+    use std::{cell::Cell, rc::Rc};
 
     use crate::datetime::date::Date;
-    use crate::quotes::simplequote::SimpleQuote;
+    use crate::handle::{Handle, RelinkableHandle};
+    use crate::patterns::observable::{Observable, Observer};
+    use crate::quotes::{quote::Quote, simplequote::SimpleQuote};
     use crate::types::Real;
 
     use crate::instruments::instrument::Instrument;
@@ -43,11 +51,95 @@ mod test {
 
     #[test]
     fn test_stock() {
-        let quote = Arc::new(SimpleQuote::new(1.5));
+        let quote: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(1.5)));
         let stock = Stock::new(quote);
         let results = stock.calculate();
         assert_eq!(results.npv, 1.5);
         assert_eq!(results.error_estimate, Real::default());
         assert_eq!(results.valuation_date, Date::default());
     }
+
+    #[test]
+    fn test_stock_relinked_quote() {
+        let quote: RelinkableHandle<dyn Quote> =
+            RelinkableHandle::new(Rc::new(SimpleQuote::new(1.5)));
+        let stock = Stock::new(quote.handle());
+        assert_eq!(stock.npv(), 1.5);
+        quote.link_to(Rc::new(SimpleQuote::new(2.5)));
+        assert_eq!(stock.npv(), 2.5);
+    }
+
+    /// Quote whose value can be bumped, counting how many times its value is read
+    struct CountingQuote {
+        value: Cell<Real>,
+        reads: Cell<usize>,
+        observable: Observable,
+    }
+
+    impl CountingQuote {
+        fn new(value: Real) -> Self {
+            Self {
+                value: Cell::new(value),
+                reads: Cell::new(0),
+                observable: Observable::new(),
+            }
+        }
+
+        /// Set a new value and notify the observers if it changed
+        fn set_value(&self, value: Real) {
+            if value != self.value.get() {
+                self.value.set(value);
+                self.observable.notify_observers();
+            }
+        }
+    }
+
+    impl Quote for CountingQuote {
+        fn value(&self) -> Real {
+            self.reads.set(self.reads.get() + 1);
+            self.value.get()
+        }
+
+        fn is_valid(&self) -> bool {
+            true
+        }
+
+        fn register_observer(&self, observer: Rc<dyn Observer>) {
+            self.observable.register_observer(observer);
+        }
+    }
+
+    #[test]
+    fn test_stock_lazy_calculation() {
+        let quote = Rc::new(CountingQuote::new(1.5));
+        let relinkable: RelinkableHandle<dyn Quote> = RelinkableHandle::new(quote.clone());
+        let stock = Stock::new(relinkable.handle());
+
+        // no changes: the second call uses the cached results
+        assert_eq!(stock.npv(), 1.5);
+        assert_eq!(stock.npv(), 1.5);
+        assert_eq!(quote.reads.get(), 1);
+
+        // setting the same value is not a change
+        quote.set_value(1.5);
+        assert_eq!(stock.npv(), 1.5);
+        assert_eq!(quote.reads.get(), 1);
+
+        // bumping the quote forces a recalculation
+        quote.set_value(1.75);
+        assert_eq!(stock.npv(), 1.75);
+        assert_eq!(stock.npv(), 1.75);
+        assert_eq!(quote.reads.get(), 2);
+
+        // so does relinking the handle, and the new quote is observed as well
+        let new_quote = Rc::new(CountingQuote::new(2.0));
+        relinkable.link_to(new_quote.clone());
+        assert_eq!(stock.npv(), 2.0);
+        new_quote.set_value(2.25);
+        assert_eq!(stock.npv(), 2.25);
+
+        // simple quotes are bumped by relinking
+        relinkable.link_to(Rc::new(SimpleQuote::new(2.5)));
+        assert_eq!(stock.npv(), 2.5);
+    }
 }
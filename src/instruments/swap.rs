@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::cashflow::CashFlowLeg,
+    currencies::currency::Currency,
+    datetime::date::Date,
+    pricingengines::pricingengine::{Arguments, Results},
+    types::{Rate, Real, Spread},
+};
+
+/// Type of an interest rate swap, from the point of view of the fixed leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapType {
+    /// Receive fixed, pay floating
+    Receiver = -1,
+    /// Pay fixed, receive floating
+    Payer = 1,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Arguments for swap calculation
+///
+/// Each leg comes with a multiplier in `payer`, `-1.0` for a paid leg and `1.0` for a received
+/// one. Swaps with a fixed leg first and a floating leg second also pass the fixed rate and the
+/// floating spread, from which the engine derives the fair rate and spread.
+#[derive(Clone)]
+pub struct SwapArguments {
+    pub legs: Vec<CashFlowLeg>,
+    pub payer: Vec<Real>,
+    /// Rate of the first leg, if it pays a fixed rate
+    pub fixed_rate: Option<Rate>,
+    /// Spread of the second leg, if it pays a floating rate
+    pub spread: Option<Spread>,
+}
+
+impl Arguments for SwapArguments {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Results from swap calculation
+///
+/// The leg NPVs and basis-point sensitivities include the sign of the `payer` multipliers. The
+/// fair rate and spread are `None` when the swap has no fixed rate or spread, or when the BPS of
+/// the corresponding leg is zero, e.g. once all its coupons have been paid.
+#[derive(Debug, Clone)]
+pub struct SwapResults {
+    pub npv: Real,
+    /// Currency of the NPV, if known
+    pub currency: Option<Rc<dyn Currency>>,
+    pub error_estimate: Real,
+    pub valuation_date: Date,
+    pub leg_npv: Vec<Real>,
+    pub leg_bps: Vec<Real>,
+    /// Fixed rate giving a zero NPV
+    pub fair_rate: Option<Rate>,
+    /// Floating spread giving a zero NPV
+    pub fair_spread: Option<Spread>,
+}
+
+impl Results for SwapResults {}
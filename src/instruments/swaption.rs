@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::date::Date,
+    pricingengines::pricingengine::{Arguments, PricingEngine, Results},
+    types::{Rate, Real, Spread},
+};
+
+use super::{
+    exercise::Exercise,
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+    swap::{SwapArguments, SwapType},
+    vanillaswap::VanillaSwap,
+};
+
+/// Option to enter into a vanilla interest rate swap
+///
+/// A payer swaption gives the right to enter into the underlying as a payer swap, i.e. paying
+/// the fixed rate, and a receiver swaption into a receiver swap; this is given by the type of
+/// the underlying swap. Settlement is physical.
+pub struct Swaption {
+    pub swap: Rc<VanillaSwap>,
+    pub exercise: Exercise,
+    pub engine: Rc<dyn PricingEngine<A = SwaptionArguments, R = SwaptionResults>>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl Swaption {
+    pub fn new(
+        swap: Rc<VanillaSwap>,
+        exercise: Exercise,
+        engine: Rc<dyn PricingEngine<A = SwaptionArguments, R = SwaptionResults>>,
+    ) -> Self {
+        // the forward swap rate is forecast on the curve of the underlying floating index
+        let cache = Rc::new(InstrumentCache::new());
+        engine.register_observer(cache.clone());
+        swap.ibor_index
+            .forwarding_term_structure
+            .register_observer(cache.clone());
+        Self {
+            swap,
+            exercise,
+            engine,
+            cache,
+        }
+    }
+
+    /// Type of the underlying swap
+    pub fn swap_type(&self) -> SwapType {
+        self.swap.swap_type
+    }
+
+    /// Fair rate of the underlying swap, using its own pricing engine, or `None` if its fixed leg
+    /// has no coupon left to pay
+    pub fn underlying_fair_rate(&self) -> Option<Rate> {
+        self.swap.fair_rate()
+    }
+
+    /// Runs the pricing engine and returns all the results
+    pub fn results(&self) -> SwaptionResults {
+        self.engine.calculate(SwaptionArguments {
+            swap_type: self.swap.swap_type,
+            fixed_rate: self.swap.fixed_rate,
+            spread: self.swap.spread,
+            swap_start_date: self.swap.start_date(),
+            swap_maturity_date: self.swap.maturity_date(),
+            swap: self.swap.arguments(),
+            exercise: self.exercise.clone(),
+        })
+    }
+}
+
+impl Instrument for Swaption {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        let results = self.results();
+        InstrumentResults::new(results.npv, results.error_estimate, results.valuation_date)
+            .with_additional_result("annuity", results.annuity)
+            .with_additional_result("forward_rate", results.forward_rate)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Arguments for swaption calculation
+#[derive(Clone)]
+pub struct SwaptionArguments {
+    pub swap_type: SwapType,
+    pub fixed_rate: Rate,
+    pub spread: Spread,
+    pub swap_start_date: Date,
+    pub swap_maturity_date: Date,
+    /// Legs of the underlying swap
+    pub swap: SwapArguments,
+    pub exercise: Exercise,
+}
+
+impl Arguments for SwaptionArguments {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Results from swaption calculation
+#[derive(Debug, Clone)]
+pub struct SwaptionResults {
+    pub npv: Real,
+    pub error_estimate: Real,
+    pub valuation_date: Date,
+    /// Value of a basis point paid on the fixed leg, per unit of rate
+    pub annuity: Real,
+    /// Forward swap rate, i.e. the fair rate of the underlying
+    pub forward_rate: Rate,
+}
+
+impl Results for SwaptionResults {}
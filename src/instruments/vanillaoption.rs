@@ -0,0 +1,343 @@
+use std::rc::Rc;
+
+use crate::{
+    datetime::date::Date,
+    handle::Handle,
+    maths::solvers1d::{brent::Brent, solver1d::Solver1D},
+    pricingengines::{
+        pricingengine::{Arguments, PricingEngine, Results},
+        vanilla::analyticeuropeanengine::AnalyticEuropeanEngine,
+    },
+    processes::blackscholesprocess::BlackScholesProcess,
+    termstructures::volatility::{
+        blackconstantvol::BlackConstantVol, blackvoltermstructure::BlackVolTermStructure,
+    },
+    types::{Real, Size, Volatility},
+};
+
+use super::{
+    exercise::{Exercise, ExerciseType},
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+    payoffs::{OptionType, PlainVanillaPayoff},
+};
+
+/// Vanilla option (no discrete dividends, no barriers) on a single asset
+pub struct VanillaOption {
+    pub payoff: PlainVanillaPayoff,
+    pub exercise: Exercise,
+    pub engine: Rc<dyn PricingEngine<A = VanillaOptionArguments, R = VanillaOptionResults>>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl VanillaOption {
+    pub fn new(
+        payoff: PlainVanillaPayoff,
+        exercise: Exercise,
+        engine: Rc<dyn PricingEngine<A = VanillaOptionArguments, R = VanillaOptionResults>>,
+    ) -> Self {
+        let cache = Rc::new(InstrumentCache::new());
+        engine.register_observer(cache.clone());
+        Self {
+            payoff,
+            exercise,
+            engine,
+            cache,
+        }
+    }
+
+    /// Runs the pricing engine and returns all the results, including the greeks
+    pub fn results(&self) -> VanillaOptionResults {
+        self.engine.calculate(VanillaOptionArguments {
+            payoff: self.payoff,
+            exercise: self.exercise.clone(),
+        })
+    }
+
+    /// Sensitivity of the value to the price of the underlying
+    pub fn delta(&self) -> Real {
+        self.results().delta.expect("delta not provided")
+    }
+
+    /// Sensitivity of the delta to the price of the underlying
+    pub fn gamma(&self) -> Real {
+        self.results().gamma.expect("gamma not provided")
+    }
+
+    /// Sensitivity of the value to the volatility of the underlying
+    pub fn vega(&self) -> Real {
+        self.results().vega.expect("vega not provided")
+    }
+
+    /// Sensitivity of the value to the passage of time, per year
+    pub fn theta(&self) -> Real {
+        self.results().theta.expect("theta not provided")
+    }
+
+    /// Sensitivity of the value to the risk-free rate
+    pub fn rho(&self) -> Real {
+        self.results().rho.expect("rho not provided")
+    }
+
+    /// Sensitivity of the value to the dividend yield
+    pub fn dividend_rho(&self) -> Real {
+        self.results()
+            .dividend_rho
+            .expect("dividend rho not provided")
+    }
+
+    /// Black-Scholes volatility reproducing the given price of this European option.
+    ///
+    /// The spot, dividends and yield curves are taken from `process`, whose volatility is
+    /// replaced by a constant one at each trial value; the resulting option is priced with the
+    /// [AnalyticEuropeanEngine], regardless of the engine of this option. The volatility is
+    /// searched with the Brent solver in `[min_vol, max_vol]`.
+    ///
+    /// Panics if the target price lies outside the no-arbitrage bounds, i.e. below the
+    /// discounted intrinsic value or at or above the discounted spot net of dividends (for a
+    /// call) or strike (for a put), as no volatility can reproduce it.
+    pub fn implied_volatility(
+        &self,
+        target_price: Real,
+        process: &BlackScholesProcess,
+        accuracy: Real,
+        max_evaluations: Size,
+        min_vol: Volatility,
+        max_vol: Volatility,
+    ) -> Volatility {
+        assert_eq!(
+            self.exercise.exercise_type,
+            ExerciseType::European,
+            "implied volatility only available for European options"
+        );
+        assert!(
+            min_vol > 0.0 && min_vol < max_vol,
+            "invalid volatility range: [{}, {}]",
+            min_vol,
+            max_vol
+        );
+
+        let maturity_date = self.exercise.last_date();
+        let spot = process.escrowed_spot(&maturity_date);
+        let strike = self.payoff.strike;
+        let risk_free_discount = process
+            .risk_free_rate
+            .current_link()
+            .discount_from_date(&maturity_date, false);
+        let dividend_discount = process
+            .dividend_yield
+            .current_link()
+            .discount_from_date(&maturity_date, false);
+        let (intrinsic, upper_bound) = match self.payoff.option_type {
+            OptionType::Call => (
+                spot * dividend_discount - strike * risk_free_discount,
+                spot * dividend_discount,
+            ),
+            OptionType::Put => (
+                strike * risk_free_discount - spot * dividend_discount,
+                strike * risk_free_discount,
+            ),
+        };
+        let lower_bound = intrinsic.max(0.0);
+        assert!(
+            target_price >= lower_bound,
+            "target price ({}) below the discounted intrinsic value ({})",
+            target_price,
+            lower_bound
+        );
+        assert!(
+            target_price < upper_bound,
+            "target price ({}) not below the no-arbitrage bound ({})",
+            target_price,
+            upper_bound
+        );
+
+        let black_volatility = process.black_volatility.current_link();
+        let reference_date = black_volatility.reference_date();
+        let daycounter = black_volatility.day_counter().clone();
+        let arguments = VanillaOptionArguments {
+            payoff: self.payoff,
+            exercise: self.exercise.clone(),
+        };
+        let price = |vol: Volatility| {
+            let volatility: Handle<dyn BlackVolTermStructure> = Handle::new(Rc::new(
+                BlackConstantVol::new(reference_date, vol, daycounter.clone()),
+            ));
+            let process = Rc::new(
+                BlackScholesProcess::new(
+                    process.x0.clone(),
+                    process.dividend_yield.clone(),
+                    process.risk_free_rate.clone(),
+                    volatility,
+                )
+                .with_dividends(process.dividends.clone()),
+            );
+            AnalyticEuropeanEngine::new(process)
+                .calculate(arguments.clone())
+                .npv
+        };
+
+        let solver = Brent::with_max_evaluations(max_evaluations);
+        solver.solve_bracketed(
+            |vol| price(vol) - target_price,
+            |_| 0.0,
+            accuracy,
+            0.5 * (min_vol + max_vol),
+            min_vol,
+            max_vol,
+        )
+    }
+}
+
+impl Instrument for VanillaOption {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        // the spot handle may have been relinked since the last calculation
+        self.engine.register_observer(self.cache.clone());
+        let results = self.results();
+        InstrumentResults::new(results.npv, results.error_estimate, results.valuation_date)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Arguments for vanilla option calculation
+#[derive(Debug, Clone)]
+pub struct VanillaOptionArguments {
+    pub payoff: PlainVanillaPayoff,
+    pub exercise: Exercise,
+}
+
+impl Arguments for VanillaOptionArguments {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Results from vanilla option calculation
+///
+/// Greeks are `None` when the engine does not provide them.
+#[derive(Debug, Clone)]
+pub struct VanillaOptionResults {
+    pub npv: Real,
+    pub error_estimate: Real,
+    pub valuation_date: Date,
+    pub delta: Option<Real>,
+    pub gamma: Option<Real>,
+    pub vega: Option<Real>,
+    pub theta: Option<Real>,
+    pub rho: Option<Real>,
+    pub dividend_rho: Option<Real>,
+}
+
+impl Results for VanillaOptionResults {}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        handle::RelinkableHandle,
+        instruments::{
+            exercise::Exercise,
+            instrument::Instrument,
+            payoffs::{OptionType, PlainVanillaPayoff},
+        },
+        pricingengines::{
+            pricingengine_test_util::CountingEngine,
+            vanilla::analyticeuropeanengine::AnalyticEuropeanEngine,
+        },
+        processes::{blackscholesprocess::BlackScholesProcess, process_test_util::flat_process},
+        quotes::{quote::Quote, simplequote::SimpleQuote},
+        types::Volatility,
+    };
+
+    use super::VanillaOption;
+
+    fn process(today: Date, vol: Volatility) -> Rc<BlackScholesProcess> {
+        Rc::new(flat_process(
+            today,
+            100.0,
+            0.01,
+            0.04,
+            vol,
+            DayCounter::actual365_fixed(),
+        ))
+    }
+
+    fn option(today: Date, option_type: OptionType, strike: f64) -> VanillaOption {
+        VanillaOption::new(
+            PlainVanillaPayoff::new(option_type, strike),
+            Exercise::european(Date::new(15, January, 2025)),
+            Rc::new(AnalyticEuropeanEngine::new(process(today, 0.25))),
+        )
+    }
+
+    #[test]
+    fn test_implied_volatility() {
+        let today = Date::new(15, January, 2024);
+        // the process given to the solver only provides spot and curves
+        let process = process(today, 0.10);
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [80.0, 100.0, 120.0] {
+                let option = option(today, option_type, strike);
+                let price = option.npv();
+                let vol = option.implied_volatility(price, &process, 1.0e-10, 100, 1.0e-4, 4.0);
+                assert!(
+                    (vol - 0.25).abs() < 1.0e-6,
+                    "{:?} {}: implied volatility {}",
+                    option_type,
+                    strike,
+                    vol
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not below the no-arbitrage bound")]
+    fn test_unattainable_price() {
+        let today = Date::new(15, January, 2024);
+        let option = option(today, OptionType::Call, 100.0);
+        // a call is never worth more than the underlying
+        option.implied_volatility(101.0, &process(today, 0.25), 1.0e-10, 100, 1.0e-4, 4.0);
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let today = Date::new(15, January, 2024);
+        let spot: RelinkableHandle<dyn Quote> =
+            RelinkableHandle::new(Rc::new(SimpleQuote::new(100.0)));
+        let mut process = flat_process(
+            today,
+            100.0,
+            0.01,
+            0.04,
+            0.25,
+            DayCounter::actual365_fixed(),
+        );
+        process.x0 = spot.handle();
+        let engine = Rc::new(CountingEngine::new(AnalyticEuropeanEngine::new(Rc::new(
+            process,
+        ))));
+        let option = VanillaOption::new(
+            PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            Exercise::european(Date::new(15, January, 2025)),
+            engine.clone(),
+        );
+
+        // the second call uses the cached results
+        let npv = option.npv();
+        assert_eq!(option.npv(), npv);
+        assert_eq!(engine.calculations.get(), 1);
+
+        // relinking the spot invalidates them
+        spot.link_to(Rc::new(SimpleQuote::new(110.0)));
+        assert!(!option.cache().unwrap().is_calculated());
+        assert!(option.npv() > npv);
+        assert_eq!(engine.calculations.get(), 2);
+    }
+}
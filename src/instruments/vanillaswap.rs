@@ -0,0 +1,466 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{
+        cashflow::{CashFlow, CashFlowLeg},
+        fixedratecoupon::FixedRateCoupon,
+        fixedrateleg::FixedRateLeg,
+        iborcoupon::IborCoupon,
+        iborleg::IborLeg,
+    },
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency, schedule::Schedule},
+    indexes::iborindex::IborIndex,
+    instruments::instrument::settlement_date,
+    pricingengines::pricingengine::PricingEngine,
+    rates::compounding::Compounding,
+    types::{Rate, Real, Spread},
+};
+
+use super::{
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+    swap::{SwapArguments, SwapResults, SwapType},
+};
+
+/// Plain vanilla swap: fixed rate vs Libor-type index plus spread
+///
+/// The fixed leg is the first leg and the floating leg the second one, both with the same
+/// nominal. A payer swap pays the fixed leg and receives the floating one.
+pub struct VanillaSwap {
+    pub swap_type: SwapType,
+    pub nominal: Real,
+    pub fixed_rate: Rate,
+    pub spread: Spread,
+    pub fixed_leg: Vec<FixedRateCoupon>,
+    pub floating_leg: Vec<IborCoupon>,
+    pub ibor_index: Rc<IborIndex>,
+    pub engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+    cache: Rc<InstrumentCache>,
+}
+
+impl VanillaSwap {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        swap_type: SwapType,
+        nominal: Real,
+        fixed_schedule: Schedule,
+        fixed_rate: Rate,
+        fixed_day_count: DayCounter,
+        float_schedule: Schedule,
+        ibor_index: Rc<IborIndex>,
+        spread: Spread,
+        floating_day_count: DayCounter,
+        engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+    ) -> Self {
+        let fixed_leg = FixedRateLeg::new(fixed_schedule, vec![nominal], vec![])
+            .with_coupon_rate(
+                fixed_rate,
+                fixed_day_count,
+                Compounding::Simple,
+                Frequency::Annual,
+            )
+            .build();
+        let floating_leg = IborLeg::new(float_schedule, ibor_index.clone())
+            .with_notional(nominal)
+            .with_payment_day_counter(floating_day_count)
+            .with_spread(spread)
+            .build();
+        let cache = Rc::new(InstrumentCache::new());
+        engine.register_observer(cache.clone());
+        ibor_index
+            .forwarding_term_structure
+            .register_observer(cache.clone());
+        Self {
+            swap_type,
+            nominal,
+            fixed_rate,
+            spread,
+            fixed_leg,
+            floating_leg,
+            ibor_index,
+            engine,
+            cache,
+        }
+    }
+
+    /// Arguments for the swap engine: the fixed leg followed by the floating leg
+    pub fn arguments(&self) -> SwapArguments {
+        let fixed_leg: CashFlowLeg = self
+            .fixed_leg
+            .iter()
+            .map(|c| Rc::new(c.clone()) as Rc<dyn CashFlow>)
+            .collect();
+        let floating_leg: CashFlowLeg = self
+            .floating_leg
+            .iter()
+            .map(|c| Rc::new(c.clone()) as Rc<dyn CashFlow>)
+            .collect();
+        let payer = match self.swap_type {
+            SwapType::Payer => vec![-1.0, 1.0],
+            SwapType::Receiver => vec![1.0, -1.0],
+        };
+        SwapArguments {
+            legs: vec![fixed_leg, floating_leg],
+            payer,
+            fixed_rate: Some(self.fixed_rate),
+            spread: Some(self.spread),
+        }
+    }
+
+    /// Runs the pricing engine and returns all the results
+    pub fn results(&self) -> SwapResults {
+        self.engine.calculate(self.arguments())
+    }
+
+    /// Settlement date of a swap traded on `date`, i.e. the spot lag of the floating index on its
+    /// fixing calendar
+    pub fn settlement_date(&self, date: Date) -> Date {
+        settlement_date(
+            date,
+            self.ibor_index.fixing_days,
+            &self.ibor_index.fixing_calendar,
+        )
+    }
+
+    /// Start of the accrual period of the first coupon
+    pub fn start_date(&self) -> Date {
+        self.fixed_leg
+            .iter()
+            .map(|c| c.accrual_start_date())
+            .chain(self.floating_leg.iter().map(|c| c.accrual_start_date()))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// End of the accrual period of the last coupon
+    pub fn maturity_date(&self) -> Date {
+        self.fixed_leg
+            .iter()
+            .map(|c| c.accrual_end_date())
+            .chain(self.floating_leg.iter().map(|c| c.accrual_end_date()))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// NPV of the fixed leg, with the sign of the swap type
+    pub fn fixed_leg_npv(&self) -> Real {
+        self.results().leg_npv[0]
+    }
+
+    /// NPV of the floating leg, with the sign of the swap type
+    pub fn floating_leg_npv(&self) -> Real {
+        self.results().leg_npv[1]
+    }
+
+    /// Change in NPV of the fixed leg for a one basis point increase of the fixed rate, with the
+    /// sign of the swap type
+    pub fn fixed_leg_bps(&self) -> Real {
+        self.results().leg_bps[0]
+    }
+
+    /// Change in NPV of the floating leg for a one basis point increase of the spread, with the
+    /// sign of the swap type
+    pub fn floating_leg_bps(&self) -> Real {
+        self.results().leg_bps[1]
+    }
+
+    /// Fixed rate giving a zero NPV, or `None` if the fixed leg has no coupon left to pay
+    pub fn fair_rate(&self) -> Option<Rate> {
+        self.results().fair_rate
+    }
+
+    /// Spread over the floating index giving a zero NPV, or `None` if the floating leg has no
+    /// coupon left to pay
+    pub fn fair_spread(&self) -> Option<Spread> {
+        self.results().fair_spread
+    }
+}
+
+impl Instrument for VanillaSwap {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        let results = self.results();
+        let mut instrument_results =
+            InstrumentResults::new(results.npv, results.error_estimate, results.valuation_date)
+                .with_additional_result("fixed_leg_npv", results.leg_npv[0])
+                .with_additional_result("floating_leg_npv", results.leg_npv[1])
+                .with_additional_result("fixed_leg_bps", results.leg_bps[0])
+                .with_additional_result("floating_leg_bps", results.leg_bps[1]);
+        if let Some(fair_rate) = results.fair_rate {
+            instrument_results = instrument_results.with_additional_result("fair_rate", fair_rate);
+        }
+        if let Some(fair_spread) = results.fair_spread {
+            instrument_results =
+                instrument_results.with_additional_result("fair_spread", fair_spread);
+        }
+        if let Some(currency) = results.currency {
+            instrument_results = instrument_results.with_currency(currency);
+        }
+        instrument_results
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        context::pricing_context::PricingContext,
+        currencies::europe::EURCurrency,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedule::Schedule,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Months, Years},
+        },
+        handle::{Handle, RelinkableHandle},
+        indexes::iborindex::IborIndex,
+        instruments::{
+            instrument::Instrument,
+            swap::{SwapArguments, SwapResults, SwapType},
+        },
+        pricingengines::{
+            pricingengine::PricingEngine, pricingengine_test_util::CountingEngine,
+            swap::discountingswapengine::DiscountingSwapEngine,
+        },
+        rates::compounding::Compounding::Continuous,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::{Rate, Real, Spread},
+    };
+
+    use super::VanillaSwap;
+
+    fn make_swap(swap_type: SwapType, fixed_rate: Rate, spread: Spread) -> VanillaSwap {
+        make_swap_on(Date::new(15, January, 2024), swap_type, fixed_rate, spread)
+    }
+
+    /// Five-year swap starting on 17 January 2024, valued on `today`
+    fn make_swap_on(
+        today: Date,
+        swap_type: SwapType,
+        fixed_rate: Rate,
+        spread: Spread,
+    ) -> VanillaSwap {
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(flat_curve(today, 0.035));
+        let engine = Rc::new(
+            DiscountingSwapEngine::new(curve.clone(), None)
+                .with_currency(Rc::new(EURCurrency::new())),
+        );
+        make_swap_with(today, curve, engine, swap_type, fixed_rate, spread)
+    }
+
+    fn flat_curve(today: Date, rate: Rate) -> Rc<dyn YieldTermStructure> {
+        Rc::new(FlatForward::new(
+            today,
+            rate,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        ))
+    }
+
+    /// Five-year swap starting on 17 January 2024, forecasting on `curve` and priced by `engine`
+    fn make_swap_with(
+        today: Date,
+        curve: Handle<dyn YieldTermStructure>,
+        engine: Rc<dyn PricingEngine<A = SwapArguments, R = SwapResults>>,
+        swap_type: SwapType,
+        fixed_rate: Rate,
+        spread: Spread,
+    ) -> VanillaSwap {
+        let index = Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(6, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            curve.clone(),
+        ));
+        let start = Date::new(17, January, 2024);
+        let schedule = |tenor: Period| -> Schedule {
+            ScheduleBuilder::new(
+                PricingContext::new(today),
+                start,
+                start + Period::new(5, Years),
+                tenor,
+                Target::new(),
+            )
+            .with_convention(ModifiedFollowing)
+            .build()
+        };
+        VanillaSwap::new(
+            swap_type,
+            1_000_000.0,
+            schedule(Period::new(1, Years)),
+            fixed_rate,
+            DayCounter::bond_basis(),
+            schedule(Period::new(6, Months)),
+            index,
+            spread,
+            DayCounter::actual360(),
+            engine,
+        )
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let today = Date::new(15, January, 2024);
+        let curve = RelinkableHandle::new(flat_curve(today, 0.035));
+        let engine = Rc::new(CountingEngine::new(DiscountingSwapEngine::new(
+            curve.handle(),
+            None,
+        )));
+        let swap = make_swap_with(
+            today,
+            curve.handle(),
+            engine.clone(),
+            SwapType::Payer,
+            0.03,
+            0.0,
+        );
+
+        // the second call uses the cached results
+        let npv = swap.npv();
+        assert_eq!(swap.npv(), npv);
+        assert_eq!(engine.calculations.get(), 1);
+        assert!(swap.cache().unwrap().is_calculated());
+
+        // relinking the curve invalidates them
+        curve.link_to(flat_curve(today, 0.04));
+        assert!(!swap.cache().unwrap().is_calculated());
+        assert!(swap.npv() > npv);
+        assert_eq!(engine.calculations.get(), 2);
+    }
+
+    #[test]
+    fn test_settlement_date() {
+        let swap = make_swap(SwapType::Payer, 0.03, 0.0);
+        // T+2 on the TARGET calendar, from Friday to Tuesday
+        assert_eq!(
+            swap.settlement_date(Date::new(12, January, 2024)),
+            Date::new(16, January, 2024)
+        );
+        // a Saturday trade date is first moved to Monday
+        assert_eq!(
+            swap.settlement_date(Date::new(13, January, 2024)),
+            Date::new(17, January, 2024)
+        );
+        assert_eq!(
+            swap.settlement_date(Date::new(15, January, 2024)),
+            swap.start_date()
+        );
+    }
+
+    #[test]
+    fn test_additional_results() {
+        let swap = make_swap(SwapType::Receiver, 0.03, 0.001);
+        let results = swap.calculate();
+        assert_eq!(results.currency.as_ref().map(|c| c.code()), Some("EUR"));
+        assert_eq!(results.npv, swap.npv());
+        assert!(swap.fair_rate().is_some());
+        assert_eq!(results.additional_result("fair_rate"), swap.fair_rate());
+        assert_eq!(results.additional_result("fair_spread"), swap.fair_spread());
+        assert_eq!(
+            results.additional_result("fixed_leg_bps"),
+            Some(swap.fixed_leg_bps())
+        );
+        assert_eq!(
+            results.additional_result("fixed_leg_npv"),
+            Some(swap.fixed_leg_npv())
+        );
+        assert_eq!(
+            swap.additional_result("floating_leg_npv"),
+            Some(swap.floating_leg_npv())
+        );
+        assert!(swap.additional_result("annuity").is_none());
+    }
+
+    #[test]
+    fn test_fair_rate_and_spread() {
+        let swap = make_swap(SwapType::Payer, 0.03, 0.001);
+        assert_eq!(swap.fixed_leg.len(), 5);
+        assert_eq!(swap.floating_leg.len(), 10);
+        let npv = swap.npv();
+        assert!(
+            (npv - (swap.fixed_leg_npv() + swap.floating_leg_npv())).abs() < 1.0e-8,
+            "npv {} differs from the sum of the legs",
+            npv
+        );
+
+        // receiver swaps have the opposite value
+        let receiver = make_swap(SwapType::Receiver, 0.03, 0.001);
+        assert!((receiver.npv() + npv).abs() < 1.0e-8);
+
+        let fair_rate = swap.fair_rate().unwrap();
+        let at_the_money = make_swap(SwapType::Payer, fair_rate, 0.001);
+        assert!(
+            at_the_money.npv().abs() < 1.0e-8,
+            "npv at the fair rate {}: {}",
+            fair_rate,
+            at_the_money.npv()
+        );
+        assert!((at_the_money.fair_rate().unwrap() - fair_rate).abs() < 1.0e-12);
+
+        let fair_spread: Real = swap.fair_spread().unwrap();
+        let at_the_money = make_swap(SwapType::Payer, 0.03, fair_spread);
+        assert!(
+            at_the_money.npv().abs() < 1.0e-8,
+            "npv at the fair spread {}: {}",
+            fair_spread,
+            at_the_money.npv()
+        );
+    }
+
+    #[test]
+    fn test_bps() {
+        let swap = make_swap(SwapType::Payer, 0.03, 0.001);
+        // paying the fixed leg, a higher fixed rate lowers the value
+        let fixed_leg_bps = swap.fixed_leg_bps();
+        assert!(fixed_leg_bps < 0.0);
+        assert!(swap.floating_leg_bps() > 0.0);
+
+        // the fixed leg value is linear in the rate, with the bps as slope
+        let higher = make_swap(SwapType::Payer, 0.0301, 0.001);
+        assert!((higher.npv() - swap.npv() - fixed_leg_bps).abs() < 1.0e-8);
+
+        // fair rate as the rate offsetting the npv with the annuity
+        let fair_rate = 0.03 - swap.npv() / (fixed_leg_bps / 1.0e-4);
+        assert!((fair_rate - swap.fair_rate().unwrap()).abs() < 1.0e-12);
+        // which gives a swap worth nothing
+        let at_the_money = make_swap(SwapType::Payer, fair_rate, 0.001);
+        assert!(
+            at_the_money.npv().abs() < 1.0e-8,
+            "npv at the fair rate {}: {}",
+            fair_rate,
+            at_the_money.npv()
+        );
+
+        let receiver = make_swap(SwapType::Receiver, 0.03, 0.001);
+        assert!((receiver.fixed_leg_bps() + fixed_leg_bps).abs() < 1.0e-8);
+        assert!((receiver.floating_leg_bps() + swap.floating_leg_bps()).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_expired_swap() {
+        // all the coupons were paid by the valuation date
+        let swap = make_swap_on(Date::new(15, January, 2030), SwapType::Payer, 0.03, 0.001);
+        assert_eq!(swap.npv(), 0.0);
+        assert_eq!(swap.fixed_leg_bps(), 0.0);
+        assert!(swap.fair_rate().is_none());
+        assert!(swap.fair_spread().is_none());
+        assert!(swap.additional_result("fair_rate").is_none());
+        assert_eq!(swap.additional_result("fixed_leg_npv"), Some(0.0));
+    }
+}
@@ -9,10 +9,15 @@ use crate::{
         period::Period,
         timeunit::TimeUnit,
     },
+    handle::Handle,
+    termstructures::yieldtermstructure::YieldTermStructure,
     types::{Integer, Real},
 };
 
-use super::bond::Bond;
+use super::{
+    bond::{discounting_results, Bond},
+    instrument::{Instrument, InstrumentCache, InstrumentResults},
+};
 
 /// Zero coupon bond
 pub struct ZeroCouponBond {
@@ -27,6 +32,9 @@ pub struct ZeroCouponBond {
     pub notional_schedule: Vec<Date>,
     pub cashflows: CashFlowLeg,
     pub redemptions: CashFlowLeg,
+    /// Curve on which the cash flows are discounted when the bond is priced as an [Instrument]
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    cache: Rc<InstrumentCache>,
 }
 
 impl Debug for ZeroCouponBond {
@@ -91,9 +99,18 @@ impl ZeroCouponBond {
             notional_schedule: vec![Date::default(), rcf.cashflow.date],
             cashflows: vec![rcf.clone()],
             redemptions: vec![rcf],
+            discount_curve: Handle::empty(),
+            cache: Rc::new(InstrumentCache::new()),
         }
     }
 
+    /// Discount the cash flows on the given curve when the bond is priced as an [Instrument]
+    pub fn with_discount_curve(mut self, discount_curve: Handle<dyn YieldTermStructure>) -> Self {
+        discount_curve.register_observer(self.cache.clone());
+        self.discount_curve = discount_curve;
+        self
+    }
+
     /// Calculate the price of a zero coupon bond (e.g. US Treasury Bill) given its discount yield
     pub fn price_from_discount_yield(&self, discount_yield: Real, settlement_date: Date) -> Real {
         let maturity_date = self.maturity_date();
@@ -114,6 +131,16 @@ impl ZeroCouponBond {
     }
 }
 
+impl Instrument for ZeroCouponBond {
+    fn cache(&self) -> Option<&InstrumentCache> {
+        Some(&self.cache)
+    }
+
+    fn perform_calculations(&self) -> InstrumentResults {
+        discounting_results(self, &self.discount_curve)
+    }
+}
+
 impl Bond for ZeroCouponBond {
     fn calendar(&self) -> &Calendar {
         &self.calendar
@@ -148,14 +175,23 @@ impl Bond for ZeroCouponBond {
 
 #[cfg(test)]
 mod test {
+    use std::rc::Rc;
+
     use crate::{
         context::pricing_context::PricingContext,
         datetime::{
-            date::Date, daycounter::DayCounter, frequency::Frequency::Semiannual,
-            holidays::unitedstates::UnitedStates, months::Month::*,
+            businessdayconvention::BusinessDayConvention::Following,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::{Annual, Semiannual},
+            holidays::{target::Target, unitedstates::UnitedStates},
+            months::Month::*,
         },
-        instruments::bond::Bond,
+        handle::RelinkableHandle,
+        instruments::{bond::Bond, instrument::Instrument},
         rates::compounding::Compounding,
+        termstructures::{flatforward::FlatForward, yieldtermstructure::YieldTermStructure},
+        types::Real,
     };
 
     use super::ZeroCouponBond;
@@ -201,4 +237,95 @@ mod test {
             (expected_bond_yield - bond_yield).abs()
         );
     }
+
+    #[test]
+    fn test_zero_coupon_bond_from_curve() {
+        let today = Date::new(15, January, 2025);
+        let calendar = Target::new();
+        let discount_curve = FlatForward::new(
+            today,
+            0.05,
+            DayCounter::actual365_fixed(),
+            Compounding::Continuous,
+            Annual,
+        );
+        // one year, i.e. 365 days, to maturity
+        let zcb = ZeroCouponBond::new_with_options(
+            0,
+            &calendar,
+            100.0,
+            Date::new(15, January, 2026),
+            Some(Following),
+            Some(100.0),
+            Some(Date::new(15, January, 2024)),
+        );
+        assert_eq!(zcb.cashflows().len(), 1);
+        let settlement_date = zcb.settlement_date(today);
+        assert_eq!(settlement_date, today);
+
+        let expected: Real = 100.0 * (-0.05_f64).exp();
+        let clean_price = zcb.clean_price_from_curve(&discount_curve, settlement_date);
+        assert!(
+            (clean_price - expected).abs() < 1.0e-12,
+            "clean price: {}, expected: {}",
+            clean_price,
+            expected
+        );
+        // no accrual on a zero coupon bond
+        assert_eq!(
+            zcb.dirty_price_from_curve(&discount_curve, settlement_date),
+            clean_price
+        );
+
+        let bond_yield = zcb.bond_yield_from_curve(
+            &discount_curve,
+            DayCounter::actual365_fixed(),
+            Compounding::Continuous,
+            Annual,
+            settlement_date,
+        );
+        assert!((bond_yield - 0.05).abs() < 1.0e-8, "yield: {}", bond_yield);
+
+        // a lower redemption scales the price
+        let zcb = ZeroCouponBond::new_with_options(
+            0,
+            &calendar,
+            100.0,
+            Date::new(15, January, 2026),
+            None,
+            Some(98.0),
+            None,
+        );
+        let clean_price = zcb.clean_price_from_curve(&discount_curve, settlement_date);
+        assert!((clean_price - 0.98 * expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let today = Date::new(15, January, 2025);
+        let flat_curve = |rate: Real| -> Rc<dyn YieldTermStructure> {
+            Rc::new(FlatForward::new(
+                today,
+                rate,
+                DayCounter::actual365_fixed(),
+                Compounding::Continuous,
+                Annual,
+            ))
+        };
+        let curve = RelinkableHandle::new(flat_curve(0.05));
+        let zcb = ZeroCouponBond::new(0, &Target::new(), 100.0, Date::new(15, January, 2026))
+            .with_discount_curve(curve.handle());
+
+        // the second call uses the cached results
+        let npv = Instrument::npv(&zcb);
+        assert!((npv - 100.0 * (-0.05_f64).exp()).abs() < 1.0e-12);
+        assert!(zcb.cache().unwrap().is_calculated());
+        assert_eq!(Instrument::npv(&zcb), npv);
+        assert_eq!(zcb.additional_result("clean_price"), Some(npv));
+
+        // relinking the curve invalidates them
+        curve.link_to(flat_curve(0.04));
+        assert!(!zcb.cache().unwrap().is_calculated());
+        assert!((Instrument::npv(&zcb) - 100.0 * (-0.04_f64).exp()).abs() < 1.0e-12);
+    }
 }
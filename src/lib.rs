@@ -1,12 +1,12 @@
 #![allow(clippy::needless_doctest_main)]
-//! 
+//!
 //! This is a pure Rust implementation of the excellent [QuantLib](https://www.quantlib.org/)
 //! library. QuantLib is an outstanding piece of work by a team of dedicated professionals in the
 //! field. Many thanks for their continuing work.
-//! 
+//!
 //! This is a work in progress. At present the focus is on implementing the essential building
 //! blocks of the library, closely following the QuantLib implementations.
-//! 
+//!
 //! Examples can be found in the examples directory. Use `cargo run --example <name>` where
 //! `<name>` is the name of the example binary (without the .rs suffix), e.g.:
 //!
@@ -35,20 +35,20 @@
 //! use rust_quantlib::instruments::zerocouponbond::ZeroCouponBond;
 //! use rust_quantlib::rates::compounding::Compounding;
 //! use rust_quantlib::types::{Integer, Real};
-//! 
+//!
 //! /// This example shows how to calcualate the maturity yield on US Treasury Bills, Notes
 //! /// and Bonds using real market data.
 //! pub fn main() {
 //!     let pricing_context = PricingContext::new(Date::new(6, June, 2022));
 //!     let common_data = CommonData::new(pricing_context, 1);
-//! 
+//!
 //!     let mut results = vec![];
 //!     zero_coupon_bonds(&common_data, make_tbill_market_data(), &mut results);
 //!     fixed_rate_bonds(&common_data, make_bond_market_data(), &mut results);
-//! 
+//!
 //!     show_results(results);
 //! }
-//! 
+//!
 //! pub fn zero_coupon_bonds(
 //!     cd: &CommonData,
 //!     market_data: TBillMarketData,
@@ -57,7 +57,7 @@
 //!     for i in 0..market_data.maturities.len() {
 //!         let maturity_date = market_data.maturities[i];
 //!         let discount_yield = market_data.discount_yields[i] / 100.0;
-//! 
+//!
 //!         let zcb = ZeroCouponBond::new(
 //!             cd.settlement_days,
 //!             &cd.calendar,
@@ -75,7 +75,7 @@
 //!                 cd.frequency,
 //!                 cd.settlement_date,
 //!             );
-//! 
+//!
 //!         results.push(Result {
 //!             bond_description: format!("{:?}/{:?}", zcb, zcb.period(cd.pricing_context.eval_date)),
 //!             price,
@@ -83,7 +83,7 @@
 //!         });
 //!     }
 //! }
-//! 
+//!
 //! pub fn fixed_rate_bonds(cd: &CommonData, md: BondMarketData, results: &mut Vec<Result>) {
 //!     for (i, maturity) in md.maturities.iter().enumerate() {
 //!         let ref_start = maturity - md.periods[i];
@@ -112,7 +112,7 @@
 //!                 cd.frequency,
 //!                 cd.settlement_date,
 //!             );
-//! 
+//!
 //!         results.push(Result {
 //!             bond_description: format!("{:?}/{:?}", frb, md.periods[i]),
 //!             price,
@@ -120,7 +120,7 @@
 //!         })
 //!     }
 //! }
-//! 
+//!
 //! pub fn make_tbill_market_data() -> TBillMarketData {
 //!     TBillMarketData {
 //!         maturities: vec![
@@ -133,7 +133,7 @@
 //!         discount_yields: vec![0.851, 1.016, 1.214, 1.694, 2.111],
 //!     }
 //! }
-//! 
+//!
 //! pub fn make_bond_market_data() -> BondMarketData {
 //!     BondMarketData {
 //!         coupons: vec![2.5, 2.75, 2.625, 2.75, 2.875, 3.25, 2.875],
@@ -166,7 +166,7 @@
 //!         ],
 //!     }
 //! }
-//! 
+//!
 //! pub fn show_results(results: Vec<Result>) {
 //!     println!(
 //!         "{:>2} {:<20} {:6} {:8}",
@@ -183,7 +183,7 @@
 //!         );
 //!     }
 //! }
-//! 
+//!
 //! /// Common data for pricing
 //! pub struct CommonData {
 //!     pub pricing_context: PricingContext,
@@ -195,13 +195,13 @@
 //!     pub frequency: Frequency,
 //!     pub face_amount: Real,
 //! }
-//! 
+//!
 //! /// US Treasury Bill market data
 //! pub struct TBillMarketData {
 //!     pub discount_yields: Vec<Real>,
 //!     pub maturities: Vec<Date>,
 //! }
-//! 
+//!
 //! /// US Note and Bond market data
 //! pub struct BondMarketData {
 //!     pub coupons: Vec<Real>,
@@ -209,13 +209,13 @@
 //!     pub periods: Vec<Period>,
 //!     pub maturities: Vec<Date>,
 //! }
-//! 
+//!
 //! pub struct Result {
 //!     pub bond_description: String,
 //!     pub price: Real,
 //!     pub bond_yield: Real,
 //! }
-//! 
+//!
 //! impl CommonData {
 //!     pub fn new(pricing_context: PricingContext, settlement_days: Integer) -> Self {
 //!         let settlement_date = pricing_context.eval_date + settlement_days;
@@ -233,8 +233,8 @@
 //! }
 //! ```
 //! #### Output
-//! 
-//! | # | Bond Description  |  price | yield  |  
+//!
+//! | # | Bond Description  |  price | yield  |
 //! |---|-------------------|--------|--------|
 //! | 1 | ZCB/2022-07-05/1M | 99.934 | 0.863% |
 //! | 2 | ZCB/2022-08-02/2M | 99.842 | 1.032% |
@@ -250,7 +250,7 @@
 //! |12 | FRB/2052-05-15/30Y| 94.391 | 3.166% |
 //!
 //! # References
-//! 
+//!
 //! * [Implementing QuantLib, Luigi Ballabio](https://leanpub.com/implementingquantlib)
 //! * <https://people.maths.ox.ac.uk/trefethen/barycentric.pdf>
 //! * <https://github.com/higham/what-is>
@@ -260,9 +260,12 @@ pub mod cashflows;
 pub mod context;
 pub mod currencies;
 pub mod datetime;
+pub mod handle;
+pub mod indexes;
 pub mod instruments;
 pub mod maths;
 pub mod misc;
+pub mod patterns;
 pub mod pricingengines;
 pub mod processes;
 pub mod quotes;
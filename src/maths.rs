@@ -1,6 +1,15 @@
 pub mod array;
 pub mod bounds;
 pub mod comparison;
+pub mod distributions;
+pub mod errorfunction;
+pub mod genericfunction;
+pub mod integrals;
 pub mod interpolations;
+pub mod matrix;
+pub mod matrixutilities;
+pub mod randomnumbers;
 pub mod rounding;
 pub mod solvers1d;
+pub mod statistics;
+pub mod timegrid;
@@ -6,13 +6,7 @@ use crate::types::Size;
 /// The index returned by this function may also be equivalent to the index of val, and not only
 /// greater. The slice must be sorted.
 pub fn lower_bound<T: PartialOrd>(xs: &[T], value: T) -> Size {
-    // TODO use binary search instead of a linear scan
-    for (i, x) in xs.iter().enumerate() {
-        if x >= &value {
-            return i;
-        }
-    }
-    xs.len()
+    xs.partition_point(|x| x < &value)
 }
 
 /// Returns the indxex of the first element in the slice `xs` which compares greater than
@@ -21,13 +15,7 @@ pub fn lower_bound<T: PartialOrd>(xs: &[T], value: T) -> Size {
 /// Unlike lower_bound, the index returned by this function cannot be equivalent to the index
 /// of `value`, only greater. The slice must be sorted.
 pub fn upper_bound<T: PartialOrd>(xs: &[T], value: T) -> Size {
-    // TODO use binary search instead of a linear scan
-    for (i, x) in xs.iter().enumerate() {
-        if x > &value {
-            return i;
-        }
-    }
-    xs.len()
+    xs.partition_point(|x| x <= &value)
 }
 
 // -------------------------------------------------------------------------------------------------
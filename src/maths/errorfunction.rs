@@ -0,0 +1,225 @@
+use crate::types::Real;
+
+// Coefficients of the rational approximations used by the FDLIBM implementation of `erf` and
+// `erfc`, which is also the one used by QuantLib.
+
+const ERX: Real = 8.450629115104675e-01;
+const EFX: Real = 1.283791670955126e-01;
+
+// |x| in [0, 0.84375]
+const PP: [Real; 5] = [
+    1.2837916709551256e-01,
+    -3.250421072470015e-01,
+    -2.848174957559851e-02,
+    -5.770270296489442e-03,
+    -2.3763016656650163e-05,
+];
+const QQ: [Real; 5] = [
+    3.9791722395915535e-01,
+    6.50222499887673e-02,
+    5.081306281875766e-03,
+    1.3249473800432164e-04,
+    -3.960228278775368e-06,
+];
+
+// |x| in [0.84375, 1.25]
+const PA: [Real; 7] = [
+    -2.3621185607526594e-03,
+    4.1485611868374833e-01,
+    -3.722078760357013e-01,
+    3.1834661990116175e-01,
+    -1.1089469428239668e-01,
+    3.5478304325618236e-02,
+    -2.166375594868791e-03,
+];
+const QA: [Real; 6] = [
+    1.0642088040084423e-01,
+    5.40397917702171e-01,
+    7.182865441419627e-02,
+    1.2617121980876164e-01,
+    1.363708391202905e-02,
+    1.1984499846799107e-02,
+];
+
+// |x| in [1.25, 1/0.35]
+const RA: [Real; 8] = [
+    -9.864944034847148e-03,
+    -6.938585727071818e-01,
+    -1.0558626225323291e+01,
+    -6.2375332450326006e+01,
+    -1.6239666946257347e+02,
+    -1.8460509290671104e+02,
+    -8.12874355063066e+01,
+    -9.814329344169145e+00,
+];
+const SA: [Real; 8] = [
+    1.9651271667439257e+01,
+    1.3765775414351904e+02,
+    4.3456587747522923e+02,
+    6.453872717332679e+02,
+    4.2900814002756783e+02,
+    1.0863500554177944e+02,
+    6.570249770319282e+00,
+    -6.04244152148581e-02,
+];
+
+// |x| in [1/0.35, 28]
+const RB: [Real; 7] = [
+    -9.8649429247001e-03,
+    -7.99283237680523e-01,
+    -1.7757954917754752e+01,
+    -1.6063638485582192e+02,
+    -6.375664433683896e+02,
+    -1.0250951316110772e+03,
+    -4.835191916086514e+02,
+];
+const SB: [Real; 7] = [
+    3.033806074348246e+01,
+    3.257925129965739e+02,
+    1.536729586084437e+03,
+    3.1998582195085955e+03,
+    2.5530504064331644e+03,
+    4.7452854120695537e+02,
+    -2.244095244658582e+01,
+];
+
+/// Evaluates the polynomial with the given coefficients (lowest degree first) at `x`
+fn polynomial(coefficients: &[Real], x: Real) -> Real {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// `1 + x * p(x)`, the form taken by all the denominators
+fn one_plus(coefficients: &[Real], x: Real) -> Real {
+    1.0 + x * polynomial(coefficients, x)
+}
+
+/// Returns `erfc(|x|)` for `|x| >= 1.25`, i.e. in the region where the asymptotic rational
+/// approximations apply.
+fn erfc_tail(ax: Real) -> Real {
+    let s = 1.0 / (ax * ax);
+    let (r, s) = if ax < 1.0 / 0.35 {
+        (polynomial(&RA, s), one_plus(&SA, s))
+    } else {
+        (polynomial(&RB, s), one_plus(&SB, s))
+    };
+    // z is ax with the lower 32 bits cleared, so that z * z is exact
+    let z = Real::from_bits(ax.to_bits() & 0xffff_ffff_0000_0000);
+    (-z * z - 0.5625).exp() * ((z - ax) * (z + ax) + r / s).exp() / ax
+}
+
+/// Error function
+///
+/// `erf(x) = 2/√π ∫₀ˣ exp(-t²) dt`
+pub fn erf(x: Real) -> Real {
+    if x.is_nan() {
+        return x;
+    }
+    let ax = x.abs();
+    if ax < 0.84375 {
+        if ax < Real::powi(2.0, -28) {
+            return x + EFX * x;
+        }
+        let z = x * x;
+        return x + x * polynomial(&PP, z) / one_plus(&QQ, z);
+    }
+    let value = if ax < 1.25 {
+        let s = ax - 1.0;
+        ERX + polynomial(&PA, s) / one_plus(&QA, s)
+    } else if ax >= 6.0 {
+        1.0
+    } else {
+        1.0 - erfc_tail(ax)
+    };
+    value.copysign(x)
+}
+
+/// Complementary error function, `erfc(x) = 1 - erf(x)`
+///
+/// Unlike `1 - erf(x)`, this retains full relative accuracy for large positive `x`.
+pub fn erfc(x: Real) -> Real {
+    if x.is_nan() {
+        return x;
+    }
+    let ax = x.abs();
+    if ax < 0.84375 {
+        if ax < Real::powi(2.0, -56) {
+            return 1.0 - x;
+        }
+        let z = x * x;
+        let y = polynomial(&PP, z) / one_plus(&QQ, z);
+        return if x < 0.25 {
+            1.0 - (x + x * y)
+        } else {
+            0.5 - (x * y + (x - 0.5))
+        };
+    }
+    if ax < 1.25 {
+        let s = ax - 1.0;
+        let p = polynomial(&PA, s) / one_plus(&QA, s);
+        return if x >= 0.0 {
+            1.0 - ERX - p
+        } else {
+            1.0 + ERX + p
+        };
+    }
+    if ax < 28.0 {
+        let r = erfc_tail(ax);
+        if x > 0.0 {
+            r
+        } else {
+            2.0 - r
+        }
+    } else if x > 0.0 {
+        0.0
+    } else {
+        2.0
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::{erf, erfc};
+
+    #[test]
+    fn test_erf() {
+        // reference values from the C standard library
+        let cases = [
+            (0.0, 0.0),
+            (0.1, 0.1124629160182849),
+            (0.5, 0.5204998778130465),
+            (1.0, 0.8427007929497149),
+            (1.5, 0.9661051464753108),
+            (2.5, 0.999593047982555),
+            (4.0, 0.9999999845827421),
+        ];
+        for (x, expected) in cases {
+            assert!((erf(x) - expected).abs() < 1.0e-15, "erf({})", x);
+            assert!((erf(-x) + expected).abs() < 1.0e-15, "erf({})", -x);
+        }
+        assert_eq!(erf(10.0), 1.0);
+    }
+
+    #[test]
+    fn test_erfc() {
+        let cases = [
+            (-1.0, 1.842700792949715),
+            (0.3, 0.6713732405408726),
+            (1.0, 0.15729920705028513),
+            (3.0, 2.2090496998585438e-05),
+            (5.0, 1.5374597944280351e-12),
+            (10.0, 2.088487583762545e-45),
+        ];
+        for (x, expected) in cases {
+            let value = erfc(x);
+            assert!(
+                ((value - expected) / expected).abs() < 1.0e-14,
+                "erfc({}) = {}, expected {}",
+                x,
+                value,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::types::Real;
+
+/// A real function of a real variable, e.g. an integrand.
+///
+/// Closures taking and returning a [Real] implement it, so that they can be passed wherever a
+/// function is expected; types with more state can implement it directly. A closure passed
+/// straight to a generic function needs the type of its argument, e.g. `|x: Real| x.sin()`.
+pub trait GenericFunction {
+    /// Value of the function at `x`
+    fn value(&self, x: Real) -> Real;
+}
+
+impl<F> GenericFunction for F
+where
+    F: Fn(Real) -> Real,
+{
+    fn value(&self, x: Real) -> Real {
+        self(x)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod gausskronrodadaptive;
+pub mod integrator;
+pub mod simpsonintegral;
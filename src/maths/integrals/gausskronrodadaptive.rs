@@ -0,0 +1,170 @@
+use crate::{
+    maths::genericfunction::GenericFunction,
+    types::{Real, Size},
+};
+
+use super::integrator::{
+    private::{self, IntegratorData},
+    Integrator,
+};
+
+/// Weights of the 7-point Gauss rule, from the centre outwards
+const G7_WEIGHTS: [Real; 4] = [
+    0.417_959_183_673_469_4,
+    0.381_830_050_505_118_9,
+    0.279_705_391_489_276_7,
+    0.129_484_966_168_869_7,
+];
+
+/// Nodes of the 15-point Kronrod rule on `[0, 1]`; those at even positions are the Gauss nodes
+const K15_NODES: [Real; 8] = [
+    0.0,
+    0.207_784_955_007_898_5,
+    0.405_845_151_377_397_2,
+    0.586_087_235_467_691_1,
+    0.741_531_185_599_394_4,
+    0.864_864_423_359_769_1,
+    0.949_107_912_342_758_5,
+    0.991_455_371_120_812_6,
+];
+
+/// Weights of the 15-point Kronrod rule, matching [K15_NODES]
+const K15_WEIGHTS: [Real; 8] = [
+    0.209_482_141_084_727_8,
+    0.204_432_940_075_298_9,
+    0.190_350_578_064_785_4,
+    0.169_004_726_639_267_9,
+    0.140_653_259_715_525_9,
+    0.104_790_010_322_250_2,
+    0.063_092_092_629_978_55,
+    0.022_935_322_010_529_22,
+];
+
+/// Integral of a one-dimensional function using the adaptive Gauss-Kronrod rule
+///
+/// Each interval is integrated with the 7-point Gauss and 15-point Kronrod rules, which share
+/// their nodes; the difference of the two estimates bounds the error. Intervals where it exceeds
+/// the tolerance are split in two, each half being given half the tolerance, so that evaluations
+/// concentrate where the function is hard to integrate.
+#[derive(Debug, Clone)]
+pub struct GaussKronrodAdaptive {
+    data: IntegratorData,
+}
+
+impl GaussKronrodAdaptive {
+    pub fn new(absolute_accuracy: Real, max_evaluations: Size) -> Self {
+        assert!(
+            max_evaluations >= 15,
+            "max_evaluations ({}) must be at least 15",
+            max_evaluations
+        );
+        Self {
+            data: IntegratorData::new(absolute_accuracy, max_evaluations),
+        }
+    }
+
+    fn integrate_recursively(
+        &self,
+        f: &dyn GenericFunction,
+        a: Real,
+        b: Real,
+        tolerance: Real,
+    ) -> Real {
+        let half_length = (b - a) / 2.0;
+        let centre = (a + b) / 2.0;
+
+        let fc = f.value(centre);
+        let mut g7 = fc * G7_WEIGHTS[0];
+        let mut k15 = fc * K15_WEIGHTS[0];
+        for j in 1..K15_NODES.len() {
+            let t = half_length * K15_NODES[j];
+            let fsum = f.value(centre - t) + f.value(centre + t);
+            k15 += fsum * K15_WEIGHTS[j];
+            if j % 2 == 0 {
+                g7 += fsum * G7_WEIGHTS[j / 2];
+            }
+        }
+        let g7 = half_length * g7;
+        let k15 = half_length * k15;
+
+        let error = (k15 - g7).abs();
+        if error < tolerance {
+            self.data
+                .absolute_error
+                .set(self.data.absolute_error.get() + error);
+            return k15;
+        }
+        assert!(
+            self.data.evaluations.get() + 30 <= self.data.max_evaluations,
+            "maximum number of function evaluations ({}) exceeded",
+            self.data.max_evaluations
+        );
+        self.integrate_recursively(f, a, centre, tolerance / 2.0)
+            + self.integrate_recursively(f, centre, b, tolerance / 2.0)
+    }
+}
+
+impl Integrator for GaussKronrodAdaptive {}
+
+impl private::IntegratorDetail for GaussKronrodAdaptive {
+    fn integrate_impl(&self, f: &dyn GenericFunction, a: Real, b: Real) -> Real {
+        self.integrate_recursively(f, a, b, self.data.absolute_accuracy)
+    }
+
+    fn data(&self) -> &IntegratorData {
+        &self.data
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+
+    use crate::{
+        maths::{
+            distributions::normaldistribution::NormalDistribution,
+            integrals::integrator::Integrator,
+        },
+        types::Real,
+    };
+
+    use super::{GaussKronrodAdaptive, G7_WEIGHTS, K15_WEIGHTS};
+
+    #[test]
+    fn test_gauss_kronrod_adaptive() {
+        // the rules integrate constants exactly
+        let g7: f64 = G7_WEIGHTS[0] + 2.0 * G7_WEIGHTS[1..].iter().sum::<f64>();
+        let k15: f64 = K15_WEIGHTS[0] + 2.0 * K15_WEIGHTS[1..].iter().sum::<f64>();
+        assert!((g7 - 2.0).abs() < 1.0e-15 && (k15 - 2.0).abs() < 1.0e-15);
+
+        let accuracy = 1.0e-10;
+        let integrator = GaussKronrodAdaptive::new(accuracy, 10_000);
+
+        let value = integrator.integrate(|x: Real| x.sin(), 0.0, PI);
+        assert!((value - 2.0).abs() < accuracy, "sin integral: {}", value);
+        assert!(integrator.integration_success());
+        assert_eq!(integrator.number_of_evaluations() % 15, 0);
+
+        let density = NormalDistribution::default();
+        let value = integrator.integrate(|x: Real| density.value(x), -10.0, 10.0);
+        assert!((value - 1.0).abs() < accuracy, "normal integral: {}", value);
+        assert!(integrator.absolute_error() < accuracy);
+
+        let value = integrator.integrate(|x: Real| x.sin(), PI, 0.0);
+        assert!((value + 2.0).abs() < accuracy);
+
+        // polynomials up to degree 13 need a single interval
+        let value = integrator.integrate(|x: Real| x.powi(13), 0.0, 1.0);
+        assert!((value - 1.0 / 14.0).abs() < 1.0e-15);
+        assert_eq!(integrator.number_of_evaluations(), 15);
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum number of function evaluations")]
+    fn test_gauss_kronrod_adaptive_max_evaluations() {
+        let integrator = GaussKronrodAdaptive::new(1.0e-12, 100);
+        integrator.integrate(|x: Real| x.sqrt(), 0.0, 1.0);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    maths::genericfunction::GenericFunction,
+    types::{Real, Size},
+};
+
+pub trait Integrator: private::IntegratorDetail {
+    /// Returns the integral of `f` over `[a, b]`, computed to the absolute accuracy of the
+    /// integrator. The bounds may be given in either order, swapping them changes the sign of
+    /// the result.
+    ///
+    /// The number of evaluations of `f` and the estimated error of the last integration are
+    /// available afterwards from [Integrator::number_of_evaluations] and
+    /// [Integrator::absolute_error].
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - function to integrate
+    /// * `a` - lower bound
+    /// * `b` - upper bound
+    fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: GenericFunction,
+    {
+        let data = self.data();
+        data.evaluations.set(0);
+        data.absolute_error.set(0.0);
+        if a == b {
+            return 0.0;
+        }
+        let counted = |x| {
+            data.evaluations.set(data.evaluations.get() + 1);
+            f.value(x)
+        };
+        if b > a {
+            self.integrate_impl(&counted, a, b)
+        } else {
+            -self.integrate_impl(&counted, b, a)
+        }
+    }
+
+    /// Required absolute accuracy of the integrals
+    fn absolute_accuracy(&self) -> Real {
+        self.data().absolute_accuracy
+    }
+
+    /// Maximum number of function evaluations allowed for an integral
+    fn max_evaluations(&self) -> Size {
+        self.data().max_evaluations
+    }
+
+    /// Number of function evaluations used by the last integration
+    fn number_of_evaluations(&self) -> Size {
+        self.data().evaluations.get()
+    }
+
+    /// Estimated absolute error of the last integration
+    fn absolute_error(&self) -> Real {
+        self.data().absolute_error.get()
+    }
+
+    /// Whether the last integration reached the required accuracy within the maximum number of
+    /// function evaluations
+    fn integration_success(&self) -> bool {
+        self.number_of_evaluations() <= self.max_evaluations()
+            && self.absolute_error() <= self.absolute_accuracy()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+pub(crate) mod private {
+    use std::cell::Cell;
+
+    use crate::{
+        maths::genericfunction::GenericFunction,
+        types::{Real, Size},
+    };
+
+    /// Settings of an integrator, together with the statistics of its last integration
+    #[derive(Debug, Clone)]
+    pub struct IntegratorData {
+        pub absolute_accuracy: Real,
+        pub max_evaluations: Size,
+        pub evaluations: Cell<Size>,
+        pub absolute_error: Cell<Real>,
+    }
+
+    impl IntegratorData {
+        pub fn new(absolute_accuracy: Real, max_evaluations: Size) -> Self {
+            assert!(
+                absolute_accuracy > 0.0,
+                "required accuracy ({}) must be positive",
+                absolute_accuracy
+            );
+            Self {
+                absolute_accuracy,
+                max_evaluations,
+                evaluations: Cell::new(0),
+                absolute_error: Cell::new(0.0),
+            }
+        }
+    }
+
+    pub trait IntegratorDetail {
+        /// Integral of `f` over `[a, b]`, with `a < b`
+        fn integrate_impl(&self, f: &dyn GenericFunction, a: Real, b: Real) -> Real;
+
+        fn data(&self) -> &IntegratorData;
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    maths::genericfunction::GenericFunction,
+    types::{Real, Size},
+};
+
+use super::integrator::{
+    private::{self, IntegratorData},
+    Integrator,
+};
+
+/// Default maximum number of refinements of the integration grid
+const MAX_ITERATIONS: Size = 20;
+
+/// Integral of a one-dimensional function using Simpson's rule
+///
+/// The number of intervals is doubled at each iteration, reusing the points already evaluated,
+/// until two successive Simpson estimates differ by less than the required accuracy. Well
+/// suited for smooth integrands; the error decreases as the fourth power of the step.
+#[derive(Debug, Clone)]
+pub struct SimpsonIntegral {
+    max_iterations: Size,
+    data: IntegratorData,
+}
+
+impl SimpsonIntegral {
+    /// Integrator giving up after `max_iterations` refinements, i.e. `2^max_iterations + 1`
+    /// function evaluations.
+    pub fn new(absolute_accuracy: Real, max_iterations: Size) -> Self {
+        assert!(
+            max_iterations > 5,
+            "at least 6 iterations are needed, {} given",
+            max_iterations
+        );
+        Self {
+            max_iterations,
+            data: IntegratorData::new(absolute_accuracy, (1 << max_iterations) + 1),
+        }
+    }
+
+    /// Integrator with up to 20 refinements of the grid
+    pub fn with_accuracy(absolute_accuracy: Real) -> Self {
+        Self::new(absolute_accuracy, MAX_ITERATIONS)
+    }
+}
+
+impl Integrator for SimpsonIntegral {}
+
+impl private::IntegratorDetail for SimpsonIntegral {
+    fn integrate_impl(&self, f: &dyn GenericFunction, a: Real, b: Real) -> Real {
+        // trapezoid estimates on 1, 2, 4, ... intervals, combined into Simpson estimates
+        let mut n = 1;
+        let mut trapezoid = (f.value(a) + f.value(b)) * (b - a) / 2.0;
+        let mut simpson = trapezoid;
+        for i in 1..=self.max_iterations {
+            let dx = (b - a) / n as Real;
+            let sum: Real = (0..n).map(|j| f.value(a + (j as Real + 0.5) * dx)).sum();
+            let refined = (trapezoid + dx * sum) / 2.0;
+            n *= 2;
+            let refined_simpson = (4.0 * refined - trapezoid) / 3.0;
+            let error = (refined_simpson - simpson).abs();
+            self.data.absolute_error.set(error);
+            // a few iterations are needed before the estimates can be trusted to be converging
+            if error <= self.data.absolute_accuracy && i > 5 {
+                return refined_simpson;
+            }
+            trapezoid = refined;
+            simpson = refined_simpson;
+        }
+        panic!(
+            "Simpson integral over [{}, {}] did not converge in {} iterations: last error {}",
+            a,
+            b,
+            self.max_iterations,
+            self.data.absolute_error.get()
+        );
+    }
+
+    fn data(&self) -> &IntegratorData {
+        &self.data
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+
+    use crate::{
+        maths::{
+            distributions::normaldistribution::NormalDistribution,
+            genericfunction::GenericFunction, integrals::integrator::Integrator,
+        },
+        types::Real,
+    };
+
+    use super::SimpsonIntegral;
+
+    #[test]
+    fn test_simpson_integral() {
+        let accuracy = 1.0e-8;
+        let integrator = SimpsonIntegral::with_accuracy(accuracy);
+
+        let value = integrator.integrate(|x: Real| x.sin(), 0.0, PI);
+        assert!((value - 2.0).abs() < accuracy, "sin integral: {}", value);
+        assert!(integrator.integration_success());
+        assert!(integrator.number_of_evaluations() <= integrator.max_evaluations());
+        assert!(integrator.number_of_evaluations() > 2);
+
+        let density = NormalDistribution::default();
+        let value = integrator.integrate(|x: Real| density.value(x), -10.0, 10.0);
+        assert!((value - 1.0).abs() < accuracy, "normal integral: {}", value);
+
+        // swapped bounds change the sign, equal ones give zero
+        let value = integrator.integrate(|x: Real| x.sin(), PI, 0.0);
+        assert!((value + 2.0).abs() < accuracy);
+        assert_eq!(integrator.integrate(|x: Real| x.sin(), 1.0, 1.0), 0.0);
+        assert_eq!(integrator.number_of_evaluations(), 0);
+
+        // cubics are integrated exactly
+        let value = integrator.integrate(|x: Real| x * x * x - x, 0.0, 2.0);
+        assert!((value - 2.0).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn test_generic_function() {
+        struct Polynomial {
+            coefficients: Vec<Real>,
+        }
+
+        impl GenericFunction for Polynomial {
+            fn value(&self, x: Real) -> Real {
+                self.coefficients
+                    .iter()
+                    .rev()
+                    .fold(0.0, |sum, c| sum * x + c)
+            }
+        }
+
+        // integrands need not be closures
+        let integrator = SimpsonIntegral::with_accuracy(1.0e-10);
+        let cubic = Polynomial {
+            coefficients: vec![0.0, -1.0, 0.0, 1.0],
+        };
+        let value = integrator.integrate(cubic, 0.0, 2.0);
+        assert!((value - 2.0).abs() < 1.0e-14);
+    }
+
+    #[test]
+    #[should_panic(expected = "did not converge")]
+    fn test_simpson_integral_max_iterations() {
+        let integrator = SimpsonIntegral::new(1.0e-15, 6);
+        integrator.integrate(|x: Real| x.sqrt(), 0.0, 1.0);
+    }
+}
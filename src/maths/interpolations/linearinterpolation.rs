@@ -1,9 +1,57 @@
 use crate::types::{Real, Size};
 
-use crate::maths::{bounds::upper_bound, comparison::close};
+use crate::maths::{bounds::lower_bound, comparison::close};
 
 use super::interpolation::Interpolation;
 
+/// Linearly interpolates the points `(xs, ys)` at `x`.
+///
+/// The abscissae must be sorted in strictly increasing order. Outside `[xs[0], xs[n - 1]]` the
+/// first or last segment is extended if `extrapolate` is true; otherwise the call panics. Unlike
+/// [LinearInterpolation], nothing is precomputed, which suits curves that evaluate their nodes
+/// only a few times.
+pub fn linear_interpolate(xs: &[Real], ys: &[Real], x: Real, extrapolate: bool) -> Real {
+    assert!(
+        xs.len() >= 2,
+        "not enough points to interpolate: {}",
+        xs.len()
+    );
+    assert_eq!(
+        xs.len(),
+        ys.len(),
+        "the number of abscissae ({}) doesn't match the number of ordinates ({})",
+        xs.len(),
+        ys.len()
+    );
+    let (xmin, xmax) = (xs[0], xs[xs.len() - 1]);
+    assert!(
+        extrapolate || (x >= xmin && x <= xmax) || close(x, xmin) || close(x, xmax),
+        "interpolation range is [{}, {}]: extrapolation at {} is not allowed",
+        xmin,
+        xmax,
+        x
+    );
+    let i = locate(xs, x);
+    if x == xs[i] {
+        return ys[i];
+    } else if x == xs[i + 1] {
+        return ys[i + 1];
+    }
+    ys[i] + (x - xs[i]) * (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i])
+}
+
+/// Index of the segment `[xs[i], xs[i + 1]]` used to interpolate at `x`; values outside the
+/// range are mapped to the first or last segment.
+fn locate(xs: &[Real], x: Real) -> Size {
+    let i = lower_bound(xs, x);
+    let i = if i < xs.len() && xs[i] == x {
+        i
+    } else {
+        i.saturating_sub(1)
+    };
+    i.min(xs.len() - 2)
+}
+
 /// Linear interpolation between discrete points
 pub struct LinearInterpolation<'a> {
     pub x: &'a [Real],
@@ -65,13 +113,7 @@ impl<'a> Interpolation for LinearInterpolation<'a> {
     }
 
     fn locate(&self, x: Real) -> Size {
-        if x < self.x[0] {
-            0
-        } else if x > self.x[self.x.len() - 1] {
-            self.x.len() - 2
-        } else {
-            upper_bound(self.x, x) - 1
-        }
+        locate(self.x, x)
     }
 
     fn update(&mut self) {
@@ -91,7 +133,7 @@ impl<'a> Interpolation for LinearInterpolation<'a> {
 mod test {
     use crate::maths::{interpolations::interpolation::Interpolation, rounding::Rounding};
 
-    use super::LinearInterpolation;
+    use super::{linear_interpolate, LinearInterpolation};
 
     #[test]
     fn test_linear_interpolation() {
@@ -125,4 +167,39 @@ mod test {
         // 142844.81325301205
         assert_eq!(rounding.round(lin.primitive(x)), 142844.81);
     }
+
+    #[test]
+    fn test_linear_interpolate() {
+        let xs = vec![0.0, 1.0, 3.0, 4.0];
+        let ys = vec![10.0, 20.0, 25.0, 40.0];
+        let lin = LinearInterpolation::new(&xs, &ys);
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert_eq!(linear_interpolate(&xs, &ys, *x, false), *y);
+            assert_eq!(lin.value(*x), *y);
+        }
+
+        // equally spaced nodes: the midpoint is the average of its neighbours
+        let xs = vec![1.0, 2.0, 3.0];
+        let ys = vec![1.0, 2.0, 4.0];
+        assert_eq!(linear_interpolate(&xs, &ys, 1.5, false), 1.5);
+        assert_eq!(linear_interpolate(&xs, &ys, 2.5, false), 3.0);
+
+        // extrapolation extends the first and last segments
+        assert_eq!(linear_interpolate(&xs, &ys, 0.0, true), 0.0);
+        assert_eq!(linear_interpolate(&xs, &ys, 4.0, true), 6.0);
+
+        let lin = LinearInterpolation::new(&xs, &ys);
+        for x in [0.5, 1.0, 1.25, 2.0, 2.75, 3.5] {
+            assert_eq!(
+                lin.value_with_extrapolation(x, true),
+                linear_interpolate(&xs, &ys, x, true)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "extrapolation at 3.5 is not allowed")]
+    fn test_linear_interpolate_out_of_range() {
+        linear_interpolate(&[1.0, 2.0, 3.0], &[1.0, 2.0, 4.0], 3.5, false);
+    }
 }
@@ -0,0 +1,309 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::types::{Real, Size};
+
+use super::array::Array;
+
+/// Matrix used in linear algebra.
+///
+/// Elements are stored in row-major order and accessed with `m[(row, column)]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: Size,
+    columns: Size,
+    data: Vec<Real>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Index<(Size, Size)> for Matrix {
+    type Output = Real;
+
+    fn index(&self, index: (Size, Size)) -> &Self::Output {
+        &self.data[index.0 * self.columns + index.1]
+    }
+}
+
+impl IndexMut<(Size, Size)> for Matrix {
+    fn index_mut(&mut self, index: (Size, Size)) -> &mut Self::Output {
+        &mut self.data[index.0 * self.columns + index.1]
+    }
+}
+
+impl Add for Matrix {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.rows == rhs.rows && self.columns == rhs.columns,
+            "matrices with different sizes ({}x{}, {}x{}) cannot be added",
+            self.rows,
+            self.columns,
+            rhs.rows,
+            rhs.columns
+        );
+        Matrix::new(
+            self.rows,
+            self.columns,
+            self.data
+                .iter()
+                .zip(rhs.data.iter())
+                .map(|(x, y)| x + y)
+                .collect(),
+        )
+    }
+}
+
+impl Sub for Matrix {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.rows == rhs.rows && self.columns == rhs.columns,
+            "matrices with different sizes ({}x{}, {}x{}) cannot be subtracted",
+            self.rows,
+            self.columns,
+            rhs.rows,
+            rhs.columns
+        );
+        Matrix::new(
+            self.rows,
+            self.columns,
+            self.data
+                .iter()
+                .zip(rhs.data.iter())
+                .map(|(x, y)| x - y)
+                .collect(),
+        )
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.columns == rhs.rows,
+            "matrices with different sizes ({}x{}, {}x{}) cannot be multiplied",
+            self.rows,
+            self.columns,
+            rhs.rows,
+            rhs.columns
+        );
+        let mut result = Matrix::zeros(self.rows, rhs.columns);
+        for i in 0..self.rows {
+            for k in 0..self.columns {
+                let a = self[(i, k)];
+                for j in 0..rhs.columns {
+                    result[(i, j)] += a * rhs[(k, j)];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Mul<Array> for Matrix {
+    type Output = Array;
+
+    fn mul(self, rhs: Array) -> Self::Output {
+        assert!(
+            self.columns == rhs.size(),
+            "vectors and matrices with different sizes ({}, {}x{}) cannot be multiplied",
+            rhs.size(),
+            self.rows,
+            self.columns
+        );
+        Array::new(
+            (0..self.rows)
+                .map(|i| (0..self.columns).map(|j| self[(i, j)] * rhs[j]).sum())
+                .collect(),
+        )
+    }
+}
+
+impl Mul<Real> for Matrix {
+    type Output = Self;
+
+    fn mul(self, rhs: Real) -> Self::Output {
+        Matrix::new(
+            self.rows,
+            self.columns,
+            self.data.iter().map(|x| x * rhs).collect(),
+        )
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Matrix {
+    /// Construct a new [Matrix] from the given elements in row-major order
+    pub fn new(rows: Size, columns: Size, data: Vec<Real>) -> Self {
+        assert_eq!(
+            rows * columns,
+            data.len(),
+            "{} elements given for a {}x{} matrix",
+            data.len(),
+            rows,
+            columns
+        );
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    /// Construct a new [Matrix] from the given rows
+    pub fn from_rows(rows: Vec<Vec<Real>>) -> Self {
+        let columns = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == columns),
+            "rows of different lengths given"
+        );
+        Matrix::new(rows.len(), columns, rows.concat())
+    }
+
+    /// Matrix with all elements equal to zero
+    pub fn zeros(rows: Size, columns: Size) -> Self {
+        Matrix::new(rows, columns, vec![0.0; rows * columns])
+    }
+
+    /// Identity matrix of the given size
+    pub fn identity(size: Size) -> Self {
+        let mut result = Matrix::zeros(size, size);
+        for i in 0..size {
+            result[(i, i)] = 1.0;
+        }
+        result
+    }
+
+    pub fn rows(&self) -> Size {
+        self.rows
+    }
+
+    pub fn columns(&self) -> Size {
+        self.columns
+    }
+
+    pub fn empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Return the transpose of self
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::zeros(self.columns, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    /// Solve the linear system `self * x = b` using an LU decomposition with partial pivoting.
+    pub fn solve(&self, b: &Array) -> Array {
+        assert!(
+            self.rows == self.columns,
+            "square matrix required, {}x{} given",
+            self.rows,
+            self.columns
+        );
+        assert!(
+            self.rows == b.size(),
+            "right-hand side of size {} given for a {}x{} system",
+            b.size(),
+            self.rows,
+            self.columns
+        );
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut x: Vec<Real> = b.data.clone();
+
+        // decomposition, applying the row swaps to the right-hand side as we go
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| lu[(i, k)].abs().total_cmp(&lu[(j, k)].abs()))
+                .unwrap_or(k);
+            assert!(lu[(pivot, k)] != 0.0, "singular matrix given");
+            if pivot != k {
+                for j in 0..n {
+                    lu.data.swap(k * n + j, pivot * n + j);
+                }
+                x.swap(k, pivot);
+            }
+            for i in k + 1..n {
+                let factor = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = factor;
+                for j in k + 1..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        // forward substitution with the unit lower triangular factor
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= lu[(i, j)] * x[j];
+            }
+        }
+        // backward substitution with the upper triangular factor
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= lu[(i, j)] * x[j];
+            }
+            x[i] /= lu[(i, i)];
+        }
+        Array::new(x)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::array::Array;
+
+    use super::Matrix;
+
+    #[test]
+    fn test_arithmetic() {
+        let m1 = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let m2 = Matrix::identity(2);
+        assert_eq!(
+            m1.clone() + m2.clone(),
+            Matrix::from_rows(vec![vec![2.0, 2.0], vec![3.0, 5.0]])
+        );
+        assert_eq!(
+            m1.clone() - m2.clone(),
+            Matrix::from_rows(vec![vec![0.0, 2.0], vec![3.0, 3.0]])
+        );
+        assert_eq!(m1.clone() * m2, m1);
+        assert_eq!(
+            m1.clone() * m1.transpose(),
+            Matrix::from_rows(vec![vec![5.0, 11.0], vec![11.0, 25.0]])
+        );
+        assert_eq!(m1 * Array::new(vec![1.0, 1.0]), Array::new(vec![3.0, 7.0]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = Matrix::from_rows(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ]);
+        let b = Array::new(vec![8.0, -11.0, -3.0]);
+        let x = m.solve(&b);
+        let expected = [2.0, 3.0, -1.0];
+        for i in 0..3 {
+            assert!(
+                (x[i] - expected[i]).abs() < 1.0e-12,
+                "x[{}] = {}, expected {}",
+                i,
+                x[i],
+                expected[i]
+            );
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    maths::{array::Array, matrix::Matrix},
+    types::Real,
+};
+
+/// Cholesky decomposition of a symmetric positive-definite matrix.
+///
+/// Holds the lower triangular matrix `L` such that `L * L^T` equals the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CholeskyDecomposition {
+    lower: Matrix,
+}
+
+impl CholeskyDecomposition {
+    /// Decompose the given matrix. If `flexible` is true, positive semi-definite (e.g. rank
+    /// deficient correlation) matrices are accepted as well: the columns of `L` corresponding to
+    /// non-positive pivots are set to zero, which gives a pseudo square root of the input.
+    pub fn new(m: &Matrix, flexible: bool) -> Self {
+        Self {
+            lower: decompose(m, flexible),
+        }
+    }
+
+    /// The lower triangular factor `L`
+    pub fn lower(&self) -> &Matrix {
+        &self.lower
+    }
+
+    /// Solve the linear system `L * L^T * x = b` by forward and backward substitution
+    pub fn solve(&self, b: &Array) -> Array {
+        let n = self.lower.rows();
+        assert!(
+            n == b.size(),
+            "right-hand side of size {} given for a {}x{} system",
+            b.size(),
+            n,
+            n
+        );
+        let l = &self.lower;
+        let mut x: Vec<Real> = b.data.clone();
+        // L * y = b
+        for i in 0..n {
+            assert!(l[(i, i)] != 0.0, "singular matrix given");
+            for j in 0..i {
+                x[i] -= l[(i, j)] * x[j];
+            }
+            x[i] /= l[(i, i)];
+        }
+        // L^T * x = y
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= l[(j, i)] * x[j];
+            }
+            x[i] /= l[(i, i)];
+        }
+        Array::new(x)
+    }
+}
+
+fn decompose(m: &Matrix, flexible: bool) -> Matrix {
+    let size = m.rows();
+    assert!(
+        size == m.columns(),
+        "input matrix is not a square matrix ({}x{})",
+        m.rows(),
+        m.columns()
+    );
+    let mut result = Matrix::zeros(size, size);
+    for i in 0..size {
+        for j in i..size {
+            let mut sum = m[(i, j)];
+            for k in 0..i {
+                sum -= result[(i, k)] * result[(j, k)];
+            }
+            if i == j {
+                assert!(
+                    flexible || sum > 0.0,
+                    "input matrix is not positive definite"
+                );
+                // To handle positive semi-definite matrices take the square root of sum if
+                // positive, else zero.
+                result[(i, i)] = sum.max(0.0).sqrt();
+            } else if result[(i, i)] == 0.0 {
+                // With positive semi-definite matrices it is possible to have result[(i, i)]
+                // equal to zero; in that case the whole column is zero.
+                result[(j, i)] = 0.0;
+            } else {
+                result[(j, i)] = sum / result[(i, i)];
+            }
+        }
+    }
+    result
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::{array::Array, matrix::Matrix};
+
+    use super::CholeskyDecomposition;
+
+    #[test]
+    fn test_cholesky_decomposition() {
+        let rho = 0.6;
+        let correlation = Matrix::from_rows(vec![vec![1.0, rho], vec![rho, 1.0]]);
+        let l = CholeskyDecomposition::new(&correlation, false)
+            .lower()
+            .clone();
+        assert_eq!(l[(0, 1)], 0.0);
+        assert!((l[(1, 1)] - 0.8).abs() < 1.0e-15);
+
+        let product = l.clone() * l.transpose();
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((product[(i, j)] - correlation[(i, j)]).abs() < 1.0e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky_decomposition_semi_definite() {
+        let correlation = Matrix::from_rows(vec![vec![1.0, 1.0], vec![1.0, 1.0]]);
+        let l = CholeskyDecomposition::new(&correlation, true)
+            .lower()
+            .clone();
+        assert_eq!(l.clone() * l.transpose(), correlation);
+    }
+
+    #[test]
+    #[should_panic(expected = "input matrix is not positive definite")]
+    fn test_cholesky_decomposition_not_positive_definite() {
+        let correlation = Matrix::from_rows(vec![vec![1.0, 1.0], vec![1.0, 1.0]]);
+        CholeskyDecomposition::new(&correlation, false);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let m = Matrix::from_rows(vec![
+            vec![4.0, 2.0, 0.4],
+            vec![2.0, 5.0, 1.0],
+            vec![0.4, 1.0, 3.0],
+        ]);
+        let b = Array::new(vec![1.0, -2.0, 0.5]);
+        let x = CholeskyDecomposition::new(&m, false).solve(&b);
+        let expected = m.solve(&b);
+        for i in 0..3 {
+            assert!((x[i] - expected[i]).abs() < 1.0e-15);
+        }
+    }
+}
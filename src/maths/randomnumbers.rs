@@ -0,0 +1,6 @@
+pub mod boxmullergaussianrng;
+pub mod haltonrsg;
+pub mod inversecumulativerng;
+pub mod mt19937uniformrng;
+pub mod sample;
+pub mod sobolrsg;
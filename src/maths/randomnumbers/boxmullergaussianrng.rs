@@ -0,0 +1,86 @@
+use crate::types::Real;
+
+use super::sample::{RandomNumberGenerator, SampleValue};
+
+/// Gaussian random number generator
+///
+/// It uses the well-known Box-Muller transformation (in its polar form) to return a normal
+/// distributed Gaussian deviate with average 0.0 and standard deviation of 1.0, from a uniform
+/// deviate in (0,1) supplied by the underlying uniform generator.
+pub struct BoxMullerGaussianRng<R: RandomNumberGenerator> {
+    uniform_generator: R,
+    return_first: bool,
+    first_value: Real,
+    second_value: Real,
+    first_weight: Real,
+    second_weight: Real,
+}
+
+impl<R: RandomNumberGenerator> BoxMullerGaussianRng<R> {
+    pub fn new(uniform_generator: R) -> Self {
+        Self {
+            uniform_generator,
+            return_first: true,
+            first_value: 0.0,
+            second_value: 0.0,
+            first_weight: 0.0,
+            second_weight: 0.0,
+        }
+    }
+}
+
+impl<R: RandomNumberGenerator> RandomNumberGenerator for BoxMullerGaussianRng<R> {
+    fn next(&mut self) -> SampleValue<Real> {
+        if self.return_first {
+            let (mut x1, mut x2, mut r);
+            loop {
+                let s1 = self.uniform_generator.next();
+                x1 = s1.value * 2.0 - 1.0;
+                self.first_weight = s1.weight;
+                let s2 = self.uniform_generator.next();
+                x2 = s2.value * 2.0 - 1.0;
+                self.second_weight = s2.weight;
+                r = x1 * x1 + x2 * x2;
+                if r < 1.0 && r != 0.0 {
+                    break;
+                }
+            }
+            let ratio = (-2.0 * r.ln() / r).sqrt();
+            self.first_value = x1 * ratio;
+            self.second_value = x2 * ratio;
+            self.return_first = false;
+            SampleValue::new(self.first_value, self.first_weight * self.second_weight)
+        } else {
+            self.return_first = true;
+            SampleValue::new(self.second_value, self.first_weight * self.second_weight)
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::randomnumbers::{
+        mt19937uniformrng::MersenneTwisterUniformRng, sample::RandomNumberGenerator,
+    };
+
+    use super::BoxMullerGaussianRng;
+
+    #[test]
+    fn test_box_muller_moments() {
+        let mut rng = BoxMullerGaussianRng::new(MersenneTwisterUniformRng::new(42));
+        let n = 100_000;
+        let (mut sum, mut sum2) = (0.0, 0.0);
+        for _ in 0..n {
+            let sample = rng.next();
+            assert_eq!(sample.weight, 1.0);
+            sum += sample.value;
+            sum2 += sample.value * sample.value;
+        }
+        let mean = sum / n as f64;
+        let variance = sum2 / n as f64 - mean * mean;
+        assert!(mean.abs() < 0.01, "mean: {}", mean);
+        assert!((variance - 1.0).abs() < 0.01, "variance: {}", variance);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::types::{Real, Size};
+
+/// Halton low-discrepancy sequence generator.
+///
+/// Each dimension is the radical inverse of the sequence counter in a different prime base.
+/// The sequence starts from the first non-zero point, so that all draws are in (0, 1).
+#[derive(Debug, Clone)]
+pub struct HaltonRsg {
+    dimensionality: Size,
+    sequence_counter: u64,
+    bases: Vec<u64>,
+}
+
+impl HaltonRsg {
+    pub fn new(dimensionality: Size) -> Self {
+        assert!(dimensionality > 0, "dimensionality must be greater than 0");
+        Self {
+            dimensionality,
+            sequence_counter: 0,
+            bases: first_primes(dimensionality),
+        }
+    }
+
+    pub fn dimension(&self) -> Size {
+        self.dimensionality
+    }
+
+    /// Return the next point of the sequence
+    pub fn next_sequence(&mut self) -> Vec<Real> {
+        self.sequence_counter += 1;
+        self.bases
+            .iter()
+            .map(|&base| radical_inverse(self.sequence_counter, base))
+            .collect()
+    }
+}
+
+fn radical_inverse(n: u64, base: u64) -> Real {
+    let mut n = n;
+    let mut result = 0.0;
+    let mut factor = 1.0 / base as Real;
+    while n > 0 {
+        result += (n % base) as Real * factor;
+        n /= base;
+        factor /= base as Real;
+    }
+    result
+}
+
+fn first_primes(n: Size) -> Vec<u64> {
+    let mut primes: Vec<u64> = Vec::with_capacity(n);
+    let mut candidate = 2;
+    while primes.len() < n {
+        if primes
+            .iter()
+            .take_while(|&&p| p * p <= candidate)
+            .all(|&p| candidate % p != 0)
+        {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::HaltonRsg;
+
+    #[test]
+    fn test_halton() {
+        let mut rsg = HaltonRsg::new(3);
+        assert_eq!(rsg.dimension(), 3);
+        let expected = [
+            [0.5, 1.0 / 3.0, 0.2],
+            [0.25, 2.0 / 3.0, 0.4],
+            [0.75, 1.0 / 9.0, 0.6],
+            [0.125, 4.0 / 9.0, 0.8],
+        ];
+        for point in expected.iter() {
+            let sample = rsg.next_sequence();
+            for (x, y) in sample.iter().zip(point.iter()) {
+                assert!((x - y).abs() < 1.0e-15, "{:?} != {:?}", sample, point);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{maths::distributions::normaldistribution::InverseCumulativeNormal, types::Real};
+
+use super::sample::{RandomNumberGenerator, SampleValue};
+
+/// Gaussian random number generator
+///
+/// It uses the inverse cumulative normal distribution to turn the uniform deviates in (0,1)
+/// supplied by the underlying generator into standard normal deviates.
+pub struct InverseCumulativeRng<R: RandomNumberGenerator> {
+    uniform_generator: R,
+    icn: InverseCumulativeNormal,
+}
+
+impl<R: RandomNumberGenerator> InverseCumulativeRng<R> {
+    pub fn new(uniform_generator: R) -> Self {
+        Self {
+            uniform_generator,
+            icn: InverseCumulativeNormal::default(),
+        }
+    }
+}
+
+impl<R: RandomNumberGenerator> RandomNumberGenerator for InverseCumulativeRng<R> {
+    fn next(&mut self) -> SampleValue<Real> {
+        let sample = self.uniform_generator.next();
+        SampleValue::new(self.icn.value(sample.value), sample.weight)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::randomnumbers::{
+        mt19937uniformrng::MersenneTwisterUniformRng, sample::RandomNumberGenerator,
+    };
+
+    use super::InverseCumulativeRng;
+
+    #[test]
+    fn test_inverse_cumulative_moments() {
+        let mut rng = InverseCumulativeRng::new(MersenneTwisterUniformRng::new(42));
+        let n = 100_000;
+        let (mut sum, mut sum2) = (0.0, 0.0);
+        for _ in 0..n {
+            let value = rng.next().value;
+            sum += value;
+            sum2 += value * value;
+        }
+        let mean = sum / n as f64;
+        let variance = sum2 / n as f64 - mean * mean;
+        assert!(mean.abs() < 0.01, "mean: {}", mean);
+        assert!((variance - 1.0).abs() < 0.01, "variance: {}", variance);
+    }
+}
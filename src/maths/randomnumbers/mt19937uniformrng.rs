@@ -0,0 +1,98 @@
+use crate::types::Real;
+
+use super::sample::{RandomNumberGenerator, SampleValue};
+
+const N: usize = 624;
+const M: usize = 397;
+const MATRIX_A: u32 = 0x9908b0df;
+const UPPER_MASK: u32 = 0x80000000;
+const LOWER_MASK: u32 = 0x7fffffff;
+
+/// Uniform random number generator
+///
+/// Mersenne Twister random number generator of period 2^19937-1, as described by M. Matsumoto
+/// and T. Nishimura. The generator is fully determined by its seed, so that results are
+/// reproducible.
+#[derive(Clone)]
+pub struct MersenneTwisterUniformRng {
+    mt: Box<[u32; N]>,
+    mti: usize,
+}
+
+impl MersenneTwisterUniformRng {
+    pub fn new(seed: u32) -> Self {
+        let mut mt = Box::new([0u32; N]);
+        mt[0] = seed;
+        for i in 1..N {
+            mt[i] = 1812433253u32
+                .wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 30))
+                .wrapping_add(i as u32);
+        }
+        Self { mt, mti: N }
+    }
+
+    /// Returns a random number in the (0.0, 1.0)-interval
+    pub fn next_real(&mut self) -> Real {
+        (self.next_int32() as Real + 0.5) / 4294967296.0
+    }
+
+    /// Returns a random integer in the [0, 0xffffffff]-interval
+    pub fn next_int32(&mut self) -> u32 {
+        if self.mti == N {
+            self.twist();
+        }
+        let mut y = self.mt[self.mti];
+        self.mti += 1;
+
+        // tempering
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c5680;
+        y ^= (y << 15) & 0xefc60000;
+        y ^= y >> 18;
+        y
+    }
+
+    /// Generate N words at one time
+    fn twist(&mut self) {
+        let mag01 = |y: u32| if y & 1 == 0 { 0 } else { MATRIX_A };
+        for kk in 0..N {
+            let y = (self.mt[kk] & UPPER_MASK) | (self.mt[(kk + 1) % N] & LOWER_MASK);
+            self.mt[kk] = self.mt[(kk + M) % N] ^ (y >> 1) ^ mag01(y);
+        }
+        self.mti = 0;
+    }
+}
+
+impl RandomNumberGenerator for MersenneTwisterUniformRng {
+    fn next(&mut self) -> SampleValue<Real> {
+        SampleValue::new(self.next_real(), 1.0)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::MersenneTwisterUniformRng;
+
+    #[test]
+    fn test_mersenne_twister() {
+        // reference output of the original implementation for the default seed
+        let mut rng = MersenneTwisterUniformRng::new(5489);
+        let expected: [u32; 10] = [
+            3499211612, 581869302, 3890346734, 3586334585, 545404204, 4161255391, 3922919429,
+            949333985, 2715962298, 1323567403,
+        ];
+        for e in expected.iter() {
+            assert_eq!(rng.next_int32(), *e);
+        }
+
+        let mut rng1 = MersenneTwisterUniformRng::new(42);
+        let mut rng2 = MersenneTwisterUniformRng::new(42);
+        for _ in 0..1000 {
+            let x = rng1.next_real();
+            assert!(x > 0.0 && x < 1.0);
+            assert_eq!(x, rng2.next_real());
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::types::Real;
+
+/// Weighted sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleValue<T> {
+    pub value: T,
+    pub weight: Real,
+}
+
+impl<T> SampleValue<T> {
+    pub fn new(value: T, weight: Real) -> Self {
+        Self { value, weight }
+    }
+}
+
+/// Generator of random numbers returning weighted samples
+pub trait RandomNumberGenerator {
+    /// Return the next sample
+    fn next(&mut self) -> SampleValue<Real>;
+}
@@ -0,0 +1,182 @@
+use crate::types::{Real, Size};
+
+/// Number of bits used for the integer representation of the sequence
+const BITS: usize = 32;
+
+/// Normalisation factor mapping the integer sequence to (0, 1)
+const NORMALIZATION_FACTOR: Real = 1.0 / (1u64 << BITS) as Real;
+
+/// Primitive polynomials and initial direction numbers from S. Joe and F. Y. Kuo,
+/// "Constructing Sobol sequences with better two-dimensional projections" (new-joe-kuo-6.21201).
+///
+/// Each entry holds the degree `s` of the polynomial, its coefficients `a` (excluding the
+/// leading and trailing ones) and the initial direction numbers `m_1, ..., m_s`. The first
+/// dimension is the van der Corput sequence and is not listed.
+const JOE_KUO: [(usize, u32, &[u32]); 15] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+/// Largest dimensionality supported by [SobolRsg], i.e. the van der Corput dimension plus one
+/// dimension per entry of the direction number table
+pub const MAX_DIMENSIONALITY: Size = JOE_KUO.len() + 1;
+
+/// Sobol low-discrepancy sequence generator.
+///
+/// Points are generated in Gray-code order (Antonov-Saleev), which only needs one XOR per
+/// dimension for each new point. The initial all-zero point is skipped, so that all draws are
+/// in (0, 1).
+///
+/// Only the first entries of the Joe-Kuo table are included, so at most [MAX_DIMENSIONALITY]
+/// (16) dimensions are supported. This covers a handful of assets or time steps; paths with
+/// more steps need a pseudo-random generator, or a Brownian bridge over the first dimensions.
+#[derive(Debug, Clone)]
+pub struct SobolRsg {
+    dimensionality: Size,
+    sequence_counter: u32,
+    integer_sequence: Vec<u32>,
+    direction_integers: Vec<[u32; BITS]>,
+}
+
+impl SobolRsg {
+    /// Generator of points in `dimensionality` dimensions, which must be between 1 and
+    /// [MAX_DIMENSIONALITY].
+    pub fn new(dimensionality: Size) -> Self {
+        assert!(dimensionality > 0, "dimensionality must be greater than 0");
+        assert!(
+            dimensionality <= MAX_DIMENSIONALITY,
+            "dimensionality {} exceeds the maximum of {} supported by the Sobol generator",
+            dimensionality,
+            MAX_DIMENSIONALITY
+        );
+
+        let mut direction_integers = Vec::with_capacity(dimensionality);
+        // first dimension: all the initial direction numbers are equal to 1
+        let mut v = [0u32; BITS];
+        for (j, vj) in v.iter_mut().enumerate() {
+            *vj = 1 << (BITS - 1 - j);
+        }
+        direction_integers.push(v);
+
+        for &(s, a, m) in JOE_KUO.iter().take(dimensionality - 1) {
+            let mut v = [0u32; BITS];
+            for j in 0..s {
+                v[j] = m[j] << (BITS - 1 - j);
+            }
+            for j in s..BITS {
+                v[j] = v[j - s] ^ (v[j - s] >> s);
+                for k in 1..s {
+                    if (a >> (s - 1 - k)) & 1 == 1 {
+                        v[j] ^= v[j - k];
+                    }
+                }
+            }
+            direction_integers.push(v);
+        }
+
+        let mut result = Self {
+            dimensionality,
+            sequence_counter: 0,
+            integer_sequence: vec![0; dimensionality],
+            direction_integers,
+        };
+        // skip the first (all zero) point
+        result.next_int32_sequence();
+        result
+    }
+
+    pub fn dimension(&self) -> Size {
+        self.dimensionality
+    }
+
+    /// Return the next point of the sequence as integers in [0, 2^32)
+    pub fn next_int32_sequence(&mut self) -> &[u32] {
+        if self.sequence_counter == 0 {
+            self.sequence_counter = 1;
+            return &self.integer_sequence;
+        }
+        // find the rightmost zero bit of the counter
+        let j = (!(self.sequence_counter - 1)).trailing_zeros() as usize;
+        assert!(j < BITS, "sequence of {} points exhausted", u32::MAX);
+        for (x, v) in self
+            .integer_sequence
+            .iter_mut()
+            .zip(self.direction_integers.iter())
+        {
+            *x ^= v[j];
+        }
+        self.sequence_counter = self.sequence_counter.wrapping_add(1);
+        &self.integer_sequence
+    }
+
+    /// Return the next point of the sequence
+    pub fn next_sequence(&mut self) -> Vec<Real> {
+        self.next_int32_sequence()
+            .iter()
+            .map(|&x| x as Real * NORMALIZATION_FACTOR)
+            .collect()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::distributions::normaldistribution::InverseCumulativeNormal;
+
+    use super::{SobolRsg, MAX_DIMENSIONALITY};
+
+    #[test]
+    fn test_sobol_first_points() {
+        let mut rsg = SobolRsg::new(3);
+        assert_eq!(rsg.dimension(), 3);
+        let expected = [
+            [0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25],
+            [0.25, 0.75, 0.75],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375],
+        ];
+        for point in expected.iter() {
+            assert_eq!(rsg.next_sequence(), point.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_sobol_gaussian_mean() {
+        let dimension = 16;
+        let samples = 4095;
+        let mut rsg = SobolRsg::new(dimension);
+        let mut sums = vec![0.0; dimension];
+        for _ in 0..samples {
+            for (sum, x) in sums.iter_mut().zip(rsg.next_sequence()) {
+                *sum += InverseCumulativeNormal::standard_value(x);
+            }
+        }
+        for (i, sum) in sums.iter().enumerate() {
+            let mean = sum / samples as f64;
+            assert!(mean.abs() < 1.0e-3, "mean in dimension {} is {}", i, mean);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the maximum of 16 supported by the Sobol generator")]
+    fn test_sobol_max_dimensionality() {
+        SobolRsg::new(MAX_DIMENSIONALITY + 1);
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{maths::comparison::close, types::Real};
+use crate::{
+    maths::comparison::close,
+    types::{Real, Size},
+};
 
 use super::solver1d::{
-    private::{self, SolverData},
+    private::{self, SolverData, MAX_FUNCTION_EVALUATIONS},
     Solver1D,
 };
 
@@ -12,6 +15,7 @@ pub struct Brent {
     upper_bound: Real,
     lower_bound_enforced: bool,
     upper_bound_enforced: bool,
+    max_evaluations: Option<Size>,
 }
 
 impl Brent {
@@ -27,6 +31,15 @@ impl Brent {
             upper_bound,
             lower_bound_enforced,
             upper_bound_enforced,
+            max_evaluations: None,
+        }
+    }
+
+    /// Unbounded solver giving up after the given number of function evaluations
+    pub fn with_max_evaluations(max_evaluations: Size) -> impl Solver1D {
+        Self {
+            max_evaluations: Some(max_evaluations),
+            ..Default::default()
         }
     }
 }
@@ -137,6 +150,10 @@ impl private::SolverDetail for Brent {
         );
     }
 
+    fn max_evaluations(&self) -> Size {
+        self.max_evaluations.unwrap_or(MAX_FUNCTION_EVALUATIONS)
+    }
+
     fn lower_bound(&self) -> Real {
         self.lower_bound
     }
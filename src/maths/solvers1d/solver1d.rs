@@ -177,7 +177,7 @@ pub trait Solver1D: private::SolverDetail {
 // -------------------------------------------------------------------------------------------------
 
 pub(crate) mod private {
-    const MAX_FUNCTION_EVALUATIONS: Size = 100;
+    pub const MAX_FUNCTION_EVALUATIONS: Size = 100;
     use crate::types::{Real, Size};
 
     #[derive(Clone, Copy, Default)]
@@ -72,7 +72,7 @@ pub(crate) fn test_not_bracketed<S, F, G>(
 {
     let accuracies = vec![1.0e-4, 1.0e-6, 1.0e-8];
     for accuracy in accuracies {
-        let root = solver.solve(&f, derivative, accuracy, guess, 0.1);
+        let root = solver.solve(f, derivative, accuracy, guess, 0.1);
         assert!(
             (root - expected).abs() <= accuracy,
             "{} solver (not bracketed), expected: {}, calculated: {}, accuracy: {}",
@@ -102,7 +102,7 @@ pub(crate) fn test_bracketed<S, F, G>(
     let accuracies = vec![1.0e-4, 1.0e-6, 1.0e-8];
     for accuracy in accuracies {
         // guess on the left side of the root, increasing function
-        let root = solver.solve_bracketed(&f, derivative, accuracy, guess, xmin, xmax);
+        let root = solver.solve_bracketed(f, derivative, accuracy, guess, xmin, xmax);
         assert!(
             (root - expected).abs() <= accuracy,
             "{} solver (bracketed), expected: {}, calculated: {}, accuracy: {}",
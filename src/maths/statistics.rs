@@ -0,0 +1,306 @@
+use crate::{
+    maths::distributions::normaldistribution::{InverseCumulativeNormal, NormalDistribution},
+    types::{Real, Size},
+};
+
+/// Running statistics of a weighted sample, e.g. the payoffs of a Monte Carlo simulation.
+///
+/// Samples are not stored: the mean and the central moments up to the fourth are updated as
+/// each sample is added, following Welford's algorithm and its extension to higher moments by
+/// Pébay, which avoids the cancellation errors of accumulating raw sums of powers.
+///
+/// Variance, skewness and kurtosis include the small sample corrections of the unweighted
+/// case, with the number of samples as sample size.
+#[derive(Debug, Clone)]
+pub struct Statistics {
+    samples: Size,
+    weight_sum: Real,
+    mean: Real,
+    m2: Real,
+    m3: Real,
+    m4: Real,
+    min: Real,
+    max: Real,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Self {
+            samples: 0,
+            weight_sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            min: Real::MAX,
+            max: Real::MIN,
+        }
+    }
+
+    /// Adds a sample with unit weight
+    pub fn add(&mut self, value: Real) {
+        self.add_weighted(value, 1.0);
+    }
+
+    /// Adds a sample with the given non-negative weight
+    pub fn add_weighted(&mut self, value: Real, weight: Real) {
+        assert!(weight >= 0.0, "negative weight ({}) not allowed", weight);
+        let w_a = self.weight_sum;
+        let w = w_a + weight;
+        self.samples += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if w == 0.0 {
+            return;
+        }
+        let delta = value - self.mean;
+        let delta_w = delta * weight / w;
+        // delta^2 * w_a * weight / w, the increment of the second moment
+        let term = delta * delta_w * w_a;
+        self.m4 += term * delta * delta * (w_a * w_a - w_a * weight + weight * weight) / (w * w)
+            + 6.0 * delta_w * delta_w * self.m2
+            - 4.0 * delta_w * self.m3;
+        self.m3 += term * delta * (w_a - weight) / w - 3.0 * delta_w * self.m2;
+        self.m2 += term;
+        self.mean += delta_w;
+        self.weight_sum = w;
+    }
+
+    /// Number of samples added
+    pub fn samples(&self) -> Size {
+        self.samples
+    }
+
+    /// Sum of the weights of the samples
+    pub fn weight_sum(&self) -> Real {
+        self.weight_sum
+    }
+
+    /// Weighted mean of the samples
+    pub fn mean(&self) -> Real {
+        assert!(
+            self.weight_sum > 0.0,
+            "sum of weights is zero, insufficient"
+        );
+        self.mean
+    }
+
+    /// Unbiased estimate of the variance, i.e. the weighted second central moment times
+    /// `N / (N - 1)`
+    pub fn variance(&self) -> Real {
+        assert!(
+            self.weight_sum > 0.0,
+            "sum of weights is zero, insufficient"
+        );
+        assert!(self.samples > 1, "sample number <= 1, insufficient");
+        let n = self.samples as Real;
+        self.m2 / self.weight_sum * n / (n - 1.0)
+    }
+
+    pub fn standard_deviation(&self) -> Real {
+        self.variance().sqrt()
+    }
+
+    /// Standard error of the mean, i.e. the standard deviation over the square root of the
+    /// number of samples
+    pub fn error_estimate(&self) -> Real {
+        (self.variance() / self.samples as Real).sqrt()
+    }
+
+    /// Skewness of the samples, zero for a normal distribution
+    pub fn skewness(&self) -> Real {
+        assert!(self.samples > 2, "sample number <= 2, insufficient");
+        let n = self.samples as Real;
+        let m3 = self.m3 / self.weight_sum;
+        let sigma = self.standard_deviation();
+        n * n / ((n - 1.0) * (n - 2.0)) * m3 / (sigma * sigma * sigma)
+    }
+
+    /// Excess kurtosis of the samples, zero for a normal distribution
+    pub fn kurtosis(&self) -> Real {
+        assert!(self.samples > 3, "sample number <= 3, insufficient");
+        let n = self.samples as Real;
+        let m4 = self.m4 / self.weight_sum;
+        let variance = self.variance();
+        let c1 = n * n * (n + 1.0) / ((n - 1.0) * (n - 2.0) * (n - 3.0));
+        let c2 = 3.0 * (n - 1.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0));
+        c1 * m4 / (variance * variance) - c2
+    }
+
+    /// Smallest sample
+    pub fn min(&self) -> Real {
+        assert!(self.samples > 0, "empty sample set");
+        self.min
+    }
+
+    /// Largest sample
+    pub fn max(&self) -> Real {
+        assert!(self.samples > 0, "empty sample set");
+        self.max
+    }
+
+    /// Discards all the samples
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Risk measures of a normal distribution with the mean and standard deviation of the samples.
+///
+/// Samples are read as profits, losses being negative, so that value at risk and expected
+/// shortfall are reported as non-negative losses.
+impl Statistics {
+    /// Value `x` such that the probability of a sample being below `x` is `p`, for
+    /// `0 < p < 1`
+    pub fn gaussian_percentile(&self, p: Real) -> Real {
+        assert!(
+            p > 0.0 && p < 1.0,
+            "percentile ({}) must be in (0.0, 1.0)",
+            p
+        );
+        InverseCumulativeNormal::new(self.mean(), self.standard_deviation()).value(p)
+    }
+
+    /// Value at risk at confidence level `p`, i.e. the loss exceeded with probability `1 - p`,
+    /// floored at zero; `p` must be in `[0.9, 1)`
+    pub fn gaussian_value_at_risk(&self, p: Real) -> Real {
+        check_confidence_level(p);
+        (-self.gaussian_percentile(1.0 - p)).max(0.0)
+    }
+
+    /// Expected shortfall at confidence level `p`, i.e. the average loss beyond the value at
+    /// risk, floored at zero; `p` must be in `[0.9, 1)`
+    pub fn gaussian_expected_shortfall(&self, p: Real) -> Real {
+        check_confidence_level(p);
+        let mean = self.mean();
+        let sigma = self.standard_deviation();
+        let var = self.gaussian_percentile(1.0 - p);
+        let density = NormalDistribution::new(mean, sigma).value(var);
+        // mean of the samples below the percentile
+        let tail_mean = mean - sigma * sigma * density / (1.0 - p);
+        (-tail_mean).max(0.0)
+    }
+}
+
+fn check_confidence_level(p: Real) {
+    assert!(
+        (0.9..1.0).contains(&p),
+        "confidence level ({}) out of range [0.9, 1.0)",
+        p
+    );
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::maths::distributions::normaldistribution::InverseCumulativeNormal;
+
+    use super::Statistics;
+
+    #[test]
+    fn test_statistics() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut s = Statistics::new();
+        for x in data {
+            s.add(x);
+        }
+        assert_eq!(s.samples(), 8);
+        assert_eq!(s.weight_sum(), 8.0);
+        assert_eq!(s.min(), 2.0);
+        assert_eq!(s.max(), 9.0);
+        assert!((s.mean() - 5.0).abs() < 1.0e-14);
+        assert!((s.variance() - 32.0 / 7.0).abs() < 1.0e-14);
+        assert!((s.standard_deviation() - 2.138089935299395).abs() < 1.0e-14);
+        assert!((s.skewness() - 0.8184875533567996).abs() < 1.0e-14);
+        assert!((s.kurtosis() - 0.9406250000000007).abs() < 1.0e-13);
+        assert_eq!(
+            s.error_estimate(),
+            s.standard_deviation() / (s.samples() as f64).sqrt()
+        );
+
+        s.reset();
+        assert_eq!(s.samples(), 0);
+        assert_eq!(s.weight_sum(), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_statistics() {
+        // integer weights give the moments of the sample with repeated values
+        let mut weighted = Statistics::new();
+        let mut repeated = Statistics::new();
+        for (x, w) in [(1.0, 2.0), (3.0, 1.0), (6.0, 3.0), (10.0, 2.0)] {
+            weighted.add_weighted(x, w);
+            for _ in 0..w as usize {
+                repeated.add(x);
+            }
+        }
+        assert_eq!(weighted.samples(), 4);
+        assert_eq!(weighted.weight_sum(), 8.0);
+        assert!((weighted.mean() - repeated.mean()).abs() < 1.0e-14);
+        let (n, m) = (4.0, 8.0);
+        let biased = |s: &Statistics, n: f64| s.variance() * (n - 1.0) / n;
+        assert!((biased(&weighted, n) - biased(&repeated, m)).abs() < 1.0e-13);
+        // and the same central moments
+        let moments = |s: &Statistics| (s.m3 / s.weight_sum, s.m4 / s.weight_sum);
+        let (m3, m4) = moments(&weighted);
+        let (expected_m3, expected_m4) = moments(&repeated);
+        assert!((m3 - expected_m3).abs() < 1.0e-12);
+        assert!((m4 - expected_m4).abs() < 1.0e-11);
+    }
+
+    #[test]
+    fn test_stability() {
+        // a large offset would wipe out the variance if computed from raw sums of squares
+        let mut s = Statistics::new();
+        for x in [4.0, 7.0, 13.0, 16.0] {
+            s.add(1.0e9 + x);
+        }
+        assert!((s.mean() - (1.0e9 + 10.0)).abs() < 1.0e-6);
+        assert!((s.variance() - 30.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "sample number <= 1, insufficient")]
+    fn test_insufficient_samples() {
+        let mut s = Statistics::new();
+        s.add(1.0);
+        s.variance();
+    }
+
+    #[test]
+    fn test_gaussian_risk_measures() {
+        // stratified standard normal sample
+        let n = 100_000;
+        let mut s = Statistics::new();
+        for i in 0..n {
+            s.add(InverseCumulativeNormal::standard_value(
+                (i as f64 + 0.5) / n as f64,
+            ));
+        }
+        assert!(s.mean().abs() < 1.0e-12);
+        assert!((s.standard_deviation() - 1.0).abs() < 1.0e-3);
+
+        assert!((s.gaussian_percentile(0.5) - s.mean()).abs() < 1.0e-12);
+        assert!((s.gaussian_percentile(0.01) + 2.326).abs() < 1.0e-2);
+        assert!((s.gaussian_value_at_risk(0.99) - 2.326).abs() < 1.0e-2);
+        assert!((s.gaussian_expected_shortfall(0.99) - 2.665).abs() < 1.0e-2);
+        assert!(s.gaussian_expected_shortfall(0.99) > s.gaussian_value_at_risk(0.99));
+
+        // a sample of gains only has no risk
+        let mut gains = Statistics::new();
+        for x in [10.0, 11.0, 12.0] {
+            gains.add(x);
+        }
+        assert_eq!(gains.gaussian_value_at_risk(0.95), 0.0);
+        assert_eq!(gains.gaussian_expected_shortfall(0.95), 0.0);
+    }
+}
@@ -0,0 +1,219 @@
+use std::ops::Index;
+
+use crate::{
+    maths::comparison::close_enough,
+    types::{Size, Time},
+};
+
+/// Time grid
+///
+/// A strictly increasing grid of times starting at 0, used for the time discretization of
+/// Monte Carlo and finite-difference methods. A grid can be required to include a set of
+/// mandatory times (e.g. exercise or fixing times), which are then reproduced exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeGrid {
+    times: Vec<Time>,
+    dt: Vec<Time>,
+    mandatory_times: Vec<Time>,
+}
+
+impl Index<Size> for TimeGrid {
+    type Output = Time;
+
+    fn index(&self, index: Size) -> &Self::Output {
+        &self.times[index]
+    }
+}
+
+impl TimeGrid {
+    /// Regularly spaced time grid from 0 to `end` with `steps` steps
+    pub fn new(end: Time, steps: Size) -> Self {
+        assert!(end > 0.0, "negative or null end time ({}) given", end);
+        assert!(steps > 0, "null number of steps given");
+        let dt = end / steps as Time;
+        let times = (0..=steps)
+            .map(|i| if i == steps { end } else { dt * i as Time })
+            .collect();
+        Self::from_times(times, vec![end])
+    }
+
+    /// Time grid with mandatory time points, and about `steps` steps overall.
+    ///
+    /// The largest step is `t_max / steps`, where `t_max` is the last mandatory time. Each
+    /// interval between consecutive mandatory times is divided in as many equal steps as are
+    /// needed for the step to be as close as possible to the largest step, and at least one.
+    pub fn new_with_mandatory_times(times: &[Time], steps: Size) -> Self {
+        assert!(!times.is_empty(), "empty time sequence");
+        let mut mandatory_times = times.to_vec();
+        mandatory_times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!(
+            mandatory_times[0] >= 0.0,
+            "negative times not allowed ({} given)",
+            mandatory_times[0]
+        );
+        // remove duplicates, including times which are numerically the same
+        mandatory_times.dedup_by(|a, b| close_enough(*a, *b));
+
+        let last = mandatory_times[mandatory_times.len() - 1];
+        assert!(last > 0.0, "at least one positive time is required");
+        let dt_max = if steps == 0 {
+            // the smallest interval between mandatory times
+            let mut diffs = vec![];
+            let mut previous = 0.0;
+            for t in mandatory_times.iter() {
+                if *t > 0.0 {
+                    diffs.push(t - previous);
+                }
+                previous = *t;
+            }
+            diffs.into_iter().fold(Time::MAX, Time::min)
+        } else {
+            last / steps as Time
+        };
+
+        let mut grid = vec![0.0];
+        let mut period_begin = 0.0;
+        for period_end in mandatory_times.iter().copied() {
+            if period_end == 0.0 {
+                continue;
+            }
+            let n = ((period_end - period_begin) / dt_max).round().max(1.0) as Size;
+            let dt = (period_end - period_begin) / n as Time;
+            for i in 1..n {
+                grid.push(period_begin + dt * i as Time);
+            }
+            grid.push(period_end);
+            period_begin = period_end;
+        }
+        Self::from_times(grid, mandatory_times)
+    }
+
+    /// Time grid with mandatory time points, and `steps_per_year` steps for each year up to the
+    /// last mandatory time.
+    pub fn new_with_steps_per_year(times: &[Time], steps_per_year: Size) -> Self {
+        assert!(steps_per_year > 0, "null number of steps per year given");
+        let last = times.iter().copied().fold(0.0, Time::max);
+        let steps = (last * steps_per_year as Time).ceil().max(1.0) as Size;
+        Self::new_with_mandatory_times(times, steps)
+    }
+
+    fn from_times(times: Vec<Time>, mandatory_times: Vec<Time>) -> Self {
+        let dt = times.windows(2).map(|w| w[1] - w[0]).collect();
+        Self {
+            times,
+            dt,
+            mandatory_times,
+        }
+    }
+
+    /// Number of points in the grid, including time 0
+    pub fn size(&self) -> Size {
+        self.times.len()
+    }
+
+    /// Returns `true` if the grid has no points
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+
+    /// The points of the grid
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+
+    /// The mandatory times the grid was built with, sorted and without duplicates
+    pub fn mandatory_times(&self) -> &[Time] {
+        &self.mandatory_times
+    }
+
+    /// Length of the `i`-th step, i.e. `times[i + 1] - times[i]`
+    pub fn dt(&self, i: Size) -> Time {
+        self.dt[i]
+    }
+
+    /// Index of the grid point closest to `t`. Ties are resolved in favour of the later point.
+    pub fn closest_index(&self, t: Time) -> Size {
+        let i = self.times.partition_point(|x| *x < t);
+        if i == 0 {
+            0
+        } else if i == self.times.len() {
+            self.times.len() - 1
+        } else if t - self.times[i - 1] < self.times[i] - t {
+            i - 1
+        } else {
+            i
+        }
+    }
+
+    /// The grid point closest to `t`
+    pub fn closest_time(&self, t: Time) -> Time {
+        self.times[self.closest_index(t)]
+    }
+
+    /// Index of the grid point equal to `t`, which must be one of the points of the grid
+    pub fn index(&self, t: Time) -> Size {
+        let i = self.closest_index(t);
+        assert!(
+            close_enough(t, self.times[i]),
+            "using inadequate time grid: {} is not a grid point (closest is {})",
+            t,
+            self.times[i]
+        );
+        i
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::TimeGrid;
+
+    #[test]
+    fn test_regular_time_grid() {
+        let grid = TimeGrid::new(1.0, 4);
+        assert_eq!(grid.size(), 5);
+        assert_eq!(grid.times(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(grid.dt(2), 0.25);
+        assert_eq!(grid[4], 1.0);
+    }
+
+    #[test]
+    fn test_time_grid_with_mandatory_times() {
+        let grid = TimeGrid::new_with_mandatory_times(&[1.0, 0.25, 1.0], 10);
+        assert_eq!(grid.mandatory_times(), &[0.25, 1.0]);
+        // steps of at most about 0.1: 0.25 is split in 3 steps and 0.75 in 8 steps
+        assert_eq!(grid.size(), 12);
+        assert_eq!(grid[0], 0.0);
+        assert_eq!(grid[3], 0.25);
+        assert_eq!(grid[11], 1.0);
+        for t in grid.mandatory_times() {
+            assert!(grid.times().contains(t));
+        }
+        assert!(grid.times().windows(2).all(|w| w[0] < w[1]));
+        for i in 0..grid.size() - 1 {
+            assert!((grid.dt(i) - 0.1).abs() < 0.03);
+        }
+
+        assert_eq!(grid.closest_index(0.26), 3);
+        assert_eq!(grid.closest_time(0.9), 0.25 + 7.0 * 0.09375);
+        assert_eq!(grid.closest_index(-1.0), 0);
+        assert_eq!(grid.closest_index(2.0), 11);
+        assert_eq!(grid.index(0.25), 3);
+    }
+
+    #[test]
+    fn test_time_grid_with_steps_per_year() {
+        let grid = TimeGrid::new_with_steps_per_year(&[0.5, 2.0], 12);
+        assert_eq!(grid.size(), 25);
+        assert!(grid.times().contains(&0.5));
+        assert_eq!(grid[24], 2.0);
+        assert!(grid.times().windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a grid point")]
+    fn test_time_grid_index_of_missing_time() {
+        TimeGrid::new(1.0, 4).index(0.3);
+    }
+}
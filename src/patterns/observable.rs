@@ -0,0 +1,110 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+/// Object that is notified when an [Observable] it is registered with changes
+pub trait Observer {
+    /// Called by the observables this object is registered with when they change
+    fn update(&self);
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Keeps track of the observers of an object and notifies them of changes.
+///
+/// Observers are held through weak references, so that registering with an observable doesn't
+/// keep them alive. Observers which have been dropped are discarded on the next notification.
+#[derive(Default)]
+pub struct Observable {
+    observers: RefCell<Vec<Weak<dyn Observer>>>,
+}
+
+impl Observable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an observer. Registering the same observer more than once has no effect.
+    pub fn register_observer(&self, observer: Rc<dyn Observer>) {
+        let observer = Rc::downgrade(&observer);
+        let mut observers = self.observers.borrow_mut();
+        if !observers.iter().any(|o| Weak::ptr_eq(o, &observer)) {
+            observers.push(observer);
+        }
+    }
+
+    /// Unregister an observer, if it was registered
+    pub fn unregister_observer(&self, observer: &Rc<dyn Observer>) {
+        let observer = Rc::downgrade(observer);
+        self.observers
+            .borrow_mut()
+            .retain(|o| !Weak::ptr_eq(o, &observer));
+    }
+
+    /// Number of live observers
+    pub fn observer_count(&self) -> usize {
+        self.observers
+            .borrow()
+            .iter()
+            .filter(|o| o.strong_count() > 0)
+            .count()
+    }
+
+    /// Notify all the live observers of a change
+    pub fn notify_observers(&self) {
+        // collect first, so that observers can register or unregister while being updated
+        let observers: Vec<Rc<dyn Observer>> = {
+            let mut observers = self.observers.borrow_mut();
+            observers.retain(|o| o.strong_count() > 0);
+            observers.iter().filter_map(|o| o.upgrade()).collect()
+        };
+        for observer in observers {
+            observer.update();
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::{Observable, Observer};
+
+    #[derive(Default)]
+    struct Counter {
+        updates: Cell<usize>,
+    }
+
+    impl Observer for Counter {
+        fn update(&self) {
+            self.updates.set(self.updates.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_notifications() {
+        let observable = Observable::new();
+        let counter = Rc::new(Counter::default());
+        let observer: Rc<dyn Observer> = counter.clone();
+        observable.register_observer(observer.clone());
+        observable.register_observer(observer.clone());
+        assert_eq!(observable.observer_count(), 1);
+
+        observable.notify_observers();
+        assert_eq!(counter.updates.get(), 1);
+
+        observable.unregister_observer(&observer);
+        observable.notify_observers();
+        assert_eq!(counter.updates.get(), 1);
+
+        // dropped observers are not kept alive
+        observable.register_observer(observer.clone());
+        drop(observer);
+        drop(counter);
+        assert_eq!(observable.observer_count(), 0);
+        observable.notify_observers();
+    }
+}
@@ -1,2 +1,8 @@
+pub mod blackformula;
 pub mod bond;
+pub mod capfloor;
 pub mod pricingengine;
+pub(crate) mod pricingengine_test_util;
+pub mod swap;
+pub mod swaption;
+pub mod vanilla;
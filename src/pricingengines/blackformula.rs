@@ -0,0 +1,282 @@
+use crate::{
+    instruments::payoffs::OptionType,
+    maths::distributions::normaldistribution::{CumulativeNormalDistribution, NormalDistribution},
+    types::{DiscountFactor, Real},
+};
+
+/// Black 1976 formula.
+///
+/// Returns the discounted value of an option with the given strike on an underlying with the
+/// given forward, where `std_dev` is the standard deviation of the log of the (displaced)
+/// forward at expiry, i.e. `σ√T`. A non-zero `displacement` gives the shifted-lognormal
+/// model, where `forward + displacement` is lognormal.
+pub fn black_formula(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    check_parameters(strike, forward, displacement);
+    assert!(std_dev >= 0.0, "stdDev ({}) must be non-negative", std_dev);
+    assert!(discount > 0.0, "discount ({}) must be positive", discount);
+
+    let omega = match option_type {
+        OptionType::Call => 1.0,
+        OptionType::Put => -1.0,
+    };
+    if std_dev == 0.0 {
+        return (omega * (forward - strike)).max(0.0) * discount;
+    }
+
+    let forward = forward + displacement;
+    let strike = strike + displacement;
+    if strike == 0.0 {
+        return match option_type {
+            OptionType::Call => forward * discount,
+            OptionType::Put => 0.0,
+        };
+    }
+
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let d2 = d1 - std_dev;
+    let cnd = CumulativeNormalDistribution::default();
+    let n_d1 = cnd.value(omega * d1);
+    let n_d2 = cnd.value(omega * d2);
+    let result = discount * omega * (forward * n_d1 - strike * n_d2);
+    // numerical inaccuracies can yield a negative answer
+    result.max(0.0)
+}
+
+/// Derivative of the [black_formula] value with respect to the forward, i.e. the forward delta
+/// of the option, times the discount.
+pub fn black_formula_forward_derivative(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    check_parameters(strike, forward, displacement);
+    assert!(std_dev >= 0.0, "stdDev ({}) must be non-negative", std_dev);
+    assert!(discount > 0.0, "discount ({}) must be positive", discount);
+
+    let omega = match option_type {
+        OptionType::Call => 1.0,
+        OptionType::Put => -1.0,
+    };
+    if std_dev == 0.0 {
+        return if omega * (forward - strike) > 0.0 {
+            omega * discount
+        } else {
+            0.0
+        };
+    }
+
+    let forward = forward + displacement;
+    let strike = strike + displacement;
+    if strike == 0.0 {
+        return match option_type {
+            OptionType::Call => discount,
+            OptionType::Put => 0.0,
+        };
+    }
+
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let cnd = CumulativeNormalDistribution::default();
+    omega * cnd.value(omega * d1) * discount
+}
+
+/// Derivative of the [black_formula] value with respect to the standard deviation `σ√T`; the
+/// vega with respect to `σ` is this times `√T`. Calls and puts have the same one.
+pub fn black_formula_stddev_derivative(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    check_parameters(strike, forward, displacement);
+    assert!(std_dev >= 0.0, "stdDev ({}) must be non-negative", std_dev);
+    assert!(discount > 0.0, "discount ({}) must be positive", discount);
+
+    let forward = forward + displacement;
+    let strike = strike + displacement;
+    if std_dev == 0.0 || strike == 0.0 {
+        return 0.0;
+    }
+
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    discount * forward * NormalDistribution::default().value(d1)
+}
+
+/// Second derivative of the [black_formula] value with respect to the forward, the same for
+/// calls and puts. Zero without volatility, where the delta is a step function.
+pub fn black_formula_gamma(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    check_parameters(strike, forward, displacement);
+    assert!(std_dev >= 0.0, "stdDev ({}) must be non-negative", std_dev);
+    assert!(discount > 0.0, "discount ({}) must be positive", discount);
+
+    let forward = forward + displacement;
+    let strike = strike + displacement;
+    if std_dev == 0.0 || strike == 0.0 {
+        return 0.0;
+    }
+
+    let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    discount * NormalDistribution::default().value(d1) / (forward * std_dev)
+}
+
+fn check_parameters(strike: Real, forward: Real, displacement: Real) {
+    assert!(
+        displacement >= 0.0,
+        "displacement ({}) must be non-negative",
+        displacement
+    );
+    assert!(
+        strike + displacement >= 0.0,
+        "strike + displacement ({} + {}) must be non-negative",
+        strike,
+        displacement
+    );
+    assert!(
+        forward + displacement > 0.0,
+        "forward + displacement ({} + {}) must be positive",
+        forward,
+        displacement
+    );
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::instruments::payoffs::OptionType::{Call, Put};
+
+    use super::{
+        black_formula, black_formula_forward_derivative, black_formula_gamma,
+        black_formula_stddev_derivative,
+    };
+
+    #[test]
+    fn test_black_formula() {
+        let forward = 0.03;
+        let discount = 0.95;
+        let std_dev = 0.2 * 2.0_f64.sqrt();
+
+        // at the money, the value is `discount * forward * (2 N(σ√T / 2) - 1)`
+        let call = black_formula(Call, forward, forward, std_dev, discount, 0.0);
+        let expected = discount * forward * 0.1124629160182849;
+        assert!(
+            (call - expected).abs() < 1.0e-12,
+            "{} vs {}",
+            call,
+            expected
+        );
+
+        // put-call parity
+        for strike in [0.01, 0.025, 0.03, 0.05] {
+            for displacement in [0.0, 0.01] {
+                let call = black_formula(Call, strike, forward, std_dev, discount, displacement);
+                let put = black_formula(Put, strike, forward, std_dev, discount, displacement);
+                assert!((call - put - discount * (forward - strike)).abs() < 1.0e-15);
+            }
+        }
+
+        // zero volatility gives the discounted intrinsic value
+        assert!((black_formula(Call, 0.02, 0.03, 0.0, 0.5, 0.0) - 0.005).abs() < 1.0e-15);
+        assert_eq!(black_formula(Put, 0.02, 0.03, 0.0, 0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_black_formula_derivatives() {
+        let forward = 0.03;
+        let discount = 0.95;
+        let std_dev = 0.2 * 2.0_f64.sqrt();
+        let h = 1.0e-5;
+        for strike in [0.01, 0.025, 0.03, 0.05] {
+            for displacement in [0.0, 0.01] {
+                let vega = black_formula_stddev_derivative(
+                    strike,
+                    forward,
+                    std_dev,
+                    discount,
+                    displacement,
+                );
+                let gamma = black_formula_gamma(strike, forward, std_dev, discount, displacement);
+                for option_type in [Call, Put] {
+                    let price = |f: f64, s: f64| {
+                        black_formula(option_type, strike, f, s, discount, displacement)
+                    };
+                    let bumped_vega =
+                        (price(forward, std_dev + h) - price(forward, std_dev - h)) / (2.0 * h);
+                    assert!(
+                        (vega - bumped_vega).abs() < 1.0e-6,
+                        "strike {}: vega {} vs {}",
+                        strike,
+                        vega,
+                        bumped_vega
+                    );
+
+                    let delta = |f: f64| {
+                        black_formula_forward_derivative(
+                            option_type,
+                            strike,
+                            f,
+                            std_dev,
+                            discount,
+                            displacement,
+                        )
+                    };
+                    let h = 1.0e-7;
+                    let bumped_delta =
+                        (price(forward + h, std_dev) - price(forward - h, std_dev)) / (2.0 * h);
+                    assert!((delta(forward) - bumped_delta).abs() < 1.0e-6);
+                    let bumped_gamma = (delta(forward + h) - delta(forward - h)) / (2.0 * h);
+                    assert!((gamma - bumped_gamma).abs() < 1.0e-6 * gamma);
+                }
+                // put-call parity: the deltas differ by the discount
+                let call_delta = black_formula_forward_derivative(
+                    Call,
+                    strike,
+                    forward,
+                    std_dev,
+                    discount,
+                    displacement,
+                );
+                let put_delta = black_formula_forward_derivative(
+                    Put,
+                    strike,
+                    forward,
+                    std_dev,
+                    discount,
+                    displacement,
+                );
+                assert!((call_delta - put_delta - discount).abs() < 1.0e-15);
+            }
+        }
+
+        // without volatility the delta is the discount in the money, and zero out of it
+        assert_eq!(
+            black_formula_forward_derivative(Call, 0.02, 0.03, 0.0, 0.5, 0.0),
+            0.5
+        );
+        assert_eq!(
+            black_formula_forward_derivative(Put, 0.02, 0.03, 0.0, 0.5, 0.0),
+            0.0
+        );
+        assert_eq!(
+            black_formula_stddev_derivative(0.02, 0.03, 0.0, 0.5, 0.0),
+            0.0
+        );
+        assert_eq!(black_formula_gamma(0.02, 0.03, 0.0, 0.5, 0.0), 0.0);
+    }
+}
@@ -1,16 +1,27 @@
 use crate::{
     cashflows::{
-        cashflow::{self, CashFlowLeg, CashFlow},
-        coupon::Coupon,
+        cash_flows,
+        cashflow::{self, CashFlow, CashFlowLeg},
     },
     datetime::{date::Date, daycounter::DayCounter, frequency::Frequency, SerialNumber},
+    instruments::bond::Bond,
     maths::solvers1d::newtonsafe::NewtonSafe,
     rates::{compounding::Compounding, interestrate::InterestRate},
+    termstructures::yieldtermstructure::YieldTermStructure,
     types::{Rate, Real, Size, Time},
 };
 
+/// Kind of duration returned by [duration]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationType {
+    /// Average time of the cash flows, weighted by their present values
+    Simple,
+    /// Relative sensitivity of the price to the yield, `-(1/P) dP/dy`
+    Modified,
+}
+
 pub fn accrued_amount<T: CashFlow>(
-    cashflows: &Vec<T>,
+    cashflows: &[T],
     notional: Real,
     date: Date,
 ) -> Real {
@@ -18,11 +29,11 @@ pub fn accrued_amount<T: CashFlow>(
     accrued_amount * 100.0 / notional
 }
 
-pub fn accrued_days<T: Coupon>(coupons: &Vec<T>, date: Date) -> SerialNumber {
+pub fn accrued_days<T: CashFlow>(coupons: &[T], date: Date) -> SerialNumber {
     cashflow::accrued_days(coupons, false, date)
 }
 
-pub fn accrued_period<T: Coupon>(coupons: &Vec<T>, date: Date) -> Time {
+pub fn accrued_period<T: CashFlow>(coupons: &[T], date: Date) -> Time {
     cashflow::accrued_period(coupons, false, date)
 }
 
@@ -65,6 +76,259 @@ pub fn dirty_price(
     npv * 100.0 / notional
 }
 
+/// Dirty price of a bond as of the settlement date, discounting each cash flow on the given curve.
+pub fn dirty_price_from_curve(
+    notional: Real,
+    cashflows: &CashFlowLeg,
+    discount_curve: &dyn YieldTermStructure,
+    settlement_date: Date,
+) -> Real {
+    let npv = cash_flows::npv(
+        cashflows,
+        discount_curve,
+        false,
+        settlement_date,
+        settlement_date,
+    );
+    npv * 100.0 / notional
+}
+
 pub fn maturity_date<T: CashFlow>(cashflows: &Vec<T>) -> Date {
     cashflow::maturity_date(cashflows)
 }
+
+// -------------------------------------------------------------------------------------------------
+// Yield analytics shared by all the bond types: prices are quoted per 100 of the notional
+// outstanding at settlement, and coupons paid on the settlement date are excluded.
+
+fn check_tradeable<B: Bond + ?Sized>(bond: &B, settlement_date: Date) {
+    assert!(
+        bond.is_tradeable(settlement_date),
+        "Non tradeable at {:?}, (maturity being {:?})",
+        settlement_date,
+        bond.maturity_date()
+    );
+}
+
+/// Dirty price of the bond given a yield `y` and settlement date
+pub fn dirty_price_from_yield<B: Bond + ?Sized>(
+    bond: &B,
+    y: &InterestRate,
+    settlement_date: Date,
+) -> Real {
+    check_tradeable(bond, settlement_date);
+    dirty_price(
+        bond.notional(settlement_date),
+        bond.cashflows(),
+        y,
+        settlement_date,
+    )
+}
+
+/// Clean price of the bond given a yield `y` and settlement date
+pub fn clean_price_from_yield<B: Bond + ?Sized>(
+    bond: &B,
+    y: &InterestRate,
+    settlement_date: Date,
+) -> Real {
+    dirty_price_from_yield(bond, y, settlement_date) - bond.accrued_amount(settlement_date)
+}
+
+/// Yield of the bond given its clean price and settlement date, under the day count,
+/// compounding and frequency conventions given.
+///
+/// The yield is solved for to within `accuracy`, starting from `guess`.
+#[allow(clippy::too_many_arguments)]
+pub fn yield_from_clean_price<B: Bond + ?Sized>(
+    bond: &B,
+    clean_price: Real,
+    daycounter: DayCounter,
+    compounding: Compounding,
+    frequency: Frequency,
+    settlement_date: Date,
+    accuracy: Real,
+    max_evaluations: Size,
+    guess: Real,
+) -> Rate {
+    check_tradeable(bond, settlement_date);
+    let current_notional = bond.notional(settlement_date);
+    if current_notional == 0.0 {
+        return 0.0;
+    }
+    let dirty_price =
+        (clean_price + bond.accrued_amount(settlement_date)) * current_notional / 100.0;
+    bond_yield(
+        bond.cashflows(),
+        dirty_price,
+        daycounter,
+        compounding,
+        frequency,
+        settlement_date,
+        accuracy,
+        max_evaluations,
+        guess,
+    )
+}
+
+/// Duration of the bond at the yield `y`, of the requested type
+pub fn duration<B: Bond + ?Sized>(
+    bond: &B,
+    y: &InterestRate,
+    duration_type: DurationType,
+    settlement_date: Date,
+) -> Real {
+    check_tradeable(bond, settlement_date);
+    match duration_type {
+        DurationType::Simple => {
+            cashflow::simple_duration(bond.cashflows(), y, false, settlement_date, settlement_date)
+        }
+        DurationType::Modified => cashflow::modified_duration(
+            bond.cashflows(),
+            y,
+            false,
+            settlement_date,
+            settlement_date,
+        ),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing, date::Date,
+            daycounter::DayCounter, frequency::Frequency::*, holidays::target::Target,
+            months::Month::*, period::Period, schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::Years,
+        },
+        instruments::{
+            amortizingfixedratebond::AmortizingFixedRateBond, bond::Bond,
+            fixedratebond::FixedRateBond, zerocouponbond::ZeroCouponBond,
+        },
+        rates::{compounding::Compounding::*, interestrate::InterestRate},
+    };
+
+    use super::{clean_price_from_yield, duration, yield_from_clean_price, DurationType};
+
+    fn bonds(today: Date) -> Vec<Rc<dyn Bond>> {
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            today,
+            today + Period::new(5, Years),
+            Period::new(1, Years),
+            Target::new(),
+        )
+        .with_convention(ModifiedFollowing)
+        .build();
+        vec![
+            Rc::new(FixedRateBond::new(
+                2,
+                100.0,
+                schedule.clone(),
+                vec![0.045],
+                DayCounter::bond_basis(),
+            )),
+            Rc::new(ZeroCouponBond::new(
+                2,
+                &Target::new(),
+                100.0,
+                today + Period::new(5, Years),
+            )),
+            Rc::new(AmortizingFixedRateBond::new(
+                2,
+                vec![100.0, 80.0, 60.0, 40.0, 20.0],
+                schedule,
+                vec![0.045],
+                DayCounter::bond_basis(),
+            )),
+        ]
+    }
+
+    #[test]
+    fn test_price_yield_round_trip() {
+        let today = Date::new(15, January, 2024);
+        let settlement_date = Date::new(10, September, 2024);
+        let dc = DayCounter::actual365_fixed();
+        for bond in bonds(today) {
+            for (compounding, frequency) in [
+                (Compounded, Annual),
+                (Compounded, Semiannual),
+                (Continuous, Annual),
+                (SimpleThenCompounded, Annual),
+            ] {
+                let y = InterestRate::new(0.037, dc.clone(), compounding.clone(), frequency);
+                let clean_price = clean_price_from_yield(bond.as_ref(), &y, settlement_date);
+                assert_eq!(
+                    clean_price,
+                    bond.clean_price_from_yield(
+                        0.037,
+                        dc.clone(),
+                        compounding.clone(),
+                        frequency,
+                        settlement_date
+                    )
+                );
+                let bond_yield = yield_from_clean_price(
+                    bond.as_ref(),
+                    clean_price,
+                    dc.clone(),
+                    compounding,
+                    frequency,
+                    settlement_date,
+                    1.0e-12,
+                    100,
+                    0.05,
+                );
+                assert!(
+                    (bond_yield - 0.037).abs() < 1.0e-10,
+                    "yield {} from price {}",
+                    bond_yield,
+                    clean_price
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_duration() {
+        let today = Date::new(15, January, 2024);
+        let settlement_date = Date::new(17, January, 2024);
+        let dc = DayCounter::actual365_fixed();
+        let y = InterestRate::new(0.04, dc.clone(), Compounded, Annual);
+
+        // a single flow: the simple duration is its time, the modified one is discounted once
+        let zero = &bonds(today)[1];
+        let t = dc.year_fraction_simple(&settlement_date, &zero.maturity_date());
+        let simple = duration(zero.as_ref(), &y, DurationType::Simple, settlement_date);
+        let modified = duration(zero.as_ref(), &y, DurationType::Modified, settlement_date);
+        assert!((simple - t).abs() < 1.0e-12);
+        assert!((modified - t / 1.04).abs() < 1.0e-12);
+
+        // coupons shorten the duration, and amortization even more so
+        let fixed = &bonds(today)[0];
+        let amortizing = &bonds(today)[2];
+        let simple_fixed = duration(fixed.as_ref(), &y, DurationType::Simple, settlement_date);
+        let simple_amortizing = duration(
+            amortizing.as_ref(),
+            &y,
+            DurationType::Simple,
+            settlement_date,
+        );
+        assert!(simple_amortizing < simple_fixed && simple_fixed < simple);
+
+        // the modified duration matches the relative price sensitivity
+        let h = 1.0e-5;
+        let price = |r| {
+            let y = InterestRate::new(r, dc.clone(), Compounded, Annual);
+            super::dirty_price_from_yield(fixed.as_ref(), &y, settlement_date)
+        };
+        let numerical = -(price(0.04 + h) - price(0.04 - h)) / (2.0 * h) / price(0.04);
+        let modified = duration(fixed.as_ref(), &y, DurationType::Modified, settlement_date);
+        assert!((modified - numerical).abs() < 1.0e-6);
+    }
+}
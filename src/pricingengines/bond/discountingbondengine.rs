@@ -1,5 +1,6 @@
+use crate::{handle::Handle, termstructures::yieldtermstructure::YieldTermStructure};
+
 pub struct DiscountingBondEngine {
-    // TODO
-    // discount_curve: YieldTermStructure,
+    pub discount_curve: Handle<dyn YieldTermStructure>,
     pub include_settlement_cashflows: Option<bool>,
 }
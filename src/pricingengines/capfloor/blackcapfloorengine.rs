@@ -0,0 +1,425 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{cashflow::CashFlow, coupon::Coupon},
+    handle::Handle,
+    instruments::{
+        capfloor::{CapFloorArguments, CapFloorResults, CapFloorType},
+        payoffs::OptionType,
+    },
+    patterns::observable::Observer,
+    pricingengines::{blackformula::black_formula, pricingengine::PricingEngine},
+    termstructures::{
+        volatility::optionletvolatilitystructure::OptionletVolatilityStructure,
+        yieldtermstructure::YieldTermStructure,
+    },
+    types::{Rate, Real},
+};
+
+/// Black-formula cap/floor engine
+///
+/// Each optionlet is valued with the Black formula on the forward fixing of its coupon, using
+/// the volatility at the fixing date, and is paid on the coupon payment date. Coupons already
+/// paid at the reference date of the discount curve are not included, while optionlets whose
+/// fixing date is not after the reference date of the volatility are given their intrinsic
+/// value. Shifted-lognormal volatilities are supported through the displacement of the
+/// volatility structure.
+pub struct BlackCapFloorEngine {
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    pub volatility: Handle<dyn OptionletVolatilityStructure>,
+}
+
+impl BlackCapFloorEngine {
+    pub fn new(
+        discount_curve: Handle<dyn YieldTermStructure>,
+        volatility: Handle<dyn OptionletVolatilityStructure>,
+    ) -> Self {
+        Self {
+            discount_curve,
+            volatility,
+        }
+    }
+}
+
+impl PricingEngine for BlackCapFloorEngine {
+    type A = CapFloorArguments;
+    type R = CapFloorResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        let discount_curve = self.discount_curve.current_link();
+        let volatility = self.volatility.current_link();
+        let valuation_date = discount_curve.reference_date();
+        let today = volatility.reference_date();
+        let displacement = volatility.displacement();
+
+        let optionlet = |option_type: OptionType, strike: Rate, forward: Rate, fixing_date| {
+            let std_dev = if fixing_date > today {
+                volatility
+                    .black_variance(&fixing_date, strike, false)
+                    .sqrt()
+            } else {
+                0.0
+            };
+            black_formula(option_type, strike, forward, std_dev, 1.0, displacement)
+        };
+
+        let mut optionlet_prices = Vec::with_capacity(arguments.floating_leg.len());
+        for (i, coupon) in arguments.floating_leg.iter().enumerate() {
+            if coupon.has_occurred(&valuation_date, false) {
+                optionlet_prices.push(0.0);
+                continue;
+            }
+            assert!(
+                coupon.gearing > 0.0,
+                "positive gearing required, {} given for coupon {}",
+                coupon.gearing,
+                i
+            );
+            let discount = discount_curve.discount_from_date(&coupon.date(), false);
+            let accrual_factor = coupon.nominal() * coupon.gearing * coupon.accrual_period();
+            let forward = coupon.index_fixing();
+            let fixing_date = coupon.fixing_date();
+            // strikes on the index fixing, net of spread and gearing
+            let effective_strike = |rate: Rate| (rate - coupon.spread) / coupon.gearing;
+
+            let mut value: Real = 0.0;
+            if arguments.cap_floor_type != CapFloorType::Floor {
+                let strike = effective_strike(arguments.cap_rates[i]);
+                value += optionlet(OptionType::Call, strike, forward, fixing_date);
+            }
+            if arguments.cap_floor_type != CapFloorType::Cap {
+                let strike = effective_strike(arguments.floor_rates[i]);
+                let floorlet = optionlet(OptionType::Put, strike, forward, fixing_date);
+                if arguments.cap_floor_type == CapFloorType::Floor {
+                    value += floorlet;
+                } else {
+                    // a collar is short the floor
+                    value -= floorlet;
+                }
+            }
+            optionlet_prices.push(discount * accrual_factor * value);
+        }
+
+        CapFloorResults {
+            npv: optionlet_prices.iter().sum(),
+            error_estimate: 0.0,
+            valuation_date,
+            optionlet_prices,
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.discount_curve.register_observer(observer.clone());
+        self.volatility.register_observer(observer);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::{cashflow::CashFlow, coupon::Coupon, iborcoupon::IborCoupon, iborleg::IborLeg},
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Months, Years},
+        },
+        handle::{Handle, RelinkableHandle},
+        indexes::iborindex::IborIndex,
+        instruments::{capfloor::CapFloor, instrument::Instrument},
+        maths::distributions::normaldistribution::CumulativeNormalDistribution,
+        pricingengines::pricingengine_test_util::CountingEngine,
+        rates::compounding::Compounding::Continuous,
+        termstructures::{
+            flatforward::FlatForward,
+            volatility::{
+                constantoptionletvolatility::ConstantOptionletVolatility,
+                optionletvolatilitystructure::OptionletVolatilityStructure,
+            },
+            yieldtermstructure::YieldTermStructure,
+        },
+        types::Real,
+    };
+
+    use super::BlackCapFloorEngine;
+
+    struct CommonVars {
+        today: Date,
+        curve: Handle<dyn YieldTermStructure>,
+        leg: Vec<IborCoupon>,
+        engine: Rc<BlackCapFloorEngine>,
+    }
+
+    fn common_vars(volatility: Real) -> CommonVars {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Rc::new(IborIndex::new(
+            "Euribor",
+            Period::new(3, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            curve.clone(),
+        ));
+        // 2 years cap, starting in 3 months so that all the caplets are still to be fixed
+        let start = Date::new(17, April, 2024);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(today),
+            start,
+            start + Period::new(2, Years),
+            Period::new(3, Months),
+            Target::new(),
+        )
+        .with_convention(ModifiedFollowing)
+        .build();
+        let leg = IborLeg::new(schedule, index)
+            .with_notional(1_000_000.0)
+            .build();
+        let vol: Handle<dyn OptionletVolatilityStructure> = Handle::new(Rc::new(
+            ConstantOptionletVolatility::new(today, volatility, DayCounter::actual365_fixed()),
+        ));
+        let engine = Rc::new(BlackCapFloorEngine::new(curve.clone(), vol));
+        CommonVars {
+            today,
+            curve,
+            leg,
+            engine,
+        }
+    }
+
+    #[test]
+    fn test_cap_vs_caplets() {
+        let volatility = 0.2;
+        let strike = 0.03;
+        let vars = common_vars(volatility);
+        assert_eq!(vars.leg.len(), 8);
+
+        let cap = CapFloor::cap(vars.leg.clone(), strike, vars.engine.clone());
+        let results = cap.results();
+
+        // Black formula, written out for each caplet
+        let cnd = CumulativeNormalDistribution::default();
+        let mut expected = 0.0;
+        for (coupon, &caplet) in vars.leg.iter().zip(&results.optionlet_prices) {
+            let start = coupon.accrual_start_date();
+            let end = coupon.accrual_end_date();
+            let tau = (end - start) as Real / 360.0;
+            let curve = vars.curve.current_link();
+            let forward = (curve.discount_from_date(&start, false)
+                / curve.discount_from_date(&end, false)
+                - 1.0)
+                / tau;
+            let t = (coupon.fixing_date() - vars.today) as Real / 365.0;
+            let std_dev = volatility * t.sqrt();
+            let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+            let d2 = d1 - std_dev;
+            let discount = curve.discount_from_date(&coupon.date(), false);
+            let value =
+                1_000_000.0 * tau * discount * (forward * cnd.value(d1) - strike * cnd.value(d2));
+            assert!(
+                (caplet - value).abs() < 1.0e-8,
+                "caplet fixing on {:?}: calculated {}, expected {}",
+                coupon.fixing_date(),
+                caplet,
+                value
+            );
+            expected += value;
+        }
+        assert!(
+            (cap.npv() - expected).abs() < 1.0e-8,
+            "cap: calculated {}, expected {}",
+            cap.npv(),
+            expected
+        );
+        assert!(cap.npv() > 0.0);
+    }
+
+    /// 1 year cap on Euribor 3M which started three months ago, its first caplet already fixed
+    fn seasoned_cap(first_fixing: Option<Real>, strike: Real) -> (CommonVars, CapFloor) {
+        let vars = common_vars(0.2);
+        let mut index = IborIndex::new(
+            "Euribor",
+            Period::new(3, Months),
+            2,
+            Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+            vars.curve.clone(),
+        );
+        if let Some(fixing) = first_fixing {
+            index.add_fixing(Date::new(13, October, 2023), fixing);
+        }
+        let start = Date::new(17, October, 2023);
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(vars.today),
+            start,
+            start + Period::new(1, Years),
+            Period::new(3, Months),
+            Target::new(),
+        )
+        .with_convention(ModifiedFollowing)
+        .build();
+        let leg = IborLeg::new(schedule, Rc::new(index))
+            .with_notional(1_000_000.0)
+            .build();
+        let cap = CapFloor::cap(leg, strike, vars.engine.clone());
+        (vars, cap)
+    }
+
+    #[test]
+    fn test_seasoned_cap() {
+        let strike = 0.03;
+        let (vars, cap) = seasoned_cap(Some(0.039), strike);
+        let leg = &cap.floating_leg;
+        assert_eq!(leg[0].fixing_date(), Date::new(13, October, 2023));
+        assert!(leg[0].fixing_date() < vars.today);
+        assert_eq!(leg[0].index_fixing(), 0.039);
+
+        // the first caplet is worth its discounted intrinsic value
+        let results = cap.results();
+        let curve = vars.curve.current_link();
+        let expected = 1_000_000.0
+            * leg[0].accrual_period()
+            * (0.039 - strike)
+            * curve.discount_from_date(&leg[0].date(), false);
+        assert!(
+            (results.optionlet_prices[0] - expected).abs() < 1.0e-8,
+            "calculated {}, expected {}",
+            results.optionlet_prices[0],
+            expected
+        );
+        // the caplet fixing today is forecast, out of the money and without time value, while
+        // the later ones still have time value
+        assert_eq!(leg[1].fixing_date(), vars.today);
+        assert!(leg[1].index_fixing() < strike);
+        assert_eq!(results.optionlet_prices[1], 0.0);
+        assert!(results.optionlet_prices[2..].iter().all(|p| *p > 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing Euribor3M Actual/360 fixing for")]
+    fn test_seasoned_cap_missing_fixing() {
+        let (_, cap) = seasoned_cap(None, 0.03);
+        cap.npv();
+    }
+
+    #[test]
+    fn test_cap_floor_parity() {
+        let vars = common_vars(0.25);
+        let curve = vars.curve.current_link();
+        for strike in [0.02, 0.03, 0.04] {
+            let cap = CapFloor::cap(vars.leg.clone(), strike, vars.engine.clone());
+            let floor = CapFloor::floor(vars.leg.clone(), strike, vars.engine.clone());
+            let collar = CapFloor::collar(vars.leg.clone(), strike, strike, vars.engine.clone());
+
+            // cap - floor is a swap paying the floating rate against the strike
+            let swap: Real = vars
+                .leg
+                .iter()
+                .map(|c| {
+                    c.nominal()
+                        * c.accrual_period()
+                        * (c.rate() - strike)
+                        * curve.discount_from_date(&c.date(), false)
+                })
+                .sum();
+            assert!(
+                (cap.npv() - floor.npv() - swap).abs() < 1.0e-8,
+                "strike {}: cap {}, floor {}, swap {}",
+                strike,
+                cap.npv(),
+                floor.npv(),
+                swap
+            );
+            assert!((collar.npv() - (cap.npv() - floor.npv())).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_zero_cost_collar() {
+        let vars = common_vars(0.25);
+        for cap_strike in [0.035, 0.04, 0.05] {
+            let floor_strike = CapFloor::zero_cost_collar_floor_strike(
+                vars.leg.clone(),
+                cap_strike,
+                vars.engine.clone(),
+            );
+            // out of the money cap, so the floor is struck below the forward rates of about 3%
+            assert!(floor_strike > 0.0 && floor_strike < 0.03);
+            let collar = CapFloor::collar(
+                vars.leg.clone(),
+                cap_strike,
+                floor_strike,
+                vars.engine.clone(),
+            );
+            assert!(
+                collar.npv().abs() < 1.0e-8,
+                "cap strike {}, floor strike {}: collar worth {}",
+                cap_strike,
+                floor_strike,
+                collar.npv()
+            );
+            let cap = CapFloor::cap(vars.leg.clone(), cap_strike, vars.engine.clone());
+            let floor = CapFloor::floor(vars.leg.clone(), floor_strike, vars.engine.clone());
+            assert!((cap.npv() - floor.npv()).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no zero-cost collar")]
+    fn test_zero_cost_collar_without_volatility() {
+        // a deep out of the money cap is worthless, and so is any collar with a positive floor
+        let vars = common_vars(0.0);
+        CapFloor::zero_cost_collar_floor_strike(vars.leg, 0.10, vars.engine);
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let vars = common_vars(0.2);
+        let vol: RelinkableHandle<dyn OptionletVolatilityStructure> =
+            RelinkableHandle::new(Rc::new(ConstantOptionletVolatility::new(
+                vars.today,
+                0.2,
+                DayCounter::actual365_fixed(),
+            )));
+        let engine = Rc::new(CountingEngine::new(BlackCapFloorEngine::new(
+            vars.curve.clone(),
+            vol.handle(),
+        )));
+        let cap = CapFloor::cap(vars.leg.clone(), 0.03, engine.clone());
+
+        // the second call uses the cached results
+        let npv = cap.npv();
+        assert_eq!(cap.npv(), npv);
+        assert_eq!(engine.calculations.get(), 1);
+
+        // relinking the volatility invalidates them
+        vol.link_to(Rc::new(ConstantOptionletVolatility::new(
+            vars.today,
+            0.3,
+            DayCounter::actual365_fixed(),
+        )));
+        assert!(!cap.cache().unwrap().is_calculated());
+        assert!(cap.npv() > npv);
+        assert_eq!(engine.calculations.get(), 2);
+    }
+}
@@ -1,3 +1,7 @@
+use std::rc::Rc;
+
+use crate::patterns::observable::Observer;
+
 /// Arguments to the pricing engine
 pub trait Arguments {}
 
@@ -10,6 +14,10 @@ pub trait PricingEngine {
     type R: Results;
 
     fn calculate(&self, arguments: Self::A) -> Self::R;
+
+    /// Register an observer with the market data the engine prices from, so that it is notified
+    /// when any of their handles is relinked. Engines without market data can keep the default.
+    fn register_observer(&self, _observer: Rc<dyn Observer>) {}
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -0,0 +1,36 @@
+#![cfg(test)]
+use std::{cell::Cell, rc::Rc};
+
+use crate::patterns::observable::Observer;
+
+use super::pricingengine::PricingEngine;
+
+/// Engine counting the calculations it forwards to the wrapped engine, used to check that
+/// instruments don't run their engine again while their inputs are unchanged
+pub(crate) struct CountingEngine<E: PricingEngine> {
+    pub engine: E,
+    pub calculations: Cell<usize>,
+}
+
+impl<E: PricingEngine> CountingEngine<E> {
+    pub fn new(engine: E) -> Self {
+        Self {
+            engine,
+            calculations: Cell::new(0),
+        }
+    }
+}
+
+impl<E: PricingEngine> PricingEngine for CountingEngine<E> {
+    type A = E::A;
+    type R = E::R;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        self.calculations.set(self.calculations.get() + 1);
+        self.engine.calculate(arguments)
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.engine.register_observer(observer);
+    }
+}
@@ -0,0 +1,107 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::cash_flows,
+    currencies::currency::Currency,
+    datetime::date::Date,
+    handle::Handle,
+    instruments::swap::{SwapArguments, SwapResults},
+    patterns::observable::Observer,
+    pricingengines::pricingengine::PricingEngine,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{Real, BASIS_POINT},
+};
+
+/// Swap engine discounting the cash flows of each leg on the given curve
+///
+/// Values are as of the reference date of the curve. Cash flows paid on that date are only
+/// included if `include_settlement_date_flows` is set, which is the default. Values are reported
+/// in `currency`, if given.
+pub struct DiscountingSwapEngine {
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    pub include_settlement_date_flows: Option<bool>,
+    pub currency: Option<Rc<dyn Currency>>,
+}
+
+impl DiscountingSwapEngine {
+    pub fn new(
+        discount_curve: Handle<dyn YieldTermStructure>,
+        include_settlement_date_flows: Option<bool>,
+    ) -> Self {
+        Self {
+            discount_curve,
+            include_settlement_date_flows,
+            currency: None,
+        }
+    }
+
+    /// Report the values in the given currency
+    pub fn with_currency(mut self, currency: Rc<dyn Currency>) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+}
+
+impl PricingEngine for DiscountingSwapEngine {
+    type A = SwapArguments;
+    type R = SwapResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        assert_eq!(
+            arguments.legs.len(),
+            arguments.payer.len(),
+            "number of legs and multipliers differ"
+        );
+        let discount_curve = self.discount_curve.current_link();
+        let valuation_date = discount_curve.reference_date();
+        let include_settlement_date_flows = self.include_settlement_date_flows.unwrap_or(true);
+
+        let mut leg_npv = Vec::with_capacity(arguments.legs.len());
+        let mut leg_bps = Vec::with_capacity(arguments.legs.len());
+        for (leg, payer) in arguments.legs.iter().zip(&arguments.payer) {
+            let npv = cash_flows::npv(
+                leg,
+                discount_curve.as_ref(),
+                include_settlement_date_flows,
+                valuation_date,
+                Date::default(),
+            );
+            let bps = cash_flows::bps(
+                leg,
+                discount_curve.as_ref(),
+                include_settlement_date_flows,
+                valuation_date,
+                Date::default(),
+            );
+            leg_npv.push(payer * npv);
+            leg_bps.push(payer * bps);
+        }
+
+        // the rate or spread moving the NPV of the corresponding leg by minus the swap NPV
+        let npv: Real = leg_npv.iter().sum();
+        let fair_value = |value: Option<Real>, leg: usize| -> Option<Real> {
+            let bps = *leg_bps.get(leg)?;
+            if bps == 0.0 {
+                return None;
+            }
+            value.map(|value| value - npv / (bps / BASIS_POINT))
+        };
+        let fair_rate = fair_value(arguments.fixed_rate, 0);
+        let fair_spread = fair_value(arguments.spread, 1);
+
+        SwapResults {
+            npv,
+            currency: self.currency.clone(),
+            error_estimate: 0.0,
+            valuation_date,
+            leg_npv,
+            leg_bps,
+            fair_rate,
+            fair_spread,
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.discount_curve.register_observer(observer);
+    }
+}
@@ -0,0 +1,324 @@
+use std::rc::Rc;
+
+use crate::{
+    handle::Handle,
+    instruments::{
+        exercise::ExerciseType,
+        payoffs::OptionType,
+        swap::SwapType,
+        swaption::{SwaptionArguments, SwaptionResults},
+    },
+    patterns::observable::Observer,
+    pricingengines::{
+        blackformula::black_formula, pricingengine::PricingEngine,
+        swap::discountingswapengine::DiscountingSwapEngine,
+    },
+    termstructures::{
+        volatility::swaptionvolatilitystructure::SwaptionVolatilityStructure,
+        yieldtermstructure::YieldTermStructure,
+    },
+    types::BASIS_POINT,
+};
+
+/// Black-formula swaption engine
+///
+/// The swaption is valued with the Black formula on the forward swap rate, using the annuity
+/// of the fixed leg as numeraire. Both are obtained by discounting the underlying swap on the
+/// given curve. A spread on the floating leg is accounted for by moving the strike and the
+/// forward by the equivalent fixed rate.
+pub struct BlackSwaptionEngine {
+    pub discount_curve: Handle<dyn YieldTermStructure>,
+    pub volatility: Handle<dyn SwaptionVolatilityStructure>,
+}
+
+impl BlackSwaptionEngine {
+    pub fn new(
+        discount_curve: Handle<dyn YieldTermStructure>,
+        volatility: Handle<dyn SwaptionVolatilityStructure>,
+    ) -> Self {
+        Self {
+            discount_curve,
+            volatility,
+        }
+    }
+}
+
+impl PricingEngine for BlackSwaptionEngine {
+    type A = SwaptionArguments;
+    type R = SwaptionResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        assert_eq!(
+            arguments.exercise.exercise_type,
+            ExerciseType::European,
+            "not an European option"
+        );
+        let volatility = self.volatility.current_link();
+        let exercise_date = arguments.exercise.last_date();
+
+        let swap_engine = DiscountingSwapEngine::new(self.discount_curve.clone(), None);
+        let swap_results = swap_engine.calculate(arguments.swap);
+        let fixed_leg_bps = swap_results.leg_bps[0];
+        let floating_leg_bps = swap_results.leg_bps[1];
+        assert!(fixed_leg_bps != 0.0, "fixed leg BPS is zero");
+        let annuity = fixed_leg_bps.abs() / BASIS_POINT;
+        let forward_rate = arguments.fixed_rate - swap_results.npv / (fixed_leg_bps / BASIS_POINT);
+
+        // the spread is equivalent to a change of the fixed rate
+        let correction = arguments.spread * (floating_leg_bps / fixed_leg_bps).abs();
+        let strike = arguments.fixed_rate - correction;
+        let atm_forward = forward_rate - correction;
+
+        let swap_length = volatility
+            .day_counter()
+            .year_fraction_simple(&arguments.swap_start_date, &arguments.swap_maturity_date);
+        let std_dev = if exercise_date > volatility.reference_date() {
+            volatility
+                .black_variance(&exercise_date, swap_length, strike, false)
+                .sqrt()
+        } else {
+            0.0
+        };
+        let option_type = match arguments.swap_type {
+            SwapType::Payer => OptionType::Call,
+            SwapType::Receiver => OptionType::Put,
+        };
+        let npv = annuity
+            * black_formula(
+                option_type,
+                strike,
+                atm_forward,
+                std_dev,
+                1.0,
+                volatility.displacement(),
+            );
+
+        SwaptionResults {
+            npv,
+            error_estimate: 0.0,
+            valuation_date: swap_results.valuation_date,
+            annuity,
+            forward_rate,
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.discount_curve.register_observer(observer.clone());
+        self.volatility.register_observer(observer);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        context::pricing_context::PricingContext,
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            schedule::Schedule,
+            schedulebuilder::ScheduleBuilder,
+            timeunit::TimeUnit::{Days, Months, Years},
+        },
+        handle::{Handle, RelinkableHandle},
+        indexes::iborindex::IborIndex,
+        instruments::{
+            exercise::Exercise, instrument::Instrument, swap::SwapType, swaption::Swaption,
+            vanillaswap::VanillaSwap,
+        },
+        maths::distributions::normaldistribution::CumulativeNormalDistribution,
+        pricingengines::{
+            pricingengine_test_util::CountingEngine,
+            swap::discountingswapengine::DiscountingSwapEngine,
+        },
+        rates::compounding::Compounding::Continuous,
+        termstructures::{
+            flatforward::FlatForward,
+            volatility::{
+                constantswaptionvolatility::ConstantSwaptionVolatility,
+                swaptionvolatilitystructure::SwaptionVolatilityStructure,
+            },
+            yieldtermstructure::YieldTermStructure,
+        },
+        types::{Rate, Real},
+    };
+
+    use super::BlackSwaptionEngine;
+
+    struct CommonVars {
+        today: Date,
+        exercise_date: Date,
+        curve: Handle<dyn YieldTermStructure>,
+        index: Rc<IborIndex>,
+        engine: Rc<BlackSwaptionEngine>,
+    }
+
+    impl CommonVars {
+        fn new(volatility: Real) -> Self {
+            let today = Date::new(15, January, 2024);
+            let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+                today,
+                0.035,
+                DayCounter::actual365_fixed(),
+                Continuous,
+                Annual,
+            )));
+            let index = Rc::new(IborIndex::new(
+                "Euribor",
+                Period::new(6, Months),
+                2,
+                Target::new(),
+                ModifiedFollowing,
+                false,
+                DayCounter::actual360(),
+                curve.clone(),
+            ));
+            let vol: Handle<dyn SwaptionVolatilityStructure> = Handle::new(Rc::new(
+                ConstantSwaptionVolatility::new(today, volatility, DayCounter::actual365_fixed()),
+            ));
+            Self {
+                today,
+                exercise_date: Date::new(15, January, 2025),
+                engine: Rc::new(BlackSwaptionEngine::new(curve.clone(), vol)),
+                curve,
+                index,
+            }
+        }
+
+        /// 5 years swap starting two business days after the exercise date
+        fn make_swap(&self, swap_type: SwapType, fixed_rate: Rate) -> Rc<VanillaSwap> {
+            let start = Target::new().advance_by_units(
+                self.exercise_date,
+                2,
+                Days,
+                ModifiedFollowing,
+                false,
+            );
+            let schedule = |tenor: Period| -> Schedule {
+                ScheduleBuilder::new(
+                    PricingContext::new(self.today),
+                    start,
+                    start + Period::new(5, Years),
+                    tenor,
+                    Target::new(),
+                )
+                .with_convention(ModifiedFollowing)
+                .build()
+            };
+            Rc::new(VanillaSwap::new(
+                swap_type,
+                1_000_000.0,
+                schedule(Period::new(1, Years)),
+                fixed_rate,
+                DayCounter::bond_basis(),
+                schedule(Period::new(6, Months)),
+                self.index.clone(),
+                0.0,
+                DayCounter::actual360(),
+                Rc::new(DiscountingSwapEngine::new(self.curve.clone(), None)),
+            ))
+        }
+
+        fn make_swaption(&self, swap: Rc<VanillaSwap>) -> Swaption {
+            Swaption::new(
+                swap,
+                Exercise::european(self.exercise_date),
+                self.engine.clone(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_atm_swaption_payer_receiver_symmetry() {
+        let volatility = 0.2;
+        let vars = CommonVars::new(volatility);
+        let fair_rate = vars.make_swap(SwapType::Payer, 0.03).fair_rate().unwrap();
+
+        let payer = vars.make_swaption(vars.make_swap(SwapType::Payer, fair_rate));
+        let receiver = vars.make_swaption(vars.make_swap(SwapType::Receiver, fair_rate));
+        assert!((payer.underlying_fair_rate().unwrap() - fair_rate).abs() < 1.0e-12);
+        assert!(
+            (payer.npv() - receiver.npv()).abs() < 1.0e-8,
+            "payer {} and receiver {} differ at the money",
+            payer.npv(),
+            receiver.npv()
+        );
+
+        // at the money, the value is `annuity * F * (2 N(σ√T / 2) - 1)`
+        let results = payer.results();
+        assert!((results.forward_rate - fair_rate).abs() < 1.0e-12);
+        let t = (vars.exercise_date - vars.today) as Real / 365.0;
+        let std_dev = volatility * t.sqrt();
+        let cnd = CumulativeNormalDistribution::default();
+        let expected = results.annuity * fair_rate * (2.0 * cnd.value(0.5 * std_dev) - 1.0);
+        assert!(
+            (payer.npv() - expected).abs() < 1.0e-8,
+            "calculated {}, expected {}",
+            payer.npv(),
+            expected
+        );
+        assert!(payer.npv() > 0.0);
+    }
+
+    #[test]
+    fn test_swaption_parity() {
+        let vars = CommonVars::new(0.3);
+        for strike in [0.02, 0.035, 0.05] {
+            let payer_swap = vars.make_swap(SwapType::Payer, strike);
+            let payer = vars.make_swaption(payer_swap.clone());
+            let receiver = vars.make_swaption(vars.make_swap(SwapType::Receiver, strike));
+            // payer - receiver is the forward starting payer swap
+            assert!(
+                (payer.npv() - receiver.npv() - payer_swap.npv()).abs() < 1.0e-8,
+                "strike {}: payer {}, receiver {}, swap {}",
+                strike,
+                payer.npv(),
+                receiver.npv(),
+                payer_swap.npv()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cached_results() {
+        let vars = CommonVars::new(0.2);
+        let vol: RelinkableHandle<dyn SwaptionVolatilityStructure> =
+            RelinkableHandle::new(Rc::new(ConstantSwaptionVolatility::new(
+                vars.today,
+                0.2,
+                DayCounter::actual365_fixed(),
+            )));
+        let engine = Rc::new(CountingEngine::new(BlackSwaptionEngine::new(
+            vars.curve.clone(),
+            vol.handle(),
+        )));
+        let swaption = Swaption::new(
+            vars.make_swap(SwapType::Payer, 0.035),
+            Exercise::european(vars.exercise_date),
+            engine.clone(),
+        );
+
+        // the second call uses the cached results
+        let npv = swaption.npv();
+        assert_eq!(swaption.npv(), npv);
+        assert_eq!(engine.calculations.get(), 1);
+
+        // relinking the volatility invalidates them
+        vol.link_to(Rc::new(ConstantSwaptionVolatility::new(
+            vars.today,
+            0.3,
+            DayCounter::actual365_fixed(),
+        )));
+        assert!(!swaption.cache().unwrap().is_calculated());
+        assert!(swaption.npv() > npv);
+        assert_eq!(engine.calculations.get(), 2);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod analyticeuropeanengine;
+pub mod binomialengine;
+pub mod fdblackscholesvanillaengine;
+pub mod mceuropeanengine;
@@ -0,0 +1,234 @@
+use std::rc::Rc;
+
+use crate::{
+    instruments::{
+        exercise::ExerciseType,
+        payoffs::OptionType,
+        vanillaoption::{VanillaOptionArguments, VanillaOptionResults},
+    },
+    maths::distributions::normaldistribution::{CumulativeNormalDistribution, NormalDistribution},
+    patterns::observable::Observer,
+    pricingengines::pricingengine::PricingEngine,
+    processes::blackscholesprocess::BlackScholesProcess,
+    types::Real,
+};
+
+/// Pricing engine for European vanilla options using analytical formulae
+///
+/// The value and greeks are given by the Black-Scholes-Merton formula applied to the forward
+/// price of the underlying, with the variance read from the volatility term structure of the
+/// process at the option strike. Discrete dividends of the process paid before expiry are
+/// handled with the escrowed dividend model, the formula being applied to the spot net of
+/// their present value; rho and theta then ignore the sensitivity of that present value.
+pub struct AnalyticEuropeanEngine {
+    pub process: Rc<BlackScholesProcess>,
+}
+
+impl AnalyticEuropeanEngine {
+    pub fn new(process: Rc<BlackScholesProcess>) -> Self {
+        Self { process }
+    }
+}
+
+impl PricingEngine for AnalyticEuropeanEngine {
+    type A = VanillaOptionArguments;
+    type R = VanillaOptionResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        assert_eq!(
+            arguments.exercise.exercise_type,
+            ExerciseType::European,
+            "not an European option"
+        );
+        let process = &self.process;
+        let payoff = arguments.payoff;
+        let maturity_date = arguments.exercise.last_date();
+        let strike = payoff.strike;
+
+        let risk_free_rate = process.risk_free_rate.current_link();
+        let dividend_yield = process.dividend_yield.current_link();
+        let black_volatility = process.black_volatility.current_link();
+
+        let maturity = process.time(&maturity_date);
+        assert!(maturity > 0.0, "option expired on {:?}", maturity_date);
+        let variance = black_volatility.black_variance(&maturity_date, strike, false);
+        assert!(variance > 0.0, "non-positive variance ({}) given", variance);
+        let std_dev = variance.sqrt();
+
+        // discrete dividends are taken out of the spot, as in the escrowed dividend model
+        let underlying = process.escrowed_underlying(&maturity_date);
+        let spot = underlying.spot;
+        let risk_free_discount = risk_free_rate.discount_from_date(&maturity_date, false);
+        let dividend_discount = dividend_yield.discount_from_date(&maturity_date, false);
+        let forward = spot * dividend_discount / risk_free_discount;
+
+        let d1 = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+        let d2 = d1 - std_dev;
+        let omega = match payoff.option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+        let cnd = CumulativeNormalDistribution::default();
+        let n_d1 = cnd.value(omega * d1);
+        let n_d2 = cnd.value(omega * d2);
+        let density_d1 = NormalDistribution::default().value(d1);
+
+        let npv = risk_free_discount * omega * (forward * n_d1 - strike * n_d2);
+        let delta = omega * dividend_discount * n_d1;
+        let gamma = dividend_discount * density_d1 / (spot * std_dev);
+        let vega = spot * dividend_discount * density_d1 * maturity.sqrt();
+        let rho = omega * maturity * risk_free_discount * strike * n_d2;
+        let dividend_rho = -omega * maturity * dividend_discount * spot * n_d1;
+
+        // theta follows from the Black-Scholes PDE, using the zero rates to maturity
+        let r = -risk_free_discount.ln() / maturity;
+        let q = -dividend_discount.ln() / maturity;
+        let theta =
+            r * npv - (r - q) * spot * delta - 0.5 * variance / maturity * spot * spot * gamma;
+
+        // delta and gamma with respect to the spot rather than the escrowed spot
+        let delta = underlying.delta(delta);
+        let gamma = underlying.gamma(gamma);
+
+        VanillaOptionResults {
+            npv,
+            error_estimate: Real::default(),
+            valuation_date: risk_free_rate.reference_date(),
+            delta: Some(delta),
+            gamma: Some(gamma),
+            vega: Some(vega),
+            theta: Some(theta),
+            rho: Some(rho),
+            dividend_rho: Some(dividend_rho),
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.process.register_observer(observer);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        cashflows::dividend::{Dividend, DividendSchedule},
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        instruments::{
+            exercise::Exercise,
+            instrument::Instrument,
+            payoffs::{OptionType, PlainVanillaPayoff},
+            vanillaoption::VanillaOption,
+        },
+        processes::process_test_util::flat_process,
+    };
+
+    use super::AnalyticEuropeanEngine;
+
+    fn option(today: Date, option_type: OptionType) -> VanillaOption {
+        let process = Rc::new(flat_process(
+            today,
+            100.0,
+            0.0,
+            0.05,
+            0.20,
+            DayCounter::actual360(),
+        ));
+        VanillaOption::new(
+            PlainVanillaPayoff::new(option_type, 100.0),
+            // one year with Actual/360
+            Exercise::european(today + 360),
+            Rc::new(AnalyticEuropeanEngine::new(process)),
+        )
+    }
+
+    fn check(name: &str, calculated: f64, expected: f64) {
+        assert!(
+            (calculated - expected).abs() < 1.0e-12,
+            "{}: calculated {}, expected {}",
+            name,
+            calculated,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_analytic_european_call() {
+        let today = Date::new(15, March, 2023);
+        let option = option(today, OptionType::Call);
+        let results = option.calculate();
+        assert_eq!(results.valuation_date, today);
+        assert_eq!(results.error_estimate, 0.0);
+        check("npv", results.npv, 10.450583572185565);
+        check("delta", option.delta(), 0.6368306511756191);
+        check("gamma", option.gamma(), 0.018762017345846895);
+        check("vega", option.vega(), 37.52403469169379);
+        check("theta", option.theta(), -6.414027546438197);
+        check("rho", option.rho(), 53.232481545376345);
+        check("dividend rho", option.dividend_rho(), -63.68306511756191);
+    }
+
+    #[test]
+    fn test_analytic_european_put() {
+        let today = Date::new(15, March, 2023);
+        let option = option(today, OptionType::Put);
+        check("npv", option.npv(), 5.573526022256971);
+        check("delta", option.delta(), -0.3631693488243809);
+        check("gamma", option.gamma(), 0.018762017345846895);
+        check("vega", option.vega(), 37.52403469169379);
+        check("theta", option.theta(), -1.657880423934627);
+        check("rho", option.rho(), -41.89046090469506);
+        check("dividend rho", option.dividend_rho(), 36.31693488243809);
+
+        // put-call parity: C - P = S - K exp(-rT)
+        let call = self::option(today, OptionType::Call);
+        check(
+            "parity",
+            call.npv() - option.npv(),
+            100.0 - 100.0 * (-0.05f64).exp(),
+        );
+    }
+
+    #[test]
+    fn test_discrete_dividend() {
+        let today = Date::new(15, March, 2023);
+        let process = |spot: f64, dividends: DividendSchedule| {
+            Rc::new(
+                flat_process(today, spot, 0.0, 0.05, 0.20, DayCounter::actual360())
+                    .with_dividends(dividends),
+            )
+        };
+        let call = |process| {
+            VanillaOption::new(
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                Exercise::european(today + 360),
+                Rc::new(AnalyticEuropeanEngine::new(process)),
+            )
+        };
+
+        // a dividend of 3 going ex in six months; the one after expiry is ignored
+        let dividends = vec![
+            Dividend::fixed(today + 180, 3.0),
+            Dividend::fixed(today + 400, 3.0),
+        ];
+        let with_dividend = call(process(100.0, dividends));
+        let escrowed_spot = 100.0 - 3.0 * (-0.05 * 0.5_f64).exp();
+        let adjusted = call(process(escrowed_spot, vec![]));
+        check("npv", with_dividend.npv(), adjusted.npv());
+        check("delta", with_dividend.delta(), adjusted.delta());
+        check("gamma", with_dividend.gamma(), adjusted.gamma());
+        assert!(with_dividend.npv() < call(process(100.0, vec![])).npv());
+
+        // a fractional dividend scales the spot, and the delta with respect to it
+        let with_dividend = call(process(
+            100.0,
+            vec![Dividend::fractional(today + 180, 0.03)],
+        ));
+        let adjusted = call(process(97.0, vec![]));
+        check("npv", with_dividend.npv(), adjusted.npv());
+        check("delta", with_dividend.delta(), 0.97 * adjusted.delta());
+    }
+}
@@ -0,0 +1,366 @@
+use std::rc::Rc;
+
+use crate::{
+    instruments::{
+        exercise::ExerciseType,
+        payoffs::Payoff,
+        vanillaoption::{VanillaOptionArguments, VanillaOptionResults},
+    },
+    patterns::observable::Observer,
+    pricingengines::pricingengine::PricingEngine,
+    processes::blackscholesprocess::BlackScholesProcess,
+    types::{Real, Size, Time},
+};
+
+/// Type of binomial tree used to discretize the Black-Scholes process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeType {
+    /// Cox-Ross-Rubinstein: symmetric moves in the logarithm of the underlying
+    CoxRossRubinstein,
+    /// Jarrow-Rudd: equal probabilities of up and down moves
+    JarrowRudd,
+    /// Tian: moves matching the first three moments of the lognormal distribution
+    Tian,
+    /// Leisen-Reimer: probabilities from the Peizer-Pratt inversion of the Black-Scholes
+    /// formula; an odd number of steps is always used
+    LeisenReimer,
+}
+
+/// Pricing engine for vanilla options using binomial trees
+///
+/// Both European and American exercise are supported. The rates and volatility are taken as
+/// constant, and equal to the zero rates and the Black volatility to maturity. The tree is
+/// built on the escrowed spot, net of the discrete dividends of the process.
+pub struct BinomialVanillaEngine {
+    pub process: Rc<BlackScholesProcess>,
+    pub tree_type: TreeType,
+    pub time_steps: Size,
+}
+
+impl BinomialVanillaEngine {
+    pub fn new(process: Rc<BlackScholesProcess>, tree_type: TreeType, time_steps: Size) -> Self {
+        assert!(
+            time_steps >= 2,
+            "at least 2 time steps required, {} provided",
+            time_steps
+        );
+        Self {
+            process,
+            tree_type,
+            time_steps,
+        }
+    }
+}
+
+/// Up and down factors and up probability of a step of a recombining tree
+struct Tree {
+    up: Real,
+    down: Real,
+    probability_up: Real,
+}
+
+impl Tree {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        tree_type: TreeType,
+        spot: Real,
+        strike: Real,
+        r: Real,
+        q: Real,
+        volatility: Real,
+        maturity: Time,
+        steps: Size,
+    ) -> Self {
+        let dt = maturity / steps as Real;
+        let drift_per_step = (r - q - 0.5 * volatility * volatility) * dt;
+        match tree_type {
+            TreeType::CoxRossRubinstein => {
+                let dx = volatility * dt.sqrt();
+                Self {
+                    up: dx.exp(),
+                    down: (-dx).exp(),
+                    probability_up: 0.5 + 0.5 * drift_per_step / dx,
+                }
+            }
+            TreeType::JarrowRudd => {
+                let dx = volatility * dt.sqrt();
+                Self {
+                    up: (drift_per_step + dx).exp(),
+                    down: (drift_per_step - dx).exp(),
+                    probability_up: 0.5,
+                }
+            }
+            TreeType::Tian => {
+                let v = (volatility * volatility * dt).exp();
+                let m = drift_per_step.exp() * v.sqrt();
+                let root = (v * v + 2.0 * v - 3.0).sqrt();
+                let up = 0.5 * m * v * (v + 1.0 + root);
+                let down = 0.5 * m * v * (v + 1.0 - root);
+                Self {
+                    up,
+                    down,
+                    probability_up: (m - down) / (up - down),
+                }
+            }
+            TreeType::LeisenReimer => {
+                let std_dev = volatility * maturity.sqrt();
+                let d1 = ((spot / strike).ln() + (r - q) * maturity) / std_dev + 0.5 * std_dev;
+                let d2 = d1 - std_dev;
+                let probability_up = peizer_pratt_inversion(d2, steps);
+                let probability_dash = peizer_pratt_inversion(d1, steps);
+                let growth = ((r - q) * dt).exp();
+                let up = growth * probability_dash / probability_up;
+                Self {
+                    up,
+                    down: (growth - probability_up * up) / (1.0 - probability_up),
+                    probability_up,
+                }
+            }
+        }
+    }
+
+    /// Value of the underlying at the `j`-th node of step `i`
+    fn underlying(&self, spot: Real, i: Size, j: Size) -> Real {
+        spot * self.up.powi(j as i32) * self.down.powi((i - j) as i32)
+    }
+}
+
+/// Peizer-Pratt method 2 inversion of the cumulative normal distribution
+fn peizer_pratt_inversion(z: Real, n: Size) -> Real {
+    assert!(n % 2 == 1, "n must be odd");
+    let n = n as Real;
+    let result = z / (n + 1.0 / 3.0 + 0.1 / (n + 1.0));
+    let result = (-result * result * (n + 1.0 / 6.0)).exp();
+    0.5 + z.signum() * 0.5 * (1.0 - result).sqrt()
+}
+
+impl PricingEngine for BinomialVanillaEngine {
+    type A = VanillaOptionArguments;
+    type R = VanillaOptionResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        let exercise_type = arguments.exercise.exercise_type;
+        assert!(
+            exercise_type != ExerciseType::Bermudan,
+            "Bermudan exercise not supported"
+        );
+        let american = exercise_type == ExerciseType::American;
+        let payoff = arguments.payoff;
+        let strike = payoff.strike;
+
+        let process = &self.process;
+        let risk_free_rate = process.risk_free_rate.current_link();
+        let dividend_yield = process.dividend_yield.current_link();
+        let black_volatility = process.black_volatility.current_link();
+
+        let maturity_date = arguments.exercise.last_date();
+        let maturity = process.time(&maturity_date);
+        assert!(maturity > 0.0, "option expired on {:?}", maturity_date);
+        // discrete dividends are taken out of the spot, as in the escrowed dividend model
+        let underlying = process.escrowed_underlying(&maturity_date);
+        let spot = underlying.spot;
+
+        let r = -risk_free_rate
+            .discount_from_date(&maturity_date, false)
+            .ln()
+            / maturity;
+        let q = -dividend_yield
+            .discount_from_date(&maturity_date, false)
+            .ln()
+            / maturity;
+        let volatility = black_volatility.black_vol(&maturity_date, strike, false);
+
+        let steps = match self.tree_type {
+            TreeType::LeisenReimer if self.time_steps % 2 == 0 => self.time_steps + 1,
+            _ => self.time_steps,
+        };
+        let tree = Tree::new(
+            self.tree_type,
+            spot,
+            strike,
+            r,
+            q,
+            volatility,
+            maturity,
+            steps,
+        );
+        let dt = maturity / steps as Real;
+        let discount = (-r * dt).exp();
+
+        // roll back from expiry, keeping the values at the first two steps for the greeks
+        let mut values: Vec<Real> = (0..=steps)
+            .map(|j| payoff.value(tree.underlying(spot, steps, j)))
+            .collect();
+        let mut step_values: [Vec<Real>; 3] = Default::default();
+        for i in (0..steps).rev() {
+            for j in 0..=i {
+                let continuation = discount
+                    * (tree.probability_up * values[j + 1]
+                        + (1.0 - tree.probability_up) * values[j]);
+                values[j] = if american {
+                    continuation.max(payoff.value(tree.underlying(spot, i, j)))
+                } else {
+                    continuation
+                };
+            }
+            values.truncate(i + 1);
+            if i <= 2 {
+                step_values[i] = values.clone();
+            }
+        }
+
+        let npv = step_values[0][0];
+        let (s1d, s1u) = (tree.underlying(spot, 1, 0), tree.underlying(spot, 1, 1));
+        let delta = (step_values[1][1] - step_values[1][0]) / (s1u - s1d);
+
+        let (s2d, s2m, s2u) = (
+            tree.underlying(spot, 2, 0),
+            tree.underlying(spot, 2, 1),
+            tree.underlying(spot, 2, 2),
+        );
+        let (p2d, p2m, p2u) = (step_values[2][0], step_values[2][1], step_values[2][2]);
+        let delta_up = (p2u - p2m) / (s2u - s2m);
+        let delta_down = (p2m - p2d) / (s2m - s2d);
+        let gamma = (delta_up - delta_down) / (0.5 * (s2u - s2d));
+        // theta from the central node two steps ahead, corrected for the spot drift
+        let theta =
+            (p2m - npv - delta * (s2m - spot) - 0.5 * gamma * (s2m - spot).powi(2)) / (2.0 * dt);
+
+        // delta and gamma with respect to the spot rather than the escrowed spot
+        let delta = underlying.delta(delta);
+        let gamma = underlying.gamma(gamma);
+
+        VanillaOptionResults {
+            npv,
+            error_estimate: Real::default(),
+            valuation_date: risk_free_rate.reference_date(),
+            delta: Some(delta),
+            gamma: Some(gamma),
+            vega: None,
+            theta: Some(theta),
+            rho: None,
+            dividend_rho: None,
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.process.register_observer(observer);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        instruments::{
+            exercise::Exercise,
+            instrument::Instrument,
+            payoffs::{OptionType, Payoff, PlainVanillaPayoff},
+            vanillaoption::VanillaOption,
+        },
+        pricingengines::vanilla::{
+            analyticeuropeanengine::AnalyticEuropeanEngine,
+            fdblackscholesvanillaengine::FdBlackScholesVanillaEngine,
+        },
+        processes::{blackscholesprocess::BlackScholesProcess, process_test_util::flat_process},
+    };
+
+    use super::{BinomialVanillaEngine, TreeType};
+
+    fn process(today: Date, spot: f64, rate: f64, vol: f64) -> Rc<BlackScholesProcess> {
+        Rc::new(flat_process(
+            today,
+            spot,
+            0.0,
+            rate,
+            vol,
+            DayCounter::actual360(),
+        ))
+    }
+
+    #[test]
+    fn test_binomial_european_call() {
+        let today = Date::new(15, March, 2023);
+        let process = process(today, 100.0, 0.05, 0.20);
+        let payoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let exercise = Exercise::european(today + 360);
+        let analytic = VanillaOption::new(
+            payoff,
+            exercise.clone(),
+            Rc::new(AnalyticEuropeanEngine::new(process.clone())),
+        );
+
+        // the Leisen-Reimer tree converges much faster than the others for at-the-money options
+        for (tree_type, tolerance) in [
+            (TreeType::CoxRossRubinstein, 5.0e-3),
+            (TreeType::JarrowRudd, 5.0e-3),
+            (TreeType::Tian, 5.0e-3),
+            (TreeType::LeisenReimer, 1.0e-3),
+        ] {
+            let binomial = VanillaOption::new(
+                payoff,
+                exercise.clone(),
+                Rc::new(BinomialVanillaEngine::new(process.clone(), tree_type, 500)),
+            );
+            assert!(
+                (binomial.npv() - analytic.npv()).abs() < tolerance,
+                "{:?}: binomial value {}, analytic value {}",
+                tree_type,
+                binomial.npv(),
+                analytic.npv()
+            );
+            assert!((binomial.delta() - analytic.delta()).abs() < 1.0e-3);
+            assert!((binomial.gamma() - analytic.gamma()).abs() < 1.0e-4);
+            assert!((binomial.theta() - analytic.theta()).abs() < 1.0e-2);
+        }
+    }
+
+    #[test]
+    fn test_binomial_american_put_converges() {
+        let today = Date::new(15, March, 2023);
+        let process = process(today, 36.0, 0.06, 0.20);
+        let payoff = PlainVanillaPayoff::new(OptionType::Put, 40.0);
+        let exercise = Exercise::american(today, today + 360);
+        let fd = VanillaOption::new(
+            payoff,
+            exercise.clone(),
+            Rc::new(FdBlackScholesVanillaEngine::new(
+                process.clone(),
+                800,
+                800,
+                2,
+            )),
+        )
+        .npv();
+        let error = |tree_type, steps| {
+            let engine = BinomialVanillaEngine::new(process.clone(), tree_type, steps);
+            let option = VanillaOption::new(payoff, exercise.clone(), Rc::new(engine));
+            assert!(option.npv() > payoff.value(36.0));
+            (option.npv() - fd).abs()
+        };
+
+        let errors: Vec<f64> = [25, 100, 800]
+            .iter()
+            .map(|steps| error(TreeType::LeisenReimer, *steps))
+            .collect();
+        assert!(
+            errors[2] < errors[1] && errors[1] < errors[0],
+            "errors {:?} do not decrease",
+            errors
+        );
+        assert!(errors[2] < 1.0e-3);
+
+        // the other trees oscillate around the limit, but get as close
+        for tree_type in [
+            TreeType::CoxRossRubinstein,
+            TreeType::JarrowRudd,
+            TreeType::Tian,
+        ] {
+            assert!(error(tree_type, 800) < 2.0e-3);
+        }
+    }
+}
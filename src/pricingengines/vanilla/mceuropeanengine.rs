@@ -0,0 +1,292 @@
+use std::rc::Rc;
+
+use crate::{
+    instruments::{
+        exercise::ExerciseType,
+        payoffs::Payoff,
+        vanillaoption::{VanillaOptionArguments, VanillaOptionResults},
+    },
+    maths::randomnumbers::{
+        boxmullergaussianrng::BoxMullerGaussianRng, mt19937uniformrng::MersenneTwisterUniformRng,
+        sample::RandomNumberGenerator,
+    },
+    maths::{statistics::Statistics, timegrid::TimeGrid},
+    patterns::observable::Observer,
+    pricingengines::pricingengine::PricingEngine,
+    processes::blackscholesprocess::BlackScholesProcess,
+    types::{Real, Size},
+};
+
+/// European option pricing engine using Monte Carlo simulation
+///
+/// The underlying is evolved to expiry along a regular [TimeGrid], by default with a single
+/// step. Steps of the Black-Scholes process are exact for deterministic rates and volatility,
+/// so more steps only change which random numbers are drawn. The error estimate of the results
+/// is the standard error of the discounted payoffs.
+pub struct MCEuropeanEngine {
+    pub process: Rc<BlackScholesProcess>,
+    pub samples: Size,
+    pub antithetic_variate: bool,
+    pub seed: u32,
+    pub time_steps: Size,
+}
+
+impl MCEuropeanEngine {
+    pub fn new(
+        process: Rc<BlackScholesProcess>,
+        samples: Size,
+        antithetic_variate: bool,
+        seed: u32,
+    ) -> Self {
+        assert!(
+            samples > 1,
+            "at least 2 samples are required, {} given",
+            samples
+        );
+        Self {
+            process,
+            samples,
+            antithetic_variate,
+            seed,
+            time_steps: 1,
+        }
+    }
+
+    /// Evolves the paths in `time_steps` steps of equal length instead of a single one
+    pub fn with_time_steps(mut self, time_steps: Size) -> Self {
+        assert!(time_steps > 0, "null number of time steps given");
+        self.time_steps = time_steps;
+        self
+    }
+}
+
+impl PricingEngine for MCEuropeanEngine {
+    type A = VanillaOptionArguments;
+    type R = VanillaOptionResults;
+
+    fn calculate(&self, arguments: Self::A) -> Self::R {
+        let (mut mean, mut error_estimate) = (0.0, 0.0);
+        self.simulate(&arguments, &[self.samples], |_, statistics| {
+            mean = statistics.mean();
+            error_estimate = statistics.error_estimate();
+        });
+
+        let risk_free_rate = self.process.risk_free_rate.current_link();
+        let discount = self.discount(&arguments);
+        VanillaOptionResults {
+            npv: discount * mean,
+            error_estimate: discount * error_estimate,
+            valuation_date: risk_free_rate.reference_date(),
+            delta: None,
+            gamma: None,
+            vega: None,
+            theta: None,
+            rho: None,
+            dividend_rho: None,
+        }
+    }
+
+    fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.process.register_observer(observer);
+    }
+}
+
+impl MCEuropeanEngine {
+    /// Convergence table of the simulation: the value and error estimate after each of the
+    /// given numbers of samples.
+    ///
+    /// The sample counts must be increasing; a single simulation is run up to the last one,
+    /// so that each row extends the samples of the previous one. The number of samples of the
+    /// engine is ignored.
+    pub fn run_with_convergence(
+        &self,
+        arguments: VanillaOptionArguments,
+        samples: &[Size],
+    ) -> Vec<(Size, Real, Real)> {
+        assert!(
+            samples.windows(2).all(|w| w[0] < w[1]),
+            "sample counts must be strictly increasing: {:?}",
+            samples
+        );
+        assert!(
+            samples.first().map_or(true, |n| *n > 1),
+            "at least 2 samples are required, {:?} given",
+            samples
+        );
+        let discount = self.discount(&arguments);
+        let mut table = Vec::with_capacity(samples.len());
+        self.simulate(&arguments, samples, |n, s| {
+            table.push((n, discount * s.mean(), discount * s.error_estimate()))
+        });
+        table
+    }
+
+    /// Draws payoffs at expiry up to the last of the `checkpoints`, passing the statistics of
+    /// the undiscounted payoffs to `on_checkpoint` whenever a checkpoint is reached
+    fn simulate<F>(
+        &self,
+        arguments: &VanillaOptionArguments,
+        checkpoints: &[Size],
+        mut on_checkpoint: F,
+    ) where
+        F: FnMut(Size, &Statistics),
+    {
+        assert_eq!(
+            arguments.exercise.exercise_type,
+            ExerciseType::European,
+            "not an European option"
+        );
+        let process = &self.process;
+        let maturity_date = arguments.exercise.last_date();
+        let grid = TimeGrid::new(process.time(&maturity_date), self.time_steps);
+        // discrete dividends are taken out of the spot, as in the escrowed dividend model
+        let x0 = process.escrowed_underlying(&maturity_date).spot;
+        let payoff = &arguments.payoff;
+        // value at expiry of the path driven by the given draws, or by their opposites
+        let path_end = |draws: &[Real], sign: Real| {
+            draws.iter().enumerate().fold(x0, |x, (i, dw)| {
+                process.evolve(grid[i], x, grid.dt(i), sign * dw)
+            })
+        };
+
+        let mut rng = BoxMullerGaussianRng::new(MersenneTwisterUniformRng::new(self.seed));
+        let mut draws = vec![0.0; self.time_steps];
+        let mut statistics = Statistics::new();
+        for checkpoint in checkpoints {
+            while statistics.samples() < *checkpoint {
+                for dw in draws.iter_mut() {
+                    *dw = rng.next().value;
+                }
+                let mut value = payoff.value(path_end(&draws, 1.0));
+                if self.antithetic_variate {
+                    value = 0.5 * (value + payoff.value(path_end(&draws, -1.0)));
+                }
+                statistics.add(value);
+            }
+            on_checkpoint(*checkpoint, &statistics);
+        }
+    }
+
+    fn discount(&self, arguments: &VanillaOptionArguments) -> Real {
+        self.process
+            .risk_free_rate
+            .current_link()
+            .discount_from_date(&arguments.exercise.last_date(), false)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        instruments::{
+            exercise::Exercise,
+            payoffs::{OptionType, PlainVanillaPayoff},
+            vanillaoption::VanillaOptionArguments,
+        },
+        pricingengines::pricingengine::PricingEngine,
+        processes::{blackscholesprocess::BlackScholesProcess, process_test_util::flat_process},
+    };
+
+    use super::MCEuropeanEngine;
+
+    fn process(today: Date) -> Rc<BlackScholesProcess> {
+        Rc::new(flat_process(
+            today,
+            100.0,
+            0.0,
+            0.05,
+            0.20,
+            DayCounter::actual360(),
+        ))
+    }
+
+    #[test]
+    fn test_mc_european_call() {
+        let today = Date::new(15, March, 2023);
+        let arguments = VanillaOptionArguments {
+            payoff: PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            // one year with Actual/360
+            exercise: Exercise::european(today + 360),
+        };
+        // Black-Scholes value for S = K = 100, r = 5%, q = 0%, vol = 20%, T = 1
+        let expected = 10.450583572185565;
+
+        for antithetic in [false, true] {
+            let engine = MCEuropeanEngine::new(process(today), 50_000, antithetic, 42);
+            let results = engine.calculate(arguments.clone());
+            assert_eq!(results.valuation_date, today);
+            assert!(
+                (results.npv - expected).abs() < 3.0 * results.error_estimate,
+                "MC value {} (error estimate {}) too far from analytic value {}",
+                results.npv,
+                results.error_estimate,
+                expected
+            );
+            assert!(results.error_estimate < 0.1);
+
+            // the same seed reproduces the same value
+            let again = engine.calculate(arguments.clone());
+            assert_eq!(again.npv, results.npv);
+        }
+    }
+
+    #[test]
+    fn test_mc_european_call_time_steps() {
+        let today = Date::new(15, March, 2023);
+        let arguments = VanillaOptionArguments {
+            payoff: PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            exercise: Exercise::european(today + 360),
+        };
+        let expected = 10.450583572185565;
+
+        let engine = MCEuropeanEngine::new(process(today), 20_000, true, 42).with_time_steps(12);
+        assert_eq!(engine.time_steps, 12);
+        let results = engine.calculate(arguments.clone());
+        assert!(
+            (results.npv - expected).abs() < 3.0 * results.error_estimate,
+            "MC value {} (error estimate {}) too far from analytic value {}",
+            results.npv,
+            results.error_estimate,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_convergence_table() {
+        let today = Date::new(15, March, 2023);
+        let arguments = VanillaOptionArguments {
+            payoff: PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            exercise: Exercise::european(today + 360),
+        };
+        let engine = MCEuropeanEngine::new(process(today), 2, false, 42);
+        let samples = [1_000, 4_000, 16_000, 64_000];
+        let table = engine.run_with_convergence(arguments.clone(), &samples);
+        assert_eq!(
+            table.iter().map(|row| row.0).collect::<Vec<_>>(),
+            samples.to_vec()
+        );
+        // quadrupling the samples roughly halves the error
+        for rows in table.windows(2) {
+            let ratio = rows[1].2 / rows[0].2;
+            assert!(
+                (ratio - 0.5).abs() < 0.1,
+                "error went from {} to {}",
+                rows[0].2,
+                rows[1].2
+            );
+        }
+
+        // each row is the value of a simulation with as many samples
+        let engine = MCEuropeanEngine::new(process(today), 16_000, false, 42);
+        let results = engine.calculate(arguments);
+        assert_eq!(
+            (results.npv, results.error_estimate),
+            (table[2].1, table[2].2)
+        );
+    }
+}
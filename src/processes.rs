@@ -1 +1,4 @@
-
+pub mod blackscholesprocess;
+pub mod hullwhite;
+pub(crate) mod process_test_util;
+pub mod vasicek;
@@ -0,0 +1,181 @@
+use std::rc::Rc;
+
+use crate::{
+    cashflows::{
+        cashflow::CashFlow,
+        dividend::{Dividend, DividendSchedule},
+    },
+    datetime::date::Date,
+    handle::Handle,
+    patterns::observable::Observer,
+    quotes::quote::Quote,
+    termstructures::{
+        volatility::blackvoltermstructure::BlackVolTermStructure,
+        yieldtermstructure::YieldTermStructure,
+    },
+    types::{Real, Time},
+};
+
+/// Generalized Black-Scholes stochastic process
+///
+/// This describes the stochastic process governed by
+///
+/// `dS(t, S) = (r(t) - q(t) - σ(t, S)²/2) dt + σ dW_t`
+///
+/// for the logarithm of the underlying, where `r` is the risk-free rate, `q` the dividend yield
+/// and `σ` the Black volatility.
+///
+/// Discrete dividends can be added on top of the dividend yield. They follow the escrowed
+/// dividend model: the process above drives the underlying net of the dividends to be paid
+/// until the option expiry, whose starting value is given by
+/// [escrowed_spot](BlackScholesProcess::escrowed_spot).
+pub struct BlackScholesProcess {
+    pub x0: Handle<dyn Quote>,
+    pub dividend_yield: Handle<dyn YieldTermStructure>,
+    pub risk_free_rate: Handle<dyn YieldTermStructure>,
+    pub black_volatility: Handle<dyn BlackVolTermStructure>,
+    pub dividends: DividendSchedule,
+}
+
+impl BlackScholesProcess {
+    pub fn new(
+        x0: Handle<dyn Quote>,
+        dividend_yield: Handle<dyn YieldTermStructure>,
+        risk_free_rate: Handle<dyn YieldTermStructure>,
+        black_volatility: Handle<dyn BlackVolTermStructure>,
+    ) -> Self {
+        Self {
+            x0,
+            dividend_yield,
+            risk_free_rate,
+            black_volatility,
+            dividends: DividendSchedule::new(),
+        }
+    }
+
+    /// Sets the discrete dividends paid by the underlying
+    pub fn with_dividends(mut self, dividends: DividendSchedule) -> Self {
+        self.dividends = dividends;
+        self
+    }
+
+    /// Register an observer with the handles of the process and with the current spot quote
+    pub fn register_observer(&self, observer: Rc<dyn Observer>) {
+        self.x0.register_observer(observer.clone());
+        self.dividend_yield.register_observer(observer.clone());
+        self.risk_free_rate.register_observer(observer.clone());
+        self.black_volatility.register_observer(observer.clone());
+        if !self.x0.is_empty() {
+            self.x0.current_link().register_observer(observer);
+        }
+    }
+
+    /// Current value of the underlying
+    pub fn x0(&self) -> Real {
+        self.x0.current_link().value()
+    }
+
+    /// Current value of the underlying net of the dividends going ex after today and up to
+    /// `maturity_date`.
+    ///
+    /// Fixed dividends are subtracted at their value discounted on the risk-free curve, while
+    /// fractional ones scale the underlying by one minus their rate. Without dividends this is
+    /// the spot.
+    pub fn escrowed_spot(&self, maturity_date: &Date) -> Real {
+        let risk_free_rate = self.risk_free_rate.current_link();
+        let fixed_dividends: Real = self
+            .dividends_until(maturity_date)
+            .map(|dividend| match dividend {
+                Dividend::Fixed { date, amount } => {
+                    amount * risk_free_rate.discount_from_date(date, false)
+                }
+                Dividend::Fractional { .. } => 0.0,
+            })
+            .sum();
+        self.x0() * self.fractional_dividend_factor(maturity_date) - fixed_dividends
+    }
+
+    /// [Escrowed spot](BlackScholesProcess::escrowed_spot) up to `maturity_date`, together
+    /// with the factor mapping greeks computed against it back to the spot. This is what the
+    /// vanilla engines price from.
+    pub fn escrowed_underlying(&self, maturity_date: &Date) -> EscrowedUnderlying {
+        let spot = self.escrowed_spot(maturity_date);
+        assert!(spot > 0.0, "negative or null underlying given");
+        EscrowedUnderlying {
+            spot,
+            spot_factor: self.fractional_dividend_factor(maturity_date),
+        }
+    }
+
+    /// Derivative of the [escrowed_spot](BlackScholesProcess::escrowed_spot) with respect to
+    /// the spot, i.e. the product of one minus the rates of the fractional dividends going ex
+    /// up to `maturity_date`
+    fn fractional_dividend_factor(&self, maturity_date: &Date) -> Real {
+        self.dividends_until(maturity_date)
+            .map(|dividend| match dividend {
+                Dividend::Fixed { .. } => 1.0,
+                Dividend::Fractional { rate, .. } => 1.0 - rate,
+            })
+            .product()
+    }
+
+    fn dividends_until<'a>(
+        &'a self,
+        maturity_date: &'a Date,
+    ) -> impl Iterator<Item = &'a Dividend> + 'a {
+        let reference_date = self.risk_free_rate.current_link().reference_date();
+        self.dividends.iter().filter(move |dividend| {
+            !dividend.has_occurred(&reference_date, false) && dividend.date() <= *maturity_date
+        })
+    }
+
+    /// Time corresponding to the given date, measured with the day counter of the risk-free
+    /// curve
+    pub fn time(&self, date: &Date) -> Time {
+        self.risk_free_rate
+            .current_link()
+            .time_from_references(date)
+    }
+
+    /// Returns the value of the underlying at time `t0 + dt` given its value `x0` at `t0` and
+    /// the standard normal draw `dw`.
+    ///
+    /// The step is exact for deterministic rates and volatility, as the logarithm of the
+    /// underlying is normally distributed.
+    pub fn evolve(&self, t0: Time, x0: Real, dt: Time, dw: Real) -> Real {
+        let t1 = t0 + dt;
+        let risk_free_rate = self.risk_free_rate.current_link();
+        let dividend_yield = self.dividend_yield.current_link();
+        let black_volatility = self.black_volatility.current_link();
+
+        let forward_factor = dividend_yield.discount_time(t1, true)
+            / dividend_yield.discount_time(t0, true)
+            * risk_free_rate.discount_time(t0, true)
+            / risk_free_rate.discount_time(t1, true);
+        let variance = black_volatility.black_variance_from_time(t1, x0, true)
+            - black_volatility.black_variance_from_time(t0, x0, true);
+        x0 * forward_factor * (-0.5 * variance + variance.sqrt() * dw).exp()
+    }
+}
+
+/// Starting value of the underlying in the escrowed dividend model, as returned by
+/// [escrowed_underlying](BlackScholesProcess::escrowed_underlying)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EscrowedUnderlying {
+    /// Spot net of the dividends paid up to the maturity
+    pub spot: Real,
+    /// Derivative of the escrowed spot with respect to the spot
+    pub spot_factor: Real,
+}
+
+impl EscrowedUnderlying {
+    /// Delta with respect to the spot, given the one with respect to the escrowed spot
+    pub fn delta(&self, escrowed_delta: Real) -> Real {
+        escrowed_delta * self.spot_factor
+    }
+
+    /// Gamma with respect to the spot, given the one with respect to the escrowed spot
+    pub fn gamma(&self, escrowed_gamma: Real) -> Real {
+        escrowed_gamma * self.spot_factor * self.spot_factor
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    datetime::frequency::Frequency,
+    handle::Handle,
+    instruments::payoffs::OptionType,
+    pricingengines::blackformula::black_formula,
+    rates::compounding::Compounding,
+    termstructures::yieldtermstructure::YieldTermStructure,
+    types::{DiscountFactor, Rate, Real, Time},
+};
+
+use super::vasicek::b_factor;
+
+/// Hull-White one-factor short-rate model
+///
+/// This is the extended Vasicek model
+///
+/// `dr(t) = (θ(t) - a r(t)) dt + σ dW_t`
+///
+/// where the time dependent `θ(t)` is fitted so that the model reproduces the discount factors
+/// of the given term structure. Zero-coupon bonds and options on them have closed form prices;
+/// the short rate at time zero is the instantaneous forward rate of the term structure.
+pub struct HullWhite {
+    pub term_structure: Handle<dyn YieldTermStructure>,
+    pub a: Real,
+    pub sigma: Real,
+}
+
+impl HullWhite {
+    pub fn new(term_structure: Handle<dyn YieldTermStructure>, a: Real, sigma: Real) -> Self {
+        assert!(a >= 0.0, "negative mean reversion speed ({}) given", a);
+        assert!(sigma >= 0.0, "negative volatility ({}) given", sigma);
+        Self {
+            term_structure,
+            a,
+            sigma,
+        }
+    }
+
+    /// Short rate at time zero
+    pub fn r0(&self) -> Rate {
+        self.forward_rate(0.0)
+    }
+
+    /// Expected short rate at time `t0 + dt` given its value `r` at `t0`
+    pub fn expectation(&self, t0: Time, r: Rate, dt: Time) -> Rate {
+        let decay = (-self.a * dt).exp();
+        r * decay + self.alpha(t0 + dt) - self.alpha(t0) * decay
+    }
+
+    /// Variance of the short rate at time `t0 + dt` given its value at `t0`
+    pub fn variance(&self, _t0: Time, dt: Time) -> Real {
+        self.sigma * self.sigma * b_factor(2.0 * self.a, dt)
+    }
+
+    /// Price at time `t` of the zero-coupon bond paying one at `maturity`, given the short
+    /// rate `r` at `t`
+    pub fn discount_bond(&self, t: Time, maturity: Time, r: Rate) -> DiscountFactor {
+        self.a_factor(t, maturity) * (-self.b_factor(t, maturity) * r).exp()
+    }
+
+    /// Price at time zero of a European option expiring at `maturity` on the zero-coupon bond
+    /// paying one at `bond_maturity`, with the given strike price
+    pub fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let std_dev = self.sigma
+            * self.b_factor(maturity, bond_maturity)
+            * b_factor(2.0 * self.a, maturity).sqrt();
+        let curve = self.term_structure.current_link();
+        let forward = curve.discount_time(bond_maturity, true);
+        let strike = curve.discount_time(maturity, true) * strike;
+        black_formula(option_type, strike, forward, std_dev, 1.0, 0.0)
+    }
+
+    /// `B(t, T) = (1 - exp(-a (T - t))) / a`
+    pub fn b_factor(&self, t: Time, maturity: Time) -> Real {
+        b_factor(self.a, maturity - t)
+    }
+
+    /// `A(t, T)`, such that the bond price is `A(t, T) exp(-B(t, T) r)`
+    pub fn a_factor(&self, t: Time, maturity: Time) -> Real {
+        let curve = self.term_structure.current_link();
+        let discount1 = curve.discount_time(t, true);
+        let discount2 = curve.discount_time(maturity, true);
+        let b = self.b_factor(t, maturity);
+        let temp = self.sigma * b;
+        let value = b * self.forward_rate(t) - 0.25 * temp * temp * b_factor(self.a, 2.0 * t);
+        value.exp() * discount2 / discount1
+    }
+
+    /// Futures convexity bias, i.e. the difference between the futures rate and the forward
+    /// rate of a deposit from `t` to `maturity`, given the futures price quoted as
+    /// `100 * (1 - rate)` and the parameters of the model.
+    ///
+    /// The bias accounts for both the futures being on a rate rather than a price, and its daily
+    /// marking to market.
+    pub fn convexity_bias(
+        futures_price: Real,
+        t: Time,
+        maturity: Time,
+        sigma: Real,
+        a: Real,
+    ) -> Rate {
+        assert!(
+            futures_price >= 0.0,
+            "negative futures price ({}) not allowed",
+            futures_price
+        );
+        assert!(t >= 0.0, "negative t ({}) not allowed", t);
+        assert!(
+            maturity >= t,
+            "T ({}) must not be less than t ({})",
+            maturity,
+            t
+        );
+        assert!(sigma >= 0.0, "negative sigma ({}) not allowed", sigma);
+        assert!(a >= 0.0, "negative a ({}) not allowed", a);
+
+        let delta_t = maturity - t;
+        let temp_delta_t = b_factor(a, delta_t);
+        let half_sigma_square = 0.5 * sigma * sigma;
+        // lambda adjusts for the futures being on a rate
+        let lambda = half_sigma_square * 2.0 * b_factor(2.0 * a, t) * temp_delta_t * temp_delta_t;
+        // phi is the marking to market adjustment
+        let temp_t = b_factor(a, t);
+        let phi = half_sigma_square * temp_delta_t * temp_t * temp_t;
+        let z = lambda + phi;
+        let futures_rate = (100.0 - futures_price) / 100.0;
+        -(-z).exp_m1() * (futures_rate + 1.0 / delta_t)
+    }
+
+    /// Expected short rate at time `t` as seen from time zero
+    fn alpha(&self, t: Time) -> Rate {
+        let temp = self.sigma * b_factor(self.a, t);
+        self.forward_rate(t) + 0.5 * temp * temp
+    }
+
+    /// Instantaneous forward rate of the term structure at time `t`
+    fn forward_rate(&self, t: Time) -> Rate {
+        self.term_structure
+            .current_link()
+            .forward_rate_time(t, t, Compounding::Continuous, Frequency::Annual, true)
+            .rate
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        handle::Handle,
+        instruments::payoffs::OptionType,
+        termstructures::{
+            nelsonsiegel::NelsonSiegelFitting, yieldtermstructure::YieldTermStructure,
+        },
+    };
+
+    use super::HullWhite;
+
+    fn model_with_sigma(sigma: f64) -> HullWhite {
+        // upward sloping curve, from 2% at the short end to 4%
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(NelsonSiegelFitting::new(
+            Date::new(15, January, 2024),
+            0.04,
+            -0.02,
+            0.01,
+            2.0,
+            DayCounter::actual365_fixed(),
+        )));
+        HullWhite::new(curve, 0.1, sigma)
+    }
+
+    #[test]
+    fn test_fits_term_structure() {
+        let model = model_with_sigma(0.01);
+        let curve = model.term_structure.current_link();
+        assert!((model.r0() - 0.02).abs() < 1.0e-6);
+        for t in [0.25, 1.0, 5.0, 10.0, 30.0] {
+            let bond = model.discount_bond(0.0, t, model.r0());
+            let discount = curve.discount_time(t, false);
+            assert!(
+                (bond - discount).abs() < 1.0e-12,
+                "bond price {} at {} differs from discount {}",
+                bond,
+                t,
+                discount
+            );
+        }
+
+        // without volatility the rate follows the forward curve, and future bond prices are
+        // the forward discount factors
+        let deterministic = model_with_sigma(0.0);
+        let (t, maturity) = (2.0, 7.0);
+        let r = deterministic.expectation(0.0, deterministic.r0(), t);
+        assert!((r - deterministic.forward_rate(t)).abs() < 1.0e-12);
+        let forward = curve.discount_time(maturity, false) / curve.discount_time(t, false);
+        assert!((deterministic.discount_bond(t, maturity, r) - forward).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_convexity_bias() {
+        // no volatility, no bias
+        assert_eq!(HullWhite::convexity_bias(96.0, 1.0, 1.25, 0.0, 0.03), 0.0);
+        // the bias grows with the expiry of the futures
+        let short = HullWhite::convexity_bias(96.0, 1.0, 1.25, 0.01, 0.03);
+        let long = HullWhite::convexity_bias(96.0, 5.0, 5.25, 0.01, 0.03);
+        assert!(short > 0.0 && long > short);
+        // without mean reversion, z = sigma^2 tau t (t + 2 tau) / 2 with tau = T - t
+        let (t, maturity, sigma): (f64, f64, f64) = (0.5, 0.75, 0.01);
+        let tau = maturity - t;
+        let z = 0.5 * sigma * sigma * tau * t * (t + 2.0 * tau);
+        let expected = (1.0 - (-z).exp()) * (0.04 + 1.0 / tau);
+        let bias = HullWhite::convexity_bias(96.0, t, maturity, sigma, 0.0);
+        assert!((bias - expected).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn test_discount_bond_option() {
+        let model = model_with_sigma(0.01);
+        let curve = model.term_structure.current_link();
+        let (maturity, bond_maturity) = (1.0, 5.0);
+        let strike = 0.88;
+        let call = model.discount_bond_option(OptionType::Call, strike, maturity, bond_maturity);
+        let put = model.discount_bond_option(OptionType::Put, strike, maturity, bond_maturity);
+        assert!(call > 0.0 && put > 0.0);
+        // put-call parity
+        let forward = curve.discount_time(bond_maturity, false)
+            - strike * curve.discount_time(maturity, false);
+        assert!((call - put - forward).abs() < 1.0e-12);
+
+        // without volatility the option is worth its discounted intrinsic value
+        let deterministic = model_with_sigma(0.0);
+        let call =
+            deterministic.discount_bond_option(OptionType::Call, strike, maturity, bond_maturity);
+        assert!((call - forward.max(0.0)).abs() < 1.0e-12);
+    }
+}
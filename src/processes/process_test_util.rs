@@ -0,0 +1,45 @@
+#![cfg(test)]
+use std::rc::Rc;
+
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency::Annual},
+    handle::Handle,
+    quotes::{quote::Quote, simplequote::SimpleQuote},
+    rates::compounding::Compounding::Continuous,
+    termstructures::{
+        flatforward::FlatForward,
+        volatility::{
+            blackconstantvol::BlackConstantVol, blackvoltermstructure::BlackVolTermStructure,
+        },
+        yieldtermstructure::YieldTermStructure,
+    },
+    types::{Rate, Real, Volatility},
+};
+
+use super::blackscholesprocess::BlackScholesProcess;
+
+/// Black-Scholes process with a constant spot, flat continuously compounded rates and a
+/// constant volatility, all starting from `today`
+pub(crate) fn flat_process(
+    today: Date,
+    spot: Real,
+    dividend_yield: Rate,
+    risk_free_rate: Rate,
+    volatility: Volatility,
+    day_counter: DayCounter,
+) -> BlackScholesProcess {
+    let flat = |rate: Rate| -> Handle<dyn YieldTermStructure> {
+        Handle::new(Rc::new(FlatForward::new(
+            today,
+            rate,
+            day_counter.clone(),
+            Continuous,
+            Annual,
+        )))
+    };
+    let spot: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(spot)));
+    let volatility: Handle<dyn BlackVolTermStructure> = Handle::new(Rc::new(
+        BlackConstantVol::new(today, volatility, day_counter.clone()),
+    ));
+    BlackScholesProcess::new(spot, flat(dividend_yield), flat(risk_free_rate), volatility)
+}
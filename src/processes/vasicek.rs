@@ -0,0 +1,135 @@
+use crate::{
+    instruments::payoffs::OptionType,
+    pricingengines::blackformula::black_formula,
+    types::{DiscountFactor, Rate, Real, Time},
+};
+
+/// Vasicek one-factor short-rate model
+///
+/// The short rate follows the mean reverting Ornstein-Uhlenbeck process
+///
+/// `dr(t) = a (b - r(t)) dt + σ dW_t`
+///
+/// where `a` is the speed of mean reversion, `b` the long-term level and `σ` the volatility.
+/// Zero-coupon bonds and options on them have closed form prices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vasicek {
+    pub r0: Rate,
+    pub a: Real,
+    pub b: Rate,
+    pub sigma: Real,
+}
+
+impl Vasicek {
+    pub fn new(r0: Rate, a: Real, b: Rate, sigma: Real) -> Self {
+        assert!(a >= 0.0, "negative mean reversion speed ({}) given", a);
+        assert!(sigma >= 0.0, "negative volatility ({}) given", sigma);
+        Self { r0, a, b, sigma }
+    }
+
+    /// Expected short rate at time `t0 + dt` given its value `r` at `t0`
+    pub fn expectation(&self, _t0: Time, r: Rate, dt: Time) -> Rate {
+        self.b + (r - self.b) * (-self.a * dt).exp()
+    }
+
+    /// Variance of the short rate at time `t0 + dt` given its value at `t0`
+    pub fn variance(&self, _t0: Time, dt: Time) -> Real {
+        self.sigma * self.sigma * b_factor(2.0 * self.a, dt)
+    }
+
+    /// Price at time `t` of the zero-coupon bond paying one at `maturity`, given the short
+    /// rate `r` at `t`
+    pub fn discount_bond(&self, t: Time, maturity: Time, r: Rate) -> DiscountFactor {
+        self.a_factor(t, maturity) * (-self.b_factor(t, maturity) * r).exp()
+    }
+
+    /// Price at time zero of a European option expiring at `maturity` on the zero-coupon bond
+    /// paying one at `bond_maturity`, with the given strike price
+    pub fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let std_dev = self.sigma
+            * self.b_factor(maturity, bond_maturity)
+            * b_factor(2.0 * self.a, maturity).sqrt();
+        let forward = self.discount_bond(0.0, bond_maturity, self.r0);
+        let strike = self.discount_bond(0.0, maturity, self.r0) * strike;
+        black_formula(option_type, strike, forward, std_dev, 1.0, 0.0)
+    }
+
+    /// `B(t, T) = (1 - exp(-a (T - t))) / a`
+    pub fn b_factor(&self, t: Time, maturity: Time) -> Real {
+        b_factor(self.a, maturity - t)
+    }
+
+    /// `A(t, T)`, such that the bond price is `A(t, T) exp(-B(t, T) r)`
+    pub fn a_factor(&self, t: Time, maturity: Time) -> Real {
+        let b = self.b_factor(t, maturity);
+        let sigma2 = self.sigma * self.sigma;
+        if self.a < f64::EPSILON.sqrt() {
+            // no mean reversion: r is a Brownian motion, b being irrelevant
+            let tau = maturity - t;
+            (sigma2 * tau * tau * tau / 6.0).exp()
+        } else {
+            let a2 = self.a * self.a;
+            ((self.b - 0.5 * sigma2 / a2) * (b - (maturity - t)) - 0.25 * sigma2 * b * b / self.a)
+                .exp()
+        }
+    }
+}
+
+/// `(1 - exp(-a t)) / a`, going to `t` as `a` goes to zero
+pub(crate) fn b_factor(a: Real, t: Time) -> Real {
+    if a < f64::EPSILON.sqrt() {
+        t
+    } else {
+        -(-a * t).exp_m1() / a
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::instruments::payoffs::OptionType;
+
+    use super::Vasicek;
+
+    #[test]
+    fn test_discount_bond() {
+        let model = Vasicek::new(0.03, 0.1, 0.05, 0.01);
+        assert!((model.discount_bond(2.0, 2.0, 0.04) - 1.0).abs() < 1.0e-15);
+
+        // without volatility the rate moves deterministically towards b
+        let model = Vasicek::new(0.03, 0.1, 0.05, 0.0);
+        let t: f64 = 5.0;
+        let integral = 0.05 * t + (0.03 - 0.05) * (1.0 - (-0.1 * t).exp()) / 0.1;
+        assert!((model.discount_bond(0.0, t, 0.03) - (-integral).exp()).abs() < 1.0e-15);
+        assert!(
+            (model.expectation(0.0, 0.03, t) - (0.05 - 0.02 * (-0.5_f64).exp())).abs() < 1.0e-15
+        );
+
+        // volatility raises bond prices through convexity
+        let volatile = Vasicek::new(0.03, 0.1, 0.05, 0.02);
+        assert!(volatile.discount_bond(0.0, t, 0.03) > model.discount_bond(0.0, t, 0.03));
+        let variance = 0.02 * 0.02 * (1.0 - (-0.2 * t).exp()) / 0.2;
+        assert!((volatile.variance(0.0, t) - variance).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn test_discount_bond_option() {
+        let model = Vasicek::new(0.03, 0.1, 0.05, 0.01);
+        let (maturity, bond_maturity) = (1.0, 5.0);
+        let strike = 0.85;
+        let call = model.discount_bond_option(OptionType::Call, strike, maturity, bond_maturity);
+        let put = model.discount_bond_option(OptionType::Put, strike, maturity, bond_maturity);
+        assert!(call > 0.0 && put > 0.0);
+        // put-call parity
+        let forward = model.discount_bond(0.0, bond_maturity, model.r0)
+            - strike * model.discount_bond(0.0, maturity, model.r0);
+        assert!((call - put - forward).abs() < 1.0e-12);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::types::Real;
+use std::rc::Rc;
+
+use crate::{patterns::observable::Observer, types::Real};
 
 /// A Quote is a market observable
 pub trait Quote {
@@ -7,4 +9,10 @@ pub trait Quote {
 
     /// Returns true if the Quote holds a valid value
     fn is_valid(&self) -> bool;
+
+    /// Register an observer to be notified when the value changes.
+    ///
+    /// Quotes whose value cannot change can rely on the default implementation, which ignores
+    /// the observer.
+    fn register_observer(&self, _observer: Rc<dyn Observer>) {}
 }
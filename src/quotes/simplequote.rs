@@ -1,6 +1,10 @@
 use crate::{quotes::quote::Quote, types::Real};
 
 /// Market element returning a stored value
+///
+/// The value doesn't change once the quote is shared, so the quote has no observers; a new value
+/// is published by relinking the [RelinkableHandle](crate::handle::RelinkableHandle) holding the
+/// quote, which notifies the observers of the handle.
 #[derive(Default)]
 pub struct SimpleQuote {
     pub value: Real,
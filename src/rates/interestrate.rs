@@ -52,7 +52,7 @@ impl InterestRate {
 
     /// Returns the compound (a.k.a capitalization) factor implied by the rate compounded at time t.
     /// Time must be measured using InterestRate's own day counter.
-    pub fn compound_factor(&self, t: Time) -> Real {        
+    pub fn compound_factor(&self, t: Time) -> Real {
         assert!(t >= 0.0, "negative time ({}) is not allowed", t);
         match self.compounding {
             Compounding::Simple => 1.0 + self.rate * t,
@@ -74,7 +74,7 @@ impl InterestRate {
             }
         }
     }
-   
+
     pub fn compound_factor_between_dates(
         &self,
         d1: &Date,
@@ -92,7 +92,7 @@ impl InterestRate {
     pub fn discount_factor(&self, t: Time) -> DiscountFactor {
         1.0 / self.compound_factor(t)
     }
-    
+
     /// Discount factor implied by the rate compounded between two dates
     pub fn discount_factor_between_dates(
         &self,
@@ -150,7 +150,7 @@ impl InterestRate {
             t2,
         )
     }
-    
+
     /// Return the [Frequency]
     pub fn frequency(&self) -> Frequency {
         if self.frequency_makes_sense {
@@ -206,6 +206,24 @@ impl InterestRate {
         InterestRate::new(r, result_dc.clone(), compounding.clone(), frequency)
     }
 
+    /// Interest rate implied by a given compound factor at a given time, without needing an
+    /// existing rate to call [InterestRate::implied_rate] on.
+    pub fn from_compound_factor(
+        compound: Real,
+        result_dc: &DayCounter,
+        compounding: &Compounding,
+        frequency: Frequency,
+        t: Time,
+    ) -> InterestRate {
+        InterestRate::new(0.0, result_dc.clone(), compounding.clone(), frequency).implied_rate(
+            compound,
+            result_dc,
+            compounding,
+            frequency,
+            t,
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn implied_rate_between_dates(
         &self,
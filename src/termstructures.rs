@@ -1,8 +1,14 @@
+pub mod flatforward;
+pub mod forwardspreadedtermstructure;
 pub mod interpolatedcurve;
 pub mod interpolateddiscountcurve;
 pub mod iterativebootstrap;
+pub mod nelsonsiegel;
 pub mod piecewiseyieldcurve;
+pub mod ratehelpers;
 pub mod termstructure;
+pub mod volatility;
 pub mod yieldtermstructure;
 pub mod zerocurve;
+pub mod zerospreadedtermstructure;
 pub mod zeroyieldstructure;
@@ -0,0 +1,169 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency},
+    rates::{compounding::Compounding, interestrate::InterestRate},
+    types::{DiscountFactor, Natural, Rate, Time},
+};
+
+use super::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure};
+
+/// Flat interest-rate curve
+///
+/// The forward rate is constant over the whole life of the curve, so that discount factors are
+/// given by the compounding of a single [InterestRate].
+#[derive(Debug, Clone)]
+pub struct FlatForward {
+    pub reference_date: Date,
+    pub rate: InterestRate,
+}
+
+impl FlatForward {
+    pub fn new(
+        reference_date: Date,
+        forward: Rate,
+        daycounter: DayCounter,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> Self {
+        Self {
+            reference_date,
+            rate: InterestRate::new(forward, daycounter, compounding, frequency),
+        }
+    }
+}
+
+impl TermStructure for FlatForward {
+    fn day_counter(&self) -> &DayCounter {
+        &self.rate.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl YieldTermStructure for FlatForward {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        self.rate.discount_factor(time)
+    }
+
+    fn instantaneous_forward_rate(&self, t: Time) -> Rate {
+        self.check_range(t, false);
+        self.rate
+            .equivalent_rate(&Compounding::Continuous, Frequency::Annual, 1.0)
+            .rate
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::*, months::Month::*,
+            period::Period, timeunit::TimeUnit::*,
+        },
+        rates::compounding::Compounding::*,
+        termstructures::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::FlatForward;
+
+    #[test]
+    fn test_flat_forward() {
+        let today = Date::new(15, March, 2023);
+        let curve = FlatForward::new(today, 0.05, DayCounter::actual360(), Continuous, Annual);
+
+        let d = today + Period::new(1, Years);
+        let t = curve.time_from_references(&d);
+        assert_eq!(t, 366.0 / 360.0);
+        assert!((curve.discount_from_date(&d, false) - (-0.05 * t).exp()).abs() < 1.0e-15);
+        assert_eq!(curve.discount_from_date(&today, false), 1.0);
+
+        let dc = DayCounter::actual360();
+        let zero = curve.zero_rate_from_date(&d, &dc, Continuous, Annual, false);
+        assert!((zero.rate - 0.05).abs() < 1.0e-12);
+        let zero = curve.zero_rate_from_date(&today, &dc, Continuous, Annual, false);
+        assert!((zero.rate - 0.05).abs() < 1.0e-12);
+
+        let forward = curve.forward_rate_time(1.0, 2.0, Compounded, Annual, false);
+        assert!((forward.rate - (0.05_f64.exp() - 1.0)).abs() < 1.0e-12);
+        let forward = curve.forward_rate_from_dates(&d, &d, &dc, Continuous, Annual, false);
+        assert!((forward.rate - 0.05).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn test_time_based_methods() {
+        let today = Date::new(15, March, 2023);
+        let dc = DayCounter::actual365_fixed();
+        let curve = FlatForward::new(today, 0.04, dc.clone(), Compounded, Semiannual);
+        for period in [
+            Period::new(3, Months),
+            Period::new(2, Years),
+            Period::new(30, Years),
+        ] {
+            let d = today + period;
+            let t = dc.year_fraction_simple(&today, &d);
+            assert_eq!(
+                curve.discount_time(t, false),
+                curve.discount_from_date(&d, false)
+            );
+            let zero = curve.zero_rate_time(t, Compounded, Semiannual, false);
+            let expected = curve.zero_rate_from_date(&d, &dc, Compounded, Semiannual, false);
+            assert!((zero.rate - expected.rate).abs() < 1.0e-15);
+            assert!((zero.rate - 0.04).abs() < 1.0e-12);
+        }
+        assert_eq!(curve.discount_time(0.0, false), 1.0);
+
+        // the former names are kept as aliases
+        #[allow(deprecated)]
+        let (discount, zero, forward) = (
+            curve.discount_frome_time(2.0, false),
+            curve.zero_rate_from_time(2.0, Compounded, Semiannual, false),
+            curve.forward_rate_from_times(1.0, 2.0, Compounded, Semiannual, false),
+        );
+        assert_eq!(discount, curve.discount_time(2.0, false));
+        assert_eq!(
+            zero.rate,
+            curve
+                .zero_rate_time(2.0, Compounded, Semiannual, false)
+                .rate
+        );
+        assert_eq!(
+            forward.rate,
+            curve
+                .forward_rate_time(1.0, 2.0, Compounded, Semiannual, false)
+                .rate
+        );
+    }
+
+    #[test]
+    fn test_instantaneous_forward_rate() {
+        let today = Date::new(15, March, 2023);
+        let dc = DayCounter::actual365_fixed();
+        for (compounding, frequency) in [(Continuous, Annual), (Compounded, Quarterly)] {
+            let curve = FlatForward::new(today, 0.04, dc.clone(), compounding, frequency);
+            let zero = curve.zero_rate_time(1.0, Continuous, Annual, false).rate;
+            for t in [0.0, 0.5, 1.0, 10.0, 30.0] {
+                assert!((curve.instantaneous_forward_rate(t) - zero).abs() < 1.0e-15);
+                // the generic finite difference agrees with the closed form
+                let t1 = (t - 0.00005_f64).max(0.0);
+                let numerical =
+                    (curve.discount_time(t1, false) / curve.discount_time(t1 + 0.0001, false)).ln()
+                        / 0.0001;
+                assert!((numerical - zero).abs() < 1.0e-10);
+                let forward = curve.forward_rate_time(t, t + 1.0, Continuous, Annual, false);
+                assert!((forward.rate - zero).abs() < 1.0e-12);
+            }
+        }
+    }
+}
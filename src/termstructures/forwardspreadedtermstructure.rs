@@ -0,0 +1,148 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency},
+    handle::Handle,
+    quotes::quote::Quote,
+    rates::compounding::Compounding,
+    types::{DiscountFactor, Natural, Rate, Time},
+};
+
+use super::{
+    termstructure::TermStructure, yieldtermstructure::YieldTermStructure,
+    zerospreadedtermstructure::TermSpread,
+};
+
+/// Yield curve obtained by adding a spread to the instantaneous forward rates of a base curve.
+///
+/// Since the discount factor is `exp(-integral of f(u) du)` over `[0, t]`, adding the spread
+/// `s(u)` to the forward rates `f(u)` multiplies the discount factors of the base curve by
+/// `exp(-integral of s(u) du)`, which is computed exactly for both kinds of [TermSpread]. The
+/// zero rate is then shifted by the average spread over `[0, t]`; for a flat spread this is
+/// the spread itself and the curve coincides with the
+/// [ZeroSpreadedTermStructure](super::zerospreadedtermstructure::ZeroSpreadedTermStructure).
+pub struct ForwardSpreadedTermStructure {
+    pub base_curve: Handle<dyn YieldTermStructure>,
+    pub spread: TermSpread,
+    daycounter: DayCounter,
+}
+
+impl ForwardSpreadedTermStructure {
+    /// Creates the spreaded curve; times are measured with the day counter of the base curve
+    /// currently linked.
+    pub fn new(base_curve: Handle<dyn YieldTermStructure>, spread: TermSpread) -> Self {
+        let daycounter = base_curve.current_link().day_counter().clone();
+        Self {
+            base_curve,
+            spread,
+            daycounter,
+        }
+    }
+
+    /// Base curve plus a flat spread read from the given quote
+    pub fn with_flat_spread(
+        base_curve: Handle<dyn YieldTermStructure>,
+        spread: Handle<dyn Quote>,
+    ) -> Self {
+        Self::new(base_curve, TermSpread::Flat(spread))
+    }
+
+    /// Continuously compounded instantaneous forward rate at time `t`: the one of the base
+    /// curve plus the spread
+    pub fn forward_rate(&self, t: Time) -> Rate {
+        let base = self.base_curve.current_link();
+        base.forward_rate_time(t, t, Compounding::Continuous, Frequency::Annual, true)
+            .rate
+            + self.spread.value(t)
+    }
+}
+
+impl TermStructure for ForwardSpreadedTermStructure {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.base_curve.current_link().max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.base_curve.current_link().reference_date()
+    }
+
+    fn settlement_days(&self) -> Natural {
+        self.base_curve.current_link().settlement_days()
+    }
+}
+
+impl YieldTermStructure for ForwardSpreadedTermStructure {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        let base = self.base_curve.current_link();
+        base.discount_time(time, true) * (-self.spread.integral(time)).exp()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+        },
+        handle::Handle,
+        quotes::{quote::Quote, simplequote::SimpleQuote},
+        rates::compounding::Compounding::Continuous,
+        termstructures::{
+            flatforward::FlatForward,
+            yieldtermstructure::YieldTermStructure,
+            zerospreadedtermstructure::{TermSpread, ZeroSpreadedTermStructure},
+        },
+    };
+
+    use super::ForwardSpreadedTermStructure;
+
+    fn base_curve() -> Handle<dyn YieldTermStructure> {
+        Handle::new(Rc::new(FlatForward::new(
+            Date::new(15, January, 2024),
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )))
+    }
+
+    #[test]
+    fn test_flat_spread() {
+        let spread: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(0.001)));
+        let forward = ForwardSpreadedTermStructure::with_flat_spread(base_curve(), spread.clone());
+        let zero = ZeroSpreadedTermStructure::with_flat_spread(base_curve(), spread);
+        for t in [0.25, 1.0, 5.0, 30.0] {
+            assert!(
+                (forward.discount_time(t, false) - zero.discount_time(t, false)).abs() < 1.0e-15
+            );
+            assert!((forward.forward_rate(t) - 0.031).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_term_spread() {
+        // spread rising from 0 to 20bp over five years
+        let spread = TermSpread::interpolated(vec![0.0, 5.0], vec![0.0, 0.002]);
+        let forward = ForwardSpreadedTermStructure::new(base_curve(), spread.clone());
+        let zero = ZeroSpreadedTermStructure::new(base_curve(), spread);
+
+        // the forward rate is shifted by the spread, hence the zero rate by its average
+        assert!((forward.forward_rate(2.5) - 0.031).abs() < 1.0e-8);
+        let implied = forward.forward_rate_time(2.5, 2.5, Continuous, Annual, false);
+        assert!((implied.rate - 0.031).abs() < 1.0e-8);
+        let zero_rate =
+            |c: &dyn YieldTermStructure, t| c.zero_rate_time(t, Continuous, Annual, false).rate;
+        assert!((zero_rate(&forward, 5.0) - 0.031).abs() < 1.0e-12);
+        assert!((zero_rate(&zero, 5.0) - 0.032).abs() < 1.0e-12);
+        // beyond the last node the spread is flat
+        assert!((zero_rate(&forward, 10.0) - (0.03 + (0.005 + 0.01) / 10.0)).abs() < 1.0e-12);
+        assert!(forward.discount_time(5.0, false) > zero.discount_time(5.0, false));
+    }
+}
@@ -1,2 +1,230 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    maths::interpolations::linearinterpolation::linear_interpolate,
+    types::{DiscountFactor, Natural, Real, Time},
+};
+
+use super::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure};
+
 /// Yield term structure based on interpolation of discount factors.
-pub struct InterpolatedDiscountCurve {}
+///
+/// Discount factors are interpolated log-linearly in time, i.e. forward rates are flat between
+/// nodes. Beyond the last node the last forward rate is extrapolated flat. Discount factors must
+/// not increase from one node to the next, i.e. forward rates must not be negative.
+#[derive(Debug, Clone)]
+pub struct InterpolatedDiscountCurve {
+    pub daycounter: DayCounter,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub discounts: Vec<DiscountFactor>,
+    log_discounts: Vec<Real>,
+}
+
+impl InterpolatedDiscountCurve {
+    /// Create a curve from the given node dates and discount factors. The first date is taken
+    /// as the reference date of the curve, and its discount factor must be 1.0.
+    pub fn new(dates: Vec<Date>, discounts: Vec<DiscountFactor>, daycounter: DayCounter) -> Self {
+        assert!(dates.len() >= 2, "not enough dates ({}) given", dates.len());
+        assert_eq!(
+            dates.len(),
+            discounts.len(),
+            "dates/discount factors count mismatch: {} vs {}",
+            dates.len(),
+            discounts.len()
+        );
+        assert!(
+            discounts[0] == 1.0,
+            "the first discount factor must be 1.0 to flag the corresponding date ({:?}) as \
+             reference date, {} given",
+            dates[0],
+            discounts[0]
+        );
+        for (date, discount) in dates.iter().zip(&discounts) {
+            assert!(
+                *discount > 0.0,
+                "non-positive discount factor ({}) given at {:?}",
+                discount,
+                date
+            );
+        }
+        for i in 1..discounts.len() {
+            assert!(
+                discounts[i] <= discounts[i - 1],
+                "discount factors not decreasing: {} at {:?} after {} at {:?}",
+                discounts[i],
+                dates[i],
+                discounts[i - 1],
+                dates[i - 1]
+            );
+        }
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| daycounter.time_from_reference(&dates[0], d))
+            .collect();
+        for i in 1..times.len() {
+            assert!(
+                times[i] > times[i - 1],
+                "dates not sorted or with duplicates: {:?}, {:?}",
+                dates[i - 1],
+                dates[i]
+            );
+        }
+        let log_discounts = discounts.iter().map(|df| df.ln()).collect();
+        Self {
+            daycounter,
+            dates,
+            times,
+            discounts,
+            log_discounts,
+        }
+    }
+}
+
+impl TermStructure for InterpolatedDiscountCurve {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.dates[self.dates.len() - 1]
+    }
+
+    fn reference_date(&self) -> Date {
+        self.dates[0]
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl YieldTermStructure for InterpolatedDiscountCurve {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        linear_interpolate(&self.times, &self.log_discounts, time, true).exp()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+        },
+        rates::compounding::Compounding::{Compounded, Continuous},
+        termstructures::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure},
+        types::{Rate, Real},
+    };
+
+    use super::InterpolatedDiscountCurve;
+
+    #[test]
+    fn test_zero_rates_from_discount_factors() {
+        let today = Date::new(1, March, 2023);
+        let dc = DayCounter::actual365_fixed();
+        let days = [0, 365, 730, 1825];
+        let zero_rates: [Rate; 4] = [0.03, 0.03, 0.035, 0.04];
+        let dates: Vec<Date> = days.iter().map(|&n| today + n).collect();
+        let discounts = days
+            .iter()
+            .zip(zero_rates)
+            .map(|(&n, r)| (-r * n as Real / 365.0).exp())
+            .collect();
+        let curve = InterpolatedDiscountCurve::new(dates.clone(), discounts, dc.clone());
+        assert_eq!(curve.reference_date(), today);
+        assert_eq!(curve.max_date(), dates[3]);
+
+        // the zero rates are reproduced at the nodes
+        for (date, rate) in dates.iter().zip(zero_rates).skip(1) {
+            let zero = curve.zero_rate_from_date(date, &dc, Continuous, Annual, false);
+            assert!(
+                (zero.rate - rate).abs() < 1.0e-12,
+                "zero rate at {:?}: {}, expected {}",
+                date,
+                zero.rate,
+                rate
+            );
+        }
+        // ... and are consistent with annual compounding
+        let zero = curve.zero_rate_from_date(&dates[2], &dc, Compounded, Annual, false);
+        assert!((zero.rate - (0.035_f64.exp() - 1.0)).abs() < 1.0e-12);
+
+        // log-linear interpolation, i.e. flat forward rate between nodes
+        let forward: Rate = 0.035 * 2.0 - 0.03;
+        let d = today + 547;
+        let expected = (-0.03 - forward * (547.0 - 365.0) / 365.0).exp();
+        assert!((curve.discount_from_date(&d, false) - expected).abs() < 1.0e-14);
+
+        // flat forward extrapolation after the last node
+        let forward: Rate = (0.04 * 5.0 - 0.035 * 2.0) / 3.0;
+        let d = today + 2190;
+        let expected = (-0.04 * 5.0 - forward).exp();
+        assert!((curve.discount_from_date(&d, true) - expected).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn test_instantaneous_forward_rate_at_the_ends() {
+        let today = Date::new(1, March, 2023);
+        let dc = DayCounter::actual365_fixed();
+        let curve = InterpolatedDiscountCurve::new(
+            vec![today, today + 365, today + 730],
+            vec![1.0, (-0.03_f64).exp(), (-0.07_f64).exp()],
+            dc,
+        );
+        // flat forwards of 3% then 4% between the nodes
+        assert!((curve.instantaneous_forward_rate(0.0) - 0.03).abs() < 1.0e-12);
+        assert!((curve.instantaneous_forward_rate(1.5) - 0.04).abs() < 1.0e-12);
+        // a backward difference at the max time, which is not read past
+        let max_time = curve.max_time();
+        assert!((curve.instantaneous_forward_rate(max_time) - 0.04).abs() < 1.0e-12);
+        let forward = curve.forward_rate_time(max_time, max_time, Continuous, Annual, false);
+        assert!((forward.rate - 0.04).abs() < 1.0e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "is past max curve time")]
+    fn test_instantaneous_forward_rate_past_max_time() {
+        let today = Date::new(1, March, 2023);
+        let curve = InterpolatedDiscountCurve::new(
+            vec![today, today + 365],
+            vec![1.0, 0.97],
+            DayCounter::actual365_fixed(),
+        );
+        curve.instantaneous_forward_rate(1.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "the first discount factor must be 1.0")]
+    fn test_first_discount_factor_must_be_one() {
+        let today = Date::new(1, March, 2023);
+        InterpolatedDiscountCurve::new(
+            vec![today, today + 365],
+            vec![0.99, 0.97],
+            DayCounter::actual365_fixed(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-positive discount factor")]
+    fn test_discount_factors_must_be_positive() {
+        let today = Date::new(1, March, 2023);
+        InterpolatedDiscountCurve::new(
+            vec![today, today + 365, today + 730],
+            vec![1.0, 0.97, 0.0],
+            DayCounter::actual365_fixed(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "discount factors not decreasing")]
+    fn test_discount_factors_must_decrease() {
+        let today = Date::new(1, March, 2023);
+        InterpolatedDiscountCurve::new(
+            vec![today, today + 365, today + 730],
+            vec![1.0, 0.97, 0.98],
+            DayCounter::actual365_fixed(),
+        );
+    }
+}
@@ -1,10 +1,148 @@
+use std::fmt;
+
+use crate::{
+    datetime::date::Date,
+    maths::solvers1d::{brent::Brent, solver1d::Solver1D},
+    types::{Rate, Real, Size},
+};
+
+use super::{ratehelpers::RateHelper, zerocurve::InterpolatedZeroCurve};
+
 /// Universal piecewise-term-structure boostrapper
+///
+/// The curve nodes are determined one at a time, from the earliest pillar to the latest, so
+/// that each rate helper is repriced exactly on the curve built so far. Node rates are searched
+/// in `[min_rate, max_rate]`.
+#[derive(Debug, Clone, Copy)]
 pub struct IterativeBootstrap {
-    //
+    pub accuracy: Real,
+    pub min_rate: Rate,
+    pub max_rate: Rate,
+}
+
+impl Default for IterativeBootstrap {
+    fn default() -> Self {
+        Self::new(1.0e-12)
+    }
 }
 
 impl IterativeBootstrap {
-    pub fn calculate(&self) {
-        todo!()
+    pub fn new(accuracy: Real) -> Self {
+        Self {
+            accuracy,
+            min_rate: -1.0,
+            max_rate: 3.0,
+        }
+    }
+
+    /// Solve for the zero rates of `curve` at nodes `1..`, where node `i` is the pillar of
+    /// `helpers[i - 1]`.
+    ///
+    /// Panics if a helper cannot be repriced; see [IterativeBootstrap::try_calculate].
+    pub fn calculate(&self, curve: &mut InterpolatedZeroCurve, helpers: &[Box<dyn RateHelper>]) {
+        self.try_calculate(curve, helpers)
+            .unwrap_or_else(|e| panic!("{}", e))
     }
+
+    /// Solve for the zero rates of `curve` at nodes `1..`, where node `i` is the pillar of
+    /// `helpers[i - 1]`, returning an error naming the first helper that cannot be repriced.
+    ///
+    /// The nodes up to the failing one are left bootstrapped.
+    pub fn try_calculate(
+        &self,
+        curve: &mut InterpolatedZeroCurve,
+        helpers: &[Box<dyn RateHelper>],
+    ) -> Result<(), BootstrapError> {
+        let solver = Brent::default();
+        for (i, helper) in helpers.iter().enumerate().map(|(i, h)| (i + 1, h)) {
+            let quote_error = |r| {
+                let mut trial = curve.truncated(i + 1);
+                trial.set_rate(i, r);
+                helper.quote_error(&trial)
+            };
+            let min_error = quote_error(self.min_rate);
+            let max_error = quote_error(self.max_rate);
+            if min_error == 0.0 || max_error == 0.0 {
+                let root = if min_error == 0.0 {
+                    self.min_rate
+                } else {
+                    self.max_rate
+                };
+                curve.set_rate(i, root);
+                continue;
+            }
+            if min_error * max_error > 0.0 || min_error.is_nan() || max_error.is_nan() {
+                return Err(BootstrapError::NoSolution {
+                    index: i - 1,
+                    pillar: helper.pillar_date(),
+                    quote: helper.quote(),
+                    min_rate: self.min_rate,
+                    max_rate: self.max_rate,
+                });
+            }
+            let guess = curve.rates[i - 1]
+                .clamp(self.min_rate + self.accuracy, self.max_rate - self.accuracy);
+            let root = solver.solve_bracketed(
+                quote_error,
+                |_| 0.0,
+                self.accuracy,
+                guess,
+                self.min_rate,
+                self.max_rate,
+            );
+            curve.set_rate(i, root);
+        }
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Reasons for which a curve cannot be bootstrapped over a set of rate helpers
+#[derive(Debug, Clone, PartialEq)]
+pub enum BootstrapError {
+    /// No rate helpers were given
+    NoHelpers,
+    /// A pillar date is not later than the previous one, or than the reference date
+    InvalidPillar { pillar: Date, reference_date: Date },
+    /// No zero rate in the search range reprices the helper at position `index`, sorted by
+    /// pillar date; its quote is typically inconsistent with the earlier ones
+    NoSolution {
+        index: Size,
+        pillar: Date,
+        quote: Real,
+        min_rate: Rate,
+        max_rate: Rate,
+    },
 }
+
+impl fmt::Display for BootstrapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootstrapError::NoHelpers => write!(f, "no rate helpers given"),
+            BootstrapError::InvalidPillar {
+                pillar,
+                reference_date,
+            } => write!(
+                f,
+                "more than one instrument with pillar date {:?}, or pillar date before the \
+                 reference date {:?}",
+                pillar, reference_date
+            ),
+            BootstrapError::NoSolution {
+                index,
+                pillar,
+                quote,
+                min_rate,
+                max_rate,
+            } => write!(
+                f,
+                "unable to reprice rate helper {} with pillar date {:?} and quote {}: no zero \
+                 rate in [{}, {}] matches the quote",
+                index, pillar, quote, min_rate, max_rate
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BootstrapError {}
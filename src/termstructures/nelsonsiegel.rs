@@ -0,0 +1,274 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    types::{DiscountFactor, Natural, Rate, Real, Time},
+};
+
+use super::{
+    termstructure::TermStructure, yieldtermstructure::YieldTermStructure,
+    zeroyieldstructure::ZeroYieldStructure,
+};
+
+/// Nelson-Siegel parametric yield curve.
+///
+/// The continuously compounded zero rate at time `t` is
+/// ```text
+/// z(t) = beta0 + beta1 * f(t / tau) + beta2 * (f(t / tau) - exp(-t / tau))
+/// ```
+/// with `f(x) = (1 - exp(-x)) / x`: `beta0` is the long-term level, `beta0 + beta1` the short
+/// rate and `beta2` drives the hump located around `tau`.
+#[derive(Debug, Clone)]
+pub struct NelsonSiegelFitting {
+    pub reference_date: Date,
+    pub daycounter: DayCounter,
+    pub beta0: Rate,
+    pub beta1: Rate,
+    pub beta2: Rate,
+    pub tau: Time,
+}
+
+impl NelsonSiegelFitting {
+    pub fn new(
+        reference_date: Date,
+        beta0: Rate,
+        beta1: Rate,
+        beta2: Rate,
+        tau: Time,
+        daycounter: DayCounter,
+    ) -> Self {
+        assert!(tau > 0.0, "tau ({}) must be positive", tau);
+        Self {
+            reference_date,
+            daycounter,
+            beta0,
+            beta1,
+            beta2,
+            tau,
+        }
+    }
+
+    /// Continuously compounded zero rate at time `t`
+    pub fn zero_rate(&self, t: Time) -> Rate {
+        self.beta0
+            + self.beta1 * slope_loading(t, self.tau)
+            + self.beta2 * hump_loading(t, self.tau)
+    }
+}
+
+impl TermStructure for NelsonSiegelFitting {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl YieldTermStructure for NelsonSiegelFitting {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        self.discount(time)
+    }
+}
+
+impl ZeroYieldStructure for NelsonSiegelFitting {
+    fn zero_yield(&self, time: Time) -> Rate {
+        self.zero_rate(time)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Svensson parametric yield curve.
+///
+/// Extends [NelsonSiegelFitting] with a second hump, weighted by `beta3` and located around
+/// `tau2`:
+/// ```text
+/// z(t) = beta0 + beta1 * f(t / tau1) + beta2 * (f(t / tau1) - exp(-t / tau1))
+///              + beta3 * (f(t / tau2) - exp(-t / tau2))
+/// ```
+#[derive(Debug, Clone)]
+pub struct SvenssonFitting {
+    pub reference_date: Date,
+    pub daycounter: DayCounter,
+    pub beta0: Rate,
+    pub beta1: Rate,
+    pub beta2: Rate,
+    pub beta3: Rate,
+    pub tau1: Time,
+    pub tau2: Time,
+}
+
+impl SvenssonFitting {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        reference_date: Date,
+        beta0: Rate,
+        beta1: Rate,
+        beta2: Rate,
+        beta3: Rate,
+        tau1: Time,
+        tau2: Time,
+        daycounter: DayCounter,
+    ) -> Self {
+        assert!(tau1 > 0.0, "tau1 ({}) must be positive", tau1);
+        assert!(tau2 > 0.0, "tau2 ({}) must be positive", tau2);
+        Self {
+            reference_date,
+            daycounter,
+            beta0,
+            beta1,
+            beta2,
+            beta3,
+            tau1,
+            tau2,
+        }
+    }
+
+    /// Continuously compounded zero rate at time `t`
+    pub fn zero_rate(&self, t: Time) -> Rate {
+        self.beta0
+            + self.beta1 * slope_loading(t, self.tau1)
+            + self.beta2 * hump_loading(t, self.tau1)
+            + self.beta3 * hump_loading(t, self.tau2)
+    }
+}
+
+impl TermStructure for SvenssonFitting {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl YieldTermStructure for SvenssonFitting {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        self.discount(time)
+    }
+}
+
+impl ZeroYieldStructure for SvenssonFitting {
+    fn zero_yield(&self, time: Time) -> Rate {
+        self.zero_rate(time)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// `(1 - exp(-t / tau)) / (t / tau)`, going to 1 as `t` goes to zero
+fn slope_loading(t: Time, tau: Time) -> Real {
+    let x = t / tau;
+    if x.abs() < 1.0e-8 {
+        // first order expansion, avoiding 0/0 at the reference date
+        1.0 - 0.5 * x
+    } else {
+        -(-x).exp_m1() / x
+    }
+}
+
+/// `(1 - exp(-t / tau)) / (t / tau) - exp(-t / tau)`, going to 0 as `t` goes to zero
+fn hump_loading(t: Time, tau: Time) -> Real {
+    slope_loading(t, tau) - (-t / tau).exp()
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        termstructures::{
+            yieldtermstructure::YieldTermStructure, zeroyieldstructure::ZeroYieldStructure,
+        },
+        types::Time,
+    };
+
+    use super::{NelsonSiegelFitting, SvenssonFitting};
+
+    #[test]
+    fn test_flat_nelson_siegel() {
+        let today = Date::new(15, January, 2024);
+        let curve =
+            NelsonSiegelFitting::new(today, 0.035, 0.0, 0.0, 1.5, DayCounter::actual365_fixed());
+        for t in [0.0, 0.25, 1.0, 5.0, 30.0] {
+            assert!((curve.zero_rate(t) - 0.035).abs() < 1.0e-15);
+            assert!((curve.discount(t) - (-0.035 * t).exp()).abs() < 1.0e-15);
+        }
+        let date = Date::new(15, January, 2034);
+        let t: Time = 3653.0 / 365.0;
+        assert!((curve.discount_from_date(&date, false) - (-0.035 * t).exp()).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn test_nelson_siegel_shape() {
+        let today = Date::new(15, January, 2024);
+        let curve =
+            NelsonSiegelFitting::new(today, 0.04, -0.02, 0.01, 2.0, DayCounter::actual365_fixed());
+        // the short end tends to beta0 + beta1 and the long end to beta0
+        assert!((curve.zero_rate(0.0) - 0.02).abs() < 1.0e-15);
+        assert!((curve.zero_rate(1.0e-10) - 0.02).abs() < 1.0e-12);
+        assert!((curve.zero_rate(1000.0) - 0.04).abs() < 1.0e-4);
+        // at t = tau, beta1 * (1 - 1/e) + beta2 * (1 - 2/e) = -0.01 with these parameters
+        assert!((curve.zero_rate(2.0) - 0.03).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn test_svensson() {
+        let today = Date::new(15, January, 2024);
+        let dc = DayCounter::actual365_fixed();
+        // without the second hump, the Svensson curve is a Nelson-Siegel one
+        let ns = NelsonSiegelFitting::new(today, 0.04, -0.02, 0.01, 2.0, dc.clone());
+        let svensson = SvenssonFitting::new(today, 0.04, -0.02, 0.01, 0.0, 2.0, 8.0, dc.clone());
+        for t in [0.5, 2.0, 10.0] {
+            assert_eq!(svensson.zero_rate(t), ns.zero_rate(t));
+        }
+
+        // second hump only: at t = tau2 its loading is 1 - 2/e
+        let svensson = SvenssonFitting::new(today, 0.04, 0.0, 0.0, 0.015, 2.0, 8.0, dc);
+        let expected = 0.04 + 0.015 * (1.0 - 2.0 / 1.0_f64.exp());
+        assert!((svensson.zero_rate(8.0) - expected).abs() < 1.0e-15);
+        assert!((svensson.discount(8.0) - (-expected * 8.0).exp()).abs() < 1.0e-15);
+        assert_eq!(svensson.zero_rate(0.0), 0.04);
+    }
+
+    #[test]
+    fn test_diebold_li_decay() {
+        // Diebold and Li (2006) fix the decay at 0.0609 per month, so that the loading on the
+        // medium-term factor peaks at about 30 months
+        let today = Date::new(15, January, 2024);
+        let tau = 1.0 / (0.0609 * 12.0);
+        let curve =
+            NelsonSiegelFitting::new(today, 0.0, 0.0, 1.0, tau, DayCounter::actual365_fixed());
+        let peak = (1..=1200)
+            .map(|tenths| tenths as Time / 120.0)
+            .max_by(|a, b| curve.zero_rate(*a).total_cmp(&curve.zero_rate(*b)))
+            .unwrap();
+        assert!(
+            peak > 29.0 / 12.0 && peak < 30.0 / 12.0,
+            "peak at {} years",
+            peak
+        );
+        // at the peak, the loading is about 0.298
+        assert!((curve.zero_rate(peak) - 0.2984).abs() < 1.0e-4);
+    }
+}
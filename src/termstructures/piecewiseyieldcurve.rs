@@ -1,12 +1,16 @@
-use std::marker::PhantomData;
-
 use crate::{
-    datetime::date::Date,
-    instruments::instrument::Instrument,
-    types::{DiscountFactor, Real, Time},
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency},
+    rates::{compounding::Compounding, interestrate::InterestRate},
+    types::{DiscountFactor, Natural, Rate, Real, Time},
 };
 
-use super::iterativebootstrap::IterativeBootstrap;
+use super::{
+    iterativebootstrap::{BootstrapError, IterativeBootstrap},
+    ratehelpers::RateHelper,
+    termstructure::TermStructure,
+    yieldtermstructure::YieldTermStructure,
+    zerocurve::InterpolatedZeroCurve,
+};
 
 /// Piecewise yield term structure
 ///
@@ -20,34 +24,266 @@ use super::iterativebootstrap::IterativeBootstrap;
 ///
 /// The bootstrapping algorithm will fail if any two instruments have the same maturity date.
 ///
-/// # Type parameters
-///
-/// * `C` - type of curve
-/// * `I` - type of interpolation
+/// The curve nodes are continuously compounded zero rates, interpolated linearly in time.
 ///
 /// # Arguments
 ///
-/// * `instruments` - vector of instruments
+/// * `helpers` - vector of rate helpers
 /// * `accuracy` - desired accuracy of the bootstrapping
 /// * `bootstrap` - bootstrapping algorithm implementation
-pub struct PiecewiseYieldCurve<C, I> {
-    pub instruments: Vec<Box<dyn Instrument>>,
+pub struct PiecewiseYieldCurve {
+    pub helpers: Vec<Box<dyn RateHelper>>,
     pub accuracy: Real,
     pub bootstrap: IterativeBootstrap,
-    _ph_c: PhantomData<C>,
-    _ph_i: PhantomData<I>,
+    curve: InterpolatedZeroCurve,
+}
+
+impl PiecewiseYieldCurve {
+    /// Bootstrap a curve with the given reference date over the given rate helpers.
+    ///
+    /// Panics if the curve cannot be bootstrapped; see [PiecewiseYieldCurve::try_new].
+    pub fn new(
+        reference_date: Date,
+        helpers: Vec<Box<dyn RateHelper>>,
+        daycounter: DayCounter,
+        accuracy: Option<Real>,
+    ) -> Self {
+        Self::try_new(reference_date, helpers, daycounter, accuracy)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Bootstrap a curve with the given reference date over the given rate helpers, returning
+    /// an error naming the offending pillar if the helpers cannot all be repriced.
+    pub fn try_new(
+        reference_date: Date,
+        helpers: Vec<Box<dyn RateHelper>>,
+        daycounter: DayCounter,
+        accuracy: Option<Real>,
+    ) -> Result<Self, BootstrapError> {
+        if helpers.is_empty() {
+            return Err(BootstrapError::NoHelpers);
+        }
+        let mut helpers = helpers;
+        helpers.sort_by_key(|h| h.pillar_date());
+
+        let mut dates = vec![reference_date];
+        for helper in helpers.iter() {
+            let pillar = helper.pillar_date();
+            if pillar <= dates[dates.len() - 1] {
+                return Err(BootstrapError::InvalidPillar {
+                    pillar,
+                    reference_date,
+                });
+            }
+            dates.push(pillar);
+        }
+        let rates = vec![0.05; dates.len()];
+
+        let bootstrap = accuracy.map_or_else(IterativeBootstrap::default, IterativeBootstrap::new);
+        let mut result = Self {
+            helpers,
+            accuracy: bootstrap.accuracy,
+            bootstrap,
+            curve: InterpolatedZeroCurve::new(dates, rates, daycounter),
+        };
+        result.try_perform_calculations()?;
+        Ok(result)
+    }
+
+    /// Dates of the curve nodes, starting with the reference date
+    pub fn node_dates(&self) -> &[Date] {
+        &self.curve.dates
+    }
+
+    /// Continuously compounded zero rates at the curve nodes
+    pub fn node_rates(&self) -> &[Rate] {
+        &self.curve.rates
+    }
+
+    /// Zero rate at the given date, in the required day-counting and compounding convention.
+    pub fn zero_rate(
+        &self,
+        date: &Date,
+        daycounter: &DayCounter,
+        compounding: Compounding,
+        frequency: Frequency,
+    ) -> InterestRate {
+        self.zero_rate_from_date(date, daycounter, compounding, frequency, false)
+    }
+
+    pub fn perform_calculations(&mut self) {
+        self.bootstrap.calculate(&mut self.curve, &self.helpers);
+    }
+
+    /// Bootstrap the curve again, e.g. after the quotes changed, returning an error naming the
+    /// first helper that cannot be repriced
+    pub fn try_perform_calculations(&mut self) -> Result<(), BootstrapError> {
+        self.bootstrap.try_calculate(&mut self.curve, &self.helpers)
+    }
 }
 
-impl<C, I> PiecewiseYieldCurve<C, I> {
-    pub fn max_date() -> Date {
-        todo!()
+impl TermStructure for PiecewiseYieldCurve {
+    fn day_counter(&self) -> &DayCounter {
+        self.curve.day_counter()
+    }
+
+    fn max_date(&self) -> Date {
+        self.curve.max_date()
     }
 
-    pub fn discount_impl(_time: Time) -> DiscountFactor {
-        todo!()
+    fn reference_date(&self) -> Date {
+        self.curve.reference_date()
+    }
+
+    fn settlement_days(&self) -> Natural {
+        self.curve.settlement_days()
+    }
+}
+
+impl YieldTermStructure for PiecewiseYieldCurve {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.curve.discount_time(time, extrapolate)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing, date::Date,
+            daycounter::DayCounter, frequency::Frequency::Annual, holidays::target::Target,
+            months::Month::*, period::Period, timeunit::TimeUnit::*,
+        },
+        handle::Handle,
+        quotes::simplequote::SimpleQuote,
+        rates::compounding::Compounding::{Continuous, Simple},
+        termstructures::{
+            iterativebootstrap::BootstrapError,
+            ratehelpers::{DepositRateHelper, RateHelper},
+        },
+    };
+
+    use super::PiecewiseYieldCurve;
+
+    fn deposits(today: Date) -> Vec<Box<dyn RateHelper>> {
+        let calendar = Target::new();
+        [(1, 0.030), (3, 0.032), (6, 0.035), (12, 0.038)]
+            .iter()
+            .map(|&(months, rate)| {
+                Box::new(DepositRateHelper::new(
+                    Handle::new(Rc::new(SimpleQuote::new(rate))),
+                    today,
+                    Period::new(months, Months),
+                    0,
+                    &calendar,
+                    ModifiedFollowing,
+                    false,
+                    DayCounter::actual360(),
+                )) as Box<dyn RateHelper>
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bootstrap_deposits() {
+        let today = Date::new(15, March, 2023);
+        let curve = PiecewiseYieldCurve::new(today, deposits(today), DayCounter::actual360(), None);
+
+        assert_eq!(curve.node_dates().len(), 5);
+        assert_eq!(curve.node_dates()[0], today);
+        // the reference date node follows the first pillar
+        assert_eq!(curve.node_rates()[0], curve.node_rates()[1]);
+
+        let dc = DayCounter::actual360();
+        for (i, helper) in curve.helpers.iter().enumerate() {
+            let pillar = curve.node_dates()[i + 1];
+            assert_eq!(pillar, helper.pillar_date());
+            assert!(
+                (helper.implied_quote(&curve) - helper.quote()).abs() < 1.0e-10,
+                "helper with pillar {:?} not repriced",
+                pillar
+            );
+            // deposits start today, so the simple zero rate at the pillar is the deposit rate
+            let zero = curve.zero_rate(&pillar, &dc, Simple, Annual);
+            assert!((zero.rate - helper.quote()).abs() < 1.0e-10);
+            let zero = curve.zero_rate(&pillar, &dc, Continuous, Annual);
+            assert!((zero.rate - curve.node_rates()[i + 1]).abs() < 1.0e-10);
+        }
+
+        // no division by zero at the reference date
+        let zero = curve.zero_rate(&today, &dc, Continuous, Annual);
+        assert!((zero.rate - curve.node_rates()[0]).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_inconsistent_helpers() {
+        let today = Date::new(15, March, 2023);
+        let calendar = Target::new();
+        // a 9M deposit at -250% would need a negative discount factor at its maturity
+        let mut helpers = deposits(today);
+        helpers.push(Box::new(DepositRateHelper::new(
+            Handle::new(Rc::new(SimpleQuote::new(-2.5))),
+            today,
+            Period::new(9, Months),
+            0,
+            &calendar,
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+        )));
+        let pillar = calendar.advance(today, Period::new(9, Months), ModifiedFollowing, false);
+
+        let error = PiecewiseYieldCurve::try_new(today, helpers, DayCounter::actual360(), None)
+            .err()
+            .unwrap();
+        match &error {
+            BootstrapError::NoSolution {
+                index,
+                pillar: failed,
+                quote,
+                ..
+            } => {
+                // sorted by pillar, the 9M deposit comes after the 1M, 3M and 6M ones
+                assert_eq!(*index, 3);
+                assert_eq!(*failed, pillar);
+                assert_eq!(*quote, -2.5);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        let message = error.to_string();
+        assert!(message.contains(&format!("{:?}", pillar)), "{}", message);
+
+        // two helpers with the same pillar
+        let mut helpers = deposits(today);
+        helpers.extend(deposits(today).into_iter().take(1));
+        assert!(matches!(
+            PiecewiseYieldCurve::try_new(today, helpers, DayCounter::actual360(), None),
+            Err(BootstrapError::InvalidPillar { .. })
+        ));
+        assert!(matches!(
+            PiecewiseYieldCurve::try_new(today, vec![], DayCounter::actual360(), None),
+            Err(BootstrapError::NoHelpers)
+        ));
     }
 
-    pub fn perform_calculations(&self) {
-        self.bootstrap.calculate();
+    #[test]
+    #[should_panic(expected = "unable to reprice rate helper 0")]
+    fn test_new_panics_on_bootstrap_failure() {
+        let today = Date::new(15, March, 2023);
+        let helper = DepositRateHelper::new(
+            Handle::new(Rc::new(SimpleQuote::new(-10.0))),
+            today,
+            Period::new(3, Months),
+            0,
+            &Target::new(),
+            ModifiedFollowing,
+            false,
+            DayCounter::actual360(),
+        );
+        PiecewiseYieldCurve::new(today, vec![Box::new(helper)], DayCounter::actual360(), None);
     }
 }
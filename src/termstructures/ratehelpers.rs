@@ -0,0 +1,440 @@
+use std::rc::Rc;
+
+use crate::{
+    context::pricing_context::PricingContext,
+    datetime::{
+        businessdayconvention::BusinessDayConvention, calendar::Calendar, date::Date,
+        daycounter::DayCounter, frequency::Frequency, imm::IMM, period::Period,
+        schedulebuilder::ScheduleBuilder, timeunit::TimeUnit::Days,
+    },
+    handle::Handle,
+    indexes::iborindex::IborIndex,
+    instruments::instrument::settlement_date,
+    processes::hullwhite::HullWhite,
+    quotes::{quote::Quote, simplequote::SimpleQuote},
+    types::{Integer, Rate, Real},
+};
+
+use super::yieldtermstructure::YieldTermStructure;
+
+/// Instrument used to bootstrap a [PiecewiseYieldCurve](super::piecewiseyieldcurve::PiecewiseYieldCurve).
+///
+/// A rate helper knows the market quote of its instrument and how to imply the same quote from
+/// a given term structure. The bootstrap adjusts the curve at the pillar date until the two
+/// agree.
+///
+/// Unlike QuantLib, helpers are not linked to the curve being bootstrapped through a
+/// `set_term_structure` call: the curve is passed to [implied_quote](RateHelper::implied_quote)
+/// instead. The helpers are owned by the curve, and holding a link back to it would make a
+/// reference cycle.
+pub trait RateHelper {
+    /// Market quote of the instrument
+    fn quote(&self) -> Real;
+
+    /// The date of the curve node which is determined by this helper
+    fn pillar_date(&self) -> Date;
+
+    /// Latest date at which the instrument needs the curve; by default the pillar date
+    fn latest_date(&self) -> Date {
+        self.pillar_date()
+    }
+
+    /// Quote implied by the given term structure
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Real;
+
+    /// Difference between the market quote and the implied quote
+    fn quote_error(&self, curve: &dyn YieldTermStructure) -> Real {
+        self.quote() - self.implied_quote(curve)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Rate helper for bootstrapping over deposit rates
+pub struct DepositRateHelper {
+    pub rate: Handle<dyn Quote>,
+    pub earliest_date: Date,
+    pub maturity_date: Date,
+    pub daycounter: DayCounter,
+}
+
+impl DepositRateHelper {
+    /// Create a deposit starting `fixing_days` business days after the evaluation date and
+    /// lasting for `tenor`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        rate: Handle<dyn Quote>,
+        eval_date: Date,
+        tenor: Period,
+        fixing_days: Integer,
+        calendar: &Calendar,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        daycounter: DayCounter,
+    ) -> Self {
+        let earliest_date = calendar.advance_by_units(
+            calendar.adjust(eval_date, convention),
+            fixing_days,
+            Days,
+            convention,
+            false,
+        );
+        let maturity_date = calendar.advance(earliest_date, tenor, convention, end_of_month);
+        Self {
+            rate,
+            earliest_date,
+            maturity_date,
+            daycounter,
+        }
+    }
+}
+
+impl RateHelper for DepositRateHelper {
+    fn quote(&self) -> Real {
+        self.rate.current_link().value()
+    }
+
+    fn pillar_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Real {
+        let t = self
+            .daycounter
+            .year_fraction_simple(&self.earliest_date, &self.maturity_date);
+        let d1 = curve.discount_from_date(&self.earliest_date, true);
+        let d2 = curve.discount_from_date(&self.maturity_date, true);
+        (d1 / d2 - 1.0) / t
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Rate helper for bootstrapping over interest-rate futures prices
+///
+/// The futures is on the deposit of the index starting at an IMM date and is quoted as
+/// `100 * (1 - futures rate)`. The futures rate exceeds the forward rate of the deposit by the
+/// convexity adjustment.
+pub struct FuturesRateHelper {
+    pub price: Handle<dyn Quote>,
+    pub earliest_date: Date,
+    pub maturity_date: Date,
+    pub daycounter: DayCounter,
+    pub convexity_adjustment: Option<Handle<dyn Quote>>,
+}
+
+impl FuturesRateHelper {
+    /// Create a futures on the deposit of `index` starting at the IMM date `imm_date`, with an
+    /// optional convexity adjustment quote; no adjustment means the futures and forward rates
+    /// agree.
+    pub fn new(
+        price: Handle<dyn Quote>,
+        imm_date: Date,
+        index: &IborIndex,
+        convexity_adjustment: Option<Handle<dyn Quote>>,
+    ) -> Self {
+        assert!(
+            IMM::new(PricingContext::default()).is_imm_date(&imm_date, false),
+            "{:?} is not a valid IMM date",
+            imm_date
+        );
+        Self {
+            price,
+            earliest_date: imm_date,
+            maturity_date: index.maturity_date(&imm_date),
+            daycounter: index.day_counter.clone(),
+            convexity_adjustment,
+        }
+    }
+
+    /// Create a futures as in [FuturesRateHelper::new], with the convexity adjustment given by
+    /// a Hull-White model with volatility `sigma` and mean reversion `a`, as of `eval_date`.
+    ///
+    /// The adjustment is computed from the price at construction.
+    pub fn with_hull_white_adjustment(
+        price: Handle<dyn Quote>,
+        imm_date: Date,
+        index: &IborIndex,
+        eval_date: Date,
+        sigma: Real,
+        a: Real,
+    ) -> Self {
+        let mut helper = Self::new(price, imm_date, index, None);
+        let t = helper
+            .daycounter
+            .year_fraction_simple(&eval_date, &helper.earliest_date);
+        let maturity = helper
+            .daycounter
+            .year_fraction_simple(&eval_date, &helper.maturity_date);
+        let bias = HullWhite::convexity_bias(helper.quote(), t, maturity, sigma, a);
+        helper.convexity_adjustment = Some(Handle::new(Rc::new(SimpleQuote::new(bias))));
+        helper
+    }
+
+    /// Convexity adjustment, i.e. futures rate minus forward rate
+    pub fn convexity_adjustment(&self) -> Rate {
+        self.convexity_adjustment
+            .as_ref()
+            .map_or(0.0, |q| q.current_link().value())
+    }
+}
+
+impl RateHelper for FuturesRateHelper {
+    fn quote(&self) -> Real {
+        self.price.current_link().value()
+    }
+
+    fn pillar_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Real {
+        let t = self
+            .daycounter
+            .year_fraction_simple(&self.earliest_date, &self.maturity_date);
+        let d1 = curve.discount_from_date(&self.earliest_date, true);
+        let d2 = curve.discount_from_date(&self.maturity_date, true);
+        let forward = (d1 / d2 - 1.0) / t;
+        100.0 * (1.0 - (forward + self.convexity_adjustment()))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Rate helper for bootstrapping over par swap rates
+///
+/// The swap starts at the spot date of the floating index and exchanges the quoted fixed rate
+/// for the index. Both legs are discounted on the curve being bootstrapped, which also forecasts
+/// the index, so that the floating leg is worth the difference of the discount factors at the
+/// start and maturity of the swap.
+pub struct SwapRateHelper {
+    pub rate: Handle<dyn Quote>,
+    pub start_date: Date,
+    pub maturity_date: Date,
+    /// Dates of the fixed leg schedule, from the start date to the maturity date
+    pub fixed_dates: Vec<Date>,
+    pub fixed_day_count: DayCounter,
+}
+
+impl SwapRateHelper {
+    /// Create a swap on `index` lasting for `tenor` from the spot date of the index, with a
+    /// fixed leg paid at the given frequency
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        rate: Handle<dyn Quote>,
+        eval_date: Date,
+        tenor: Period,
+        calendar: &Calendar,
+        fixed_frequency: Frequency,
+        fixed_convention: BusinessDayConvention,
+        fixed_day_count: DayCounter,
+        index: &IborIndex,
+    ) -> Self {
+        let start_date = settlement_date(eval_date, index.fixing_days, &index.fixing_calendar);
+        // roll from the unadjusted end date, so that an adjusted maturity leaves no stub
+        let schedule = ScheduleBuilder::new(
+            PricingContext::new(eval_date),
+            start_date,
+            start_date + tenor,
+            Period::from(fixed_frequency),
+            calendar.clone(),
+        )
+        .with_convention(fixed_convention)
+        .with_termination_convention(fixed_convention)
+        .build();
+        let fixed_dates = schedule.dates();
+        Self {
+            rate,
+            start_date,
+            maturity_date: fixed_dates[fixed_dates.len() - 1],
+            fixed_dates,
+            fixed_day_count,
+        }
+    }
+}
+
+impl RateHelper for SwapRateHelper {
+    fn quote(&self) -> Real {
+        self.rate.current_link().value()
+    }
+
+    fn pillar_date(&self) -> Date {
+        self.maturity_date
+    }
+
+    fn implied_quote(&self, curve: &dyn YieldTermStructure) -> Real {
+        let annuity: Real = self
+            .fixed_dates
+            .windows(2)
+            .map(|period| {
+                self.fixed_day_count
+                    .year_fraction_simple(&period[0], &period[1])
+                    * curve.discount_from_date(&period[1], true)
+            })
+            .sum();
+        let floating_leg = curve.discount_from_date(&self.start_date, true)
+            - curve.discount_from_date(&self.maturity_date, true);
+        floating_leg / annuity
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            businessdayconvention::BusinessDayConvention::ModifiedFollowing,
+            date::Date,
+            daycounter::DayCounter,
+            frequency::Frequency::Annual,
+            holidays::target::Target,
+            months::Month::*,
+            period::Period,
+            timeunit::TimeUnit::{Months, Years},
+        },
+        handle::Handle,
+        indexes::ibor::euribor::Euribor,
+        quotes::{quote::Quote, simplequote::SimpleQuote},
+        rates::compounding::Compounding::Continuous,
+        termstructures::{
+            flatforward::FlatForward, piecewiseyieldcurve::PiecewiseYieldCurve,
+            yieldtermstructure::YieldTermStructure,
+        },
+        types::Real,
+    };
+
+    use super::{DepositRateHelper, FuturesRateHelper, RateHelper, SwapRateHelper};
+
+    #[test]
+    fn test_futures_rate_helper() {
+        let today = Date::new(15, January, 2024);
+        let curve: Handle<dyn YieldTermStructure> = Handle::new(Rc::new(FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        )));
+        let index = Euribor::new(Period::new(3, Months), curve.clone());
+        let imm_date = Date::new(20, March, 2024);
+        let price: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(96.5)));
+
+        // without convexity adjustment the futures price is 100 * (1 - forward)
+        let helper = FuturesRateHelper::new(price.clone(), imm_date, &index, None);
+        assert_eq!(helper.maturity_date, Date::new(20, June, 2024));
+        assert_eq!(helper.pillar_date(), helper.maturity_date);
+        let flat = curve.current_link();
+        let t = DayCounter::actual360().year_fraction_simple(&imm_date, &helper.maturity_date);
+        let forward = (flat.discount_from_date(&imm_date, false)
+            / flat.discount_from_date(&helper.maturity_date, false)
+            - 1.0)
+            / t;
+        assert!((helper.implied_quote(flat.as_ref()) - 100.0 * (1.0 - forward)).abs() < 1.0e-12);
+
+        // a positive adjustment lowers the implied price
+        let adjustment: Handle<dyn Quote> = Handle::new(Rc::new(SimpleQuote::new(0.0005)));
+        let adjusted = FuturesRateHelper::new(price.clone(), imm_date, &index, Some(adjustment));
+        let difference =
+            helper.implied_quote(flat.as_ref()) - adjusted.implied_quote(flat.as_ref());
+        assert!((difference - 0.05).abs() < 1.0e-12);
+
+        let hull_white = FuturesRateHelper::with_hull_white_adjustment(
+            price, imm_date, &index, today, 0.01, 0.03,
+        );
+        assert!(hull_white.convexity_adjustment() > 0.0);
+        assert!(hull_white.convexity_adjustment() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_bootstrap_futures() {
+        let today = Date::new(15, January, 2024);
+        let index = Euribor::new(Period::new(3, Months), Handle::default());
+        let helpers = [
+            (Date::new(17, January, 2024), 96.20),
+            (Date::new(20, March, 2024), 96.30),
+            (Date::new(19, June, 2024), 96.55),
+            (Date::new(18, September, 2024), 96.80),
+        ]
+        .iter()
+        .map(|&(imm_date, price)| {
+            Box::new(FuturesRateHelper::new(
+                Handle::new(Rc::new(SimpleQuote::new(price))),
+                imm_date,
+                &index,
+                None,
+            )) as Box<dyn RateHelper>
+        })
+        .collect();
+        let curve = PiecewiseYieldCurve::new(today, helpers, DayCounter::actual365_fixed(), None);
+        for helper in curve.helpers.iter() {
+            assert!((helper.implied_quote(&curve) - helper.quote()).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_swaps() {
+        let today = Date::new(15, January, 2024);
+        let flat = FlatForward::new(
+            today,
+            0.03,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        );
+        let index = Euribor::new(Period::new(6, Months), Handle::default());
+        let calendar = Target::new();
+        let deposit = |tenor: Period, rate: Real| {
+            DepositRateHelper::new(
+                Handle::new(Rc::new(SimpleQuote::new(rate))),
+                today,
+                tenor,
+                2,
+                &calendar,
+                ModifiedFollowing,
+                false,
+                DayCounter::actual360(),
+            )
+        };
+        let swap = |tenor: Period, rate: Real| {
+            SwapRateHelper::new(
+                Handle::new(Rc::new(SimpleQuote::new(rate))),
+                today,
+                tenor,
+                &calendar,
+                Annual,
+                ModifiedFollowing,
+                DayCounter::bond_basis(),
+                &index,
+            )
+        };
+
+        let helper = swap(Period::new(2, Years), 0.0);
+        assert_eq!(helper.start_date, Date::new(17, January, 2024));
+        assert_eq!(helper.maturity_date, Date::new(19, January, 2026));
+        assert_eq!(helper.fixed_dates.len(), 3);
+
+        // quotes implied by the flat curve are repriced, and the flat curve recovered
+        let mut helpers: Vec<Box<dyn RateHelper>> = vec![];
+        for months in [3, 6] {
+            let tenor = Period::new(months, Months);
+            let rate = deposit(tenor, 0.0).implied_quote(&flat);
+            helpers.push(Box::new(deposit(tenor, rate)));
+        }
+        for years in [1, 2, 5, 10] {
+            let tenor = Period::new(years, Years);
+            let rate = swap(tenor, 0.0).implied_quote(&flat);
+            helpers.push(Box::new(swap(tenor, rate)));
+        }
+        let curve = PiecewiseYieldCurve::new(today, helpers, DayCounter::actual365_fixed(), None);
+        for helper in curve.helpers.iter() {
+            assert!((helper.implied_quote(&curve) - helper.quote()).abs() < 1.0e-8);
+            let pillar = helper.pillar_date();
+            let difference =
+                curve.discount_from_date(&pillar, false) - flat.discount_from_date(&pillar, false);
+            assert!(difference.abs() < 1.0e-8, "{:?}: {}", pillar, difference);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::datetime::date::Date;
+use crate::datetime::daycounter::DayCounter;
 use crate::types::{Natural, Time};
 
 /// Basic term structure functionality
@@ -10,18 +11,39 @@ use crate::types::{Natural, Time};
 /// * The third is that it is based on the reference date of some other structure.
 ///
 pub trait TermStructure {
+    /// The day counter used for date/time conversion
+    fn day_counter(&self) -> &DayCounter;
+
     /// Date/Time conversion
-    fn time_from_references(&self, date: &Date) -> Time;
+    fn time_from_references(&self, date: &Date) -> Time {
+        let reference_date = self.reference_date();
+        self.day_counter()
+            .time_from_reference(&reference_date, date)
+    }
 
     /// The latest date for which the curve can return values
     fn max_date(&self) -> Date;
 
     /// The latest time for which the curve can return values
-    fn max_time(&self) -> Time;
+    fn max_time(&self) -> Time {
+        self.time_from_references(&self.max_date())
+    }
 
     /// The date at which discount = 1.0 and/or variance = 0.0
     fn reference_date(&self) -> Date;
 
     /// The settlementDays used for reference date calculation
     fn settlement_days(&self) -> Natural;
+
+    /// Check that the given time is within the range of the curve, unless extrapolation is
+    /// allowed.
+    fn check_range(&self, time: Time, extrapolate: bool) {
+        assert!(time >= 0.0, "negative time ({}) given", time);
+        assert!(
+            extrapolate || time <= self.max_time(),
+            "time ({}) is past max curve time ({})",
+            time,
+            self.max_time()
+        );
+    }
 }
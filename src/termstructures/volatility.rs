@@ -0,0 +1,8 @@
+pub mod blackconstantvol;
+pub mod blackvariancecurve;
+pub mod blackvariancesurface;
+pub mod blackvoltermstructure;
+pub mod constantoptionletvolatility;
+pub mod constantswaptionvolatility;
+pub mod optionletvolatilitystructure;
+pub mod swaptionvolatilitystructure;
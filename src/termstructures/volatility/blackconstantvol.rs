@@ -0,0 +1,76 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    termstructures::termstructure::TermStructure,
+    types::{Natural, Real, Time, Volatility},
+};
+
+use super::blackvoltermstructure::BlackVolTermStructure;
+
+/// Constant Black volatility, no time-strike dependence
+#[derive(Debug, Clone)]
+pub struct BlackConstantVol {
+    pub reference_date: Date,
+    pub volatility: Volatility,
+    pub daycounter: DayCounter,
+}
+
+impl BlackConstantVol {
+    pub fn new(reference_date: Date, volatility: Volatility, daycounter: DayCounter) -> Self {
+        Self {
+            reference_date,
+            volatility,
+            daycounter,
+        }
+    }
+}
+
+impl TermStructure for BlackConstantVol {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl BlackVolTermStructure for BlackConstantVol {
+    fn black_vol_from_time(&self, time: Time, _strike: Real, extrapolate: bool) -> Volatility {
+        self.check_range(time, extrapolate);
+        self.volatility
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        termstructures::volatility::blackvoltermstructure::BlackVolTermStructure,
+    };
+
+    use super::BlackConstantVol;
+
+    #[test]
+    fn test_black_constant_vol() {
+        let today = Date::new(15, March, 2023);
+        let vol = BlackConstantVol::new(today, 0.2, DayCounter::actual360());
+        for days in [1, 30, 360, 3600] {
+            let date = today + days;
+            for strike in [50.0, 100.0, 200.0] {
+                assert_eq!(vol.black_vol(&date, strike, false), 0.2);
+                let t = days as f64 / 360.0;
+                assert!((vol.black_variance(&date, strike, false) - 0.04 * t).abs() < 1.0e-15);
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    maths::interpolations::linearinterpolation::linear_interpolate,
+    termstructures::termstructure::TermStructure,
+    types::{Natural, Real, Time, Volatility},
+};
+
+use super::blackvoltermstructure::BlackVolTermStructure;
+
+/// Black volatility curve modelled as a variance curve
+///
+/// This calculates time-dependent Black volatilities using as input a vector of (ATM) Black
+/// volatilities observed in the market. The calculation is performed by linear interpolation of
+/// the total variance `σ² t` in time, which keeps the forward variance non-negative as long as
+/// the total variance is non-decreasing. Beyond the last date the last volatility is kept
+/// constant. The strike is ignored.
+#[derive(Debug, Clone)]
+pub struct BlackVarianceCurve {
+    pub reference_date: Date,
+    pub daycounter: DayCounter,
+    max_date: Date,
+    times: Vec<Time>,
+    variances: Vec<Real>,
+}
+
+impl BlackVarianceCurve {
+    pub fn new(
+        reference_date: Date,
+        dates: &[Date],
+        black_vols: &[Volatility],
+        daycounter: DayCounter,
+    ) -> Self {
+        assert!(
+            dates.len() == black_vols.len(),
+            "mismatch between date vector ({}) and black vol vector ({})",
+            dates.len(),
+            black_vols.len()
+        );
+        assert!(!dates.is_empty(), "no dates given");
+        assert!(
+            dates[0] > reference_date,
+            "cannot have dates[0] <= reference date"
+        );
+
+        let mut times = vec![0.0];
+        let mut variances = vec![0.0];
+        for (date, vol) in dates.iter().zip(black_vols) {
+            let time = daycounter.time_from_reference(&reference_date, date);
+            let variance = time * vol * vol;
+            assert!(
+                time > times[times.len() - 1],
+                "dates must be sorted unique, found {:?}",
+                date
+            );
+            assert!(
+                variance >= variances[variances.len() - 1],
+                "variance must be non-decreasing, found {:?} with volatility {}",
+                date,
+                vol
+            );
+            times.push(time);
+            variances.push(variance);
+        }
+        Self {
+            reference_date,
+            daycounter,
+            max_date: dates[dates.len() - 1],
+            times,
+            variances,
+        }
+    }
+}
+
+impl TermStructure for BlackVarianceCurve {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.max_date
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl BlackVolTermStructure for BlackVarianceCurve {
+    fn black_vol_from_time(&self, time: Time, strike: Real, extrapolate: bool) -> Volatility {
+        // at time 0, the volatility is the limit of the first interpolation segment
+        let time = time.max(self.times[1] * 1.0e-6);
+        (self.black_variance_from_time(time, strike, extrapolate) / time).sqrt()
+    }
+
+    fn black_variance_from_time(&self, time: Time, _strike: Real, extrapolate: bool) -> Real {
+        self.check_range(time, extrapolate);
+        let last = self.times.len() - 1;
+        if time <= self.times[last] {
+            linear_interpolate(&self.times, &self.variances, time, false)
+        } else {
+            // flat volatility extrapolation
+            self.variances[last] * time / self.times[last]
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        termstructures::{
+            termstructure::TermStructure, volatility::blackvoltermstructure::BlackVolTermStructure,
+        },
+    };
+
+    use super::BlackVarianceCurve;
+
+    #[test]
+    fn test_black_variance_curve() {
+        let today = Date::new(15, March, 2023);
+        let dc = DayCounter::actual360();
+        let dates = [today + 90, today + 180, today + 360];
+        let vols = [0.25, 0.22, 0.20];
+        let curve = BlackVarianceCurve::new(today, &dates, &vols, dc);
+
+        // at the nodes the variance is vol^2 * t
+        for (date, vol) in dates.iter().zip(vols) {
+            let t = curve.time_from_references(date);
+            assert!((curve.black_variance(date, 100.0, false) - vol * vol * t).abs() < 1.0e-15);
+            assert!((curve.black_vol(date, 100.0, false) - vol).abs() < 1.0e-15);
+        }
+
+        // total variance is linear in time between the nodes
+        let variance = 0.5 * (0.25 * 0.25 * 0.25 + 0.22 * 0.22 * 0.5);
+        assert!((curve.black_variance_from_time(0.375, 100.0, false) - variance).abs() < 1.0e-15);
+
+        // flat volatility beyond the last date
+        assert!((curve.black_vol_from_time(2.0, 100.0, true) - 0.20).abs() < 1.0e-15);
+        assert!((curve.black_vol_from_time(0.0, 100.0, false) - 0.25).abs() < 1.0e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "variance must be non-decreasing")]
+    fn test_black_variance_curve_decreasing_variance() {
+        let today = Date::new(15, March, 2023);
+        let dates = [today + 180, today + 360];
+        BlackVarianceCurve::new(today, &dates, &[0.30, 0.10], DayCounter::actual360());
+    }
+}
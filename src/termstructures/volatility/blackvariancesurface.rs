@@ -0,0 +1,184 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    maths::{interpolations::linearinterpolation::linear_interpolate, matrix::Matrix},
+    termstructures::termstructure::TermStructure,
+    types::{Natural, Real, Time, Volatility},
+};
+
+use super::blackvoltermstructure::BlackVolTermStructure;
+
+/// Black volatility surface modelled as a variance surface
+///
+/// This calculates time/strike dependent Black volatilities using as input a matrix of Black
+/// volatilities observed in the market, with one row per strike and one column per date. The
+/// total variance is interpolated bilinearly in strike and time. Outside the strike range the
+/// variance of the closest strike is used, and beyond the last date the volatility is kept
+/// constant.
+#[derive(Debug, Clone)]
+pub struct BlackVarianceSurface {
+    pub reference_date: Date,
+    pub daycounter: DayCounter,
+    max_date: Date,
+    strikes: Vec<Real>,
+    times: Vec<Time>,
+    // variances[(strike, time)], with a first column of zeros at time 0
+    variances: Matrix,
+}
+
+impl BlackVarianceSurface {
+    pub fn new(
+        reference_date: Date,
+        dates: &[Date],
+        strikes: Vec<Real>,
+        black_vols: &Matrix,
+        daycounter: DayCounter,
+    ) -> Self {
+        assert!(
+            dates.len() == black_vols.columns(),
+            "mismatch between date vector ({}) and black vol matrix columns ({})",
+            dates.len(),
+            black_vols.columns()
+        );
+        assert!(
+            strikes.len() == black_vols.rows(),
+            "mismatch between strike vector ({}) and black vol matrix rows ({})",
+            strikes.len(),
+            black_vols.rows()
+        );
+        assert!(!dates.is_empty(), "no dates given");
+        assert!(
+            dates[0] > reference_date,
+            "cannot have dates[0] <= reference date"
+        );
+        assert!(
+            strikes.windows(2).all(|w| w[0] < w[1]),
+            "strikes must be sorted unique"
+        );
+
+        let mut times = vec![0.0];
+        for date in dates {
+            let time = daycounter.time_from_reference(&reference_date, date);
+            assert!(
+                time > times[times.len() - 1],
+                "dates must be sorted unique, found {:?}",
+                date
+            );
+            times.push(time);
+        }
+        let mut variances = Matrix::zeros(strikes.len(), times.len());
+        for i in 0..strikes.len() {
+            for j in 1..times.len() {
+                let vol = black_vols[(i, j - 1)];
+                variances[(i, j)] = times[j] * vol * vol;
+                assert!(
+                    variances[(i, j)] >= variances[(i, j - 1)],
+                    "variance must be non-decreasing, found {:?} with strike {}",
+                    dates[j - 1],
+                    strikes[i]
+                );
+            }
+        }
+        Self {
+            reference_date,
+            daycounter,
+            max_date: dates[dates.len() - 1],
+            strikes,
+            times,
+            variances,
+        }
+    }
+
+    /// Variance at the given strike for each of the time nodes, with flat extrapolation
+    /// outside the strike range
+    fn variances_at_strike(&self, strike: Real) -> Vec<Real> {
+        let last = self.strikes.len() - 1;
+        let strike = strike.clamp(self.strikes[0], self.strikes[last]);
+        (0..self.times.len())
+            .map(|j| {
+                if self.strikes.len() == 1 {
+                    return self.variances[(0, j)];
+                }
+                let column: Vec<Real> = (0..self.strikes.len())
+                    .map(|i| self.variances[(i, j)])
+                    .collect();
+                linear_interpolate(&self.strikes, &column, strike, false)
+            })
+            .collect()
+    }
+}
+
+impl TermStructure for BlackVarianceSurface {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.max_date
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl BlackVolTermStructure for BlackVarianceSurface {
+    fn black_vol_from_time(&self, time: Time, strike: Real, extrapolate: bool) -> Volatility {
+        // at time 0, the volatility is the limit of the first interpolation segment
+        let time = time.max(self.times[1] * 1.0e-6);
+        (self.black_variance_from_time(time, strike, extrapolate) / time).sqrt()
+    }
+
+    fn black_variance_from_time(&self, time: Time, strike: Real, extrapolate: bool) -> Real {
+        self.check_range(time, extrapolate);
+        let variances = self.variances_at_strike(strike);
+        let last = self.times.len() - 1;
+        if time <= self.times[last] {
+            linear_interpolate(&self.times, &variances, time, false)
+        } else {
+            // flat volatility extrapolation
+            variances[last] * time / self.times[last]
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        maths::matrix::Matrix,
+        termstructures::volatility::blackvoltermstructure::BlackVolTermStructure,
+    };
+
+    use super::BlackVarianceSurface;
+
+    #[test]
+    fn test_black_variance_surface() {
+        let today = Date::new(15, March, 2023);
+        let dc = DayCounter::actual360();
+        let dates = [today + 180, today + 360];
+        let strikes = vec![90.0, 100.0, 110.0];
+        let vols = Matrix::from_rows(vec![vec![0.30, 0.28], vec![0.25, 0.24], vec![0.22, 0.21]]);
+        let surface = BlackVarianceSurface::new(today, &dates, strikes, &vols, dc);
+
+        // nodes are reproduced
+        assert!((surface.black_vol(&dates[0], 90.0, false) - 0.30).abs() < 1.0e-15);
+        assert!((surface.black_vol(&dates[1], 100.0, false) - 0.24).abs() < 1.0e-15);
+        assert!(
+            (surface.black_variance_from_time(1.0, 110.0, false) - 0.21 * 0.21).abs() < 1.0e-15
+        );
+
+        // linear in variance along the strike
+        let variance = 0.5 * (0.25 * 0.25 + 0.22 * 0.22) * 0.5;
+        assert!((surface.black_variance_from_time(0.5, 105.0, false) - variance).abs() < 1.0e-15);
+
+        // flat extrapolation in strike and volatility
+        assert!((surface.black_vol_from_time(1.0, 50.0, false) - 0.28).abs() < 1.0e-15);
+        assert!((surface.black_vol_from_time(3.0, 150.0, true) - 0.21).abs() < 1.0e-15);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    datetime::date::Date,
+    termstructures::termstructure::TermStructure,
+    types::{Real, Time, Volatility},
+};
+
+/// Black-volatility term structure
+///
+/// This abstract trait defines the interface of concrete Black-volatility term structures. Both
+/// the volatility and the variance are given as functions of time and strike; implementations
+/// provide either of them and inherit the other.
+pub trait BlackVolTermStructure: TermStructure {
+    /// Spot volatility at the given date and strike
+    fn black_vol(&self, date: &Date, strike: Real, extrapolate: bool) -> Volatility {
+        self.black_vol_from_time(self.time_from_references(date), strike, extrapolate)
+    }
+
+    /// Spot volatility at the given time and strike
+    fn black_vol_from_time(&self, time: Time, strike: Real, extrapolate: bool) -> Volatility;
+
+    /// Spot variance at the given date and strike
+    fn black_variance(&self, date: &Date, strike: Real, extrapolate: bool) -> Real {
+        self.black_variance_from_time(self.time_from_references(date), strike, extrapolate)
+    }
+
+    /// Spot variance at the given time and strike
+    fn black_variance_from_time(&self, time: Time, strike: Real, extrapolate: bool) -> Real {
+        let vol = self.black_vol_from_time(time, strike, extrapolate);
+        vol * vol * time
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    datetime::{
+        businessdayconvention::BusinessDayConvention::Following, calendar::Calendar, date::Date,
+        daycounter::DayCounter, timeunit::TimeUnit::Days,
+    },
+    termstructures::termstructure::TermStructure,
+    types::{Integer, Natural, Rate, Real, Time, Volatility},
+};
+
+use super::optionletvolatilitystructure::OptionletVolatilityStructure;
+
+/// Constant caplet volatility, no time-strike dependence
+///
+/// With a non-zero displacement, the volatility is quoted for the shifted-lognormal model, in
+/// which `forward + displacement` is lognormal; this allows for negative rates.
+#[derive(Debug, Clone)]
+pub struct ConstantOptionletVolatility {
+    pub reference_date: Date,
+    pub settlement_days: Natural,
+    pub volatility: Volatility,
+    pub daycounter: DayCounter,
+    pub displacement: Real,
+}
+
+impl ConstantOptionletVolatility {
+    /// Volatility with a fixed reference date
+    pub fn new(reference_date: Date, volatility: Volatility, daycounter: DayCounter) -> Self {
+        Self {
+            reference_date,
+            settlement_days: 0,
+            volatility,
+            daycounter,
+            displacement: 0.0,
+        }
+    }
+
+    /// Volatility whose reference date is `settlement_days` business days after the
+    /// evaluation date
+    pub fn new_with_settlement_days(
+        eval_date: Date,
+        settlement_days: Natural,
+        calendar: &Calendar,
+        volatility: Volatility,
+        daycounter: DayCounter,
+    ) -> Self {
+        let reference_date = calendar.advance_by_units(
+            eval_date,
+            settlement_days as Integer,
+            Days,
+            Following,
+            false,
+        );
+        Self {
+            reference_date,
+            settlement_days,
+            volatility,
+            daycounter,
+            displacement: 0.0,
+        }
+    }
+
+    /// Set the shift for shifted-lognormal quoting
+    pub fn with_displacement(mut self, displacement: Real) -> Self {
+        assert!(
+            displacement >= 0.0,
+            "displacement ({}) must be non-negative",
+            displacement
+        );
+        self.displacement = displacement;
+        self
+    }
+}
+
+impl TermStructure for ConstantOptionletVolatility {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        self.settlement_days
+    }
+}
+
+impl OptionletVolatilityStructure for ConstantOptionletVolatility {
+    fn volatility_from_time(
+        &self,
+        option_time: Time,
+        _strike: Rate,
+        extrapolate: bool,
+    ) -> Volatility {
+        self.check_range(option_time, extrapolate);
+        self.volatility
+    }
+
+    fn displacement(&self) -> Real {
+        self.displacement
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, holidays::target::Target, months::Month::*,
+        },
+        termstructures::{
+            termstructure::TermStructure,
+            volatility::optionletvolatilitystructure::OptionletVolatilityStructure,
+        },
+    };
+
+    use super::ConstantOptionletVolatility;
+
+    #[test]
+    fn test_constant_optionlet_volatility() {
+        let today = Date::new(15, March, 2023);
+        let vol = ConstantOptionletVolatility::new(today, 0.25, DayCounter::actual365_fixed());
+        assert_eq!(vol.displacement(), 0.0);
+        for days in [1, 91, 365, 3650] {
+            let date = today + days;
+            let t = days as f64 / 365.0;
+            for strike in [-0.005, 0.01, 0.05] {
+                assert_eq!(vol.volatility(&date, strike, false), 0.25);
+                let variance = vol.black_variance(&date, strike, false);
+                assert!((variance - 0.0625 * t).abs() < 1.0e-15);
+            }
+        }
+        assert_eq!(vol.black_variance(&today, 0.01, false), 0.0);
+    }
+
+    #[test]
+    fn test_settlement_days_and_displacement() {
+        // Friday, two business days settlement on TARGET
+        let eval_date = Date::new(17, March, 2023);
+        let vol = ConstantOptionletVolatility::new_with_settlement_days(
+            eval_date,
+            2,
+            &Target::new(),
+            0.15,
+            DayCounter::actual360(),
+        )
+        .with_displacement(0.02);
+        let reference_date = Date::new(21, March, 2023);
+        assert_eq!(vol.reference_date(), reference_date);
+        assert_eq!(vol.settlement_days(), 2);
+        assert_eq!(vol.displacement(), 0.02);
+
+        // variance accrues from the reference date, not the evaluation date
+        let date = reference_date + 180;
+        let variance = vol.black_variance(&date, -0.01, false);
+        assert!((variance - 0.0225 * 0.5).abs() < 1.0e-15);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    termstructures::termstructure::TermStructure,
+    types::{Natural, Rate, Time, Volatility},
+};
+
+use super::swaptionvolatilitystructure::SwaptionVolatilityStructure;
+
+/// Constant swaption volatility, no time-length-strike dependence
+#[derive(Debug, Clone)]
+pub struct ConstantSwaptionVolatility {
+    pub reference_date: Date,
+    pub volatility: Volatility,
+    pub daycounter: DayCounter,
+}
+
+impl ConstantSwaptionVolatility {
+    pub fn new(reference_date: Date, volatility: Volatility, daycounter: DayCounter) -> Self {
+        Self {
+            reference_date,
+            volatility,
+            daycounter,
+        }
+    }
+}
+
+impl TermStructure for ConstantSwaptionVolatility {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        Date::max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl SwaptionVolatilityStructure for ConstantSwaptionVolatility {
+    fn volatility_from_time(
+        &self,
+        option_time: Time,
+        _swap_length: Time,
+        _strike: Rate,
+        extrapolate: bool,
+    ) -> Volatility {
+        self.check_range(option_time, extrapolate);
+        self.volatility
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    datetime::date::Date,
+    termstructures::termstructure::TermStructure,
+    types::{Rate, Real, Time, Volatility},
+};
+
+/// Optionlet (caplet/floorlet) volatility structure
+///
+/// This abstract trait defines the interface of concrete optionlet volatility structures, which
+/// give the Black volatility of a forward rate as a function of the option expiry and strike.
+/// Volatilities may be quoted for a shifted-lognormal model, see
+/// [OptionletVolatilityStructure::displacement].
+pub trait OptionletVolatilityStructure: TermStructure {
+    /// Shift of the forward rate for shifted-lognormal volatilities, i.e. `forward +
+    /// displacement` is lognormal. Zero for plain lognormal volatilities.
+    fn displacement(&self) -> Real {
+        0.0
+    }
+
+    /// Volatility for the given option date and strike
+    fn volatility(&self, option_date: &Date, strike: Rate, extrapolate: bool) -> Volatility {
+        self.volatility_from_time(self.time_from_references(option_date), strike, extrapolate)
+    }
+
+    /// Volatility for the given option time and strike
+    fn volatility_from_time(
+        &self,
+        option_time: Time,
+        strike: Rate,
+        extrapolate: bool,
+    ) -> Volatility;
+
+    /// Black variance for the given option date and strike
+    fn black_variance(&self, option_date: &Date, strike: Rate, extrapolate: bool) -> Real {
+        self.black_variance_from_time(self.time_from_references(option_date), strike, extrapolate)
+    }
+
+    /// Black variance for the given option time and strike
+    fn black_variance_from_time(&self, option_time: Time, strike: Rate, extrapolate: bool) -> Real {
+        let vol = self.volatility_from_time(option_time, strike, extrapolate);
+        vol * vol * option_time
+    }
+}
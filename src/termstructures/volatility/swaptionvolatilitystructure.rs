@@ -0,0 +1,63 @@
+use crate::{
+    datetime::date::Date,
+    termstructures::termstructure::TermStructure,
+    types::{Rate, Real, Time, Volatility},
+};
+
+/// Swaption volatility structure
+///
+/// This abstract trait defines the interface of concrete swaption volatility structures, which
+/// give the Black volatility of a forward swap rate as a function of the option expiry, the
+/// length of the underlying swap (in years) and the strike.
+pub trait SwaptionVolatilityStructure: TermStructure {
+    /// Shift of the forward swap rate for shifted-lognormal volatilities, i.e. `forward +
+    /// displacement` is lognormal. Zero for plain lognormal volatilities.
+    fn displacement(&self) -> Real {
+        0.0
+    }
+
+    /// Volatility for the given option date, swap length and strike
+    fn volatility(
+        &self,
+        option_date: &Date,
+        swap_length: Time,
+        strike: Rate,
+        extrapolate: bool,
+    ) -> Volatility {
+        let option_time = self.time_from_references(option_date);
+        self.volatility_from_time(option_time, swap_length, strike, extrapolate)
+    }
+
+    /// Volatility for the given option time, swap length and strike
+    fn volatility_from_time(
+        &self,
+        option_time: Time,
+        swap_length: Time,
+        strike: Rate,
+        extrapolate: bool,
+    ) -> Volatility;
+
+    /// Black variance for the given option date, swap length and strike
+    fn black_variance(
+        &self,
+        option_date: &Date,
+        swap_length: Time,
+        strike: Rate,
+        extrapolate: bool,
+    ) -> Real {
+        let option_time = self.time_from_references(option_date);
+        self.black_variance_from_time(option_time, swap_length, strike, extrapolate)
+    }
+
+    /// Black variance for the given option time, swap length and strike
+    fn black_variance_from_time(
+        &self,
+        option_time: Time,
+        swap_length: Time,
+        strike: Rate,
+        extrapolate: bool,
+    ) -> Real {
+        let vol = self.volatility_from_time(option_time, swap_length, strike, extrapolate);
+        vol * vol * option_time
+    }
+}
@@ -4,19 +4,32 @@ use crate::rates::interestrate::InterestRate;
 
 use crate::{
     termstructures::termstructure::TermStructure,
-    types::{DiscountFactor, Time},
+    types::{DiscountFactor, Rate, Time},
 };
 
+/// Time step used to approximate instantaneous rates
+const DT: Time = 0.0001;
+
 /// Interest rate term structure
 pub trait YieldTermStructure: TermStructure {
     /// Return the discount factor from a given date to the reference date.
     fn discount_from_date(&self, date: &Date, extrapolate: bool) -> DiscountFactor {
-        self.discount_frome_time(self.time_from_references(date), extrapolate)
+        self.discount_time(self.time_from_references(date), extrapolate)
     }
 
     /// Return the discount factor from a given time to the reference date.
     /// The time is calculated as a fraction of year from the reference date.
-    fn discount_frome_time(&self, time: Time, extrapolate: bool) -> DiscountFactor;
+    ///
+    /// Engines working in continuous time can call this directly rather than going through
+    /// dates; `discount_time(self.time_from_references(d), false)` equals the discount at date
+    /// `d`.
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor;
+
+    /// Former name of [YieldTermStructure::discount_time]
+    #[deprecated(note = "use discount_time instead")]
+    fn discount_frome_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.discount_time(time, extrapolate)
+    }
 
     /// Return the implied zero-yield rate for a given date. The time is calculated as a fraction
     /// of year from the reference date.    
@@ -27,18 +40,51 @@ pub trait YieldTermStructure: TermStructure {
         compounding: Compounding,
         frequency: Frequency, // TODO default is Annual
         extrapolate: bool,
-    ) -> InterestRate;
+    ) -> InterestRate {
+        let reference_date = self.reference_date();
+        if *date == reference_date {
+            let compound = 1.0 / self.discount_time(DT, extrapolate);
+            // t has been calculated with a possibly different daycounter but the difference
+            // should not matter for very small times
+            return InterestRate::from_compound_factor(
+                compound,
+                result_day_counter,
+                &compounding,
+                frequency,
+                DT,
+            );
+        }
+        let compound = 1.0 / self.discount_from_date(date, extrapolate);
+        let t = result_day_counter.time_from_reference(&reference_date, date);
+        InterestRate::from_compound_factor(compound, result_day_counter, &compounding, frequency, t)
+    }
 
     /// Return the implied zero-yield rate for a given time.
     /// The resulting interest rate has the same day-counting rule used by the term structure.
     /// The same rule should be used for calculating the passed time t.
-    fn zero_rate_from_time(
+    fn zero_rate_time(
         &self,
         time: Time,
         compounding: Compounding,
         frequency: Frequency, // TODO default is Annual
         extrapolate: bool,
-    ) -> InterestRate;
+    ) -> InterestRate {
+        let t = if time == 0.0 { DT } else { time };
+        let compound = 1.0 / self.discount_time(t, extrapolate);
+        InterestRate::from_compound_factor(compound, self.day_counter(), &compounding, frequency, t)
+    }
+
+    /// Former name of [YieldTermStructure::zero_rate_time]
+    #[deprecated(note = "use zero_rate_time instead")]
+    fn zero_rate_from_time(
+        &self,
+        time: Time,
+        compounding: Compounding,
+        frequency: Frequency,
+        extrapolate: bool,
+    ) -> InterestRate {
+        self.zero_rate_time(time, compounding, frequency, extrapolate)
+    }
 
     /// Returns the forward interest rate between two dates. Ttimes are calculated as fractions of
     /// year from the reference date. If both dates are equal the instantaneous forward rate is
@@ -53,7 +99,28 @@ pub trait YieldTermStructure: TermStructure {
         compounding: Compounding,
         frequency: Frequency, // TODO default is Annual
         extrapolate: bool,
-    ) -> InterestRate;
+    ) -> InterestRate {
+        if d1 == d2 {
+            let t1 = (self.time_from_references(d1) - DT / 2.0).max(0.0);
+            let t2 = t1 + DT;
+            let compound =
+                self.discount_time(t1, extrapolate) / self.discount_time(t2, extrapolate);
+            // times have been calculated with a possibly different daycounter but the difference
+            // should not matter for very small times
+            return InterestRate::from_compound_factor(
+                compound,
+                result_day_counter,
+                &compounding,
+                frequency,
+                DT,
+            );
+        }
+        assert!(d1 < d2, "{:?} later than {:?}", d1, d2);
+        let compound =
+            self.discount_from_date(d1, extrapolate) / self.discount_from_date(d2, extrapolate);
+        let t = result_day_counter.year_fraction_simple(d1, d2);
+        InterestRate::from_compound_factor(compound, result_day_counter, &compounding, frequency, t)
+    }
 
     /// Returns the forward interest rate between `d1` and period `p` after `d1`..
     /// Ttimes are calculated as fractions of year from the reference date. If both dates are
@@ -84,19 +151,79 @@ pub trait YieldTermStructure: TermStructure {
     /// instantaneous forward rate is returned.
     ///
     /// The resulting interest rate has the same day-counting rule used by the term structure.
-    /// The same rule should be used for calculating the passed times `t1` and `t2`.    
-    fn forward_rate_from_times(
+    /// The same rule should be used for calculating the passed times `t1` and `t2`.
+    fn forward_rate_time(
         &self,
         t1: Time,
         t2: Time,
         compounding: Compounding,
         frequency: Frequency, // TODO default is Annual
         extrapolate: bool,
-    ) -> InterestRate;
+    ) -> InterestRate {
+        let (t1, t2) = if t1 == t2 {
+            instantaneous_interval(self, t1, extrapolate)
+        } else {
+            assert!(t2 > t1, "t2 ({}) < t1 ({})", t2, t1);
+            (t1, t2)
+        };
+        let compound = self.discount_time(t1, extrapolate) / self.discount_time(t2, extrapolate);
+        InterestRate::from_compound_factor(
+            compound,
+            self.day_counter(),
+            &compounding,
+            frequency,
+            t2 - t1,
+        )
+    }
+
+    /// Former name of [YieldTermStructure::forward_rate_time]
+    #[deprecated(note = "use forward_rate_time instead")]
+    fn forward_rate_from_times(
+        &self,
+        t1: Time,
+        t2: Time,
+        compounding: Compounding,
+        frequency: Frequency,
+        extrapolate: bool,
+    ) -> InterestRate {
+        self.forward_rate_time(t1, t2, compounding, frequency, extrapolate)
+    }
+
+    /// Return the continuously-compounded instantaneous forward rate `f(t) = -d ln D(t) / dt` at
+    /// time `t`, without extrapolation.
+    ///
+    /// The default implementation takes a central difference of the log discount over a small
+    /// interval around `t`, a forward difference at `t = 0` and a backward one at the max time.
+    /// Curves with a closed form for the derivative should override it; short-rate models
+    /// calibrated to the curve depend on it.
+    fn instantaneous_forward_rate(&self, t: Time) -> Rate {
+        self.check_range(t, false);
+        let (t1, t2) = instantaneous_interval(self, t, false);
+        (self.discount_time(t1, false) / self.discount_time(t2, false)).ln() / DT
+    }
 
     /// Return the jump dates
-    fn jump_dates(&self) -> Vec<Date>;
+    fn jump_dates(&self) -> Vec<Date> {
+        vec![]
+    }
 
     /// Return the jump times
-    fn jump_times(&self) -> Vec<Time>;
+    fn jump_times(&self) -> Vec<Time> {
+        vec![]
+    }
+}
+
+/// Interval of length [DT] around `t` over which instantaneous rates are approximated, moved
+/// forward near the reference date and, unless extrapolating, backward near the max time so
+/// that the curve is only read within its range
+fn instantaneous_interval<Y: YieldTermStructure + ?Sized>(
+    curve: &Y,
+    t: Time,
+    extrapolate: bool,
+) -> (Time, Time) {
+    let mut t1 = (t - DT / 2.0).max(0.0);
+    if !extrapolate {
+        t1 = t1.min((curve.max_time() - DT).max(0.0));
+    }
+    (t1, t1 + DT)
 }
@@ -1,4 +1,145 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter},
+    maths::interpolations::linearinterpolation::linear_interpolate,
+    types::{DiscountFactor, Natural, Rate, Time},
+};
+
+use super::{
+    termstructure::TermStructure, yieldtermstructure::YieldTermStructure,
+    zeroyieldstructure::ZeroYieldStructure,
+};
+
 /// YieldTermStructure based on interpolation of zero rates
+///
+/// Rates are continuously compounded and are interpolated linearly in time. Beyond the last
+/// node the last rate is extrapolated flat.
+#[derive(Debug, Clone)]
 pub struct InterpolatedZeroCurve {
-    //
+    pub daycounter: DayCounter,
+    pub dates: Vec<Date>,
+    pub times: Vec<Time>,
+    pub rates: Vec<Rate>,
+}
+
+impl InterpolatedZeroCurve {
+    /// Create a curve from the given node dates and zero rates. The first date is taken as the
+    /// reference date of the curve.
+    pub fn new(dates: Vec<Date>, rates: Vec<Rate>, daycounter: DayCounter) -> Self {
+        assert!(dates.len() >= 2, "not enough dates ({}) given", dates.len());
+        assert_eq!(
+            dates.len(),
+            rates.len(),
+            "dates/rates count mismatch: {} vs {}",
+            dates.len(),
+            rates.len()
+        );
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| daycounter.time_from_reference(&dates[0], d))
+            .collect();
+        for i in 1..times.len() {
+            assert!(
+                times[i] > times[i - 1],
+                "dates not sorted or with duplicates: {:?}, {:?}",
+                dates[i - 1],
+                dates[i]
+            );
+        }
+        Self {
+            daycounter,
+            dates,
+            times,
+            rates,
+        }
+    }
+
+    /// Copy of the first `n` nodes of the curve
+    pub(crate) fn truncated(&self, n: usize) -> Self {
+        Self {
+            daycounter: self.daycounter.clone(),
+            dates: self.dates[..n].to_vec(),
+            times: self.times[..n].to_vec(),
+            rates: self.rates[..n].to_vec(),
+        }
+    }
+
+    /// Set the zero rate of node `i`.
+    ///
+    /// The rate at the reference date cannot be implied from a discount factor (t = 0), so it
+    /// follows the rate of the first pillar.
+    pub(crate) fn set_rate(&mut self, i: usize, rate: Rate) {
+        self.rates[i] = rate;
+        if i == 1 {
+            self.rates[0] = rate;
+        }
+    }
+}
+
+impl TermStructure for InterpolatedZeroCurve {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.dates[self.dates.len() - 1]
+    }
+
+    fn reference_date(&self) -> Date {
+        self.dates[0]
+    }
+
+    fn settlement_days(&self) -> Natural {
+        0
+    }
+}
+
+impl YieldTermStructure for InterpolatedZeroCurve {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        self.discount(time)
+    }
+}
+
+impl ZeroYieldStructure for InterpolatedZeroCurve {
+    fn zero_yield(&self, time: Time) -> Rate {
+        let n = self.times.len();
+        if time >= self.times[n - 1] {
+            return self.rates[n - 1];
+        }
+        linear_interpolate(&self.times, &self.rates, time, false)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        datetime::{date::Date, daycounter::DayCounter, months::Month::*},
+        termstructures::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure},
+    };
+
+    use super::InterpolatedZeroCurve;
+
+    #[test]
+    fn test_interpolated_zero_curve() {
+        let today = Date::new(1, March, 2023);
+        let dates = vec![today, today + 180, today + 360];
+        let curve = InterpolatedZeroCurve::new(
+            dates.clone(),
+            vec![0.02, 0.02, 0.04],
+            DayCounter::actual360(),
+        );
+        assert_eq!(curve.reference_date(), dates[0]);
+        assert_eq!(curve.max_date(), dates[2]);
+
+        // half way between the last two nodes
+        let d = today + 270;
+        let t = curve.time_from_references(&d);
+        assert!((curve.discount_from_date(&d, false) - (-0.03 * t).exp()).abs() < 1.0e-15);
+        // flat extrapolation
+        let d = today + 720;
+        let t = curve.time_from_references(&d);
+        assert!((curve.discount_from_date(&d, true) - (-0.04 * t).exp()).abs() < 1.0e-15);
+    }
 }
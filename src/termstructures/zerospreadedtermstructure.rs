@@ -0,0 +1,227 @@
+use crate::{
+    datetime::{date::Date, daycounter::DayCounter, frequency::Frequency},
+    handle::Handle,
+    maths::interpolations::linearinterpolation::linear_interpolate,
+    quotes::quote::Quote,
+    rates::compounding::Compounding,
+    types::{DiscountFactor, Natural, Rate, Real, Spread, Time},
+};
+
+use super::{termstructure::TermStructure, yieldtermstructure::YieldTermStructure};
+
+/// Spread added to a base curve, either flat or depending on time
+#[derive(Clone)]
+pub enum TermSpread {
+    /// The same spread at all times, read from a quote
+    Flat(Handle<dyn Quote>),
+    /// Spreads given at increasing times, interpolated linearly in between and extrapolated
+    /// flat outside
+    Interpolated {
+        times: Vec<Time>,
+        spreads: Vec<Spread>,
+    },
+}
+
+impl TermSpread {
+    /// Time dependent spread from the given nodes
+    pub fn interpolated(times: Vec<Time>, spreads: Vec<Spread>) -> Self {
+        assert!(!times.is_empty(), "no spreads given");
+        assert_eq!(
+            times.len(),
+            spreads.len(),
+            "times/spreads count mismatch: {} vs {}",
+            times.len(),
+            spreads.len()
+        );
+        assert!(
+            times.windows(2).all(|w| w[0] < w[1]),
+            "times must be sorted in strictly increasing order: {:?}",
+            times
+        );
+        TermSpread::Interpolated { times, spreads }
+    }
+
+    /// Spread at time `t`
+    pub fn value(&self, t: Time) -> Spread {
+        match self {
+            TermSpread::Flat(quote) => quote.current_link().value(),
+            TermSpread::Interpolated { times, spreads } => {
+                let n = times.len();
+                if n == 1 || t <= times[0] {
+                    spreads[0]
+                } else if t >= times[n - 1] {
+                    spreads[n - 1]
+                } else {
+                    linear_interpolate(times, spreads, t, false)
+                }
+            }
+        }
+    }
+
+    /// Integral of the spread from 0 to `t`, exact for both the flat and the piecewise linear
+    /// spreads
+    pub fn integral(&self, t: Time) -> Real {
+        match self {
+            TermSpread::Flat(quote) => quote.current_link().value() * t,
+            TermSpread::Interpolated { times, .. } => {
+                // trapezoids between the nodes in (0, t), the spread being linear in between
+                let mut result = 0.0;
+                let (mut t0, mut s0) = (0.0, self.value(0.0));
+                for t1 in times.iter().copied().filter(|t1| *t1 > 0.0 && *t1 < t) {
+                    let s1 = self.value(t1);
+                    result += 0.5 * (s0 + s1) * (t1 - t0);
+                    (t0, s0) = (t1, s1);
+                }
+                result + 0.5 * (s0 + self.value(t)) * (t - t0)
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Yield curve obtained by adding a spread to the zero rates of a base curve.
+///
+/// Zero rates are continuously compounded, so that discount factors are those of the base
+/// curve times `exp(-spread(t) * t)`. The base curve is held through a handle and can be
+/// relinked; reference date and maximum date follow the current link.
+pub struct ZeroSpreadedTermStructure {
+    pub base_curve: Handle<dyn YieldTermStructure>,
+    pub spread: TermSpread,
+    daycounter: DayCounter,
+}
+
+impl ZeroSpreadedTermStructure {
+    /// Creates the spreaded curve; times are measured with the day counter of the base curve
+    /// currently linked.
+    pub fn new(base_curve: Handle<dyn YieldTermStructure>, spread: TermSpread) -> Self {
+        let daycounter = base_curve.current_link().day_counter().clone();
+        Self {
+            base_curve,
+            spread,
+            daycounter,
+        }
+    }
+
+    /// Base curve plus a flat spread read from the given quote
+    pub fn with_flat_spread(
+        base_curve: Handle<dyn YieldTermStructure>,
+        spread: Handle<dyn Quote>,
+    ) -> Self {
+        Self::new(base_curve, TermSpread::Flat(spread))
+    }
+
+    /// Continuously compounded zero rate at time `t`: the one of the base curve plus the spread
+    pub fn zero_rate(&self, t: Time) -> Rate {
+        let base = self.base_curve.current_link();
+        base.zero_rate_time(t, Compounding::Continuous, Frequency::Annual, true)
+            .rate
+            + self.spread.value(t)
+    }
+}
+
+impl TermStructure for ZeroSpreadedTermStructure {
+    fn day_counter(&self) -> &DayCounter {
+        &self.daycounter
+    }
+
+    fn max_date(&self) -> Date {
+        self.base_curve.current_link().max_date()
+    }
+
+    fn reference_date(&self) -> Date {
+        self.base_curve.current_link().reference_date()
+    }
+
+    fn settlement_days(&self) -> Natural {
+        self.base_curve.current_link().settlement_days()
+    }
+}
+
+impl YieldTermStructure for ZeroSpreadedTermStructure {
+    fn discount_time(&self, time: Time, extrapolate: bool) -> DiscountFactor {
+        self.check_range(time, extrapolate);
+        let base = self.base_curve.current_link();
+        base.discount_time(time, true) * (-self.spread.value(time) * time).exp()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{
+        datetime::{
+            date::Date, daycounter::DayCounter, frequency::Frequency::Annual, months::Month::*,
+        },
+        handle::{Handle, RelinkableHandle},
+        quotes::{quote::Quote, simplequote::SimpleQuote},
+        rates::compounding::Compounding::Continuous,
+        termstructures::{
+            flatforward::FlatForward, termstructure::TermStructure,
+            yieldtermstructure::YieldTermStructure,
+        },
+        types::Rate,
+    };
+
+    use super::{TermSpread, ZeroSpreadedTermStructure};
+
+    fn flat(today: Date, rate: Rate) -> Rc<FlatForward> {
+        Rc::new(FlatForward::new(
+            today,
+            rate,
+            DayCounter::actual365_fixed(),
+            Continuous,
+            Annual,
+        ))
+    }
+
+    #[test]
+    fn test_flat_spread() {
+        let today = Date::new(15, January, 2024);
+        let base: Handle<dyn YieldTermStructure> = Handle::new(flat(today, 0.03));
+        let spread = RelinkableHandle::<dyn Quote>::new(Rc::new(SimpleQuote::new(0.001)));
+        let curve = ZeroSpreadedTermStructure::with_flat_spread(base.clone(), spread.handle());
+        assert_eq!(curve.reference_date(), today);
+
+        let base = base.current_link();
+        for t in [0.25, 1.0, 5.0, 30.0] {
+            let zero = curve.zero_rate_time(t, Continuous, Annual, false).rate;
+            assert!(
+                (zero - 0.031).abs() < 1.0e-12,
+                "zero rate {} at {}",
+                zero,
+                t
+            );
+            assert!((curve.zero_rate(t) - 0.031).abs() < 1.0e-12);
+            let discount = curve.discount_time(t, false);
+            let expected = base.discount_time(t, false) * (-0.001 * t).exp();
+            assert!((discount - expected).abs() < 1.0e-15);
+            assert!(discount < base.discount_time(t, false));
+        }
+
+        // the spread follows its quote
+        spread.link_to(Rc::new(SimpleQuote::new(-0.002)));
+        assert!((curve.zero_rate(2.0) - 0.028).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_relinked_base_and_term_spread() {
+        let today = Date::new(15, January, 2024);
+        let base = RelinkableHandle::<dyn YieldTermStructure>::new(flat(today, 0.03));
+        let curve = ZeroSpreadedTermStructure::new(
+            base.handle(),
+            TermSpread::interpolated(vec![1.0, 5.0], vec![0.001, 0.003]),
+        );
+        assert!((curve.zero_rate(0.5) - 0.031).abs() < 1.0e-12);
+        assert!((curve.zero_rate(3.0) - 0.032).abs() < 1.0e-12);
+        assert!((curve.zero_rate(10.0) - 0.033).abs() < 1.0e-12);
+
+        base.link_to(flat(today, 0.04));
+        assert!((curve.zero_rate(3.0) - 0.042).abs() < 1.0e-12);
+        let discount = curve.discount_time(3.0, false);
+        assert!((discount - (-0.042 * 3.0_f64).exp()).abs() < 1.0e-15);
+    }
+}
@@ -1,3 +1,27 @@
+//! Numeric types used throughout the library.
+//!
+//! These are aliases rather than distinct types: they document the meaning of a value in
+//! signatures, e.g. a [Rate] versus a [Spread] versus a [DiscountFactor], without any
+//! conversion cost. Values of different aliases mix freely with each other and with [Real]:
+//!
+//! ```
+//! use rust_quantlib::types::{DiscountFactor, Rate, Real, Spread, Time, Volatility};
+//!
+//! let rate: Rate = 0.03;
+//! let spread: Spread = 0.0025;
+//! let t: Time = 2.0;
+//! let discount: DiscountFactor = (-(rate + spread) * t).exp();
+//! let vol: Volatility = 0.2;
+//! let variance: Real = vol * vol * t;
+//!
+//! fn years(t: Real) -> Real {
+//!     t
+//! }
+//! assert_eq!(years(t), 2.0);
+//! assert!((discount - 0.937067463).abs() < 1.0e-9);
+//! assert!((variance - 0.08).abs() < 1.0e-15);
+//! ```
+
 /// Integer number
 pub type Integer = i32;
 
@@ -36,3 +60,6 @@ pub type Volatility = Real;
 
 /// Probability
 pub type Probability = Real;
+
+/// One basis point, i.e. 0.01%
+pub const BASIS_POINT: Spread = 1.0e-4;